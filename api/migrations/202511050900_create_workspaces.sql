@@ -0,0 +1,39 @@
+-- Workspaces own documents; users reach them through memberships.
+CREATE TABLE IF NOT EXISTS workspaces (
+  id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+  name TEXT NOT NULL,
+  is_personal BOOLEAN NOT NULL DEFAULT false,
+  created_by uuid NULL REFERENCES users(id) ON DELETE SET NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS workspace_members (
+  workspace_id uuid NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  role TEXT NOT NULL CHECK (role IN ('owner','admin','member','guest')),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  PRIMARY KEY (workspace_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_workspace_members_user ON workspace_members(user_id);
+
+-- Every existing user gets a personal workspace sharing the user's id, so that
+-- storage roots (uploads/<id>), git_configs/git_sync_logs/git_repository_state/git_commits and
+-- plugin_installations keyed by the former user id keep resolving unchanged.
+INSERT INTO workspaces (id, name, is_personal, created_by)
+SELECT u.id, u.name, true, u.id FROM users u
+ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO workspace_members (workspace_id, user_id, role)
+SELECT u.id, u.id, 'owner' FROM users u
+ON CONFLICT (workspace_id, user_id) DO NOTHING;
+
+ALTER TABLE documents
+  ADD COLUMN IF NOT EXISTS workspace_id uuid NULL REFERENCES workspaces(id) ON DELETE CASCADE;
+
+UPDATE documents SET workspace_id = owner_id WHERE workspace_id IS NULL;
+
+ALTER TABLE documents ALTER COLUMN workspace_id SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_documents_workspace ON documents(workspace_id);
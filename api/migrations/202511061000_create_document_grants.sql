@@ -0,0 +1,11 @@
+-- Named per-user grants on documents and folders; folder grants apply to the whole subtree.
+CREATE TABLE IF NOT EXISTS document_grants (
+  document_id uuid NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  permission TEXT NOT NULL CHECK (permission IN ('view','edit')),
+  granted_by uuid NULL REFERENCES users(id) ON DELETE SET NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  PRIMARY KEY (document_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_document_grants_user ON document_grants(user_id);
@@ -0,0 +1,8 @@
+-- Comment-only access: read-only content, but allowed to write comment data.
+ALTER TABLE shares DROP CONSTRAINT IF EXISTS shares_permission_check;
+ALTER TABLE shares
+  ADD CONSTRAINT shares_permission_check CHECK (permission IN ('view','comment','edit'));
+
+ALTER TABLE document_grants DROP CONSTRAINT IF EXISTS document_grants_permission_check;
+ALTER TABLE document_grants
+  ADD CONSTRAINT document_grants_permission_check CHECK (permission IN ('view','comment','edit'));
@@ -0,0 +1,37 @@
+-- Threaded comments anchored to Yjs relative positions within a document's `content` text.
+CREATE TABLE IF NOT EXISTS comment_threads (
+  id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+  document_id uuid NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+  -- Encoded Y.RelativePosition (v1); both NULL for document-level threads
+  anchor_start BYTEA NULL,
+  anchor_end BYTEA NULL,
+  quote TEXT NULL,
+  status TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open','resolved')),
+  created_by uuid NULL REFERENCES users(id) ON DELETE SET NULL,
+  resolved_by uuid NULL REFERENCES users(id) ON DELETE SET NULL,
+  resolved_at TIMESTAMPTZ NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_comment_threads_document ON comment_threads(document_id, created_at);
+
+CREATE TABLE IF NOT EXISTS comments (
+  id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+  thread_id uuid NOT NULL REFERENCES comment_threads(id) ON DELETE CASCADE,
+  -- NULL for anonymous share-link reviewers and deleted accounts
+  author_id uuid NULL REFERENCES users(id) ON DELETE SET NULL,
+  body TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_comments_thread ON comments(thread_id, created_at);
+
+CREATE TABLE IF NOT EXISTS comment_mentions (
+  comment_id uuid NOT NULL REFERENCES comments(id) ON DELETE CASCADE,
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  PRIMARY KEY (comment_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_comment_mentions_user ON comment_mentions(user_id);
@@ -0,0 +1,18 @@
+-- Server-side login sessions. Access tokens carry the session id (`sid`) and are rejected once
+-- the session is revoked; refresh tokens rotate on every use and are stored hashed.
+CREATE TABLE IF NOT EXISTS user_sessions (
+  id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  refresh_token_hash TEXT NOT NULL UNIQUE,
+  -- Hash of the refresh token replaced by the last rotation; presenting it again revokes the session
+  previous_refresh_hash TEXT NULL,
+  user_agent TEXT NULL,
+  ip_address TEXT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  last_seen_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  expires_at TIMESTAMPTZ NOT NULL,
+  revoked_at TIMESTAMPTZ NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_sessions_user ON user_sessions(user_id);
+CREATE INDEX IF NOT EXISTS idx_user_sessions_previous_hash ON user_sessions(previous_refresh_hash);
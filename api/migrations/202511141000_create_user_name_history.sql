@@ -0,0 +1,11 @@
+-- Names a user has given up, so public pages under /api/public/users/:name can redirect.
+CREATE TABLE IF NOT EXISTS user_name_history (
+  name CITEXT PRIMARY KEY,
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  changed_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_name_history_user ON user_name_history(user_id);
+
+-- Profile updates check name uniqueness case-insensitively.
+CREATE INDEX IF NOT EXISTS idx_users_lower_name ON users(lower(name));
@@ -0,0 +1,19 @@
+-- Asynchronous full-account exports, built by a background worker.
+CREATE TABLE IF NOT EXISTS takeout_jobs (
+  id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending','running','completed','failed')),
+  error TEXT NULL,
+  storage_path TEXT NULL,
+  size_bytes BIGINT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  started_at TIMESTAMPTZ NULL,
+  completed_at TIMESTAMPTZ NULL,
+  expires_at TIMESTAMPTZ NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_takeout_jobs_user ON takeout_jobs(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_takeout_jobs_pending ON takeout_jobs(created_at) WHERE status = 'pending';
+-- At most one export in flight per user.
+CREATE UNIQUE INDEX IF NOT EXISTS uq_takeout_jobs_active ON takeout_jobs(user_id)
+  WHERE status IN ('pending','running');
@@ -0,0 +1,10 @@
+-- Full-text index over persisted document Markdown, refreshed by SnapshotService::write_markdown.
+CREATE TABLE IF NOT EXISTS document_search_index (
+  document_id uuid PRIMARY KEY REFERENCES documents(id) ON DELETE CASCADE,
+  content TEXT NOT NULL,
+  tsv tsvector NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_document_search_tsv ON document_search_index USING GIN (tsv);
+CREATE INDEX IF NOT EXISTS idx_documents_updated_at ON documents(updated_at DESC);
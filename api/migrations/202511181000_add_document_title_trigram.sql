@@ -0,0 +1,5 @@
+-- Typo-tolerant title matching for the quick switcher.
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_documents_title_trgm
+  ON documents USING GIN (lower(title) gin_trgm_ops);
@@ -0,0 +1,14 @@
+-- Documents or folders marked as reusable templates.
+CREATE TABLE IF NOT EXISTS document_templates (
+  document_id uuid PRIMARY KEY REFERENCES documents(id) ON DELETE CASCADE,
+  workspace_id uuid NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  description TEXT NULL,
+  -- [{ "name": "project", "label": "Project", "default": null }]
+  prompts JSONB NOT NULL DEFAULT '[]'::jsonb,
+  created_by uuid NULL REFERENCES users(id) ON DELETE SET NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_document_templates_workspace ON document_templates(workspace_id, name);
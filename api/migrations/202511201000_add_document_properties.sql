@@ -0,0 +1,4 @@
+-- Front matter of the latest persisted content, as a JSON object.
+ALTER TABLE documents ADD COLUMN IF NOT EXISTS properties JSONB NOT NULL DEFAULT '{}'::jsonb;
+
+CREATE INDEX IF NOT EXISTS idx_documents_properties ON documents USING GIN (properties jsonb_path_ops);
@@ -0,0 +1,8 @@
+ALTER TABLE documents
+    ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ NULL,
+    ADD COLUMN IF NOT EXISTS deleted_by UUID NULL REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS deleted_parent_id UUID NULL REFERENCES documents(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_documents_workspace_trashed
+    ON documents(workspace_id, deleted_at)
+    WHERE deleted_at IS NOT NULL;
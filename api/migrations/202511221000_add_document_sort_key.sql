@@ -0,0 +1,6 @@
+-- Manual order among siblings; NULL sorts after arranged documents (folders first, then by title)
+ALTER TABLE documents
+    ADD COLUMN IF NOT EXISTS sort_key INTEGER NULL;
+
+CREATE INDEX IF NOT EXISTS idx_documents_parent_sort
+    ON documents(parent_id, sort_key);
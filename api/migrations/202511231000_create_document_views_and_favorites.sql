@@ -0,0 +1,17 @@
+-- Per-user document state: last opens and starred documents.
+CREATE TABLE IF NOT EXISTS document_views (
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  document_id uuid NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+  view_count INTEGER NOT NULL DEFAULT 1,
+  last_viewed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  PRIMARY KEY (user_id, document_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_document_views_user_recent ON document_views(user_id, last_viewed_at DESC);
+
+CREATE TABLE IF NOT EXISTS document_favorites (
+  user_id uuid NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  document_id uuid NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  PRIMARY KEY (user_id, document_id)
+);
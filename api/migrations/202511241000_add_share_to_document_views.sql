@@ -0,0 +1,6 @@
+-- Share link a view or favorite was reached through, for documents the user can't open directly.
+ALTER TABLE document_views
+  ADD COLUMN IF NOT EXISTS share_id uuid NULL REFERENCES shares(id) ON DELETE SET NULL;
+
+ALTER TABLE document_favorites
+  ADD COLUMN IF NOT EXISTS share_id uuid NULL REFERENCES shares(id) ON DELETE SET NULL;
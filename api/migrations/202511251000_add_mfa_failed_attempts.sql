@@ -0,0 +1,4 @@
+-- Failed second-factor attempts across all challenges; past the threshold each further
+-- attempt is only accepted once the lock expires.
+ALTER TABLE user_totp ADD COLUMN IF NOT EXISTS failed_attempts INT NOT NULL DEFAULT 0;
+ALTER TABLE user_totp ADD COLUMN IF NOT EXISTS locked_until TIMESTAMPTZ NULL;
@@ -3,6 +3,9 @@ use uuid::Uuid;
 use crate::application::ports::access_repository::AccessRepository;
 use crate::application::ports::share_access_port::ShareAccessPort;
 
+#[cfg(test)]
+pub(crate) mod testing;
+
 #[derive(Debug, Clone)]
 pub enum Actor {
     User(Uuid),
@@ -14,9 +17,25 @@ pub enum Actor {
 pub enum Capability {
     None,
     View,
+    Comment,
     Edit,
 }
 
+impl Capability {
+    /// Maps a stored share/grant permission to a capability; unknown values degrade to view.
+    pub fn from_permission(permission: &str) -> Self {
+        match permission {
+            "edit" => Capability::Edit,
+            "comment" => Capability::Comment,
+            _ => Capability::View,
+        }
+    }
+}
+
+pub fn is_valid_permission(permission: &str) -> bool {
+    matches!(permission, "view" | "comment" | "edit")
+}
+
 // Presentation layer is responsible for building Actor from HTTP inputs.
 // This module intentionally avoids depending on presentation types.
 
@@ -30,25 +49,48 @@ where
     A: AccessRepository + ?Sized,
     R: ShareAccessPort + ?Sized,
 {
+    // Trashed documents are only reachable through the trash endpoints.
+    if access_repo
+        .is_document_trashed(doc_id)
+        .await
+        .unwrap_or(false)
+    {
+        return Capability::None;
+    }
     match actor {
         Actor::User(uid) => {
-            let owns = access_repo
-                .user_owns_document(doc_id, *uid)
+            let role = access_repo
+                .workspace_role_for_document(doc_id, *uid)
                 .await
-                .unwrap_or(false);
-            if owns {
-                let archived = access_repo
+                .ok()
+                .flatten();
+            let mut cap = match role {
+                Some(role) if role.can_edit() => Capability::Edit,
+                Some(_) => Capability::View,
+                None => Capability::None,
+            };
+            // Named grants (direct or inherited from a folder) can raise a user's capability
+            if cap < Capability::Edit {
+                let granted = access_repo
+                    .grant_permission_for_document(doc_id, *uid)
+                    .await
+                    .ok()
+                    .flatten();
+                let grant_cap = granted
+                    .as_deref()
+                    .map(Capability::from_permission)
+                    .unwrap_or(Capability::None);
+                cap = cap.max(grant_cap);
+            }
+            if cap > Capability::View
+                && access_repo
                     .is_document_archived(doc_id)
                     .await
-                    .unwrap_or(false);
-                if archived {
-                    Capability::View
-                } else {
-                    Capability::Edit
-                }
-            } else {
-                Capability::None
+                    .unwrap_or(false)
+            {
+                cap = Capability::View;
             }
+            cap
         }
         Actor::ShareToken(t) => {
             // Resolve token target and then decide access when document matches token scope
@@ -70,11 +112,7 @@ where
                 }
                 if shared_type != "folder" {
                     if shared_id == doc_id {
-                        if perm == "edit" {
-                            Capability::Edit
-                        } else {
-                            Capability::View
-                        }
+                        Capability::from_permission(&perm)
                     } else {
                         Capability::None
                     }
@@ -84,13 +122,7 @@ where
                         .get_materialized_permission(share_id, doc_id)
                         .await
                     {
-                        Ok(Some(p)) => {
-                            if p == "edit" {
-                                Capability::Edit
-                            } else {
-                                Capability::View
-                            }
-                        }
+                        Ok(Some(p)) => Capability::from_permission(&p),
                         _ => Capability::None,
                     }
                 }
@@ -131,6 +163,24 @@ where
     }
 }
 
+pub async fn require_comment<A, R>(
+    access_repo: &A,
+    shares_repo: &R,
+    actor: &Actor,
+    doc_id: Uuid,
+) -> anyhow::Result<Capability>
+where
+    A: AccessRepository + ?Sized,
+    R: ShareAccessPort + ?Sized,
+{
+    let cap = resolve_document(access_repo, shares_repo, actor, doc_id).await;
+    if cap >= Capability::Comment {
+        Ok(cap)
+    } else {
+        anyhow::bail!("forbidden")
+    }
+}
+
 pub async fn require_edit<A, R>(
     access_repo: &A,
     shares_repo: &R,
@@ -148,3 +198,93 @@ where
         anyhow::bail!("forbidden")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::testing::FakeAccess;
+    use super::*;
+    use crate::application::ports::workspace_repository::WorkspaceRole;
+
+    #[tokio::test]
+    async fn user_capability_is_role_raised_by_grants_and_capped_when_archived() {
+        let (doc, user) = (Uuid::new_v4(), Uuid::new_v4());
+        let actor = Actor::User(user);
+        let resolve = |fake: FakeAccess| {
+            let actor = actor.clone();
+            async move { resolve_document(&fake, &fake, &actor, doc).await }
+        };
+
+        assert_eq!(resolve(FakeAccess::default()).await, Capability::None);
+        let guest = || FakeAccess::default().member(doc, user, WorkspaceRole::Guest);
+        assert_eq!(resolve(guest()).await, Capability::View);
+        assert_eq!(
+            resolve(guest().grant(doc, user, "comment")).await,
+            Capability::Comment
+        );
+        // A weaker grant never lowers the workspace role
+        let member = || FakeAccess::default().member(doc, user, WorkspaceRole::Member);
+        assert_eq!(
+            resolve(member().grant(doc, user, "view")).await,
+            Capability::Edit
+        );
+        // Grants alone, without membership
+        let granted = || FakeAccess::default().grant(doc, user, "edit");
+        assert_eq!(resolve(granted()).await, Capability::Edit);
+
+        let mut archived = granted();
+        archived.archived.insert(doc);
+        assert_eq!(resolve(archived).await, Capability::View);
+        let mut trashed = member();
+        trashed.trashed.insert(doc);
+        assert_eq!(resolve(trashed).await, Capability::None);
+    }
+
+    #[tokio::test]
+    async fn share_tokens_reach_only_their_document_or_materialized_children() {
+        let (doc, other, folder) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut fake = FakeAccess::default()
+            .share("doc-token", doc, "document", "comment")
+            .share("folder-token", folder, "folder", "view");
+        let folder_share = fake.shares["folder-token"].id;
+        fake.materialized.insert((folder_share, doc), "edit".into());
+        let token = |t: &str| Actor::ShareToken(t.to_string());
+
+        assert_eq!(
+            resolve_document(&fake, &fake, &token("doc-token"), doc).await,
+            Capability::Comment
+        );
+        assert_eq!(
+            resolve_document(&fake, &fake, &token("doc-token"), other).await,
+            Capability::None
+        );
+        assert_eq!(
+            resolve_document(&fake, &fake, &token("folder-token"), doc).await,
+            Capability::Edit
+        );
+        assert_eq!(
+            resolve_document(&fake, &fake, &token("folder-token"), other).await,
+            Capability::None
+        );
+        assert_eq!(
+            resolve_document(&fake, &fake, &token("unknown"), doc).await,
+            Capability::None
+        );
+
+        fake.shares.get_mut("doc-token").unwrap().expires_at =
+            Some(chrono::Utc::now() - chrono::Duration::minutes(1));
+        assert_eq!(
+            resolve_document(&fake, &fake, &token("doc-token"), doc).await,
+            Capability::None
+        );
+        fake.archived.insert(doc);
+        assert_eq!(
+            resolve_document(&fake, &fake, &token("folder-token"), doc).await,
+            Capability::None
+        );
+        fake.public.insert(doc);
+        assert_eq!(
+            resolve_document(&fake, &fake, &Actor::Public, doc).await,
+            Capability::View
+        );
+    }
+}
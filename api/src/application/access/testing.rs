@@ -0,0 +1,123 @@
+//! In-memory access ports for use-case tests.
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::share_access_port::ShareAccessPort;
+use crate::application::ports::workspace_repository::WorkspaceRole;
+
+pub(crate) struct FakeShare {
+    pub id: Uuid,
+    pub permission: String,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub document_id: Uuid,
+    pub document_type: String,
+}
+
+#[derive(Default)]
+pub(crate) struct FakeAccess {
+    /// (document, user) -> workspace role
+    pub roles: HashMap<(Uuid, Uuid), WorkspaceRole>,
+    /// (document, user) -> strongest grant, inheritance already applied
+    pub grants: HashMap<(Uuid, Uuid), String>,
+    pub public: HashSet<Uuid>,
+    pub archived: HashSet<Uuid>,
+    pub trashed: HashSet<Uuid>,
+    pub shares: HashMap<String, FakeShare>,
+    /// (folder share, document) -> permission
+    pub materialized: HashMap<(Uuid, Uuid), String>,
+}
+
+impl FakeAccess {
+    pub fn member(mut self, doc_id: Uuid, user_id: Uuid, role: WorkspaceRole) -> Self {
+        self.roles.insert((doc_id, user_id), role);
+        self
+    }
+
+    pub fn grant(mut self, doc_id: Uuid, user_id: Uuid, permission: &str) -> Self {
+        self.grants
+            .insert((doc_id, user_id), permission.to_string());
+        self
+    }
+
+    pub fn share(mut self, token: &str, doc_id: Uuid, doc_type: &str, permission: &str) -> Self {
+        self.shares.insert(
+            token.to_string(),
+            FakeShare {
+                id: Uuid::new_v4(),
+                permission: permission.to_string(),
+                expires_at: None,
+                document_id: doc_id,
+                document_type: doc_type.to_string(),
+            },
+        );
+        self
+    }
+}
+
+#[async_trait]
+impl AccessRepository for FakeAccess {
+    async fn workspace_role_for_document(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<WorkspaceRole>> {
+        Ok(self.roles.get(&(doc_id, user_id)).copied())
+    }
+
+    async fn grant_permission_for_document(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<String>> {
+        Ok(self.grants.get(&(doc_id, user_id)).cloned())
+    }
+
+    async fn is_document_public(&self, doc_id: Uuid) -> anyhow::Result<bool> {
+        Ok(self.public.contains(&doc_id))
+    }
+
+    async fn is_document_archived(&self, doc_id: Uuid) -> anyhow::Result<bool> {
+        Ok(self.archived.contains(&doc_id))
+    }
+
+    async fn is_document_trashed(&self, doc_id: Uuid) -> anyhow::Result<bool> {
+        Ok(self.trashed.contains(&doc_id))
+    }
+}
+
+#[async_trait]
+impl ShareAccessPort for FakeAccess {
+    async fn resolve_share_by_token(
+        &self,
+        token: &str,
+    ) -> anyhow::Result<
+        Option<(
+            Uuid,
+            String,
+            Option<chrono::DateTime<chrono::Utc>>,
+            Uuid,
+            String,
+        )>,
+    > {
+        Ok(self.shares.get(token).map(|s| {
+            (
+                s.id,
+                s.permission.clone(),
+                s.expires_at,
+                s.document_id,
+                s.document_type.clone(),
+            )
+        }))
+    }
+
+    async fn get_materialized_permission(
+        &self,
+        parent_share_id: Uuid,
+        doc_id: Uuid,
+    ) -> anyhow::Result<Option<String>> {
+        Ok(self.materialized.get(&(parent_share_id, doc_id)).cloned())
+    }
+}
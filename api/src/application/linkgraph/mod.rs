@@ -112,9 +112,26 @@ fn parse_target(txt: &str) -> LinkTarget {
     }
 }
 
+/// Rewrites `[[target|alias]]` links (embeds and mentions included) for which `rewrite`
+/// returns new inner text; the callback receives the trimmed target and the alias.
+pub fn rewrite_wiki_links<F>(content: &str, mut rewrite: F) -> String
+where
+    F: FnMut(&str, Option<&str>) -> Option<String>,
+{
+    WIKI_LINK_REGEX
+        .replace_all(content, |cap: &regex::Captures| {
+            let alias = cap.get(2).map(|m| m.as_str());
+            match rewrite(cap[1].trim(), alias) {
+                Some(inner) => format!("[[{inner}]]"),
+                None => cap[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
 pub async fn update_document_links<R: LinkGraphRepository + ?Sized>(
     repo: &R,
-    owner_id: Uuid,
+    workspace_id: Uuid,
     source_id: Uuid,
     content: &str,
 ) -> anyhow::Result<()> {
@@ -123,17 +140,17 @@ pub async fn update_document_links<R: LinkGraphRepository + ?Sized>(
     repo.clear_links_for_source(source_id).await?;
 
     for link in links {
-        // Resolve target by id or title for the same owner
+        // Resolve target by id or title within the source's workspace
         let target_doc_id: Option<Uuid> = match link.target {
             LinkTarget::Id(id) => {
-                if repo.exists_doc_for_owner(id, owner_id).await? {
+                if repo.exists_doc_in_workspace(id, workspace_id).await? {
                     Some(id)
                 } else {
                     None
                 }
             }
             LinkTarget::Title(title) => {
-                repo.find_doc_id_by_owner_and_title(owner_id, &title)
+                repo.find_doc_id_by_workspace_and_title(workspace_id, &title)
                     .await?
             }
         };
@@ -1,9 +1,23 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
+use crate::application::ports::workspace_repository::WorkspaceRole;
+
 #[async_trait]
 pub trait AccessRepository: Send + Sync {
-    async fn user_owns_document(&self, doc_id: Uuid, user_id: Uuid) -> anyhow::Result<bool>;
+    // Role of the user in the workspace that owns the document, if a member
+    async fn workspace_role_for_document(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<WorkspaceRole>>;
+    // Strongest named grant on the document or any ancestor folder
+    async fn grant_permission_for_document(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<String>>;
     async fn is_document_public(&self, doc_id: Uuid) -> anyhow::Result<bool>;
     async fn is_document_archived(&self, doc_id: Uuid) -> anyhow::Result<bool>;
+    async fn is_document_trashed(&self, doc_id: Uuid) -> anyhow::Result<bool>;
 }
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct AdminUserRow {
+    pub id: Uuid,
+    pub email: String,
+    pub name: String,
+    pub role: String,
+    pub email_verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub disabled_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub document_count: i64,
+    /// Sum of attachment sizes on documents the user owns
+    pub storage_bytes: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstanceStats {
+    pub users: i64,
+    pub admins: i64,
+    pub disabled_users: i64,
+    pub workspaces: i64,
+    pub documents: i64,
+    pub files: i64,
+    pub storage_bytes: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdminAuditRow {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub actor_email: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub details: serde_json::Value,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[async_trait]
+pub trait AdminRepository: Send + Sync {
+    // Matches `query` against email and name (case-insensitive substring)
+    async fn list_users(
+        &self,
+        query: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> anyhow::Result<Vec<AdminUserRow>>;
+    async fn get_user(&self, id: Uuid) -> anyhow::Result<Option<AdminUserRow>>;
+    async fn set_disabled(&self, id: Uuid, disabled: bool) -> anyhow::Result<bool>;
+    async fn set_role(&self, id: Uuid, role: &str) -> anyhow::Result<bool>;
+    // Grants the admin role to existing accounts with these emails
+    async fn promote_admins(&self, emails: &[String]) -> anyhow::Result<u64>;
+    async fn instance_stats(&self) -> anyhow::Result<InstanceStats>;
+    // Deletes regardless of ownership; returns the document type
+    async fn delete_document(&self, id: Uuid) -> anyhow::Result<Option<String>>;
+    async fn record_audit(
+        &self,
+        actor_id: Uuid,
+        action: &str,
+        target_type: &str,
+        target_id: Option<&str>,
+        details: &serde_json::Value,
+    ) -> anyhow::Result<()>;
+    async fn list_audit(&self, limit: i64, offset: i64) -> anyhow::Result<Vec<AdminAuditRow>>;
+}
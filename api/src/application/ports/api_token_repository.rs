@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct ApiTokenRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub token_prefix: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[async_trait]
+pub trait ApiTokenRepository: Send + Sync {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        token_hash: &str,
+        token_prefix: &str,
+        scopes: &[String],
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> anyhow::Result<ApiTokenRow>;
+    async fn list_for_user(&self, user_id: Uuid) -> anyhow::Result<Vec<ApiTokenRow>>;
+    async fn delete(&self, token_id: Uuid, user_id: Uuid) -> anyhow::Result<bool>;
+    // Unexpired token of an enabled account; refreshes last_used_at at most once a minute
+    async fn authenticate(&self, token_hash: &str) -> anyhow::Result<Option<ApiTokenRow>>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AuthLinkRepository: Send + Sync {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        purpose: &str,
+        nonce_hash: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()>;
+    // Marks the link used; false when unknown, expired or already used
+    async fn consume(&self, user_id: Uuid, purpose: &str, nonce_hash: &str)
+    -> anyhow::Result<bool>;
+    // Retires every outstanding link of this purpose for the user
+    async fn invalidate_all(&self, user_id: Uuid, purpose: &str) -> anyhow::Result<()>;
+}
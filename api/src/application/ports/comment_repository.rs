@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct CommentThreadRow {
+    pub id: Uuid,
+    pub document_id: Uuid,
+    pub anchor_start: Option<Vec<u8>>,
+    pub anchor_end: Option<Vec<u8>>,
+    pub quote: Option<String>,
+    pub status: String,
+    pub created_by: Option<Uuid>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommentRow {
+    pub id: Uuid,
+    pub thread_id: Uuid,
+    pub author_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    pub body: String,
+    pub mentions: Vec<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MentionRow {
+    pub comment_id: Uuid,
+    pub thread_id: Uuid,
+    pub document_id: Uuid,
+    pub document_title: String,
+    pub author_name: Option<String>,
+    pub body: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    async fn list_threads(&self, doc_id: Uuid) -> anyhow::Result<Vec<CommentThreadRow>>;
+    // All comments of a document's threads, oldest first
+    async fn list_comments(&self, doc_id: Uuid) -> anyhow::Result<Vec<CommentRow>>;
+    async fn get_thread(&self, thread_id: Uuid) -> anyhow::Result<Option<CommentThreadRow>>;
+    async fn create_thread(
+        &self,
+        doc_id: Uuid,
+        created_by: Option<Uuid>,
+        anchor_start: Option<&[u8]>,
+        anchor_end: Option<&[u8]>,
+        quote: Option<&str>,
+    ) -> anyhow::Result<CommentThreadRow>;
+    async fn set_thread_status(
+        &self,
+        thread_id: Uuid,
+        status: &str,
+        actor: Option<Uuid>,
+    ) -> anyhow::Result<Option<CommentThreadRow>>;
+    async fn delete_thread(&self, thread_id: Uuid) -> anyhow::Result<bool>;
+    async fn delete_thread_if_empty(&self, thread_id: Uuid) -> anyhow::Result<bool>;
+    // Marks open threads of the given documents resolved; used when documents are archived
+    async fn resolve_open_for_documents(&self, doc_ids: &[Uuid]) -> anyhow::Result<u64>;
+    async fn get_comment(&self, comment_id: Uuid) -> anyhow::Result<Option<CommentRow>>;
+    async fn add_comment(
+        &self,
+        thread_id: Uuid,
+        author_id: Option<Uuid>,
+        body: &str,
+        mentions: &[Uuid],
+    ) -> anyhow::Result<CommentRow>;
+    async fn update_comment(
+        &self,
+        comment_id: Uuid,
+        body: &str,
+        mentions: &[Uuid],
+    ) -> anyhow::Result<Option<CommentRow>>;
+    async fn delete_comment(&self, comment_id: Uuid) -> anyhow::Result<bool>;
+    async fn list_mentions_for_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+    ) -> anyhow::Result<Vec<MentionRow>>;
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DocumentGrantRow {
+    pub user_id: Uuid,
+    pub email: String,
+    pub name: String,
+    pub permission: String,
+    pub granted_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SharedWithMeRow {
+    pub document_id: Uuid,
+    pub title: String,
+    pub doc_type: String,
+    pub permission: String,
+    pub granted_by_name: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[async_trait]
+pub trait DocumentGrantsRepository: Send + Sync {
+    async fn list_for_document(&self, doc_id: Uuid) -> anyhow::Result<Vec<DocumentGrantRow>>;
+    async fn upsert(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+        permission: &str,
+        granted_by: Uuid,
+    ) -> anyhow::Result<()>;
+    async fn revoke(&self, doc_id: Uuid, user_id: Uuid) -> anyhow::Result<bool>;
+    // Documents and folders granted directly to the user, excluding archived and trashed ones
+    async fn list_shared_with_user(&self, user_id: Uuid) -> anyhow::Result<Vec<SharedWithMeRow>>;
+}
@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use crate::domain::documents::document::Document as DomainDocument;
 use crate::domain::documents::document::{
-    BacklinkInfo as DomBacklinkInfo, OutgoingLink as DomOutgoingLink, SearchHit,
+    BacklinkInfo as DomBacklinkInfo, OutgoingLink as DomOutgoingLink, QuickSearchHit, SearchHit,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,30 +19,53 @@ impl Default for DocumentListState {
     }
 }
 
+/// Front matter filter: documents having `key`, optionally with a value equal to `value`
+/// (or, for lists, containing it).
+#[derive(Debug, Clone)]
+pub struct PropertyFilter {
+    pub key: String,
+    pub value: Option<String>,
+}
+
 #[async_trait]
 pub trait DocumentRepository: Send + Sync {
-    async fn list_for_user(
+    async fn list_for_workspace(
         &self,
-        user_id: Uuid,
+        workspace_id: Uuid,
         query: Option<String>,
         tag: Option<String>,
+        property: Option<PropertyFilter>,
         state: DocumentListState,
     ) -> anyhow::Result<Vec<DomainDocument>>;
 
     async fn list_ids_for_user(&self, user_id: Uuid) -> anyhow::Result<Vec<Uuid>>;
 
+    // Top-level documents of a workspace, archived ones included
+    async fn list_workspace_roots(
+        &self,
+        workspace_id: Uuid,
+    ) -> anyhow::Result<Vec<SubtreeDocument>>;
+
     async fn get_by_id(&self, id: Uuid) -> anyhow::Result<Option<DomainDocument>>;
 
     async fn search_for_user(
         &self,
         user_id: Uuid,
-        query: Option<String>,
-        limit: i64,
+        search: &DocumentSearch,
     ) -> anyhow::Result<Vec<SearchHit>>;
 
+    // Fuzzy title match (trigram similarity plus a recency boost) over active documents
+    async fn quick_search(
+        &self,
+        user_id: Uuid,
+        query: &str,
+        limit: i64,
+    ) -> anyhow::Result<Vec<QuickSearchHit>>;
+
     async fn create_for_user(
         &self,
         user_id: Uuid,
+        workspace_id: Uuid,
         title: &str,
         parent_id: Option<Uuid>,
         doc_type: &str,
@@ -72,7 +95,7 @@ pub trait DocumentRepository: Send + Sync {
         source_id: Uuid,
     ) -> anyhow::Result<Vec<DomOutgoingLink>>;
 
-    // Lightweight meta for ownership-scoped queries
+    // Lightweight meta for queries scoped to workspaces the user can edit
     async fn get_meta_for_owner(
         &self,
         doc_id: Uuid,
@@ -97,6 +120,79 @@ pub trait DocumentRepository: Send + Sync {
         owner_id: Uuid,
         root_id: Uuid,
     ) -> anyhow::Result<Vec<SubtreeDocument>>;
+
+    // Active subtree in parent-before-child order, root first
+    async fn list_owned_subtree_nodes(
+        &self,
+        owner_id: Uuid,
+        root_id: Uuid,
+    ) -> anyhow::Result<Vec<SubtreeNode>>;
+
+    // Active documents of a workspace whose title matches one of `titles` (case-insensitive)
+    async fn find_active_by_titles(
+        &self,
+        workspace_id: Uuid,
+        titles: &[String],
+    ) -> anyhow::Result<Vec<SubtreeNode>>;
+
+    // Moves an active subtree to the trash; returns the trashed nodes, empty when not found
+    async fn trash_subtree(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Vec<SubtreeDocument>>;
+
+    // Restores a trash item to its original parent when that is still active, else to the root
+    async fn restore_subtree(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Vec<DomainDocument>>;
+
+    // Trash items (roots of trashed subtrees) of a workspace, most recent first
+    async fn list_trash(&self, workspace_id: Uuid) -> anyhow::Result<Vec<TrashedDocument>>;
+
+    // Every trashed node, optionally limited to a workspace and/or to those trashed before a cutoff;
+    // deepest first
+    async fn list_trashed_nodes(
+        &self,
+        workspace_id: Option<Uuid>,
+        deleted_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> anyhow::Result<Vec<SubtreeDocument>>;
+
+    // Deletes rows that are still in the trash
+    async fn purge_trashed(&self, ids: &[Uuid]) -> anyhow::Result<u64>;
+
+    // Active children of a folder (or the workspace root) in sibling order
+    async fn list_child_ids(
+        &self,
+        workspace_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> anyhow::Result<Vec<Uuid>>;
+
+    // Persists `ordered` as the sibling order; ids that are not children of `parent_id` are ignored
+    async fn set_sort_keys(
+        &self,
+        workspace_id: Uuid,
+        parent_id: Option<Uuid>,
+        ordered: &[Uuid],
+    ) -> anyhow::Result<u64>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DocumentSearch {
+    // Raw input, matched against titles as a substring
+    pub text: Option<String>,
+    // Parsed `tsquery` for title and content matching
+    pub tsquery: Option<String>,
+    pub tag: Option<String>,
+    // Restricts hits to this folder's subtree
+    pub folder_id: Option<Uuid>,
+    pub state: DocumentListState,
+    pub updated_after: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_before: Option<chrono::DateTime<chrono::Utc>>,
+    pub limit: i64,
+    pub offset: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -105,6 +201,18 @@ pub struct DocMeta {
     pub path: Option<String>,
     pub title: String,
     pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub workspace_id: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub struct TrashedDocument {
+    pub id: Uuid,
+    pub title: String,
+    pub doc_type: String,
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+    pub deleted_by: Option<Uuid>,
+    pub original_parent_id: Option<Uuid>,
+    pub original_parent_title: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,3 +220,11 @@ pub struct SubtreeDocument {
     pub id: Uuid,
     pub doc_type: String,
 }
+
+#[derive(Debug, Clone)]
+pub struct SubtreeNode {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub title: String,
+    pub doc_type: String,
+}
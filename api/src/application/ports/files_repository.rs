@@ -16,11 +16,19 @@ pub trait FilesRepository: Send + Sync {
     async fn get_file_meta(
         &self,
         file_id: Uuid,
-    ) -> anyhow::Result<Option<(String, Option<String>, Uuid)>>; // (storage_path, content_type, owner_id)
+    ) -> anyhow::Result<Option<(String, Option<String>, Uuid)>>; // (storage_path, content_type, document_id)
     async fn get_file_path_by_doc_and_name(
         &self,
         doc_id: Uuid,
         filename: &str,
     ) -> anyhow::Result<Option<(String, Option<String>)>>;
     async fn list_storage_paths_for_document(&self, doc_id: Uuid) -> anyhow::Result<Vec<String>>;
+    async fn list_files_for_document(&self, doc_id: Uuid) -> anyhow::Result<Vec<StoredFileRow>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct StoredFileRow {
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub storage_path: String,
 }
@@ -4,10 +4,14 @@ use uuid::Uuid;
 #[async_trait]
 pub trait LinkGraphRepository: Send + Sync {
     async fn clear_links_for_source(&self, source_id: Uuid) -> anyhow::Result<()>;
-    async fn exists_doc_for_owner(&self, doc_id: Uuid, owner_id: Uuid) -> anyhow::Result<bool>;
-    async fn find_doc_id_by_owner_and_title(
+    async fn exists_doc_in_workspace(
         &self,
-        owner_id: Uuid,
+        doc_id: Uuid,
+        workspace_id: Uuid,
+    ) -> anyhow::Result<bool>;
+    async fn find_doc_id_by_workspace_and_title(
+        &self,
+        workspace_id: Uuid,
         title: &str,
     ) -> anyhow::Result<Option<Uuid>>;
     async fn upsert_link(
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    /// Plain-text body
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: &MailMessage) -> anyhow::Result<()>;
+}
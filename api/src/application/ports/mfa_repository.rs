@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct TotpRow {
+    pub user_id: Uuid,
+    /// Base32 shared secret (decrypted)
+    pub secret: String,
+    pub enabled_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_used_step: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MfaChallengeRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub attempts: i32,
+}
+
+#[async_trait]
+pub trait MfaRepository: Send + Sync {
+    async fn get_totp(&self, user_id: Uuid) -> anyhow::Result<Option<TotpRow>>;
+    // Stores a new pending secret; false when TOTP is already enabled
+    async fn upsert_pending_totp(&self, user_id: Uuid, secret: &str) -> anyhow::Result<bool>;
+    // Marks TOTP enabled at `step` and replaces the recovery codes
+    async fn enable_totp(
+        &self,
+        user_id: Uuid,
+        step: i64,
+        recovery_code_hashes: &[String],
+    ) -> anyhow::Result<bool>;
+    // Records a used time step; false when it is not newer than the last one (replay)
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> anyhow::Result<bool>;
+    async fn consume_recovery_code(&self, user_id: Uuid, code_hash: &str) -> anyhow::Result<bool>;
+    async fn count_recovery_codes(&self, user_id: Uuid) -> anyhow::Result<i64>;
+    // Removes the secret, recovery codes and pending challenges
+    async fn disable_totp(&self, user_id: Uuid) -> anyhow::Result<bool>;
+    async fn create_challenge(
+        &self,
+        user_id: Uuid,
+        token_hash: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()>;
+    // Unexpired challenge for this token
+    async fn find_challenge(&self, token_hash: &str) -> anyhow::Result<Option<MfaChallengeRow>>;
+    // Counts an attempt on the challenge before it is verified; returns the new count
+    async fn count_challenge_attempt(&self, challenge_id: Uuid) -> anyhow::Result<Option<i32>>;
+    async fn delete_challenge(&self, challenge_id: Uuid) -> anyhow::Result<()>;
+    // Counts a second-factor attempt for the user before it is verified and, from `max_failures`
+    // on, locks further attempts for `lock_secs`; None while locked or without enabled TOTP
+    async fn begin_second_factor_attempt(
+        &self,
+        user_id: Uuid,
+        max_failures: i32,
+        lock_secs: i64,
+    ) -> anyhow::Result<Option<i32>>;
+    // Clears the failure count after a successful attempt
+    async fn reset_second_factor_failures(&self, user_id: Uuid) -> anyhow::Result<()>;
+}
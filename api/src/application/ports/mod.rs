@@ -1,5 +1,10 @@
 pub mod access_repository;
+pub mod admin_repository;
+pub mod api_token_repository;
+pub mod auth_link_repository;
 pub mod awareness_port;
+pub mod comment_repository;
+pub mod document_grants_repository;
 pub mod document_repository;
 pub mod document_snapshot_archive_repository;
 pub mod files_repository;
@@ -8,6 +13,10 @@ pub mod git_storage;
 pub mod git_workspace;
 pub mod gitignore_port;
 pub mod linkgraph_repository;
+pub mod mailer;
+pub mod mfa_repository;
+pub mod oidc_provider;
+pub mod oidc_repository;
 pub mod plugin_asset_store;
 pub mod plugin_event_publisher;
 pub mod plugin_installation_repository;
@@ -15,14 +24,21 @@ pub mod plugin_installer;
 pub mod plugin_package_fetcher;
 pub mod plugin_repository;
 pub mod plugin_runtime;
+pub mod property_repository;
 pub mod public_repository;
 pub mod realtime_hydration_port;
 pub mod realtime_persistence_port;
 pub mod realtime_port;
 pub mod realtime_types;
+pub mod search_index_repository;
+pub mod session_repository;
 pub mod share_access_port;
 pub mod shares_repository;
 pub mod storage_port;
 pub mod tag_repository;
 pub mod tagging_repository;
+pub mod takeout_repository;
+pub mod template_repository;
+pub mod user_documents_repository;
 pub mod user_repository;
+pub mod workspace_repository;
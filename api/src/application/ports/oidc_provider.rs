@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+/// Identity asserted by a verified ID token.
+#[derive(Debug, Clone)]
+pub struct OidcIdentity {
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+#[async_trait]
+pub trait OidcProvider: Send + Sync {
+    /// Display name for the login button
+    fn name(&self) -> &str;
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> anyhow::Result<String>;
+    // Redeems the code and verifies the ID token (signature, issuer, audience, expiry, nonce)
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> anyhow::Result<OidcIdentity>;
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::user_repository::UserRow;
+
+#[derive(Debug, Clone)]
+pub struct OidcLoginStateRow {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub redirect_to: Option<String>,
+}
+
+#[async_trait]
+pub trait OidcRepository: Send + Sync {
+    async fn create_login_state(
+        &self,
+        row: &OidcLoginStateRow,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()>;
+    // Removes and returns an unexpired state; expired states are purged on the way
+    async fn consume_login_state(&self, state: &str) -> anyhow::Result<Option<OidcLoginStateRow>>;
+    // User linked to the identity; bumps last_login_at
+    async fn find_user_by_identity(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> anyhow::Result<Option<UserRow>>;
+    async fn link_identity(
+        &self,
+        user_id: Uuid,
+        issuer: &str,
+        subject: &str,
+        email: Option<&str>,
+    ) -> anyhow::Result<()>;
+}
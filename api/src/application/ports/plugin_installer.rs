@@ -22,4 +22,6 @@ pub trait PluginInstaller: Send + Sync {
         user_id: Uuid,
         archive: &[u8],
     ) -> Result<InstalledPlugin, PluginInstallError>;
+    // Available to every user and workspace
+    async fn install_global(&self, archive: &[u8]) -> Result<InstalledPlugin, PluginInstallError>;
 }
@@ -14,6 +14,16 @@ pub struct PluginRecord {
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
+#[derive(Debug, Clone)]
+pub struct PluginKvEntry {
+    pub plugin: String,
+    pub scope: String,
+    pub scope_id: Option<Uuid>,
+    pub key: String,
+    pub value: JsonValue,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
 #[async_trait]
 pub trait PluginRepository: Send + Sync {
     // KV
@@ -66,4 +76,16 @@ pub trait PluginRepository: Send + Sync {
     async fn delete_scoped_kv(&self, scope: &str, scope_ids: &[Uuid]) -> anyhow::Result<()>;
 
     async fn delete_scoped_records(&self, scope: &str, scope_ids: &[Uuid]) -> anyhow::Result<()>;
+
+    async fn list_scoped_kv(
+        &self,
+        scope: &str,
+        scope_ids: &[Uuid],
+    ) -> anyhow::Result<Vec<PluginKvEntry>>;
+
+    async fn list_scoped_records(
+        &self,
+        scope: &str,
+        scope_ids: &[Uuid],
+    ) -> anyhow::Result<Vec<PluginRecord>>;
 }
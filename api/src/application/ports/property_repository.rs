@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PropertyRepository: Send + Sync {
+    // Replaces the document's front matter properties (a JSON object)
+    async fn set_document_properties(
+        &self,
+        doc_id: Uuid,
+        properties: &serde_json::Value,
+    ) -> anyhow::Result<()>;
+}
@@ -44,6 +44,8 @@ pub trait PublicRepository: Send + Sync {
             Option<chrono::DateTime<chrono::Utc>>,
             Option<Uuid>,
             Option<Uuid>,
+            serde_json::Value,
+            Option<i32>,
         )>,
     >;
     async fn public_exists_by_owner_and_id(
@@ -51,4 +53,6 @@ pub trait PublicRepository: Send + Sync {
         owner_name: &str,
         doc_id: Uuid,
     ) -> anyhow::Result<bool>;
+    // Current name of the user who gave up `name`, unless someone holds it now
+    async fn resolve_renamed_owner(&self, name: &str) -> anyhow::Result<Option<String>>;
 }
@@ -25,6 +25,7 @@ pub struct DocumentRecord {
     pub path: Option<String>,
     pub title: String,
     pub owner_id: Option<Uuid>,
+    pub workspace_id: Option<Uuid>,
 }
 
 #[async_trait]
@@ -21,7 +21,9 @@ impl fmt::Display for RealtimeError {
 
 impl std::error::Error for RealtimeError {}
 
-use super::realtime_types::{DynRealtimeSink, DynRealtimeStream};
+use super::realtime_types::{
+    ContentEdit, ContentState, DynRealtimeSink, DynRealtimeStream, RealtimeAccess,
+};
 use yrs::Doc;
 
 #[async_trait]
@@ -31,7 +33,7 @@ pub trait RealtimeEngine: Send + Sync {
         doc_id: &str,
         sink: DynRealtimeSink,
         stream: DynRealtimeStream,
-        can_edit: bool,
+        access: RealtimeAccess,
     ) -> anyhow::Result<()>;
 
     async fn get_content(&self, doc_id: &str) -> anyhow::Result<Option<String>>;
@@ -44,7 +46,33 @@ pub trait RealtimeEngine: Send + Sync {
 
     async fn apply_snapshot(&self, doc_id: &str, doc: &Doc) -> anyhow::Result<()>;
 
+    /// Applies `edit` as minimal CRDT operations that connected editors receive live.
+    /// Fails with `precondition_failed` when `if_match` is not the current content hash.
+    /// May fail with `conflict` when concurrent updates keep the edit from landing.
+    async fn edit_content(
+        &self,
+        doc_id: &str,
+        edit: &ContentEdit,
+        if_match: Option<&str>,
+    ) -> anyhow::Result<ContentState>;
+
     async fn set_document_editable(&self, _doc_id: &str, _editable: bool) -> anyhow::Result<()> {
         Ok(())
     }
+
+    /// Fans out a JSON application event (e.g. comment changes) to every session of the document.
+    async fn publish_event(
+        &self,
+        _doc_id: &str,
+        _payload: &serde_json::Value,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Resolves encoded Yjs relative positions to current UTF-16 offsets within `content`.
+    async fn resolve_anchors(
+        &self,
+        doc_id: &str,
+        anchors: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<Option<u32>>>;
 }
@@ -10,3 +10,35 @@ pub type DynRealtimeSink =
     Arc<Mutex<Pin<Box<dyn Sink<Vec<u8>, Error = RealtimeError> + Send + Sync + 'static>>>>;
 pub type DynRealtimeStream =
     Pin<Box<dyn Stream<Item = Result<Vec<u8>, RealtimeError>> + Send + Sync + 'static>>;
+
+/// Write access granted to a realtime session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RealtimeAccess {
+    ReadOnly,
+    /// Content stays read-only; updates that leave the `content` text untouched (comments) pass.
+    Comment,
+    Edit,
+}
+
+/// Splice against the document text; offsets and lengths count Unicode scalar values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextOperation {
+    pub offset: usize,
+    pub delete: usize,
+    pub insert: String,
+}
+
+/// Content change applied through `RealtimeEngine::edit_content`.
+#[derive(Debug, Clone)]
+pub enum ContentEdit {
+    Replace(String),
+    /// Applied in order, each against the result of the previous one.
+    Operations(Vec<TextOperation>),
+}
+
+/// Document text together with its content hash (used as ETag).
+#[derive(Debug, Clone)]
+pub struct ContentState {
+    pub content: String,
+    pub hash: String,
+}
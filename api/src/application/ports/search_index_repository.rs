@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SearchIndexRepository: Send + Sync {
+    async fn upsert_document_content(&self, doc_id: Uuid, content: &str) -> anyhow::Result<()>;
+    // Non-folder documents that have never been indexed, by id after `after`
+    async fn list_unindexed(&self, after: Option<Uuid>, limit: i64) -> anyhow::Result<Vec<Uuid>>;
+}
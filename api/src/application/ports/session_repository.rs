@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SessionRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub last_seen_at: chrono::DateTime<chrono::Utc>,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    pub revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        refresh_token_hash: &str,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<SessionRow>;
+    // Active (not revoked, not expired) session holding this refresh token
+    async fn find_by_refresh_hash(
+        &self,
+        refresh_token_hash: &str,
+    ) -> anyhow::Result<Option<SessionRow>>;
+    async fn find_by_previous_hash(
+        &self,
+        refresh_token_hash: &str,
+    ) -> anyhow::Result<Option<SessionRow>>;
+    // Swaps the refresh token only if `old_hash` is still current; false when another request won
+    async fn rotate(
+        &self,
+        session_id: Uuid,
+        old_hash: &str,
+        new_hash: &str,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<bool>;
+    // True when the session is active; refreshes last_seen_at at most once a minute
+    async fn touch(&self, session_id: Uuid, user_id: Uuid) -> anyhow::Result<bool>;
+    async fn list_active(&self, user_id: Uuid) -> anyhow::Result<Vec<SessionRow>>;
+    async fn revoke(&self, session_id: Uuid, user_id: Uuid) -> anyhow::Result<bool>;
+    async fn revoke_all(&self, user_id: Uuid) -> anyhow::Result<u64>;
+    async fn revoke_others(&self, user_id: Uuid, keep_session_id: Uuid) -> anyhow::Result<u64>;
+}
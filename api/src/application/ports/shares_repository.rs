@@ -60,6 +60,8 @@ pub trait SharesRepository: Send + Sync {
 
     async fn get_document_owner_by_token(&self, token: &str) -> anyhow::Result<Option<Uuid>>;
 
+    async fn get_document_workspace_by_token(&self, token: &str) -> anyhow::Result<Option<Uuid>>;
+
     async fn list_subtree_nodes(
         &self,
         root_id: Uuid,
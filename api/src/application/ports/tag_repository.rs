@@ -5,7 +5,7 @@ use uuid::Uuid;
 pub trait TagRepository: Send + Sync {
     async fn list_tags(
         &self,
-        owner_id: Uuid,
+        workspace_id: Uuid,
         filter: Option<String>,
     ) -> anyhow::Result<Vec<(String, i64)>>;
 }
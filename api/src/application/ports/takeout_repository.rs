@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct TakeoutJobRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: String,
+    pub error: Option<String>,
+    pub storage_path: Option<String>,
+    pub size_bytes: Option<i64>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TakeoutDocumentRow {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub title: String,
+    pub doc_type: String,
+    pub tags: Vec<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    // Position among siblings when arranged manually
+    pub sort_key: Option<i32>,
+}
+
+#[async_trait]
+pub trait TakeoutRepository: Send + Sync {
+    // Fails with "conflict" while another export for the user is pending or running
+    async fn create_job(&self, user_id: Uuid) -> anyhow::Result<TakeoutJobRow>;
+    async fn list_jobs(&self, user_id: Uuid) -> anyhow::Result<Vec<TakeoutJobRow>>;
+    async fn get_job(&self, user_id: Uuid, id: Uuid) -> anyhow::Result<Option<TakeoutJobRow>>;
+    // Moves the oldest pending job to running; safe to call from several workers
+    async fn claim_next(&self) -> anyhow::Result<Option<TakeoutJobRow>>;
+    async fn mark_completed(
+        &self,
+        id: Uuid,
+        storage_path: &str,
+        size_bytes: i64,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()>;
+    async fn mark_failed(&self, id: Uuid, error: &str) -> anyhow::Result<()>;
+    // Returns running jobs started before `cutoff` to the queue
+    async fn requeue_stale(&self, cutoff: chrono::DateTime<chrono::Utc>) -> anyhow::Result<u64>;
+    // Deletes expired jobs, returning their archive paths
+    async fn delete_expired(&self) -> anyhow::Result<Vec<String>>;
+    // Documents owned by the user, folders included
+    async fn list_documents(&self, user_id: Uuid) -> anyhow::Result<Vec<TakeoutDocumentRow>>;
+}
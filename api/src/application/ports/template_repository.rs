@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Custom variable asked for when instantiating a template.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplatePrompt {
+    pub name: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TemplateRow {
+    pub document_id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub prompts: Vec<TemplatePrompt>,
+    pub doc_type: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[async_trait]
+pub trait TemplateRepository: Send + Sync {
+    async fn upsert(
+        &self,
+        document_id: Uuid,
+        workspace_id: Uuid,
+        name: &str,
+        description: Option<&str>,
+        prompts: &[TemplatePrompt],
+        created_by: Uuid,
+    ) -> anyhow::Result<TemplateRow>;
+    // Templates whose document is neither archived nor trashed
+    async fn list_for_workspace(&self, workspace_id: Uuid) -> anyhow::Result<Vec<TemplateRow>>;
+    async fn get(&self, document_id: Uuid) -> anyhow::Result<Option<TemplateRow>>;
+    async fn delete(&self, document_id: Uuid) -> anyhow::Result<bool>;
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct RecentDocumentRow {
+    pub document_id: Uuid,
+    pub workspace_id: Uuid,
+    pub title: String,
+    pub doc_type: String,
+    pub view_count: i32,
+    pub last_viewed_at: chrono::DateTime<chrono::Utc>,
+    /// Token of the share link the document was opened through, if any
+    pub share_token: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FavoriteDocumentRow {
+    pub document_id: Uuid,
+    pub workspace_id: Uuid,
+    pub title: String,
+    pub doc_type: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Token of the share link the document was starred through, if any
+    pub share_token: Option<String>,
+}
+
+#[async_trait]
+pub trait UserDocumentsRepository: Send + Sync {
+    /// Bumps the user's view of a document; only the latest `keep` views per user are retained.
+    /// A `share_id` is kept until a later view through another share replaces it.
+    async fn record_view(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_id: Option<Uuid>,
+        keep: i64,
+    ) -> anyhow::Result<()>;
+    // Most recent first, excluding trashed documents
+    async fn list_recent(&self, user_id: Uuid) -> anyhow::Result<Vec<RecentDocumentRow>>;
+    /// `false` when the document was already a favorite.
+    async fn add_favorite(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_id: Option<Uuid>,
+    ) -> anyhow::Result<bool>;
+    async fn remove_favorite(&self, user_id: Uuid, doc_id: Uuid) -> anyhow::Result<bool>;
+    // Newest first, excluding trashed documents
+    async fn list_favorites(&self, user_id: Uuid) -> anyhow::Result<Vec<FavoriteDocumentRow>>;
+}
@@ -7,6 +7,16 @@ pub struct UserRow {
     pub email: String,
     pub name: String,
     pub password_hash: Option<String>,
+    pub email_verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// user | admin
+    pub role: String,
+    pub disabled_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl UserRow {
+    pub fn is_admin(&self) -> bool {
+        self.role == "admin"
+    }
 }
 
 #[async_trait]
@@ -15,9 +25,18 @@ pub trait UserRepository: Send + Sync {
         &self,
         email: &str,
         name: &str,
-        password_hash: &str,
+        // None for accounts that only sign in through SSO
+        password_hash: Option<&str>,
     ) -> anyhow::Result<UserRow>;
     async fn find_by_email(&self, email: &str) -> anyhow::Result<Option<UserRow>>;
     async fn find_by_id(&self, id: Uuid) -> anyhow::Result<Option<UserRow>>;
     async fn delete_user(&self, id: Uuid) -> anyhow::Result<bool>;
+    async fn set_password(&self, id: Uuid, password_hash: &str) -> anyhow::Result<bool>;
+    async fn mark_email_verified(&self, id: Uuid) -> anyhow::Result<bool>;
+    // Case-insensitive; used for the uniqueness check on profile updates
+    async fn find_by_name(&self, name: &str) -> anyhow::Result<Option<UserRow>>;
+    // Records the previous name for public-page redirects and renames the personal workspace
+    async fn update_name(&self, id: Uuid, name: &str) -> anyhow::Result<Option<UserRow>>;
+    // Clears email_verified_at; fails with "conflict" when the address is taken
+    async fn update_email(&self, id: Uuid, email: &str) -> anyhow::Result<Option<UserRow>>;
 }
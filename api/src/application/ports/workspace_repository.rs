@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WorkspaceRole {
+    Guest,
+    Member,
+    Admin,
+    Owner,
+}
+
+impl WorkspaceRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkspaceRole::Guest => "guest",
+            WorkspaceRole::Member => "member",
+            WorkspaceRole::Admin => "admin",
+            WorkspaceRole::Owner => "owner",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "guest" => Some(WorkspaceRole::Guest),
+            "member" => Some(WorkspaceRole::Member),
+            "admin" => Some(WorkspaceRole::Admin),
+            "owner" => Some(WorkspaceRole::Owner),
+            _ => None,
+        }
+    }
+
+    // Guests are read-only; every other role may edit documents.
+    pub fn can_edit(&self) -> bool {
+        *self >= WorkspaceRole::Member
+    }
+
+    // Admins and owners manage members, git config and plugin installations.
+    pub fn can_manage(&self) -> bool {
+        *self >= WorkspaceRole::Admin
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkspaceRow {
+    pub id: Uuid,
+    pub name: String,
+    pub is_personal: bool,
+    pub role: WorkspaceRole,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkspaceMemberRow {
+    pub user_id: Uuid,
+    pub email: String,
+    pub name: String,
+    pub role: WorkspaceRole,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[async_trait]
+pub trait WorkspaceRepository: Send + Sync {
+    async fn list_for_user(&self, user_id: Uuid) -> anyhow::Result<Vec<WorkspaceRow>>;
+    async fn get_for_user(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<WorkspaceRow>>;
+    async fn create(&self, name: &str, owner_id: Uuid) -> anyhow::Result<WorkspaceRow>;
+    async fn rename(&self, workspace_id: Uuid, name: &str) -> anyhow::Result<bool>;
+    // Personal workspaces cannot be deleted; returns false for them.
+    async fn delete(&self, workspace_id: Uuid) -> anyhow::Result<bool>;
+    async fn get_role(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<WorkspaceRole>>;
+    async fn list_members(&self, workspace_id: Uuid) -> anyhow::Result<Vec<WorkspaceMemberRow>>;
+    async fn upsert_member(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        role: WorkspaceRole,
+    ) -> anyhow::Result<()>;
+    async fn remove_member(&self, workspace_id: Uuid, user_id: Uuid) -> anyhow::Result<bool>;
+    async fn count_owners(&self, workspace_id: Uuid) -> anyhow::Result<i64>;
+    // Hands documents the user created in workspaces shared with others over to a
+    // remaining member, promoting one to owner when the user was the last owner.
+    async fn release_user(&self, user_id: Uuid) -> anyhow::Result<()>;
+    // Deletes the personal workspace and any workspace the user is the only member of.
+    async fn delete_sole_member_workspaces(&self, user_id: Uuid) -> anyhow::Result<()>;
+}
@@ -0,0 +1,4 @@
+pub mod scopes;
+pub mod signed_links;
+pub mod tokens;
+pub mod totp;
@@ -0,0 +1,57 @@
+/// Permissions a personal access token can carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiTokenScope {
+    DocumentsRead,
+    DocumentsWrite,
+    GitSync,
+}
+
+impl ApiTokenScope {
+    pub const ALL: [ApiTokenScope; 3] = [
+        ApiTokenScope::DocumentsRead,
+        ApiTokenScope::DocumentsWrite,
+        ApiTokenScope::GitSync,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiTokenScope::DocumentsRead => "documents:read",
+            ApiTokenScope::DocumentsWrite => "documents:write",
+            ApiTokenScope::GitSync => "git:sync",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.as_str() == s)
+    }
+
+    /// Whether a token granted `granted` may act with this scope; write implies read.
+    pub fn granted_by(&self, granted: &[String]) -> bool {
+        granted.iter().any(|g| {
+            g == self.as_str()
+                || (*self == ApiTokenScope::DocumentsRead
+                    && g == ApiTokenScope::DocumentsWrite.as_str())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_implies_read_but_nothing_else() {
+        let granted =
+            |scopes: &[&str]| -> Vec<String> { scopes.iter().map(|s| s.to_string()).collect() };
+        let write = granted(&["documents:write"]);
+        assert!(ApiTokenScope::DocumentsRead.granted_by(&write));
+        assert!(ApiTokenScope::DocumentsWrite.granted_by(&write));
+        assert!(!ApiTokenScope::GitSync.granted_by(&write));
+
+        let read = granted(&["documents:read"]);
+        assert!(ApiTokenScope::DocumentsRead.granted_by(&read));
+        assert!(!ApiTokenScope::DocumentsWrite.granted_by(&read));
+        assert!(!ApiTokenScope::DocumentsRead.granted_by(&granted(&["git:sync"])));
+        assert!(!ApiTokenScope::DocumentsRead.granted_by(&[]));
+    }
+}
@@ -0,0 +1,63 @@
+use base64::Engine as _;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::application::services::auth::tokens::generate_opaque_token;
+
+pub const PURPOSE_PASSWORD_RESET: &str = "password_reset";
+pub const PURPOSE_EMAIL_VERIFICATION: &str = "email_verification";
+
+/// Claims carried by a verified link token.
+#[derive(Debug, Clone)]
+pub struct LinkClaims {
+    pub user_id: Uuid,
+    pub nonce: String,
+}
+
+fn mac(secret: &str) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("hmac accepts any key length")
+}
+
+/// Issues `payload.signature` where the payload binds purpose, user, a random nonce and the expiry.
+/// Returns the token and its nonce; the nonce is what gets recorded for single use.
+pub fn sign(
+    secret: &str,
+    purpose: &str,
+    user_id: Uuid,
+    expires_at: chrono::DateTime<chrono::Utc>,
+) -> (String, String) {
+    let nonce = generate_opaque_token();
+    let payload = format!("{purpose}:{user_id}:{nonce}:{}", expires_at.timestamp());
+    let mut m = mac(secret);
+    m.update(payload.as_bytes());
+    let sig = m.finalize().into_bytes();
+    let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let token = format!("{}.{}", engine.encode(payload), engine.encode(sig));
+    (token, nonce)
+}
+
+/// Checks signature, purpose and expiry; single use is enforced by the caller.
+pub fn verify(secret: &str, purpose: &str, token: &str) -> Option<LinkClaims> {
+    let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let (payload_b64, sig_b64) = token.trim().split_once('.')?;
+    let payload = engine.decode(payload_b64).ok()?;
+    let sig = engine.decode(sig_b64).ok()?;
+    let mut m = mac(secret);
+    m.update(&payload);
+    m.verify_slice(&sig).ok()?;
+
+    let payload = String::from_utf8(payload).ok()?;
+    let mut parts = payload.splitn(4, ':');
+    let (p, uid, nonce, exp) = (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+    if p != purpose {
+        return None;
+    }
+    if exp.parse::<i64>().ok()? <= chrono::Utc::now().timestamp() {
+        return None;
+    }
+    Some(LinkClaims {
+        user_id: Uuid::parse_str(uid).ok()?,
+        nonce: nonce.to_string(),
+    })
+}
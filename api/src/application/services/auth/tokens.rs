@@ -0,0 +1,34 @@
+use base64::Engine as _;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Random 256-bit token, URL-safe base64 without padding.
+pub fn generate_opaque_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Opaque tokens are stored only as their SHA-256 hex digest.
+pub fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Personal access tokens carry a fixed prefix so they can be told apart from JWTs.
+pub const API_TOKEN_PREFIX: &str = "refmd_pat_";
+
+pub fn generate_api_token() -> String {
+    format!("{API_TOKEN_PREFIX}{}", generate_opaque_token())
+}
+
+pub fn is_api_token(token: &str) -> bool {
+    token.starts_with(API_TOKEN_PREFIX)
+}
+
+/// PKCE S256 code challenge for a code verifier.
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    let digest = Sha256::digest(code_verifier.as_bytes());
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest)
+}
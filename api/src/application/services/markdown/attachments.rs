@@ -0,0 +1,65 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// `<stem>_<YYYYMMDD-HHMMSS>[-n].<ext>` as produced by `StoragePort::store_doc_attachment`.
+static STORED_SUFFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.+)_\d{8}-\d{6}(?:-\d+)?(\.[^.]+)?$").unwrap());
+
+/// Points `attachments/<original>` links (with or without `./`) at `stored`.
+pub fn rewrite_attachment_refs(markdown: &str, original: &str, stored: &str) -> String {
+    let needle = format!("attachments/{original}");
+    let mut out = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+    while let Some(pos) = rest.find(&needle) {
+        let end = pos + needle.len();
+        let starts_path = rest[..pos]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '-'));
+        let ends_path = rest[end..].chars().next().is_none_or(|c| {
+            c.is_whitespace() || matches!(c, ')' | ']' | '"' | '\'' | '>' | '|' | '?' | '#')
+        });
+        out.push_str(&rest[..pos]);
+        if starts_path && ends_path {
+            out.push_str("attachments/");
+            out.push_str(stored);
+        } else {
+            out.push_str(&needle);
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Drops the upload timestamp from a stored attachment name so re-storing it doesn't stack suffixes.
+pub fn original_attachment_name(stored: &str) -> String {
+    match STORED_SUFFIX.captures(stored) {
+        Some(cap) => format!(
+            "{}{}",
+            &cap[1],
+            cap.get(2).map(|m| m.as_str()).unwrap_or("")
+        ),
+        None => stored.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_only_whole_attachment_paths() {
+        let md = "![a](./attachments/pic.png) [b](attachments/pic.png?x) myattachments/pic.png attachments/pic.png.bak";
+        assert_eq!(
+            rewrite_attachment_refs(md, "pic.png", "pic_1.png"),
+            "![a](./attachments/pic_1.png) [b](attachments/pic_1.png?x) myattachments/pic.png attachments/pic.png.bak"
+        );
+        assert_eq!(
+            original_attachment_name("pic_20251117-101010-2.png"),
+            "pic.png"
+        );
+        assert_eq!(original_attachment_name("notes_20251117-101010"), "notes");
+        assert_eq!(original_attachment_name("plain.txt"), "plain.txt");
+    }
+}
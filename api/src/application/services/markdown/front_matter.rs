@@ -0,0 +1,120 @@
+use serde_json::{Map, Value};
+
+/// Splits a leading `---` YAML block (closed by `---` or `...`) from the body: `(yaml, body)`.
+pub fn split_front_matter(markdown: &str) -> Option<(&str, &str)> {
+    let rest = markdown.strip_prefix("---")?;
+    let rest = rest
+        .strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        let fence = line.trim_end_matches(['\r', '\n']);
+        if fence == "---" || fence == "..." {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Front matter as a JSON object; `None` when absent or not a YAML mapping.
+pub fn parse_front_matter(markdown: &str) -> Option<Map<String, Value>> {
+    let (yaml, _) = split_front_matter(markdown)?;
+    if yaml.trim().is_empty() {
+        return Some(Map::new());
+    }
+    match serde_yaml::from_str::<serde_yaml::Value>(yaml).ok()? {
+        serde_yaml::Value::Mapping(map) => Some(mapping_to_json(map)),
+        _ => None,
+    }
+}
+
+/// Renders properties as a two-column `<table class="front-matter">`.
+pub fn properties_table_html(properties: &Map<String, Value>) -> String {
+    let mut html = String::from("<table class=\"front-matter\"><tbody>");
+    for (key, value) in properties {
+        html.push_str("<tr><th>");
+        html.push_str(&htmlescape::encode_minimal(key));
+        html.push_str("</th><td>");
+        html.push_str(&htmlescape::encode_minimal(&display_value(value)));
+        html.push_str("</td></tr>");
+    }
+    html.push_str("</tbody></table>\n");
+    html
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn mapping_to_json(map: serde_yaml::Mapping) -> Map<String, Value> {
+    map.into_iter()
+        .filter_map(|(k, v)| Some((yaml_key(k)?, yaml_to_json(v)?)))
+        .collect()
+}
+
+fn yaml_key(key: serde_yaml::Value) -> Option<String> {
+    match key {
+        serde_yaml::Value::String(s) => Some(s),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn yaml_to_json(value: serde_yaml::Value) -> Option<Value> {
+    use serde_yaml::Value as Yaml;
+    Some(match value {
+        Yaml::Null => Value::Null,
+        Yaml::Bool(b) => Value::Bool(b),
+        Yaml::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::from(i)
+            } else if let Some(u) = n.as_u64() {
+                Value::from(u)
+            } else {
+                // NaN and infinities have no JSON form
+                Value::Number(serde_json::Number::from_f64(n.as_f64()?)?)
+            }
+        }
+        Yaml::String(s) => Value::String(s),
+        Yaml::Sequence(items) => Value::Array(items.into_iter().filter_map(yaml_to_json).collect()),
+        Yaml::Mapping(map) => Value::Object(mapping_to_json(map)),
+        Yaml::Tagged(tagged) => return yaml_to_json(tagged.value),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_typed_properties() {
+        let md = "---\nstatus: done\npriority: 2\ndraft: false\ntags: [a, b]\ndue: 2025-11-20\n---\n# Body\n";
+        let (_, body) = split_front_matter(md).unwrap();
+        assert_eq!(body, "# Body\n");
+        let props = parse_front_matter(md).unwrap();
+        assert_eq!(
+            Value::Object(props),
+            serde_json::json!({
+                "status": "done",
+                "priority": 2,
+                "draft": false,
+                "tags": ["a", "b"],
+                "due": "2025-11-20"
+            })
+        );
+        assert!(parse_front_matter("---\n- a list\n---\n").is_none());
+        assert!(parse_front_matter("# No front matter\n---\n").is_none());
+        assert!(parse_front_matter("---\nunterminated: yes\n").is_none());
+    }
+}
@@ -1,3 +1,6 @@
+pub mod attachments;
+pub mod front_matter;
+
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -103,10 +106,20 @@ pub fn render(
     // Allow HtmlBlock/HtmlInline to pass through; will be sanitized by ammonia afterwards
     c_opts.render.unsafe_ = true;
 
+    // Front matter becomes a metadata table; blank lines keep data-sourcepos aligned
+    let properties = front_matter::parse_front_matter(&text);
+    let source = match (&properties, front_matter::split_front_matter(&text)) {
+        (Some(_), Some((_, body))) => {
+            let lines = text[..text.len() - body.len()].matches('\n').count();
+            std::borrow::Cow::Owned(format!("{}{}", "\n".repeat(lines), body))
+        }
+        _ => std::borrow::Cow::Borrowed(text.as_str()),
+    };
+
     // Parse AST
     use comrak::nodes::AstNode;
     let arena = comrak::Arena::new();
-    let root = comrak::parse_document(&arena, &text, &c_opts);
+    let root = comrak::parse_document(&arena, &source, &c_opts);
 
     // Transform: capture code fences, highlight code blocks, and inline tag links
     let mut placeholders: Vec<PlaceholderItem> = Vec::new();
@@ -584,7 +597,10 @@ pub fn render(
     // Render HTML
     let mut html = Vec::new();
     comrak::format_html(root, &c_opts, &mut html)?;
-    let html = String::from_utf8(html)?;
+    let mut html = String::from_utf8(html)?;
+    if let Some(properties) = properties.filter(|p| !p.is_empty()) {
+        html.insert_str(0, &front_matter::properties_table_html(&properties));
+    }
 
     // Sanitize
     let mut builder = ammonia::Builder::default();
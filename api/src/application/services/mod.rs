@@ -1,5 +1,8 @@
+pub mod auth;
 pub mod diff;
 pub mod markdown;
 pub mod plugins;
 pub mod realtime;
+pub mod search;
 pub mod tagging;
+pub mod templates;
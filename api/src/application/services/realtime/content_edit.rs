@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+use similar::{Algorithm, ChangeTag, TextDiff};
+
+use crate::application::ports::realtime_types::ContentEdit;
+
+// Past this the diff degrades to coarser (still correct) splices.
+const DIFF_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Replacement at a byte `index` of the text as left by the preceding splices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextSplice {
+    pub index: usize,
+    pub delete: usize,
+    pub insert: String,
+}
+
+pub fn content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+/// Computes the edited text; out-of-range operations fail with `bad_request`.
+pub fn apply_edit(current: &str, edit: &ContentEdit) -> anyhow::Result<String> {
+    let ops = match edit {
+        ContentEdit::Replace(content) => return Ok(content.clone()),
+        ContentEdit::Operations(ops) => ops,
+    };
+    let mut text = current.to_string();
+    for op in ops {
+        let start = byte_offset(&text, op.offset);
+        let end = op
+            .offset
+            .checked_add(op.delete)
+            .and_then(|end| byte_offset(&text, end));
+        let (Some(start), Some(end)) = (start, end) else {
+            anyhow::bail!("bad_request");
+        };
+        text.replace_range(start..end, &op.insert);
+    }
+    Ok(text)
+}
+
+/// Smallest set of splices turning `old` into `new`, so concurrent edits elsewhere survive.
+pub fn minimal_splices(old: &str, new: &str) -> Vec<TextSplice> {
+    let prefix = common_prefix_len(old, new);
+    let suffix = common_suffix_len(&old[prefix..], &new[prefix..]);
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+    if old_mid.is_empty() && new_mid.is_empty() {
+        return Vec::new();
+    }
+
+    let diff = TextDiff::configure()
+        .algorithm(Algorithm::Myers)
+        .timeout(DIFF_TIMEOUT)
+        .diff_chars(old_mid, new_mid);
+    let mut splices = Vec::new();
+    let mut pending: Option<TextSplice> = None;
+    let mut pos = prefix;
+    for change in diff.iter_all_changes() {
+        let value = change.value();
+        match change.tag() {
+            ChangeTag::Equal => {
+                if let Some(splice) = pending.take() {
+                    pos += splice.insert.len();
+                    splices.push(splice);
+                }
+                pos += value.len();
+            }
+            ChangeTag::Delete => {
+                pending.get_or_insert_with(|| empty_splice(pos)).delete += value.len();
+            }
+            ChangeTag::Insert => {
+                pending
+                    .get_or_insert_with(|| empty_splice(pos))
+                    .insert
+                    .push_str(value);
+            }
+        }
+    }
+    splices.extend(pending);
+    splices
+}
+
+fn empty_splice(index: usize) -> TextSplice {
+    TextSplice {
+        index,
+        delete: 0,
+        insert: String::new(),
+    }
+}
+
+fn byte_offset(text: &str, chars: usize) -> Option<usize> {
+    text.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .nth(chars)
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, ca), cb)| ca != cb)
+        .map(|((i, _), _)| i)
+        .unwrap_or_else(|| a.len().min(b.len()))
+}
+
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(ca, cb)| ca == cb)
+        .map(|(c, _)| c.len_utf8())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::realtime_types::TextOperation;
+
+    fn replay(old: &str, splices: &[TextSplice]) -> String {
+        let mut text = old.to_string();
+        for s in splices {
+            text.replace_range(s.index..s.index + s.delete, &s.insert);
+        }
+        text
+    }
+
+    #[test]
+    fn splices_and_operations_reproduce_target() {
+        let old = "# Título\n\nhello wörld\nkeep me\n";
+        let new = "# Title\n\nhello brave wörld\nkeep me\nbye 👋\n";
+        let splices = minimal_splices(old, new);
+        assert_eq!(replay(old, &splices), new);
+        assert!(
+            splices
+                .iter()
+                .all(|s| s.delete + s.insert.len() < old.len())
+        );
+        assert!(minimal_splices(old, old).is_empty());
+
+        let edit = ContentEdit::Operations(vec![
+            TextOperation {
+                offset: 3,
+                delete: 5,
+                insert: "itle".into(),
+            },
+            TextOperation {
+                offset: 15,
+                delete: 0,
+                insert: "brave ".into(),
+            },
+        ]);
+        assert_eq!(
+            apply_edit(old, &edit).unwrap(),
+            "# Title\n\nhello brave wörld\nkeep me\n"
+        );
+        let out_of_range = ContentEdit::Operations(vec![TextOperation {
+            offset: 40,
+            delete: 1,
+            insert: String::new(),
+        }]);
+        assert!(apply_edit(old, &out_of_range).is_err());
+    }
+}
@@ -1,3 +1,4 @@
 pub mod awareness;
+pub mod content_edit;
 pub mod doc_hydration;
 pub mod snapshot;
@@ -5,17 +5,20 @@ use sha2::{Digest, Sha256};
 use tokio::task;
 use uuid::Uuid;
 use yrs::updates::decoder::Decode;
-use yrs::{Doc, GetString, ReadTxn, StateVector, Transact, Update};
+use yrs::{Doc, GetString, ReadTxn, StateVector, Text, Transact, Update};
 
 use crate::application::linkgraph;
 use crate::application::ports::document_snapshot_archive_repository::{
     DocumentSnapshotArchiveRepository, SnapshotArchiveInsert, SnapshotArchiveRecord,
 };
 use crate::application::ports::linkgraph_repository::LinkGraphRepository;
+use crate::application::ports::property_repository::PropertyRepository;
 use crate::application::ports::realtime_hydration_port::DocStateReader;
 use crate::application::ports::realtime_persistence_port::DocPersistencePort;
+use crate::application::ports::search_index_repository::SearchIndexRepository;
 use crate::application::ports::storage_port::StoragePort;
 use crate::application::ports::tagging_repository::TaggingRepository;
+use crate::application::services::markdown::front_matter;
 use crate::application::services::tagging;
 
 pub struct SnapshotService {
@@ -24,6 +27,8 @@ pub struct SnapshotService {
     storage: Arc<dyn StoragePort>,
     linkgraph_repo: Arc<dyn LinkGraphRepository>,
     tagging_repo: Arc<dyn TaggingRepository>,
+    search_index_repo: Arc<dyn SearchIndexRepository>,
+    property_repo: Arc<dyn PropertyRepository>,
     archive_repo: Arc<dyn DocumentSnapshotArchiveRepository>,
 }
 
@@ -88,6 +93,8 @@ impl SnapshotService {
         storage: Arc<dyn StoragePort>,
         linkgraph_repo: Arc<dyn LinkGraphRepository>,
         tagging_repo: Arc<dyn TaggingRepository>,
+        search_index_repo: Arc<dyn SearchIndexRepository>,
+        property_repo: Arc<dyn PropertyRepository>,
         archive_repo: Arc<dyn DocumentSnapshotArchiveRepository>,
     ) -> Self {
         Self {
@@ -96,6 +103,8 @@ impl SnapshotService {
             storage,
             linkgraph_repo,
             tagging_repo,
+            search_index_repo,
+            property_repo,
             archive_repo,
         }
     }
@@ -169,6 +178,21 @@ impl SnapshotService {
         })
     }
 
+    /// Stores `markdown` as the initial state of a document that has no Yjs history yet,
+    /// then writes the file and refreshes links, tags and the search index.
+    pub async fn seed_markdown(&self, doc_id: &Uuid, markdown: &str) -> anyhow::Result<()> {
+        let doc = Doc::new();
+        {
+            let txt = doc.get_or_insert_text("content");
+            let mut txn = doc.transact_mut();
+            txt.insert(&mut txn, 0, markdown);
+        }
+        self.persist_snapshot(doc_id, &doc, SnapshotPersistOptions::default())
+            .await?;
+        self.write_markdown(doc_id, &doc).await?;
+        Ok(())
+    }
+
     pub async fn write_markdown(
         &self,
         doc_id: &Uuid,
@@ -199,10 +223,10 @@ impl SnapshotService {
         if should_write {
             self.storage.write_bytes(path.as_path(), &bytes).await?;
         }
-        if let Some(owner_id) = record.owner_id {
+        if let Some(workspace_id) = record.workspace_id {
             let _ = linkgraph::update_document_links(
                 self.linkgraph_repo.as_ref(),
-                owner_id,
+                workspace_id,
                 *doc_id,
                 &contents,
             )
@@ -210,11 +234,26 @@ impl SnapshotService {
             let _ = tagging::update_document_tags(
                 self.tagging_repo.as_ref(),
                 *doc_id,
-                owner_id,
+                workspace_id,
                 &contents,
             )
             .await;
         }
+        if let Err(e) = self
+            .search_index_repo
+            .upsert_document_content(*doc_id, &contents)
+            .await
+        {
+            tracing::warn!(document_id = %doc_id, error = ?e, "search_index_update_failed");
+        }
+        let properties = front_matter::parse_front_matter(&contents).unwrap_or_default();
+        if let Err(e) = self
+            .property_repo
+            .set_document_properties(*doc_id, &serde_json::Value::Object(properties))
+            .await
+        {
+            tracing::warn!(document_id = %doc_id, error = ?e, "document_properties_update_failed");
+        }
         Ok(MarkdownPersistResult {
             written: should_write,
         })
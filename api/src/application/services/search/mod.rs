@@ -0,0 +1,107 @@
+//! Translates user search input into a Postgres `tsquery` expression.
+//!
+//! Supported syntax: bare words (AND-ed), `"quoted phrases"`, `prefix*`,
+//! `-excluded` terms and `OR` between terms.
+
+enum Token {
+    Term(String),
+    Phrase(Vec<String>),
+    Or,
+}
+
+fn tokenize(input: &str) -> Vec<(bool, Token)> {
+    let mut out = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let negated = c == '-';
+        if negated {
+            chars.next();
+        }
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            let mut phrase = String::new();
+            for ch in chars.by_ref() {
+                if ch == '"' {
+                    break;
+                }
+                phrase.push(ch);
+            }
+            let words: Vec<String> = phrase.split_whitespace().map(str::to_string).collect();
+            if !words.is_empty() {
+                out.push((negated, Token::Phrase(words)));
+            }
+            continue;
+        }
+        let mut word = String::new();
+        while let Some(&ch) = chars.peek() {
+            if ch.is_whitespace() {
+                break;
+            }
+            word.push(ch);
+            chars.next();
+        }
+        if word == "OR" && !negated {
+            out.push((false, Token::Or));
+        } else if !word.is_empty() {
+            out.push((negated, Token::Term(word)));
+        }
+    }
+    out
+}
+
+fn lexeme(word: &str) -> String {
+    format!("'{}'", word.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+/// Returns `None` when the input has no searchable terms.
+pub fn build_tsquery(input: &str) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut pending_or = false;
+    for (negated, token) in tokenize(input) {
+        let expr = match token {
+            Token::Or => {
+                pending_or = !parts.is_empty();
+                continue;
+            }
+            Token::Term(word) => match word.strip_suffix('*') {
+                Some(stem) if !stem.is_empty() => format!("{}:*", lexeme(stem)),
+                Some(_) => continue,
+                None => lexeme(&word),
+            },
+            Token::Phrase(words) => {
+                let joined: Vec<String> = words.iter().map(|w| lexeme(w)).collect();
+                format!("({})", joined.join(" <-> "))
+            }
+        };
+        let expr = if negated { format!("!{expr}") } else { expr };
+        if !parts.is_empty() {
+            parts.push(if pending_or { "|" } else { "&" }.to_string());
+        }
+        parts.push(expr);
+        pending_or = false;
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_phrase_prefix_and_negation() {
+        assert_eq!(
+            build_tsquery(r#"retro "action items" plan* -draft OR notes"#).as_deref(),
+            Some("'retro' & ('action' <-> 'items') & 'plan':* & !'draft' | 'notes'")
+        );
+        assert_eq!(build_tsquery("it's").as_deref(), Some("'it''s'"));
+        assert_eq!(build_tsquery("  * \"\" OR ").as_deref(), None);
+    }
+}
@@ -11,7 +11,7 @@ static TAG_RE: Lazy<Regex> = Lazy::new(|| {
 pub async fn update_document_tags<R: TaggingRepository + ?Sized>(
     repo: &R,
     doc_id: Uuid,
-    owner_id: Uuid,
+    workspace_id: Uuid,
     content: &str,
 ) -> anyhow::Result<()> {
     use std::collections::HashSet;
@@ -33,8 +33,8 @@ pub async fn update_document_tags<R: TaggingRepository + ?Sized>(
     for name in set {
         // upsert tag (global unique by name)
         let tag_id = repo.upsert_tag_return_id(&name).await?;
-        // associate if document belongs to owner
-        if repo.owner_doc_exists(doc_id, owner_id).await? {
+        // associate if document belongs to the workspace
+        if repo.workspace_doc_exists(doc_id, workspace_id).await? {
             repo.associate_document_tag(doc_id, tag_id).await?;
         }
     }
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+static VARIABLE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{\s*([A-Za-z0-9_.-]+)\s*\}\}").unwrap());
+
+/// Substitutes `{{name}}` placeholders; unknown names are left as written.
+pub fn render_template(text: &str, variables: &HashMap<String, String>) -> String {
+    VARIABLE_RE
+        .replace_all(text, |caps: &Captures| match variables.get(&caps[1]) {
+            Some(value) => value.clone(),
+            None => caps[0].to_string(),
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_known_variables_only() {
+        let vars = HashMap::from([
+            ("title".to_string(), "Weekly sync".to_string()),
+            ("date".to_string(), "2025-11-19".to_string()),
+        ]);
+        let text = "# {{ title }}\n{{date}} – {{project}}";
+        assert_eq!(
+            render_template(text, &vars),
+            "# Weekly sync\n2025-11-19 – {{project}}"
+        );
+    }
+}
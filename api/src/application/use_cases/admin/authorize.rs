@@ -0,0 +1,16 @@
+use uuid::Uuid;
+
+use crate::application::ports::user_repository::{UserRepository, UserRow};
+
+pub struct AuthorizeAdmin<'a, U: UserRepository + ?Sized> {
+    pub users: &'a U,
+}
+
+impl<'a, U: UserRepository + ?Sized> AuthorizeAdmin<'a, U> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<UserRow> {
+        match self.users.find_by_id(user_id).await? {
+            Some(user) if user.is_admin() && user.disabled_at.is_none() => Ok(user),
+            _ => anyhow::bail!("forbidden"),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::AdminRepository;
+use crate::application::ports::storage_port::StoragePort;
+
+pub struct ForceDeleteDocument<'a, A, S>
+where
+    A: AdminRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub admin: &'a A,
+    pub storage: &'a S,
+}
+
+impl<'a, A, S> ForceDeleteDocument<'a, A, S>
+where
+    A: AdminRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub async fn execute(&self, actor_id: Uuid, document_id: Uuid) -> anyhow::Result<()> {
+        let Some(dtype) = self.admin.delete_document(document_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        let res = if dtype == "folder" {
+            self.storage
+                .delete_folder_physical(document_id)
+                .await
+                .map(|_| ())
+        } else {
+            self.storage.delete_doc_physical(document_id).await
+        };
+        if let Err(err) = res {
+            tracing::warn!(document_id = %document_id, error = ?err, "failed to remove document artifacts during admin deletion");
+        }
+        self.admin
+            .record_audit(
+                actor_id,
+                "document.delete",
+                "document",
+                Some(&document_id.to_string()),
+                &serde_json::json!({ "type": dtype }),
+            )
+            .await
+    }
+}
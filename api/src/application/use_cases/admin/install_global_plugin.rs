@@ -0,0 +1,72 @@
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::AdminRepository;
+use crate::application::ports::plugin_event_publisher::{PluginEventPublisher, PluginScopedEvent};
+use crate::application::ports::plugin_installer::{InstalledPlugin, PluginInstaller};
+use crate::application::ports::plugin_package_fetcher::PluginPackageFetcher;
+use crate::application::use_cases::plugins::install_from_url::InstallPluginError;
+
+pub struct InstallGlobalPlugin<'a, F, I, E, A>
+where
+    F: PluginPackageFetcher + ?Sized,
+    I: PluginInstaller + ?Sized,
+    E: PluginEventPublisher + ?Sized,
+    A: AdminRepository + ?Sized,
+{
+    pub fetcher: &'a F,
+    pub installer: &'a I,
+    pub events: &'a E,
+    pub admin: &'a A,
+}
+
+impl<'a, F, I, E, A> InstallGlobalPlugin<'a, F, I, E, A>
+where
+    F: PluginPackageFetcher + ?Sized,
+    I: PluginInstaller + ?Sized,
+    E: PluginEventPublisher + ?Sized,
+    A: AdminRepository + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        url: &str,
+        token: Option<&str>,
+    ) -> Result<InstalledPlugin, InstallPluginError> {
+        let bytes = self
+            .fetcher
+            .fetch(url, token)
+            .await
+            .map_err(InstallPluginError::Download)?;
+        let installed = self
+            .installer
+            .install_global(&bytes)
+            .await
+            .map_err(InstallPluginError::Install)?;
+
+        self.admin
+            .record_audit(
+                actor_id,
+                "plugin.install_global",
+                "plugin",
+                Some(&installed.id),
+                &serde_json::json!({ "version": installed.version, "url": url }),
+            )
+            .await
+            .map_err(InstallPluginError::Persist)?;
+
+        // No user scope: every workspace stream receives it
+        let event = PluginScopedEvent {
+            user_id: None,
+            payload: serde_json::json!({
+                "event": "installed",
+                "id": installed.id,
+                "version": installed.version,
+            }),
+        };
+        self.events
+            .publish(&event)
+            .await
+            .map_err(InstallPluginError::Event)?;
+        Ok(installed)
+    }
+}
@@ -0,0 +1,11 @@
+use crate::application::ports::admin_repository::{AdminRepository, InstanceStats};
+
+pub struct GetInstanceStats<'a, A: AdminRepository + ?Sized> {
+    pub admin: &'a A,
+}
+
+impl<'a, A: AdminRepository + ?Sized> GetInstanceStats<'a, A> {
+    pub async fn execute(&self) -> anyhow::Result<InstanceStats> {
+        self.admin.instance_stats().await
+    }
+}
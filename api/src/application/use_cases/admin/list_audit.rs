@@ -0,0 +1,20 @@
+use crate::application::ports::admin_repository::{AdminAuditRow, AdminRepository};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 500;
+
+pub struct ListAuditLog<'a, A: AdminRepository + ?Sized> {
+    pub admin: &'a A,
+}
+
+impl<'a, A: AdminRepository + ?Sized> ListAuditLog<'a, A> {
+    pub async fn execute(
+        &self,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> anyhow::Result<Vec<AdminAuditRow>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let offset = offset.unwrap_or(0).max(0);
+        self.admin.list_audit(limit, offset).await
+    }
+}
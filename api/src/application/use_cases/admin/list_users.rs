@@ -0,0 +1,37 @@
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::{AdminRepository, AdminUserRow};
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+pub struct ListUsers<'a, A: AdminRepository + ?Sized> {
+    pub admin: &'a A,
+}
+
+impl<'a, A: AdminRepository + ?Sized> ListUsers<'a, A> {
+    pub async fn execute(
+        &self,
+        query: Option<&str>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> anyhow::Result<Vec<AdminUserRow>> {
+        let query = query.map(str::trim).filter(|q| !q.is_empty());
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let offset = offset.unwrap_or(0).max(0);
+        self.admin.list_users(query, limit, offset).await
+    }
+}
+
+pub struct GetUser<'a, A: AdminRepository + ?Sized> {
+    pub admin: &'a A,
+}
+
+impl<'a, A: AdminRepository + ?Sized> GetUser<'a, A> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<AdminUserRow> {
+        match self.admin.get_user(user_id).await? {
+            Some(user) => Ok(user),
+            None => anyhow::bail!("not_found"),
+        }
+    }
+}
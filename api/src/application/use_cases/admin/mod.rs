@@ -0,0 +1,9 @@
+pub mod authorize;
+pub mod delete_document;
+pub mod install_global_plugin;
+pub mod instance_stats;
+pub mod list_audit;
+pub mod list_users;
+pub mod send_password_reset;
+pub mod set_user_disabled;
+pub mod set_user_role;
@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::AdminRepository;
+use crate::application::ports::auth_link_repository::AuthLinkRepository;
+use crate::application::ports::mailer::Mailer;
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::use_cases::auth::password_reset::RequestPasswordReset;
+
+pub struct SendPasswordReset<'a, A, U, L, M>
+where
+    A: AdminRepository + ?Sized,
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    pub admin: &'a A,
+    pub users: &'a U,
+    pub links: &'a L,
+    pub mailer: &'a M,
+    pub signing_secret: &'a str,
+    /// Frontend origin the link points at
+    pub base_url: &'a str,
+}
+
+impl<'a, A, U, L, M> SendPasswordReset<'a, A, U, L, M>
+where
+    A: AdminRepository + ?Sized,
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    /// Mails the user a reset link; the admin never learns or sets the password.
+    pub async fn execute(&self, actor_id: Uuid, user_id: Uuid) -> anyhow::Result<()> {
+        let Some(user) = self.users.find_by_id(user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        let reset = RequestPasswordReset {
+            users: self.users,
+            links: self.links,
+            mailer: self.mailer,
+            signing_secret: self.signing_secret,
+            base_url: self.base_url,
+        };
+        reset.execute(&user.email).await?;
+        self.admin
+            .record_audit(
+                actor_id,
+                "user.password_reset",
+                "user",
+                Some(&user_id.to_string()),
+                &serde_json::json!({}),
+            )
+            .await
+    }
+}
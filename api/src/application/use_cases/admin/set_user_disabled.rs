@@ -0,0 +1,270 @@
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::{AdminRepository, AdminUserRow};
+use crate::application::ports::session_repository::SessionRepository;
+
+pub struct SetUserDisabled<'a, A, S>
+where
+    A: AdminRepository + ?Sized,
+    S: SessionRepository + ?Sized,
+{
+    pub admin: &'a A,
+    pub sessions: &'a S,
+}
+
+impl<'a, A, S> SetUserDisabled<'a, A, S>
+where
+    A: AdminRepository + ?Sized,
+    S: SessionRepository + ?Sized,
+{
+    /// Disabling also signs the account out everywhere.
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        user_id: Uuid,
+        disabled: bool,
+    ) -> anyhow::Result<AdminUserRow> {
+        if actor_id == user_id {
+            anyhow::bail!("bad_request");
+        }
+        if !self.admin.set_disabled(user_id, disabled).await? {
+            anyhow::bail!("not_found");
+        }
+        let revoked = if disabled {
+            self.sessions.revoke_all(user_id).await?
+        } else {
+            0
+        };
+        let action = if disabled {
+            "user.disable"
+        } else {
+            "user.enable"
+        };
+        self.admin
+            .record_audit(
+                actor_id,
+                action,
+                "user",
+                Some(&user_id.to_string()),
+                &serde_json::json!({ "sessions_revoked": revoked }),
+            )
+            .await?;
+        match self.admin.get_user(user_id).await? {
+            Some(user) => Ok(user),
+            None => anyhow::bail!("not_found"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::application::ports::admin_repository::{AdminAuditRow, InstanceStats};
+    use crate::application::use_cases::auth::create_session::CreateSession;
+    use crate::application::use_cases::auth::refresh_session::RefreshSession;
+    use crate::application::use_cases::auth::testing::FakeSessions;
+
+    #[derive(Default)]
+    struct FakeAdmin {
+        users: Mutex<Vec<AdminUserRow>>,
+        audit: Mutex<Vec<AdminAuditRow>>,
+    }
+
+    impl FakeAdmin {
+        fn add_user(&self) -> Uuid {
+            let id = Uuid::new_v4();
+            self.users.lock().unwrap().push(AdminUserRow {
+                id,
+                email: format!("{id}@example.test"),
+                name: "user".to_string(),
+                role: "user".to_string(),
+                email_verified_at: None,
+                disabled_at: None,
+                created_at: chrono::Utc::now(),
+                document_count: 0,
+                storage_bytes: 0,
+            });
+            id
+        }
+    }
+
+    #[async_trait]
+    impl AdminRepository for FakeAdmin {
+        async fn list_users(
+            &self,
+            _query: Option<&str>,
+            _limit: i64,
+            _offset: i64,
+        ) -> anyhow::Result<Vec<AdminUserRow>> {
+            Ok(self.users.lock().unwrap().clone())
+        }
+
+        async fn get_user(&self, id: Uuid) -> anyhow::Result<Option<AdminUserRow>> {
+            Ok(self
+                .users
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|u| u.id == id)
+                .cloned())
+        }
+
+        async fn set_disabled(&self, id: Uuid, disabled: bool) -> anyhow::Result<bool> {
+            let mut users = self.users.lock().unwrap();
+            let Some(user) = users.iter_mut().find(|u| u.id == id) else {
+                return Ok(false);
+            };
+            user.disabled_at = disabled.then(chrono::Utc::now);
+            Ok(true)
+        }
+
+        async fn set_role(&self, id: Uuid, role: &str) -> anyhow::Result<bool> {
+            let mut users = self.users.lock().unwrap();
+            let Some(user) = users.iter_mut().find(|u| u.id == id) else {
+                return Ok(false);
+            };
+            user.role = role.to_string();
+            Ok(true)
+        }
+
+        async fn promote_admins(&self, emails: &[String]) -> anyhow::Result<u64> {
+            let mut promoted = 0;
+            for user in self.users.lock().unwrap().iter_mut() {
+                if emails.contains(&user.email) && user.role != "admin" {
+                    user.role = "admin".to_string();
+                    promoted += 1;
+                }
+            }
+            Ok(promoted)
+        }
+
+        async fn instance_stats(&self) -> anyhow::Result<InstanceStats> {
+            let users = self.users.lock().unwrap();
+            Ok(InstanceStats {
+                users: users.len() as i64,
+                admins: users.iter().filter(|u| u.role == "admin").count() as i64,
+                disabled_users: users.iter().filter(|u| u.disabled_at.is_some()).count() as i64,
+                workspaces: 0,
+                documents: 0,
+                files: 0,
+                storage_bytes: 0,
+            })
+        }
+
+        async fn delete_document(&self, _id: Uuid) -> anyhow::Result<Option<String>> {
+            Ok(None)
+        }
+
+        async fn record_audit(
+            &self,
+            actor_id: Uuid,
+            action: &str,
+            target_type: &str,
+            target_id: Option<&str>,
+            details: &serde_json::Value,
+        ) -> anyhow::Result<()> {
+            self.audit.lock().unwrap().push(AdminAuditRow {
+                id: Uuid::new_v4(),
+                actor_id: Some(actor_id),
+                actor_email: None,
+                action: action.to_string(),
+                target_type: target_type.to_string(),
+                target_id: target_id.map(str::to_string),
+                details: details.clone(),
+                created_at: chrono::Utc::now(),
+            });
+            Ok(())
+        }
+
+        async fn list_audit(&self, limit: i64, offset: i64) -> anyhow::Result<Vec<AdminAuditRow>> {
+            Ok(self
+                .audit
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn disabling_signs_the_user_out_everywhere() {
+        let admin = FakeAdmin::default();
+        let sessions = FakeSessions::default();
+        let actor = admin.add_user();
+        let user = admin.add_user();
+        let create = CreateSession {
+            sessions: &sessions,
+        };
+        let laptop = create.execute(user, None, None, 3600).await.unwrap();
+        let phone = create.execute(user, None, None, 3600).await.unwrap();
+        let actor_session = create.execute(actor, None, None, 3600).await.unwrap();
+        let uc = SetUserDisabled {
+            admin: &admin,
+            sessions: &sessions,
+        };
+
+        let row = uc.execute(actor, user, true).await.unwrap();
+        assert!(row.disabled_at.is_some());
+        // Access tokens die with their session, and refresh tokens no longer work
+        assert!(!sessions.touch(laptop.session.id, user).await.unwrap());
+        assert!(!sessions.touch(phone.session.id, user).await.unwrap());
+        let refresh = RefreshSession {
+            sessions: &sessions,
+        };
+        assert!(
+            refresh
+                .execute(&phone.refresh_token, None, None, 3600)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            sessions
+                .touch(actor_session.session.id, actor)
+                .await
+                .unwrap()
+        );
+        {
+            let audit = admin.audit.lock().unwrap();
+            let user_id = user.to_string();
+            assert_eq!(audit[0].action, "user.disable");
+            assert_eq!(audit[0].target_id.as_deref(), Some(user_id.as_str()));
+            assert_eq!(audit[0].details["sessions_revoked"], 2);
+        }
+
+        // Re-enabling does not bring old sessions back
+        let row = uc.execute(actor, user, false).await.unwrap();
+        assert!(row.disabled_at.is_none());
+        assert!(!sessions.touch(laptop.session.id, user).await.unwrap());
+        assert_eq!(admin.audit.lock().unwrap()[1].action, "user.enable");
+    }
+
+    #[tokio::test]
+    async fn admins_cannot_disable_themselves_or_unknown_users() {
+        let admin = FakeAdmin::default();
+        let sessions = FakeSessions::default();
+        let actor = admin.add_user();
+        let uc = SetUserDisabled {
+            admin: &admin,
+            sessions: &sessions,
+        };
+        let err = |r: anyhow::Result<AdminUserRow>| r.err().map(|e| e.to_string());
+        assert_eq!(
+            err(uc.execute(actor, actor, true).await).as_deref(),
+            Some("bad_request")
+        );
+        assert_eq!(
+            err(uc.execute(actor, Uuid::new_v4(), true).await).as_deref(),
+            Some("not_found")
+        );
+        assert!(admin.audit.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::{AdminRepository, AdminUserRow};
+
+pub struct SetUserRole<'a, A: AdminRepository + ?Sized> {
+    pub admin: &'a A,
+}
+
+impl<'a, A: AdminRepository + ?Sized> SetUserRole<'a, A> {
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        user_id: Uuid,
+        role: &str,
+    ) -> anyhow::Result<AdminUserRow> {
+        if !matches!(role, "user" | "admin") {
+            anyhow::bail!("bad_request");
+        }
+        // Admins cannot demote themselves and lock the instance out
+        if actor_id == user_id && role != "admin" {
+            anyhow::bail!("bad_request");
+        }
+        if !self.admin.set_role(user_id, role).await? {
+            anyhow::bail!("not_found");
+        }
+        self.admin
+            .record_audit(
+                actor_id,
+                "user.role",
+                "user",
+                Some(&user_id.to_string()),
+                &serde_json::json!({ "role": role }),
+            )
+            .await?;
+        match self.admin.get_user(user_id).await? {
+            Some(user) => Ok(user),
+            None => anyhow::bail!("not_found"),
+        }
+    }
+}
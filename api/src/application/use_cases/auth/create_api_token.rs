@@ -0,0 +1,67 @@
+use uuid::Uuid;
+
+use crate::application::ports::api_token_repository::{ApiTokenRepository, ApiTokenRow};
+use crate::application::services::auth::scopes::ApiTokenScope;
+use crate::application::services::auth::tokens::{generate_api_token, hash_token};
+
+const MAX_NAME_LEN: usize = 100;
+// Enough of the token to recognise it in a list without weakening it
+const DISPLAY_PREFIX_LEN: usize = 16;
+
+pub struct IssuedApiToken {
+    pub token: ApiTokenRow,
+    /// Plain token; shown once, only its hash is stored
+    pub secret: String,
+}
+
+pub struct CreateApiToken<'a, R: ApiTokenRepository + ?Sized> {
+    pub tokens: &'a R,
+}
+
+impl<'a, R: ApiTokenRepository + ?Sized> CreateApiToken<'a, R> {
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        scopes: &[String],
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> anyhow::Result<IssuedApiToken> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            anyhow::bail!("bad_request");
+        }
+        let mut normalized: Vec<String> = Vec::new();
+        for scope in scopes {
+            let Some(parsed) = ApiTokenScope::parse(scope.trim()) else {
+                anyhow::bail!("bad_request");
+            };
+            let s = parsed.as_str().to_string();
+            if !normalized.contains(&s) {
+                normalized.push(s);
+            }
+        }
+        if normalized.is_empty() {
+            anyhow::bail!("bad_request");
+        }
+        if let Some(exp) = expires_at
+            && exp <= chrono::Utc::now()
+        {
+            anyhow::bail!("bad_request");
+        }
+
+        let secret = generate_api_token();
+        let prefix: String = secret.chars().take(DISPLAY_PREFIX_LEN).collect();
+        let token = self
+            .tokens
+            .create(
+                user_id,
+                name,
+                &hash_token(&secret),
+                &prefix,
+                &normalized,
+                expires_at,
+            )
+            .await?;
+        Ok(IssuedApiToken { token, secret })
+    }
+}
@@ -0,0 +1,41 @@
+use uuid::Uuid;
+
+use crate::application::ports::session_repository::{SessionRepository, SessionRow};
+use crate::application::services::auth::tokens::{generate_opaque_token, hash_token};
+
+pub struct IssuedSession {
+    pub session: SessionRow,
+    /// Plain refresh token; only its hash is stored
+    pub refresh_token: String,
+}
+
+pub struct CreateSession<'a, R: SessionRepository + ?Sized> {
+    pub sessions: &'a R,
+}
+
+impl<'a, R: SessionRepository + ?Sized> CreateSession<'a, R> {
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+        ttl_secs: i64,
+    ) -> anyhow::Result<IssuedSession> {
+        let refresh_token = generate_opaque_token();
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(ttl_secs.max(60));
+        let session = self
+            .sessions
+            .create(
+                user_id,
+                &hash_token(&refresh_token),
+                user_agent,
+                ip_address,
+                expires_at,
+            )
+            .await?;
+        Ok(IssuedSession {
+            session,
+            refresh_token,
+        })
+    }
+}
@@ -10,19 +10,24 @@ use crate::application::ports::plugin_asset_store::PluginAssetStore;
 use crate::application::ports::plugin_installation_repository::PluginInstallationRepository;
 use crate::application::ports::plugin_repository::PluginRepository;
 use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::takeout_repository::TakeoutRepository;
 use crate::application::ports::user_repository::UserRepository;
+use crate::application::ports::workspace_repository::WorkspaceRepository;
 
-pub struct DeleteAccount<'a, UR, DR, SP, PIR, PR, GR, GW>
+pub struct DeleteAccount<'a, UR, WR, DR, SP, PIR, PR, GR, GW, TK>
 where
     UR: UserRepository + ?Sized,
+    WR: WorkspaceRepository + ?Sized,
     DR: DocumentRepository + ?Sized,
     SP: StoragePort + ?Sized,
     PIR: PluginInstallationRepository + ?Sized,
     PR: PluginRepository + ?Sized,
     GR: GitRepository + ?Sized,
     GW: GitWorkspacePort + ?Sized,
+    TK: TakeoutRepository + ?Sized,
 {
     pub user_repo: &'a UR,
+    pub workspace_repo: &'a WR,
     pub document_repo: &'a DR,
     pub storage: &'a SP,
     pub plugin_installations: &'a PIR,
@@ -30,41 +35,63 @@ where
     pub plugin_assets: Arc<dyn PluginAssetStore>,
     pub git_repo: &'a GR,
     pub git_workspace: &'a GW,
+    pub takeouts: &'a TK,
 }
 
-impl<'a, UR, DR, SP, PIR, PR, GR, GW> DeleteAccount<'a, UR, DR, SP, PIR, PR, GR, GW>
+impl<'a, UR, WR, DR, SP, PIR, PR, GR, GW, TK> DeleteAccount<'a, UR, WR, DR, SP, PIR, PR, GR, GW, TK>
 where
     UR: UserRepository + ?Sized,
+    WR: WorkspaceRepository + ?Sized,
     DR: DocumentRepository + ?Sized,
     SP: StoragePort + ?Sized,
     PIR: PluginInstallationRepository + ?Sized,
     PR: PluginRepository + ?Sized,
     GR: GitRepository + ?Sized,
     GW: GitWorkspacePort + ?Sized,
+    TK: TakeoutRepository + ?Sized,
 {
     pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<()> {
+        // Documents in workspaces shared with others stay behind with a remaining member.
+        self.workspace_repo.release_user(user_id).await?;
+
+        // The personal workspace shares the user's id; shared workspaces where the user is
+        // the last member are removed together with the account.
+        let mut workspace_ids = vec![user_id];
+        for ws in self.workspace_repo.list_for_user(user_id).await? {
+            if !ws.is_personal && self.workspace_repo.list_members(ws.id).await?.len() == 1 {
+                workspace_ids.push(ws.id);
+            }
+        }
+
         let doc_ids = self.document_repo.list_ids_for_user(user_id).await?;
 
-        let installations = self.plugin_installations.list_for_user(user_id).await?;
-        for inst in &installations {
-            let plugin_id = inst.plugin_id.clone();
-            let plugin_for_log = plugin_id.clone();
-            let assets = Arc::clone(&self.plugin_assets);
-            match task::spawn_blocking(move || assets.remove_user_plugin_dir(&user_id, &plugin_id))
+        for workspace_id in workspace_ids.iter().copied() {
+            let installations = self
+                .plugin_installations
+                .list_for_user(workspace_id)
+                .await?;
+            for inst in &installations {
+                let plugin_id = inst.plugin_id.clone();
+                let plugin_for_log = plugin_id.clone();
+                let assets = Arc::clone(&self.plugin_assets);
+                match task::spawn_blocking(move || {
+                    assets.remove_user_plugin_dir(&workspace_id, &plugin_id)
+                })
                 .await
-            {
-                Ok(Ok(())) => {}
-                Ok(Err(err)) => {
-                    tracing::warn!(user_id = %user_id, plugin_id = %plugin_for_log, error = ?err, "failed to remove plugin assets for user");
-                }
-                Err(err) => {
-                    tracing::warn!(user_id = %user_id, plugin_id = %plugin_for_log, error = ?err, "failed to join plugin asset removal task");
+                {
+                    Ok(Ok(())) => {}
+                    Ok(Err(err)) => {
+                        tracing::warn!(user_id = %user_id, workspace_id = %workspace_id, plugin_id = %plugin_for_log, error = ?err, "failed to remove plugin assets for user");
+                    }
+                    Err(err) => {
+                        tracing::warn!(user_id = %user_id, workspace_id = %workspace_id, plugin_id = %plugin_for_log, error = ?err, "failed to join plugin asset removal task");
+                    }
                 }
             }
+            self.plugin_installations
+                .remove_all_for_user(workspace_id)
+                .await?;
         }
-        self.plugin_installations
-            .remove_all_for_user(user_id)
-            .await?;
 
         self.plugin_repo
             .delete_scoped_kv("user", &[user_id])
@@ -86,12 +113,28 @@ where
             }
         }
 
-        self.git_repo.delete_sync_logs(user_id).await?;
-        if let Err(err) = self.git_workspace.remove_repository(user_id).await {
-            tracing::warn!(user_id = %user_id, error = ?err, "failed to remove git workspace during account deletion");
+        for workspace_id in workspace_ids.iter().copied() {
+            self.git_repo.delete_sync_logs(workspace_id).await?;
+            if let Err(err) = self.git_workspace.remove_repository(workspace_id).await {
+                tracing::warn!(user_id = %user_id, workspace_id = %workspace_id, error = ?err, "failed to remove git workspace during account deletion");
+            }
+            let _ = self.git_repo.delete_config(workspace_id).await?;
+            self.git_repo.delete_repository_state(workspace_id).await?;
         }
-        let _ = self.git_repo.delete_config(user_id).await?;
-        self.git_repo.delete_repository_state(user_id).await?;
+
+        for job in self.takeouts.list_jobs(user_id).await? {
+            let Some(path) = job.storage_path else {
+                continue;
+            };
+            let abs = self.storage.absolute_from_relative(&path);
+            if let Err(err) = self.storage.delete_bytes(abs.as_path()).await {
+                tracing::warn!(user_id = %user_id, job_id = %job.id, error = ?err, "failed to remove takeout archive during account deletion");
+            }
+        }
+
+        self.workspace_repo
+            .delete_sole_member_workspaces(user_id)
+            .await?;
 
         let deleted = self.user_repo.delete_user(user_id).await?;
         anyhow::ensure!(deleted, "user not found");
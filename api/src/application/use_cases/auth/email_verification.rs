@@ -0,0 +1,112 @@
+use crate::application::ports::auth_link_repository::AuthLinkRepository;
+use crate::application::ports::mailer::{MailMessage, Mailer};
+use crate::application::ports::user_repository::{UserRepository, UserRow};
+use crate::application::services::auth::signed_links::{self, PURPOSE_EMAIL_VERIFICATION};
+use crate::application::services::auth::tokens::hash_token;
+
+const VERIFICATION_LINK_TTL_SECS: i64 = 48 * 60 * 60;
+
+pub struct SendEmailVerification<'a, U, L, M>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    pub users: &'a U,
+    pub links: &'a L,
+    pub mailer: &'a M,
+    pub signing_secret: &'a str,
+    /// Frontend origin the link points at
+    pub base_url: &'a str,
+}
+
+impl<'a, U, L, M> SendEmailVerification<'a, U, L, M>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    pub async fn execute(&self, user: &UserRow) -> anyhow::Result<()> {
+        if user.email_verified_at.is_some() {
+            return Ok(());
+        }
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(VERIFICATION_LINK_TTL_SECS);
+        let (token, nonce) = signed_links::sign(
+            self.signing_secret,
+            PURPOSE_EMAIL_VERIFICATION,
+            user.id,
+            expires_at,
+        );
+        self.links
+            .create(
+                user.id,
+                PURPOSE_EMAIL_VERIFICATION,
+                &hash_token(&nonce),
+                expires_at,
+            )
+            .await?;
+        let link = format!(
+            "{}/auth/verify-email?token={}",
+            self.base_url.trim_end_matches('/'),
+            token
+        );
+        self.mailer
+            .send(&MailMessage {
+                to: user.email.clone(),
+                subject: "Confirm your email for RefMD".into(),
+                body: format!(
+                    "Hi {},\n\nPlease confirm this address for your RefMD account:\n\n{}\n\nThe link is valid for 48 hours.\n",
+                    user.name, link
+                ),
+            })
+            .await
+    }
+
+    /// Resend by address; silent for unknown or already verified accounts.
+    pub async fn execute_for_email(&self, email: &str) -> anyhow::Result<()> {
+        match self.users.find_by_email(email.trim()).await? {
+            Some(user) => self.execute(&user).await,
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct VerifyEmail<'a, U, L>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+{
+    pub users: &'a U,
+    pub links: &'a L,
+    pub signing_secret: &'a str,
+}
+
+impl<'a, U, L> VerifyEmail<'a, U, L>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+{
+    pub async fn execute(&self, token: &str) -> anyhow::Result<()> {
+        let Some(claims) =
+            signed_links::verify(self.signing_secret, PURPOSE_EMAIL_VERIFICATION, token)
+        else {
+            anyhow::bail!("invalid_token");
+        };
+        if !self
+            .links
+            .consume(
+                claims.user_id,
+                PURPOSE_EMAIL_VERIFICATION,
+                &hash_token(&claims.nonce),
+            )
+            .await?
+        {
+            anyhow::bail!("invalid_token");
+        }
+        self.users.mark_email_verified(claims.user_id).await?;
+        self.links
+            .invalidate_all(claims.user_id, PURPOSE_EMAIL_VERIFICATION)
+            .await?;
+        Ok(())
+    }
+}
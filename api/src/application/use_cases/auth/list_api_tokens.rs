@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::application::ports::api_token_repository::{ApiTokenRepository, ApiTokenRow};
+
+pub struct ListApiTokens<'a, R: ApiTokenRepository + ?Sized> {
+    pub tokens: &'a R,
+}
+
+impl<'a, R: ApiTokenRepository + ?Sized> ListApiTokens<'a, R> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<Vec<ApiTokenRow>> {
+        self.tokens.list_for_user(user_id).await
+    }
+}
@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::application::ports::session_repository::{SessionRepository, SessionRow};
+
+pub struct ListSessions<'a, R: SessionRepository + ?Sized> {
+    pub sessions: &'a R,
+}
+
+impl<'a, R: SessionRepository + ?Sized> ListSessions<'a, R> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<Vec<SessionRow>> {
+        self.sessions.list_active(user_id).await
+    }
+}
@@ -21,20 +21,24 @@ impl<'a, R: UserRepository + ?Sized> Login<'a, R> {
             Some(r) => r,
             None => return Ok(None),
         };
-        let hash = row.password_hash.clone().unwrap_or_default();
-        let parsed = PasswordHash::new(&hash).map_err(|e| anyhow::anyhow!(e.to_string()))?;
-        if Argon2::default()
-            .verify_password(req.password.as_bytes(), &parsed)
-            .is_ok()
-        {
+        // SSO-only accounts have no password to check
+        let Some(hash) = row.password_hash.clone() else {
+            return Ok(None);
+        };
+        if verify_password(&hash, &req.password)? {
             Ok(Some(UserRow {
-                id: row.id,
-                email: row.email,
-                name: row.name,
                 password_hash: None,
+                ..row
             }))
         } else {
             Ok(None)
         }
     }
 }
+
+pub fn verify_password(hash: &str, password: &str) -> anyhow::Result<bool> {
+    let parsed = PasswordHash::new(hash).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+    Ok(Argon2::default()
+        .verify_password(password.as_bytes(), &parsed)
+        .is_ok())
+}
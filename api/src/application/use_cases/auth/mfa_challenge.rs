@@ -0,0 +1,172 @@
+use uuid::Uuid;
+
+use crate::application::ports::mfa_repository::{MfaRepository, TotpRow};
+use crate::application::services::auth::tokens::{generate_opaque_token, hash_token};
+use crate::application::services::auth::totp;
+
+const CHALLENGE_TTL_SECS: i64 = 5 * 60;
+const MAX_ATTEMPTS: i32 = 5;
+// Failed codes per user, across challenges, before each further attempt waits out a lock
+const MAX_USER_FAILURES: i32 = 10;
+const LOCK_SECS: i64 = 15 * 60;
+
+/// Accepts a current TOTP code (each time step once) or an unused recovery code.
+/// Every attempt counts against the user before it is checked; fails with `locked` while
+/// too many have failed recently.
+pub async fn verify_second_factor<M: MfaRepository + ?Sized>(
+    mfa: &M,
+    totp_row: &TotpRow,
+    code: &str,
+) -> anyhow::Result<bool> {
+    if mfa
+        .begin_second_factor_attempt(totp_row.user_id, MAX_USER_FAILURES, LOCK_SECS)
+        .await?
+        .is_none()
+    {
+        anyhow::bail!("locked");
+    }
+    let ok = check_code(mfa, totp_row, code).await?;
+    if ok {
+        mfa.reset_second_factor_failures(totp_row.user_id).await?;
+    }
+    Ok(ok)
+}
+
+async fn check_code<M: MfaRepository + ?Sized>(
+    mfa: &M,
+    totp_row: &TotpRow,
+    code: &str,
+) -> anyhow::Result<bool> {
+    if let Some(step) = totp::verify(&totp_row.secret, code, chrono::Utc::now().timestamp()) {
+        return mfa.record_totp_step(totp_row.user_id, step).await;
+    }
+    let normalized = totp::normalize_recovery_code(code);
+    if normalized.is_empty() {
+        return Ok(false);
+    }
+    mfa.consume_recovery_code(totp_row.user_id, &hash_token(&normalized))
+        .await
+}
+
+pub struct StartMfaChallenge<'a, M: MfaRepository + ?Sized> {
+    pub mfa: &'a M,
+}
+
+impl<'a, M: MfaRepository + ?Sized> StartMfaChallenge<'a, M> {
+    /// Returns a challenge token when the user has TOTP enabled.
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<Option<String>> {
+        let enabled = self
+            .mfa
+            .get_totp(user_id)
+            .await?
+            .is_some_and(|t| t.enabled_at.is_some());
+        if !enabled {
+            return Ok(None);
+        }
+        let token = generate_opaque_token();
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(CHALLENGE_TTL_SECS);
+        self.mfa
+            .create_challenge(user_id, &hash_token(&token), expires_at)
+            .await?;
+        Ok(Some(token))
+    }
+}
+
+pub struct CompleteMfaChallenge<'a, M: MfaRepository + ?Sized> {
+    pub mfa: &'a M,
+}
+
+impl<'a, M: MfaRepository + ?Sized> CompleteMfaChallenge<'a, M> {
+    /// Verifies the second factor and returns the user id; the challenge is single-use.
+    pub async fn execute(&self, token: &str, code: &str) -> anyhow::Result<Uuid> {
+        let Some(challenge) = self.mfa.find_challenge(&hash_token(token)).await? else {
+            anyhow::bail!("unauthorized");
+        };
+        // Counted up front so concurrent guesses cannot all pass the check
+        let attempts = self.mfa.count_challenge_attempt(challenge.id).await?;
+        if attempts.is_none_or(|n| n > MAX_ATTEMPTS) {
+            self.mfa.delete_challenge(challenge.id).await?;
+            anyhow::bail!("unauthorized");
+        }
+        let Some(totp_row) = self
+            .mfa
+            .get_totp(challenge.user_id)
+            .await?
+            .filter(|t| t.enabled_at.is_some())
+        else {
+            self.mfa.delete_challenge(challenge.id).await?;
+            anyhow::bail!("unauthorized");
+        };
+        if !verify_second_factor(self.mfa, &totp_row, code).await? {
+            anyhow::bail!("invalid_code");
+        }
+        self.mfa.delete_challenge(challenge.id).await?;
+        Ok(challenge.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::auth::testing::FakeMfa;
+
+    const RECOVERY: &str = "abcd-efgh";
+
+    fn fake(user_id: Uuid) -> FakeMfa {
+        let secret = totp::generate_secret();
+        let hash = hash_token(&totp::normalize_recovery_code(RECOVERY));
+        FakeMfa::with_totp(user_id, &secret, &[hash])
+    }
+
+    async fn guess(mfa: &FakeMfa, token: &str, code: &str) -> String {
+        let uc = CompleteMfaChallenge { mfa };
+        match uc.execute(token, code).await {
+            Ok(_) => "ok".to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn failures_lock_the_user_across_challenges() {
+        let user = Uuid::new_v4();
+        let mfa = fake(user);
+        let start = StartMfaChallenge { mfa: &mfa };
+
+        // Fresh challenges do not reset the count
+        let mut failures = 0;
+        while failures < MAX_USER_FAILURES {
+            let token = start.execute(user).await.unwrap().unwrap();
+            for _ in 0..MAX_ATTEMPTS - 1 {
+                if failures == MAX_USER_FAILURES {
+                    break;
+                }
+                assert_eq!(guess(&mfa, &token, "000000").await, "invalid_code");
+                failures += 1;
+            }
+        }
+        assert_eq!(mfa.failed_attempts(user), MAX_USER_FAILURES);
+        // Even the right code waits out the lock
+        let token = start.execute(user).await.unwrap().unwrap();
+        assert_eq!(guess(&mfa, &token, RECOVERY).await, "locked");
+    }
+
+    #[tokio::test]
+    async fn success_clears_failures_and_challenges_are_capped() {
+        let user = Uuid::new_v4();
+        let mfa = fake(user);
+        let start = StartMfaChallenge { mfa: &mfa };
+
+        let token = start.execute(user).await.unwrap().unwrap();
+        for _ in 0..MAX_ATTEMPTS {
+            assert_eq!(guess(&mfa, &token, "000000").await, "invalid_code");
+        }
+        // The challenge is spent, even for a valid code
+        assert_eq!(guess(&mfa, &token, RECOVERY).await, "unauthorized");
+
+        let token = start.execute(user).await.unwrap().unwrap();
+        assert_eq!(guess(&mfa, &token, RECOVERY).await, "ok");
+        assert_eq!(mfa.failed_attempts(user), 0);
+        // Single-use, like the recovery code
+        assert_eq!(guess(&mfa, &token, RECOVERY).await, "unauthorized");
+    }
+}
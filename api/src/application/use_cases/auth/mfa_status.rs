@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::application::ports::mfa_repository::MfaRepository;
+
+pub struct MfaStatus {
+    pub totp_enabled: bool,
+    pub recovery_codes_remaining: i64,
+}
+
+pub struct GetMfaStatus<'a, M: MfaRepository + ?Sized> {
+    pub mfa: &'a M,
+}
+
+impl<'a, M: MfaRepository + ?Sized> GetMfaStatus<'a, M> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<MfaStatus> {
+        let totp_enabled = self
+            .mfa
+            .get_totp(user_id)
+            .await?
+            .is_some_and(|t| t.enabled_at.is_some());
+        let recovery_codes_remaining = if totp_enabled {
+            self.mfa.count_recovery_codes(user_id).await?
+        } else {
+            0
+        };
+        Ok(MfaStatus {
+            totp_enabled,
+            recovery_codes_remaining,
+        })
+    }
+}
@@ -1,4 +1,22 @@
+pub mod create_api_token;
+pub mod create_session;
 pub mod delete_account;
+pub mod email_verification;
+pub mod list_api_tokens;
+pub mod list_sessions;
 pub mod login;
 pub mod me;
+pub mod mfa_challenge;
+pub mod mfa_status;
+pub mod oidc_login;
+pub mod password_reset;
+pub mod profile;
+pub mod refresh_session;
 pub mod register;
+pub mod revoke_api_token;
+pub mod revoke_session;
+pub mod totp_disable;
+pub mod totp_setup;
+
+#[cfg(test)]
+pub(crate) mod testing;
@@ -0,0 +1,296 @@
+use crate::application::ports::oidc_provider::OidcProvider;
+use crate::application::ports::oidc_repository::{OidcLoginStateRow, OidcRepository};
+use crate::application::ports::user_repository::{UserRepository, UserRow};
+use crate::application::services::auth::tokens::{generate_opaque_token, pkce_challenge};
+
+const LOGIN_STATE_TTL_SECS: i64 = 10 * 60;
+
+pub struct BeginOidcLogin<'a, P, R>
+where
+    P: OidcProvider + ?Sized,
+    R: OidcRepository + ?Sized,
+{
+    pub provider: &'a P,
+    pub repo: &'a R,
+}
+
+pub struct OidcRedirect {
+    pub authorization_url: String,
+    pub state: String,
+}
+
+impl<'a, P, R> BeginOidcLogin<'a, P, R>
+where
+    P: OidcProvider + ?Sized,
+    R: OidcRepository + ?Sized,
+{
+    pub async fn execute(&self, redirect_to: Option<&str>) -> anyhow::Result<OidcRedirect> {
+        let row = OidcLoginStateRow {
+            state: generate_opaque_token(),
+            nonce: generate_opaque_token(),
+            code_verifier: generate_opaque_token(),
+            redirect_to: redirect_to.filter(|r| is_local_path(r)).map(str::to_string),
+        };
+        let authorization_url = self
+            .provider
+            .authorization_url(&row.state, &row.nonce, &pkce_challenge(&row.code_verifier))
+            .await?;
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(LOGIN_STATE_TTL_SECS);
+        self.repo.create_login_state(&row, expires_at).await?;
+        Ok(OidcRedirect {
+            authorization_url,
+            state: row.state,
+        })
+    }
+}
+
+pub struct CompleteOidcLogin<'a, P, R, U>
+where
+    P: OidcProvider + ?Sized,
+    R: OidcRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    pub provider: &'a P,
+    pub repo: &'a R,
+    pub users: &'a U,
+    pub allowed_email_domains: &'a [String],
+}
+
+pub struct OidcLoginResult {
+    pub user: UserRow,
+    pub redirect_to: Option<String>,
+}
+
+impl<'a, P, R, U> CompleteOidcLogin<'a, P, R, U>
+where
+    P: OidcProvider + ?Sized,
+    R: OidcRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    pub async fn execute(&self, state: &str, code: &str) -> anyhow::Result<OidcLoginResult> {
+        let Some(login) = self.repo.consume_login_state(state).await? else {
+            anyhow::bail!("invalid_state");
+        };
+        let identity = self
+            .provider
+            .exchange_code(code, &login.code_verifier, &login.nonce)
+            .await?;
+        let email = identity
+            .email
+            .as_deref()
+            .map(str::trim)
+            .filter(|e| !e.is_empty());
+
+        if !self.allowed_email_domains.is_empty() {
+            let domain = email
+                .filter(|_| identity.email_verified)
+                .and_then(|e| e.rsplit_once('@'))
+                .map(|(_, d)| d.to_lowercase());
+            if !domain.is_some_and(|d| self.allowed_email_domains.contains(&d)) {
+                anyhow::bail!("forbidden");
+            }
+        }
+
+        if let Some(user) = self
+            .repo
+            .find_user_by_identity(&identity.issuer, &identity.subject)
+            .await?
+        {
+            return Ok(OidcLoginResult {
+                user,
+                redirect_to: login.redirect_to,
+            });
+        }
+
+        let Some(email) = email else {
+            anyhow::bail!("bad_request");
+        };
+        let user = match self.users.find_by_email(email).await? {
+            // Only a verified address may take over an existing account
+            Some(_) if !identity.email_verified => anyhow::bail!("conflict"),
+            Some(existing) => existing,
+            None => {
+                let name = identity
+                    .name
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|n| !n.is_empty())
+                    .unwrap_or_else(|| email.split('@').next().unwrap_or(email));
+                self.users.create_user(email, name, None).await?
+            }
+        };
+        self.repo
+            .link_identity(user.id, &identity.issuer, &identity.subject, Some(email))
+            .await?;
+        if identity.email_verified && user.email_verified_at.is_none() {
+            self.users.mark_email_verified(user.id).await?;
+        }
+        Ok(OidcLoginResult {
+            user: UserRow {
+                password_hash: None,
+                ..user
+            },
+            redirect_to: login.redirect_to,
+        })
+    }
+}
+
+/// Same-origin path, so the callback cannot be used as an open redirect.
+fn is_local_path(path: &str) -> bool {
+    path.starts_with('/') && !path.starts_with("//") && !path.contains('\\')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::oidc_provider::OidcIdentity;
+    use crate::application::use_cases::auth::testing::{FakeAccounts, FakeOidcProvider};
+
+    fn identity(email: &str, verified: bool) -> OidcIdentity {
+        OidcIdentity {
+            issuer: "https://idp.example.test".to_string(),
+            subject: format!("sub-{email}"),
+            email: Some(email.to_string()),
+            email_verified: verified,
+            name: None,
+        }
+    }
+
+    /// Runs the whole flow: begin, sign in at the IdP, callback with the returned state.
+    async fn sign_in(
+        provider: &FakeOidcProvider,
+        accounts: &FakeAccounts,
+        domains: &[String],
+    ) -> anyhow::Result<OidcLoginResult> {
+        let begin = BeginOidcLogin {
+            provider,
+            repo: accounts,
+        };
+        let redirect = begin.execute(Some("/documents/1")).await?;
+        let code = provider.authorize(&redirect.state);
+        complete(provider, accounts, domains)
+            .execute(&redirect.state, &code)
+            .await
+    }
+
+    fn complete<'a>(
+        provider: &'a FakeOidcProvider,
+        accounts: &'a FakeAccounts,
+        domains: &'a [String],
+    ) -> CompleteOidcLogin<'a, FakeOidcProvider, FakeAccounts, FakeAccounts> {
+        CompleteOidcLogin {
+            provider,
+            repo: accounts,
+            users: accounts,
+            allowed_email_domains: domains,
+        }
+    }
+
+    fn err(result: anyhow::Result<OidcLoginResult>) -> String {
+        match result {
+            Ok(_) => "ok".to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn state_must_match_and_is_single_use() {
+        let provider = FakeOidcProvider::new(identity("ann@example.com", true));
+        let accounts = FakeAccounts::default();
+        let begin = BeginOidcLogin {
+            provider: &provider,
+            repo: &accounts,
+        };
+        let redirect = begin.execute(Some("//evil.example")).await.unwrap();
+        let code = provider.authorize(&redirect.state);
+        let uc = complete(&provider, &accounts, &[]);
+
+        assert_eq!(err(uc.execute("forged", &code).await), "invalid_state");
+        let result = uc.execute(&redirect.state, &code).await.unwrap();
+        assert_eq!(result.user.email, "ann@example.com");
+        // Off-site redirect targets are dropped at the start
+        assert_eq!(result.redirect_to, None);
+        assert_eq!(
+            err(uc.execute(&redirect.state, &code).await),
+            "invalid_state"
+        );
+    }
+
+    #[tokio::test]
+    async fn nonce_from_the_stored_state_must_match_the_token() {
+        let provider = FakeOidcProvider::new(identity("ann@example.com", true));
+        let accounts = FakeAccounts::default();
+        let begin = BeginOidcLogin {
+            provider: &provider,
+            repo: &accounts,
+        };
+        let redirect = begin.execute(None).await.unwrap();
+        let code = provider.authorize(&redirect.state);
+        accounts
+            .login_states
+            .lock()
+            .unwrap()
+            .get_mut(&redirect.state)
+            .unwrap()
+            .nonce = "replayed".to_string();
+
+        let result = complete(&provider, &accounts, &[])
+            .execute(&redirect.state, &code)
+            .await;
+        assert_eq!(err(result), "oidc nonce mismatch");
+        assert!(accounts.users.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn allowed_domains_need_a_verified_address() {
+        let domains = vec!["example.com".to_string()];
+        let accounts = FakeAccounts::default();
+
+        let provider = FakeOidcProvider::new(identity("ann@other.org", true));
+        assert_eq!(
+            err(sign_in(&provider, &accounts, &domains).await),
+            "forbidden"
+        );
+        let provider = FakeOidcProvider::new(identity("ann@example.com", false));
+        assert_eq!(
+            err(sign_in(&provider, &accounts, &domains).await),
+            "forbidden"
+        );
+        let provider = FakeOidcProvider::new(identity("ann@Example.COM", true));
+        let result = sign_in(&provider, &accounts, &domains).await.unwrap();
+        assert_eq!(result.redirect_to.as_deref(), Some("/documents/1"));
+        assert!(
+            accounts
+                .user(result.user.id)
+                .unwrap()
+                .email_verified_at
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn unverified_email_cannot_take_over_an_existing_account() {
+        let accounts = FakeAccounts::default();
+        let existing = accounts.add_user("ann@example.com", Some("hash"));
+
+        let provider = FakeOidcProvider::new(identity("ANN@example.com", false));
+        assert_eq!(err(sign_in(&provider, &accounts, &[]).await), "conflict");
+        assert!(accounts.identities.lock().unwrap().is_empty());
+
+        // A verified address links the identity to the existing account
+        *provider.identity.lock().unwrap() = identity("ANN@example.com", true);
+        let result = sign_in(&provider, &accounts, &[]).await.unwrap();
+        assert_eq!(result.user.id, existing.id);
+        assert_eq!(result.user.password_hash, None);
+        assert_eq!(accounts.users.lock().unwrap().len(), 1);
+
+        // Later sign-ins go through the link, whatever the email claim says now
+        *provider.identity.lock().unwrap() = OidcIdentity {
+            email: None,
+            email_verified: false,
+            ..identity("ANN@example.com", true)
+        };
+        let result = sign_in(&provider, &accounts, &[]).await.unwrap();
+        assert_eq!(result.user.id, existing.id);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::application::ports::auth_link_repository::AuthLinkRepository;
+use crate::application::ports::mailer::{MailMessage, Mailer};
+use crate::application::ports::session_repository::SessionRepository;
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::services::auth::signed_links::{self, PURPOSE_PASSWORD_RESET};
+use crate::application::services::auth::tokens::hash_token;
+use crate::application::use_cases::auth::register::hash_password;
+
+const RESET_LINK_TTL_SECS: i64 = 60 * 60;
+
+pub struct RequestPasswordReset<'a, U, L, M>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    pub users: &'a U,
+    pub links: &'a L,
+    pub mailer: &'a M,
+    pub signing_secret: &'a str,
+    /// Frontend origin the link points at
+    pub base_url: &'a str,
+}
+
+impl<'a, U, L, M> RequestPasswordReset<'a, U, L, M>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    /// Succeeds whether or not the address is registered, so callers cannot probe accounts.
+    pub async fn execute(&self, email: &str) -> anyhow::Result<()> {
+        let Some(user) = self.users.find_by_email(email.trim()).await? else {
+            return Ok(());
+        };
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(RESET_LINK_TTL_SECS);
+        let (token, nonce) = signed_links::sign(
+            self.signing_secret,
+            PURPOSE_PASSWORD_RESET,
+            user.id,
+            expires_at,
+        );
+        self.links
+            .create(
+                user.id,
+                PURPOSE_PASSWORD_RESET,
+                &hash_token(&nonce),
+                expires_at,
+            )
+            .await?;
+        let link = format!(
+            "{}/auth/reset-password?token={}",
+            self.base_url.trim_end_matches('/'),
+            token
+        );
+        self.mailer
+            .send(&MailMessage {
+                to: user.email.clone(),
+                subject: "Reset your RefMD password".into(),
+                body: format!(
+                    "Hi {},\n\nSomeone asked to reset the password for your RefMD account.\nOpen this link within the next hour to choose a new one:\n\n{}\n\nIf it wasn't you, you can ignore this email.\n",
+                    user.name, link
+                ),
+            })
+            .await
+    }
+}
+
+pub struct ResetPassword<'a, U, L, S>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    S: SessionRepository + ?Sized,
+{
+    pub users: &'a U,
+    pub links: &'a L,
+    pub sessions: &'a S,
+    pub signing_secret: &'a str,
+}
+
+impl<'a, U, L, S> ResetPassword<'a, U, L, S>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    S: SessionRepository + ?Sized,
+{
+    pub async fn execute(&self, token: &str, new_password: &str) -> anyhow::Result<()> {
+        if new_password.is_empty() {
+            anyhow::bail!("bad_request");
+        }
+        let Some(claims) = signed_links::verify(self.signing_secret, PURPOSE_PASSWORD_RESET, token)
+        else {
+            anyhow::bail!("invalid_token");
+        };
+        if !self
+            .links
+            .consume(
+                claims.user_id,
+                PURPOSE_PASSWORD_RESET,
+                &hash_token(&claims.nonce),
+            )
+            .await?
+        {
+            anyhow::bail!("invalid_token");
+        }
+        let hash = hash_password(new_password)?;
+        if !self.users.set_password(claims.user_id, &hash).await? {
+            anyhow::bail!("invalid_token");
+        }
+        self.links
+            .invalidate_all(claims.user_id, PURPOSE_PASSWORD_RESET)
+            .await?;
+        // The link proves control of the inbox; existing sessions may belong to whoever
+        // knew the old password.
+        self.users.mark_email_verified(claims.user_id).await?;
+        self.sessions.revoke_all(claims.user_id).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use uuid::Uuid;
+
+use crate::application::ports::auth_link_repository::AuthLinkRepository;
+use crate::application::ports::mailer::{MailMessage, Mailer};
+use crate::application::ports::session_repository::SessionRepository;
+use crate::application::ports::user_repository::{UserRepository, UserRow};
+use crate::application::services::auth::signed_links::{
+    PURPOSE_EMAIL_VERIFICATION, PURPOSE_PASSWORD_RESET,
+};
+use crate::application::use_cases::auth::email_verification::SendEmailVerification;
+use crate::application::use_cases::auth::login::verify_password;
+use crate::application::use_cases::auth::register::hash_password;
+
+const MAX_NAME_LEN: usize = 64;
+
+/// Accounts with a password must re-enter it; SSO-only accounts have nothing to confirm.
+fn confirm_password(user: &UserRow, password: Option<&str>) -> anyhow::Result<()> {
+    if let Some(hash) = user.password_hash.as_deref() {
+        let ok = match password {
+            Some(p) => verify_password(hash, p)?,
+            None => false,
+        };
+        if !ok {
+            anyhow::bail!("invalid_password");
+        }
+    }
+    Ok(())
+}
+
+/// The name doubles as the `/api/public/users/:name` path segment.
+fn normalize_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    if name.is_empty()
+        || name.chars().count() > MAX_NAME_LEN
+        || name
+            .chars()
+            .any(|c| c.is_control() || matches!(c, '/' | '\\' | '?' | '#' | '%'))
+    {
+        return None;
+    }
+    Some(name.to_string())
+}
+
+pub struct UpdateName<'a, U: UserRepository + ?Sized> {
+    pub users: &'a U,
+}
+
+impl<'a, U: UserRepository + ?Sized> UpdateName<'a, U> {
+    pub async fn execute(&self, user_id: Uuid, name: &str) -> anyhow::Result<UserRow> {
+        let Some(name) = normalize_name(name) else {
+            anyhow::bail!("bad_request");
+        };
+        if let Some(other) = self.users.find_by_name(&name).await?
+            && other.id != user_id
+        {
+            anyhow::bail!("conflict");
+        }
+        match self.users.update_name(user_id, &name).await? {
+            Some(user) => Ok(user),
+            None => anyhow::bail!("not_found"),
+        }
+    }
+}
+
+pub struct ChangeEmail<'a, U, L, M>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    pub users: &'a U,
+    pub links: &'a L,
+    pub mailer: &'a M,
+    pub signing_secret: &'a str,
+    /// Frontend origin the verification link points at
+    pub base_url: &'a str,
+}
+
+impl<'a, U, L, M> ChangeEmail<'a, U, L, M>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    M: Mailer + ?Sized,
+{
+    /// The new address starts unverified; a verification link goes to it and a notice
+    /// to the old one.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        email: &str,
+        password: Option<&str>,
+    ) -> anyhow::Result<UserRow> {
+        let Some(user) = self.users.find_by_id(user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        confirm_password(&user, password)?;
+        let email = email.trim();
+        if email.is_empty() || !email.contains('@') {
+            anyhow::bail!("bad_request");
+        }
+        if email == user.email {
+            return Ok(user);
+        }
+        if let Some(other) = self.users.find_by_email(email).await?
+            && other.id != user_id
+        {
+            anyhow::bail!("conflict");
+        }
+        let Some(updated) = self.users.update_email(user_id, email).await? else {
+            anyhow::bail!("not_found");
+        };
+        // Outstanding links were mailed to the old address
+        self.links
+            .invalidate_all(user_id, PURPOSE_PASSWORD_RESET)
+            .await?;
+        self.links
+            .invalidate_all(user_id, PURPOSE_EMAIL_VERIFICATION)
+            .await?;
+
+        let verification = SendEmailVerification {
+            users: self.users,
+            links: self.links,
+            mailer: self.mailer,
+            signing_secret: self.signing_secret,
+            base_url: self.base_url,
+        };
+        if let Err(e) = verification.execute(&updated).await {
+            tracing::warn!(error = ?e, user_id = %user_id, "verification_email_failed");
+        }
+        let notice = MailMessage {
+            to: user.email.clone(),
+            subject: "Your RefMD email address was changed".into(),
+            body: format!(
+                "Hi {},\n\nThe email address of your RefMD account was changed to {}.\n\nIf you didn't do this, reset your password and contact your administrator.\n",
+                user.name, updated.email
+            ),
+        };
+        if let Err(e) = self.mailer.send(&notice).await {
+            tracing::warn!(error = ?e, user_id = %user_id, "email_change_notice_failed");
+        }
+        Ok(updated)
+    }
+}
+
+pub struct ChangePassword<'a, U, L, S>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    S: SessionRepository + ?Sized,
+{
+    pub users: &'a U,
+    pub links: &'a L,
+    pub sessions: &'a S,
+}
+
+impl<'a, U, L, S> ChangePassword<'a, U, L, S>
+where
+    U: UserRepository + ?Sized,
+    L: AuthLinkRepository + ?Sized,
+    S: SessionRepository + ?Sized,
+{
+    /// Signs out every session except `current_session_id`.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        current_session_id: Uuid,
+        current_password: Option<&str>,
+        new_password: &str,
+    ) -> anyhow::Result<()> {
+        if new_password.is_empty() {
+            anyhow::bail!("bad_request");
+        }
+        let Some(user) = self.users.find_by_id(user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        confirm_password(&user, current_password)?;
+        let hash = hash_password(new_password)?;
+        if !self.users.set_password(user_id, &hash).await? {
+            anyhow::bail!("not_found");
+        }
+        self.links
+            .invalidate_all(user_id, PURPOSE_PASSWORD_RESET)
+            .await?;
+        self.sessions
+            .revoke_others(user_id, current_session_id)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use crate::application::ports::session_repository::SessionRepository;
+use crate::application::services::auth::tokens::{generate_opaque_token, hash_token};
+use crate::application::use_cases::auth::create_session::IssuedSession;
+
+pub struct RefreshSession<'a, R: SessionRepository + ?Sized> {
+    pub sessions: &'a R,
+}
+
+impl<'a, R: SessionRepository + ?Sized> RefreshSession<'a, R> {
+    /// Exchanges a refresh token for a new one. Replaying an already rotated token revokes the
+    /// whole session, since it means the token leaked.
+    pub async fn execute(
+        &self,
+        refresh_token: &str,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+        ttl_secs: i64,
+    ) -> anyhow::Result<Option<IssuedSession>> {
+        let old_hash = hash_token(refresh_token.trim());
+        let mut session = match self.sessions.find_by_refresh_hash(&old_hash).await? {
+            Some(s) => s,
+            None => {
+                if let Some(reused) = self.sessions.find_by_previous_hash(&old_hash).await?
+                    && reused.revoked_at.is_none()
+                {
+                    tracing::warn!(session_id = %reused.id, user_id = %reused.user_id, "refresh_token_reuse_detected");
+                    self.sessions.revoke(reused.id, reused.user_id).await?;
+                }
+                return Ok(None);
+            }
+        };
+        let new_token = generate_opaque_token();
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(ttl_secs.max(60));
+        let rotated = self
+            .sessions
+            .rotate(
+                session.id,
+                &old_hash,
+                &hash_token(&new_token),
+                user_agent,
+                ip_address,
+                expires_at,
+            )
+            .await?;
+        if !rotated {
+            return Ok(None);
+        }
+        session.expires_at = expires_at;
+        session.last_seen_at = chrono::Utc::now();
+        Ok(Some(IssuedSession {
+            session,
+            refresh_token: new_token,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::application::use_cases::auth::create_session::CreateSession;
+    use crate::application::use_cases::auth::testing::FakeSessions;
+
+    const TTL: i64 = 3600;
+
+    #[tokio::test]
+    async fn refresh_rotates_the_token() {
+        let sessions = FakeSessions::default();
+        let issued = CreateSession {
+            sessions: &sessions,
+        }
+        .execute(Uuid::new_v4(), None, None, TTL)
+        .await
+        .unwrap();
+        let uc = RefreshSession {
+            sessions: &sessions,
+        };
+
+        let first = uc
+            .execute(&issued.refresh_token, Some("agent"), None, TTL)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.session.id, issued.session.id);
+        assert_ne!(first.refresh_token, issued.refresh_token);
+        let second = uc
+            .execute(&first.refresh_token, None, None, TTL)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.session.id, issued.session.id);
+        assert!(
+            uc.execute("unknown", None, None, TTL)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(!sessions.is_revoked(issued.session.id));
+    }
+
+    #[tokio::test]
+    async fn replaying_a_rotated_token_revokes_the_session() {
+        let sessions = FakeSessions::default();
+        let user_id = Uuid::new_v4();
+        let create = CreateSession {
+            sessions: &sessions,
+        };
+        let issued = create.execute(user_id, None, None, TTL).await.unwrap();
+        let other = create.execute(user_id, None, None, TTL).await.unwrap();
+        let uc = RefreshSession {
+            sessions: &sessions,
+        };
+        let rotated = uc
+            .execute(&issued.refresh_token, None, None, TTL)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // The old token shows up again: someone else holds a copy
+        assert!(
+            uc.execute(&issued.refresh_token, None, None, TTL)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(sessions.is_revoked(issued.session.id));
+        // The legitimate holder is signed out too, other sessions are not
+        assert!(
+            uc.execute(&rotated.refresh_token, None, None, TTL)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(!sessions.is_revoked(other.session.id));
+    }
+}
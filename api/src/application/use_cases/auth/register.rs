@@ -19,12 +19,19 @@ pub struct RegisterRequest {
 
 impl<'a, R: UserRepository + ?Sized> Register<'a, R> {
     pub async fn execute(&self, req: &RegisterRequest) -> anyhow::Result<UserRow> {
-        let salt = SaltString::generate(&mut OsRng);
-        let hash = Argon2::default()
-            .hash_password(req.password.as_bytes(), &salt)
-            .map_err(|e| anyhow::anyhow!(e.to_string()))?
-            .to_string();
-        let user = self.repo.create_user(&req.email, &req.name, &hash).await?;
+        let hash = hash_password(&req.password)?;
+        let user = self
+            .repo
+            .create_user(&req.email, &req.name, Some(&hash))
+            .await?;
         Ok(user)
     }
 }
+
+pub fn hash_password(password: &str) -> anyhow::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Ok(Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow::anyhow!(e.to_string()))?
+        .to_string())
+}
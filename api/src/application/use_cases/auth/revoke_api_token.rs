@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::application::ports::api_token_repository::ApiTokenRepository;
+
+pub struct RevokeApiToken<'a, R: ApiTokenRepository + ?Sized> {
+    pub tokens: &'a R,
+}
+
+impl<'a, R: ApiTokenRepository + ?Sized> RevokeApiToken<'a, R> {
+    pub async fn execute(&self, user_id: Uuid, token_id: Uuid) -> anyhow::Result<bool> {
+        self.tokens.delete(token_id, user_id).await
+    }
+}
@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+use crate::application::ports::session_repository::SessionRepository;
+
+pub struct RevokeSession<'a, R: SessionRepository + ?Sized> {
+    pub sessions: &'a R,
+}
+
+impl<'a, R: SessionRepository + ?Sized> RevokeSession<'a, R> {
+    pub async fn execute(&self, user_id: Uuid, session_id: Uuid) -> anyhow::Result<bool> {
+        self.sessions.revoke(session_id, user_id).await
+    }
+}
+
+pub struct RevokeAllSessions<'a, R: SessionRepository + ?Sized> {
+    pub sessions: &'a R,
+}
+
+impl<'a, R: SessionRepository + ?Sized> RevokeAllSessions<'a, R> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<u64> {
+        self.sessions.revoke_all(user_id).await
+    }
+}
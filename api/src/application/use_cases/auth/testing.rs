@@ -0,0 +1,611 @@
+//! In-memory auth state for use-case tests.
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::ports::mfa_repository::{MfaChallengeRow, MfaRepository, TotpRow};
+use crate::application::ports::oidc_provider::{OidcIdentity, OidcProvider};
+use crate::application::ports::oidc_repository::{OidcLoginStateRow, OidcRepository};
+use crate::application::ports::session_repository::{SessionRepository, SessionRow};
+use crate::application::ports::user_repository::{UserRepository, UserRow};
+use crate::application::services::auth::tokens::pkce_challenge;
+
+#[derive(Default)]
+pub(crate) struct FakeMfaUser {
+    pub totp: Option<TotpRow>,
+    /// (hash, used)
+    pub recovery_codes: Vec<(String, bool)>,
+    pub failed_attempts: i32,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Default)]
+pub(crate) struct FakeMfa {
+    pub users: Mutex<HashMap<Uuid, FakeMfaUser>>,
+    /// token hash -> challenge
+    pub challenges: Mutex<HashMap<String, MfaChallengeRow>>,
+}
+
+impl FakeMfa {
+    /// A user with TOTP enabled on `secret` and the given recovery code hashes.
+    pub fn with_totp(user_id: Uuid, secret: &str, recovery_code_hashes: &[String]) -> Self {
+        let fake = Self::default();
+        fake.users.lock().unwrap().insert(
+            user_id,
+            FakeMfaUser {
+                totp: Some(TotpRow {
+                    user_id,
+                    secret: secret.to_string(),
+                    enabled_at: Some(Utc::now()),
+                    last_used_step: None,
+                }),
+                recovery_codes: recovery_code_hashes
+                    .iter()
+                    .map(|h| (h.clone(), false))
+                    .collect(),
+                ..Default::default()
+            },
+        );
+        fake
+    }
+
+    pub fn failed_attempts(&self, user_id: Uuid) -> i32 {
+        self.users
+            .lock()
+            .unwrap()
+            .get(&user_id)
+            .map_or(0, |u| u.failed_attempts)
+    }
+}
+
+#[async_trait]
+impl MfaRepository for FakeMfa {
+    async fn get_totp(&self, user_id: Uuid) -> anyhow::Result<Option<TotpRow>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .get(&user_id)
+            .and_then(|u| u.totp.clone()))
+    }
+
+    async fn upsert_pending_totp(&self, user_id: Uuid, secret: &str) -> anyhow::Result<bool> {
+        let mut users = self.users.lock().unwrap();
+        let user = users.entry(user_id).or_default();
+        if user.totp.as_ref().is_some_and(|t| t.enabled_at.is_some()) {
+            return Ok(false);
+        }
+        user.totp = Some(TotpRow {
+            user_id,
+            secret: secret.to_string(),
+            enabled_at: None,
+            last_used_step: None,
+        });
+        Ok(true)
+    }
+
+    async fn enable_totp(
+        &self,
+        user_id: Uuid,
+        step: i64,
+        recovery_code_hashes: &[String],
+    ) -> anyhow::Result<bool> {
+        let mut users = self.users.lock().unwrap();
+        let Some(user) = users.get_mut(&user_id) else {
+            return Ok(false);
+        };
+        let Some(totp) = user.totp.as_mut().filter(|t| t.enabled_at.is_none()) else {
+            return Ok(false);
+        };
+        totp.enabled_at = Some(Utc::now());
+        totp.last_used_step = Some(step);
+        user.recovery_codes = recovery_code_hashes
+            .iter()
+            .map(|h| (h.clone(), false))
+            .collect();
+        Ok(true)
+    }
+
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> anyhow::Result<bool> {
+        let mut users = self.users.lock().unwrap();
+        let Some(totp) = users.get_mut(&user_id).and_then(|u| u.totp.as_mut()) else {
+            return Ok(false);
+        };
+        if totp.last_used_step.is_some_and(|last| last >= step) {
+            return Ok(false);
+        }
+        totp.last_used_step = Some(step);
+        Ok(true)
+    }
+
+    async fn consume_recovery_code(&self, user_id: Uuid, code_hash: &str) -> anyhow::Result<bool> {
+        let mut users = self.users.lock().unwrap();
+        let Some(user) = users.get_mut(&user_id) else {
+            return Ok(false);
+        };
+        match user
+            .recovery_codes
+            .iter_mut()
+            .find(|(h, used)| h == code_hash && !*used)
+        {
+            Some(entry) => {
+                entry.1 = true;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn count_recovery_codes(&self, user_id: Uuid) -> anyhow::Result<i64> {
+        Ok(self.users.lock().unwrap().get(&user_id).map_or(0, |u| {
+            u.recovery_codes.iter().filter(|(_, used)| !used).count() as i64
+        }))
+    }
+
+    async fn disable_totp(&self, user_id: Uuid) -> anyhow::Result<bool> {
+        self.challenges
+            .lock()
+            .unwrap()
+            .retain(|_, c| c.user_id != user_id);
+        Ok(self.users.lock().unwrap().remove(&user_id).is_some())
+    }
+
+    async fn create_challenge(
+        &self,
+        user_id: Uuid,
+        token_hash: &str,
+        _expires_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        self.challenges.lock().unwrap().insert(
+            token_hash.to_string(),
+            MfaChallengeRow {
+                id: Uuid::new_v4(),
+                user_id,
+                attempts: 0,
+            },
+        );
+        Ok(())
+    }
+
+    async fn find_challenge(&self, token_hash: &str) -> anyhow::Result<Option<MfaChallengeRow>> {
+        Ok(self.challenges.lock().unwrap().get(token_hash).cloned())
+    }
+
+    async fn count_challenge_attempt(&self, challenge_id: Uuid) -> anyhow::Result<Option<i32>> {
+        let mut challenges = self.challenges.lock().unwrap();
+        Ok(challenges
+            .values_mut()
+            .find(|c| c.id == challenge_id)
+            .map(|c| {
+                c.attempts += 1;
+                c.attempts
+            }))
+    }
+
+    async fn delete_challenge(&self, challenge_id: Uuid) -> anyhow::Result<()> {
+        self.challenges
+            .lock()
+            .unwrap()
+            .retain(|_, c| c.id != challenge_id);
+        Ok(())
+    }
+
+    async fn begin_second_factor_attempt(
+        &self,
+        user_id: Uuid,
+        max_failures: i32,
+        lock_secs: i64,
+    ) -> anyhow::Result<Option<i32>> {
+        let now = Utc::now();
+        let mut users = self.users.lock().unwrap();
+        let Some(user) = users
+            .get_mut(&user_id)
+            .filter(|u| u.totp.as_ref().is_some_and(|t| t.enabled_at.is_some()))
+        else {
+            return Ok(None);
+        };
+        if user.locked_until.is_some_and(|until| until > now) {
+            return Ok(None);
+        }
+        user.failed_attempts += 1;
+        if user.failed_attempts >= max_failures {
+            user.locked_until = Some(now + chrono::Duration::seconds(lock_secs));
+        }
+        Ok(Some(user.failed_attempts))
+    }
+
+    async fn reset_second_factor_failures(&self, user_id: Uuid) -> anyhow::Result<()> {
+        if let Some(user) = self.users.lock().unwrap().get_mut(&user_id) {
+            user.failed_attempts = 0;
+            user.locked_until = None;
+        }
+        Ok(())
+    }
+}
+
+/// Identity provider that checks PKCE and the nonce like a real one: `authorize` stands in
+/// for the user signing in and returns the code the callback receives.
+pub(crate) struct FakeOidcProvider {
+    pub identity: Mutex<OidcIdentity>,
+    /// state -> (nonce, code challenge)
+    requests: Mutex<HashMap<String, (String, String)>>,
+    /// code -> (nonce, code challenge)
+    codes: Mutex<HashMap<String, (String, String)>>,
+}
+
+impl FakeOidcProvider {
+    pub fn new(identity: OidcIdentity) -> Self {
+        Self {
+            identity: Mutex::new(identity),
+            requests: Mutex::default(),
+            codes: Mutex::default(),
+        }
+    }
+
+    pub fn authorize(&self, state: &str) -> String {
+        let request = self
+            .requests
+            .lock()
+            .unwrap()
+            .remove(state)
+            .expect("authorization requested for this state");
+        let code = Uuid::new_v4().to_string();
+        self.codes.lock().unwrap().insert(code.clone(), request);
+        code
+    }
+}
+
+#[async_trait]
+impl OidcProvider for FakeOidcProvider {
+    fn name(&self) -> &str {
+        "Fake IdP"
+    }
+
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> anyhow::Result<String> {
+        self.requests.lock().unwrap().insert(
+            state.to_string(),
+            (nonce.to_string(), code_challenge.to_string()),
+        );
+        Ok(format!("https://idp.example.test/authorize?state={state}"))
+    }
+
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> anyhow::Result<OidcIdentity> {
+        let Some((issued_nonce, challenge)) = self.codes.lock().unwrap().remove(code) else {
+            anyhow::bail!("oidc token endpoint returned status 400 Bad Request");
+        };
+        if pkce_challenge(code_verifier) != challenge {
+            anyhow::bail!("oidc token endpoint returned status 400 Bad Request");
+        }
+        if issued_nonce != nonce {
+            anyhow::bail!("oidc nonce mismatch");
+        }
+        Ok(self.identity.lock().unwrap().clone())
+    }
+}
+
+/// Users and linked identities.
+#[derive(Default)]
+pub(crate) struct FakeAccounts {
+    pub users: Mutex<Vec<UserRow>>,
+    pub login_states: Mutex<HashMap<String, OidcLoginStateRow>>,
+    /// (issuer, subject) -> user
+    pub identities: Mutex<HashMap<(String, String), Uuid>>,
+}
+
+impl FakeAccounts {
+    pub fn add_user(&self, email: &str, password_hash: Option<&str>) -> UserRow {
+        let user = UserRow {
+            id: Uuid::new_v4(),
+            email: email.to_string(),
+            name: email.split('@').next().unwrap_or(email).to_string(),
+            password_hash: password_hash.map(str::to_string),
+            email_verified_at: None,
+            role: "user".to_string(),
+            disabled_at: None,
+        };
+        self.users.lock().unwrap().push(user.clone());
+        user
+    }
+
+    pub fn user(&self, id: Uuid) -> Option<UserRow> {
+        self.users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.id == id)
+            .cloned()
+    }
+
+    fn update<T>(&self, id: Uuid, f: impl FnOnce(&mut UserRow) -> T) -> Option<T> {
+        self.users
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|u| u.id == id)
+            .map(f)
+    }
+}
+
+#[async_trait]
+impl UserRepository for FakeAccounts {
+    async fn create_user(
+        &self,
+        email: &str,
+        _name: &str,
+        password_hash: Option<&str>,
+    ) -> anyhow::Result<UserRow> {
+        Ok(self.add_user(email, password_hash))
+    }
+
+    async fn find_by_email(&self, email: &str) -> anyhow::Result<Option<UserRow>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.email.eq_ignore_ascii_case(email))
+            .cloned())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> anyhow::Result<Option<UserRow>> {
+        Ok(self.user(id))
+    }
+
+    async fn delete_user(&self, id: Uuid) -> anyhow::Result<bool> {
+        let mut users = self.users.lock().unwrap();
+        let before = users.len();
+        users.retain(|u| u.id != id);
+        Ok(users.len() < before)
+    }
+
+    async fn set_password(&self, id: Uuid, password_hash: &str) -> anyhow::Result<bool> {
+        Ok(self
+            .update(id, |u| u.password_hash = Some(password_hash.to_string()))
+            .is_some())
+    }
+
+    async fn mark_email_verified(&self, id: Uuid) -> anyhow::Result<bool> {
+        Ok(self
+            .update(id, |u| u.email_verified_at = Some(Utc::now()))
+            .is_some())
+    }
+
+    async fn find_by_name(&self, name: &str) -> anyhow::Result<Option<UserRow>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.name.eq_ignore_ascii_case(name))
+            .cloned())
+    }
+
+    async fn update_name(&self, id: Uuid, name: &str) -> anyhow::Result<Option<UserRow>> {
+        Ok(self.update(id, |u| {
+            u.name = name.to_string();
+            u.clone()
+        }))
+    }
+
+    async fn update_email(&self, id: Uuid, email: &str) -> anyhow::Result<Option<UserRow>> {
+        if self
+            .find_by_email(email)
+            .await?
+            .is_some_and(|other| other.id != id)
+        {
+            anyhow::bail!("conflict");
+        }
+        Ok(self.update(id, |u| {
+            u.email = email.to_string();
+            u.email_verified_at = None;
+            u.clone()
+        }))
+    }
+}
+
+#[async_trait]
+impl OidcRepository for FakeAccounts {
+    async fn create_login_state(
+        &self,
+        row: &OidcLoginStateRow,
+        _expires_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        self.login_states
+            .lock()
+            .unwrap()
+            .insert(row.state.clone(), row.clone());
+        Ok(())
+    }
+
+    async fn consume_login_state(&self, state: &str) -> anyhow::Result<Option<OidcLoginStateRow>> {
+        Ok(self.login_states.lock().unwrap().remove(state))
+    }
+
+    async fn find_user_by_identity(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> anyhow::Result<Option<UserRow>> {
+        let id = self
+            .identities
+            .lock()
+            .unwrap()
+            .get(&(issuer.to_string(), subject.to_string()))
+            .copied();
+        Ok(id.and_then(|id| self.user(id)))
+    }
+
+    async fn link_identity(
+        &self,
+        user_id: Uuid,
+        issuer: &str,
+        subject: &str,
+        _email: Option<&str>,
+    ) -> anyhow::Result<()> {
+        self.identities
+            .lock()
+            .unwrap()
+            .insert((issuer.to_string(), subject.to_string()), user_id);
+        Ok(())
+    }
+}
+
+pub(crate) struct FakeSession {
+    pub row: SessionRow,
+    pub refresh_hash: String,
+    pub previous_hash: Option<String>,
+}
+
+impl FakeSession {
+    fn active(&self) -> bool {
+        self.row.revoked_at.is_none() && self.row.expires_at > Utc::now()
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct FakeSessions {
+    pub sessions: Mutex<Vec<FakeSession>>,
+}
+
+impl FakeSessions {
+    pub fn is_revoked(&self, session_id: Uuid) -> bool {
+        self.sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|s| s.row.id == session_id && s.row.revoked_at.is_some())
+    }
+
+    fn revoke_where(&self, f: impl Fn(&SessionRow) -> bool) -> u64 {
+        let mut count = 0;
+        for s in self.sessions.lock().unwrap().iter_mut() {
+            if s.row.revoked_at.is_none() && f(&s.row) {
+                s.row.revoked_at = Some(Utc::now());
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+#[async_trait]
+impl SessionRepository for FakeSessions {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        refresh_token_hash: &str,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+        expires_at: DateTime<Utc>,
+    ) -> anyhow::Result<SessionRow> {
+        let now = Utc::now();
+        let row = SessionRow {
+            id: Uuid::new_v4(),
+            user_id,
+            user_agent: user_agent.map(str::to_string),
+            ip_address: ip_address.map(str::to_string),
+            created_at: now,
+            last_seen_at: now,
+            expires_at,
+            revoked_at: None,
+        };
+        self.sessions.lock().unwrap().push(FakeSession {
+            row: row.clone(),
+            refresh_hash: refresh_token_hash.to_string(),
+            previous_hash: None,
+        });
+        Ok(row)
+    }
+
+    async fn find_by_refresh_hash(
+        &self,
+        refresh_token_hash: &str,
+    ) -> anyhow::Result<Option<SessionRow>> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.refresh_hash == refresh_token_hash && s.active())
+            .map(|s| s.row.clone()))
+    }
+
+    async fn find_by_previous_hash(
+        &self,
+        refresh_token_hash: &str,
+    ) -> anyhow::Result<Option<SessionRow>> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.previous_hash.as_deref() == Some(refresh_token_hash))
+            .map(|s| s.row.clone()))
+    }
+
+    async fn rotate(
+        &self,
+        session_id: Uuid,
+        old_hash: &str,
+        new_hash: &str,
+        _user_agent: Option<&str>,
+        _ip_address: Option<&str>,
+        expires_at: DateTime<Utc>,
+    ) -> anyhow::Result<bool> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let Some(s) = sessions.iter_mut().find(|s| {
+            s.row.id == session_id && s.refresh_hash == old_hash && s.row.revoked_at.is_none()
+        }) else {
+            return Ok(false);
+        };
+        s.previous_hash = Some(std::mem::replace(&mut s.refresh_hash, new_hash.to_string()));
+        s.row.expires_at = expires_at;
+        s.row.last_seen_at = Utc::now();
+        Ok(true)
+    }
+
+    async fn touch(&self, session_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|s| s.row.id == session_id && s.row.user_id == user_id && s.active()))
+    }
+
+    async fn list_active(&self, user_id: Uuid) -> anyhow::Result<Vec<SessionRow>> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| s.row.user_id == user_id && s.active())
+            .map(|s| s.row.clone())
+            .collect())
+    }
+
+    async fn revoke(&self, session_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
+        Ok(self.revoke_where(|r| r.id == session_id && r.user_id == user_id) > 0)
+    }
+
+    async fn revoke_all(&self, user_id: Uuid) -> anyhow::Result<u64> {
+        Ok(self.revoke_where(|r| r.user_id == user_id))
+    }
+
+    async fn revoke_others(&self, user_id: Uuid, keep_session_id: Uuid) -> anyhow::Result<u64> {
+        Ok(self.revoke_where(|r| r.user_id == user_id && r.id != keep_session_id))
+    }
+}
@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::application::ports::mfa_repository::MfaRepository;
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::use_cases::auth::login::verify_password;
+use crate::application::use_cases::auth::mfa_challenge::verify_second_factor;
+
+pub struct DisableTotp<'a, M, U>
+where
+    M: MfaRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    pub mfa: &'a M,
+    pub users: &'a U,
+}
+
+impl<'a, M, U> DisableTotp<'a, M, U>
+where
+    M: MfaRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    /// Requires the password (for accounts that have one) and a current second factor.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        password: Option<&str>,
+        code: &str,
+    ) -> anyhow::Result<()> {
+        let Some(user) = self.users.find_by_id(user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        let Some(row) = self
+            .mfa
+            .get_totp(user_id)
+            .await?
+            .filter(|t| t.enabled_at.is_some())
+        else {
+            anyhow::bail!("not_found");
+        };
+        if let Some(hash) = user.password_hash.as_deref() {
+            let ok = match password {
+                Some(p) => verify_password(hash, p)?,
+                None => false,
+            };
+            if !ok {
+                anyhow::bail!("unauthorized");
+            }
+        }
+        if !verify_second_factor(self.mfa, &row, code).await? {
+            anyhow::bail!("invalid_code");
+        }
+        self.mfa.disable_totp(user_id).await?;
+        Ok(())
+    }
+}
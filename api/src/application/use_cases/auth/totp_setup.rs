@@ -0,0 +1,71 @@
+use uuid::Uuid;
+
+use crate::application::ports::mfa_repository::MfaRepository;
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::services::auth::tokens::hash_token;
+use crate::application::services::auth::totp;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+
+pub struct TotpEnrollment {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+pub struct SetupTotp<'a, M, U>
+where
+    M: MfaRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    pub mfa: &'a M,
+    pub users: &'a U,
+}
+
+impl<'a, M, U> SetupTotp<'a, M, U>
+where
+    M: MfaRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    /// Issues a fresh pending secret; it takes effect once confirmed via `EnableTotp`.
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<TotpEnrollment> {
+        let Some(user) = self.users.find_by_id(user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        let secret = totp::generate_secret();
+        if !self.mfa.upsert_pending_totp(user_id, &secret).await? {
+            anyhow::bail!("conflict");
+        }
+        Ok(TotpEnrollment {
+            otpauth_uri: totp::provisioning_uri(&user.email, &secret),
+            secret,
+        })
+    }
+}
+
+pub struct EnableTotp<'a, M: MfaRepository + ?Sized> {
+    pub mfa: &'a M,
+}
+
+impl<'a, M: MfaRepository + ?Sized> EnableTotp<'a, M> {
+    /// Confirms the pending secret with a first code and returns the plain recovery codes.
+    pub async fn execute(&self, user_id: Uuid, code: &str) -> anyhow::Result<Vec<String>> {
+        let Some(row) = self.mfa.get_totp(user_id).await? else {
+            anyhow::bail!("bad_request");
+        };
+        if row.enabled_at.is_some() {
+            anyhow::bail!("conflict");
+        }
+        let Some(step) = totp::verify(&row.secret, code, chrono::Utc::now().timestamp()) else {
+            anyhow::bail!("invalid_code");
+        };
+        let codes = totp::generate_recovery_codes(RECOVERY_CODE_COUNT);
+        let hashes: Vec<String> = codes
+            .iter()
+            .map(|c| hash_token(&totp::normalize_recovery_code(c)))
+            .collect();
+        if !self.mfa.enable_totp(user_id, step, &hashes).await? {
+            anyhow::bail!("conflict");
+        }
+        Ok(codes)
+    }
+}
@@ -0,0 +1,65 @@
+use uuid::Uuid;
+
+use super::{actor_user_id, filter_mentions, normalize_body, publish_event};
+use crate::application::access::{self, Actor};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::{CommentRepository, CommentRow};
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct AddReply<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, C, A, S, RT> AddReply<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        thread_id: Uuid,
+        body: &str,
+        mentions: &[Uuid],
+    ) -> anyhow::Result<CommentRow> {
+        let thread = match self.comments.get_thread(thread_id).await? {
+            Some(t) => t,
+            None => anyhow::bail!("not_found"),
+        };
+        if access::require_view(self.access, self.shares, actor, thread.document_id)
+            .await
+            .is_err()
+        {
+            anyhow::bail!("not_found");
+        }
+        access::require_comment(self.access, self.shares, actor, thread.document_id).await?;
+        let body = normalize_body(body)?;
+        let mentions =
+            filter_mentions(self.access, self.shares, thread.document_id, mentions).await;
+        let comment = self
+            .comments
+            .add_comment(thread.id, actor_user_id(actor), &body, &mentions)
+            .await?;
+        publish_event(
+            self.realtime,
+            thread.document_id,
+            "comment_added",
+            thread.id,
+            Some(comment.id),
+        )
+        .await;
+        Ok(comment)
+    }
+}
@@ -0,0 +1,99 @@
+use uuid::Uuid;
+use yrs::StickyIndex;
+use yrs::updates::decoder::Decode;
+
+use super::{CommentThreadView, actor_user_id, filter_mentions, normalize_body, publish_event};
+use crate::application::access::{self, Actor};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::CommentRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct CreateThreadInput<'a> {
+    /// Encoded Y.RelativePosition bounds; omit both for a document-level thread
+    pub anchor_start: Option<&'a [u8]>,
+    pub anchor_end: Option<&'a [u8]>,
+    pub quote: Option<&'a str>,
+    pub body: &'a str,
+    pub mentions: &'a [Uuid],
+}
+
+pub struct CreateThread<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, C, A, S, RT> CreateThread<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        doc_id: Uuid,
+        input: CreateThreadInput<'_>,
+    ) -> anyhow::Result<CommentThreadView> {
+        access::require_comment(self.access, self.shares, actor, doc_id).await?;
+        let body = normalize_body(input.body)?;
+        match (input.anchor_start, input.anchor_end) {
+            (Some(start), Some(end)) => {
+                if StickyIndex::decode_v1(start).is_err() || StickyIndex::decode_v1(end).is_err() {
+                    anyhow::bail!("bad_request");
+                }
+            }
+            (None, None) => {}
+            _ => anyhow::bail!("bad_request"),
+        }
+        let mentions = filter_mentions(self.access, self.shares, doc_id, input.mentions).await;
+        let author = actor_user_id(actor);
+
+        let thread = self
+            .comments
+            .create_thread(
+                doc_id,
+                author,
+                input.anchor_start,
+                input.anchor_end,
+                input.quote.map(str::trim).filter(|q| !q.is_empty()),
+            )
+            .await?;
+        let comment = self
+            .comments
+            .add_comment(thread.id, author, &body, &mentions)
+            .await?;
+        publish_event(
+            self.realtime,
+            doc_id,
+            "thread_created",
+            thread.id,
+            Some(comment.id),
+        )
+        .await;
+
+        let offsets = match (&thread.anchor_start, &thread.anchor_end) {
+            (Some(start), Some(end)) => self
+                .realtime
+                .resolve_anchors(&doc_id.to_string(), &[start.clone(), end.clone()])
+                .await
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        Ok(CommentThreadView {
+            start_offset: offsets.first().copied().flatten(),
+            end_offset: offsets.get(1).copied().flatten(),
+            thread,
+            comments: vec![comment],
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use uuid::Uuid;
+
+use super::{actor_user_id, publish_event};
+use crate::application::access::{self, Actor, Capability};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::CommentRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct DeleteComment<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, C, A, S, RT> DeleteComment<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    /// Authors may delete their own comments; editors may delete any. A thread left without
+    /// comments is removed as well.
+    pub async fn execute(&self, actor: &Actor, comment_id: Uuid) -> anyhow::Result<()> {
+        let comment = match self.comments.get_comment(comment_id).await? {
+            Some(c) => c,
+            None => anyhow::bail!("not_found"),
+        };
+        let thread = match self.comments.get_thread(comment.thread_id).await? {
+            Some(t) => t,
+            None => anyhow::bail!("not_found"),
+        };
+        let cap =
+            access::resolve_document(self.access, self.shares, actor, thread.document_id).await;
+        if cap < Capability::View {
+            anyhow::bail!("not_found");
+        }
+        let author = actor_user_id(actor);
+        let is_author = author.is_some() && comment.author_id == author;
+        if !(cap >= Capability::Edit || (is_author && cap >= Capability::Comment)) {
+            anyhow::bail!("forbidden");
+        }
+        if !self.comments.delete_comment(comment.id).await? {
+            anyhow::bail!("not_found");
+        }
+        if self.comments.delete_thread_if_empty(thread.id).await? {
+            publish_event(
+                self.realtime,
+                thread.document_id,
+                "thread_deleted",
+                thread.id,
+                None,
+            )
+            .await;
+        } else {
+            publish_event(
+                self.realtime,
+                thread.document_id,
+                "comment_deleted",
+                thread.id,
+                Some(comment.id),
+            )
+            .await;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use uuid::Uuid;
+
+use super::{actor_user_id, publish_event};
+use crate::application::access::{self, Actor, Capability};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::CommentRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct DeleteThread<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, C, A, S, RT> DeleteThread<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    /// Thread starters may delete their own threads; editors may delete any.
+    pub async fn execute(&self, actor: &Actor, thread_id: Uuid) -> anyhow::Result<()> {
+        let thread = match self.comments.get_thread(thread_id).await? {
+            Some(t) => t,
+            None => anyhow::bail!("not_found"),
+        };
+        let cap =
+            access::resolve_document(self.access, self.shares, actor, thread.document_id).await;
+        if cap < Capability::View {
+            anyhow::bail!("not_found");
+        }
+        let author = actor_user_id(actor);
+        let is_creator = author.is_some() && thread.created_by == author;
+        if !(cap >= Capability::Edit || (is_creator && cap >= Capability::Comment)) {
+            anyhow::bail!("forbidden");
+        }
+        if !self.comments.delete_thread(thread.id).await? {
+            anyhow::bail!("not_found");
+        }
+        publish_event(
+            self.realtime,
+            thread.document_id,
+            "thread_deleted",
+            thread.id,
+            None,
+        )
+        .await;
+        Ok(())
+    }
+}
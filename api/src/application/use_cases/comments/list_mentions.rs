@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::application::access::{self, Actor, Capability};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::{CommentRepository, MentionRow};
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct ListMentions<'a, C, A, S>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+}
+
+impl<'a, C, A, S> ListMentions<'a, C, A, S>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    /// Mentions in documents the user can still view; access is re-checked on every read.
+    pub async fn execute(&self, user_id: Uuid, limit: i64) -> anyhow::Result<Vec<MentionRow>> {
+        let limit = limit.clamp(1, 200) as usize;
+        // Read past `limit` so mentions in documents that became invisible don't shrink the page.
+        let rows = self
+            .comments
+            .list_mentions_for_user(user_id, (limit * 4) as i64)
+            .await?;
+        let actor = Actor::User(user_id);
+        let mut visible: HashMap<Uuid, bool> = HashMap::new();
+        let mut out = Vec::new();
+        for row in rows {
+            if out.len() >= limit {
+                break;
+            }
+            let can_view = match visible.get(&row.document_id) {
+                Some(v) => *v,
+                None => {
+                    let cap =
+                        access::resolve_document(self.access, self.shares, &actor, row.document_id)
+                            .await;
+                    let v = cap >= Capability::View;
+                    visible.insert(row.document_id, v);
+                    v
+                }
+            };
+            if can_view {
+                out.push(row);
+            }
+        }
+        Ok(out)
+    }
+}
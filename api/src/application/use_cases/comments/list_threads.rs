@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use super::CommentThreadView;
+use crate::application::access::{self, Actor};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::{CommentRepository, CommentRow};
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct ListThreads<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, C, A, S, RT> ListThreads<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        doc_id: Uuid,
+    ) -> anyhow::Result<Option<Vec<CommentThreadView>>> {
+        if access::require_view(self.access, self.shares, actor, doc_id)
+            .await
+            .is_err()
+        {
+            return Ok(None);
+        }
+        let threads = self.comments.list_threads(doc_id).await?;
+        let mut by_thread: HashMap<Uuid, Vec<CommentRow>> = HashMap::new();
+        for c in self.comments.list_comments(doc_id).await? {
+            by_thread.entry(c.thread_id).or_default().push(c);
+        }
+
+        // Resolve every anchor against the live document in one pass.
+        let mut anchors: Vec<Vec<u8>> = Vec::new();
+        for t in &threads {
+            anchors.push(t.anchor_start.clone().unwrap_or_default());
+            anchors.push(t.anchor_end.clone().unwrap_or_default());
+        }
+        let offsets = if threads.iter().any(|t| t.anchor_start.is_some()) {
+            match self
+                .realtime
+                .resolve_anchors(&doc_id.to_string(), &anchors)
+                .await
+            {
+                Ok(o) => o,
+                Err(e) => {
+                    tracing::warn!(document_id = %doc_id, error = ?e, "comment_anchor_resolution_failed");
+                    vec![None; anchors.len()]
+                }
+            }
+        } else {
+            vec![None; anchors.len()]
+        };
+
+        Ok(Some(
+            threads
+                .into_iter()
+                .enumerate()
+                .map(|(i, thread)| {
+                    let comments = by_thread.remove(&thread.id).unwrap_or_default();
+                    CommentThreadView {
+                        start_offset: offsets.get(i * 2).copied().flatten(),
+                        end_offset: offsets.get(i * 2 + 1).copied().flatten(),
+                        thread,
+                        comments,
+                    }
+                })
+                .collect(),
+        ))
+    }
+}
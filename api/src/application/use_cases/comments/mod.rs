@@ -0,0 +1,85 @@
+use uuid::Uuid;
+
+use crate::application::access::{self, Actor, Capability};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::{CommentRow, CommentThreadRow};
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub mod add_reply;
+pub mod create_thread;
+pub mod delete_comment;
+pub mod delete_thread;
+pub mod list_mentions;
+pub mod list_threads;
+pub mod set_thread_status;
+pub mod update_comment;
+
+const MAX_BODY_LEN: usize = 10_000;
+
+#[derive(Debug, Clone)]
+pub struct CommentThreadView {
+    pub thread: CommentThreadRow,
+    pub comments: Vec<CommentRow>,
+    /// Current UTF-16 offsets of the anchors in the document content
+    pub start_offset: Option<u32>,
+    pub end_offset: Option<u32>,
+}
+
+fn actor_user_id(actor: &Actor) -> Option<Uuid> {
+    match actor {
+        Actor::User(uid) => Some(*uid),
+        _ => None,
+    }
+}
+
+fn normalize_body(body: &str) -> anyhow::Result<String> {
+    let body = body.trim();
+    if body.is_empty() || body.len() > MAX_BODY_LEN {
+        anyhow::bail!("bad_request");
+    }
+    Ok(body.to_string())
+}
+
+// Keeps only mentioned users that can see the document, so mentions never leak access.
+async fn filter_mentions<A, S>(
+    access_repo: &A,
+    shares: &S,
+    doc_id: Uuid,
+    mentions: &[Uuid],
+) -> Vec<Uuid>
+where
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    let mut out: Vec<Uuid> = Vec::new();
+    for uid in mentions {
+        if out.contains(uid) {
+            continue;
+        }
+        let cap = access::resolve_document(access_repo, shares, &Actor::User(*uid), doc_id).await;
+        if cap >= Capability::View {
+            out.push(*uid);
+        }
+    }
+    out
+}
+
+async fn publish_event<RT: RealtimeEngine + ?Sized>(
+    realtime: &RT,
+    doc_id: Uuid,
+    event: &str,
+    thread_id: Uuid,
+    comment_id: Option<Uuid>,
+) {
+    let payload = serde_json::json!({
+        "type": "comments",
+        "event": event,
+        "document_id": doc_id,
+        "thread_id": thread_id,
+        "comment_id": comment_id,
+    });
+    if let Err(e) = realtime.publish_event(&doc_id.to_string(), &payload).await {
+        tracing::warn!(document_id = %doc_id, error = ?e, "comment_event_publish_failed");
+    }
+}
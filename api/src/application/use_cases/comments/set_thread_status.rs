@@ -0,0 +1,64 @@
+use uuid::Uuid;
+
+use super::{actor_user_id, publish_event};
+use crate::application::access::{self, Actor};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::{CommentRepository, CommentThreadRow};
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct SetThreadStatus<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, C, A, S, RT> SetThreadStatus<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    /// Resolves (`resolved = true`) or reopens a thread.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        thread_id: Uuid,
+        resolved: bool,
+    ) -> anyhow::Result<CommentThreadRow> {
+        let thread = match self.comments.get_thread(thread_id).await? {
+            Some(t) => t,
+            None => anyhow::bail!("not_found"),
+        };
+        if access::require_view(self.access, self.shares, actor, thread.document_id)
+            .await
+            .is_err()
+        {
+            anyhow::bail!("not_found");
+        }
+        access::require_comment(self.access, self.shares, actor, thread.document_id).await?;
+        let (status, event) = if resolved {
+            ("resolved", "thread_resolved")
+        } else {
+            ("open", "thread_reopened")
+        };
+        let updated = match self
+            .comments
+            .set_thread_status(thread.id, status, actor_user_id(actor))
+            .await?
+        {
+            Some(t) => t,
+            None => anyhow::bail!("not_found"),
+        };
+        publish_event(self.realtime, thread.document_id, event, thread.id, None).await;
+        Ok(updated)
+    }
+}
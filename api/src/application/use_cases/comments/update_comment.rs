@@ -0,0 +1,78 @@
+use uuid::Uuid;
+
+use super::{actor_user_id, filter_mentions, normalize_body, publish_event};
+use crate::application::access::{self, Actor};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::comment_repository::{CommentRepository, CommentRow};
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub struct UpdateComment<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub comments: &'a C,
+    pub access: &'a A,
+    pub shares: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, C, A, S, RT> UpdateComment<'a, C, A, S, RT>
+where
+    C: CommentRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    /// Only the author may edit a comment.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        comment_id: Uuid,
+        body: &str,
+        mentions: &[Uuid],
+    ) -> anyhow::Result<CommentRow> {
+        let comment = match self.comments.get_comment(comment_id).await? {
+            Some(c) => c,
+            None => anyhow::bail!("not_found"),
+        };
+        let thread = match self.comments.get_thread(comment.thread_id).await? {
+            Some(t) => t,
+            None => anyhow::bail!("not_found"),
+        };
+        if access::require_view(self.access, self.shares, actor, thread.document_id)
+            .await
+            .is_err()
+        {
+            anyhow::bail!("not_found");
+        }
+        let author = actor_user_id(actor);
+        if author.is_none() || comment.author_id != author {
+            anyhow::bail!("forbidden");
+        }
+        access::require_comment(self.access, self.shares, actor, thread.document_id).await?;
+        let body = normalize_body(body)?;
+        let mentions =
+            filter_mentions(self.access, self.shares, thread.document_id, mentions).await;
+        let updated = match self
+            .comments
+            .update_comment(comment.id, &body, &mentions)
+            .await?
+        {
+            Some(c) => c,
+            None => anyhow::bail!("not_found"),
+        };
+        publish_event(
+            self.realtime,
+            thread.document_id,
+            "comment_updated",
+            thread.id,
+            Some(comment.id),
+        )
+        .await;
+        Ok(updated)
+    }
+}
@@ -1,26 +1,30 @@
 use uuid::Uuid;
 
+use crate::application::ports::comment_repository::CommentRepository;
 use crate::application::ports::document_repository::DocumentRepository;
 use crate::application::ports::realtime_port::RealtimeEngine;
 use crate::application::ports::storage_port::StoragePort;
 use crate::domain::documents::document::Document as DomainDocument;
 
-pub struct ArchiveDocument<'a, R, RT, S>
+pub struct ArchiveDocument<'a, R, RT, S, C>
 where
     R: DocumentRepository + ?Sized,
     RT: RealtimeEngine + ?Sized,
     S: StoragePort + ?Sized,
+    C: CommentRepository + ?Sized,
 {
     pub repo: &'a R,
     pub realtime: &'a RT,
     pub storage: &'a S,
+    pub comments: &'a C,
 }
 
-impl<'a, R, RT, S> ArchiveDocument<'a, R, RT, S>
+impl<'a, R, RT, S, C> ArchiveDocument<'a, R, RT, S, C>
 where
     R: DocumentRepository + ?Sized,
     RT: RealtimeEngine + ?Sized,
     S: StoragePort + ?Sized,
+    C: CommentRepository + ?Sized,
 {
     pub async fn execute(
         &self,
@@ -61,6 +65,9 @@ where
                     .set_document_editable(&node.id.to_string(), false)
                     .await?;
             }
+            // Archived documents are read-only, so their discussions are closed out.
+            let ids: Vec<Uuid> = subtree.iter().map(|node| node.id).collect();
+            self.comments.resolve_open_for_documents(&ids).await?;
         }
 
         Ok(doc)
@@ -1,22 +1,245 @@
 use uuid::Uuid;
 
 use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::files_repository::FilesRepository;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::services::markdown::attachments::rewrite_attachment_refs;
+use crate::application::services::realtime::snapshot::SnapshotService;
+use crate::application::use_cases::files::upload_file::UploadFile;
 use crate::domain::documents::document::Document as DomainDocument;
 
-pub struct CreateDocument<'a, R: DocumentRepository + ?Sized> {
+pub struct InitialAttachment {
+    pub filename: Option<String>,
+    pub content_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+/// Optional body for a new document. Links to `./attachments/<filename>` are pointed at the
+/// stored attachment names.
+#[derive(Default)]
+pub struct InitialContent {
+    pub markdown: Option<String>,
+    pub attachments: Vec<InitialAttachment>,
+}
+
+impl InitialContent {
+    fn is_empty(&self) -> bool {
+        self.markdown.as_deref().is_none_or(str::is_empty) && self.attachments.is_empty()
+    }
+}
+
+pub struct CreateDocument<'a, R, F, S>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
     pub repo: &'a R,
+    pub files: &'a F,
+    pub storage: &'a S,
+    pub snapshots: &'a SnapshotService,
+    pub public_base_url: Option<String>,
 }
 
-impl<'a, R: DocumentRepository + ?Sized> CreateDocument<'a, R> {
+impl<'a, R, F, S> CreateDocument<'a, R, F, S>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
     pub async fn execute(
         &self,
         user_id: Uuid,
+        workspace_id: Uuid,
         title: &str,
         parent_id: Option<Uuid>,
         doc_type: &str,
+        initial: InitialContent,
     ) -> anyhow::Result<DomainDocument> {
-        self.repo
-            .create_for_user(user_id, title, parent_id, doc_type)
+        if doc_type == "folder" && !initial.is_empty() {
+            anyhow::bail!("bad_request");
+        }
+        let doc = self
+            .repo
+            .create_for_user(user_id, workspace_id, title, parent_id, doc_type)
+            .await?;
+        if initial.is_empty() {
+            return Ok(doc);
+        }
+        if let Err(err) = self.seed(user_id, doc.id, initial).await {
+            // Don't leave a half-initialised document behind.
+            if let Ok(Some(_)) = self.repo.delete_owned(doc.id, user_id).await {
+                let _ = self.storage.delete_doc_physical(doc.id).await;
+            }
+            return Err(err);
+        }
+        Ok(doc)
+    }
+
+    async fn seed(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        initial: InitialContent,
+    ) -> anyhow::Result<()> {
+        let upload = UploadFile {
+            repo: self.files,
+            storage: self.storage,
+            public_base_url: self.public_base_url.clone(),
+        };
+        let mut markdown = initial.markdown.unwrap_or_default();
+        for attachment in initial.attachments {
+            let original = attachment.filename.clone();
+            let Some(file) = upload
+                .execute(
+                    user_id,
+                    doc_id,
+                    attachment.bytes,
+                    attachment.filename,
+                    attachment.content_type,
+                )
+                .await?
+            else {
+                anyhow::bail!("forbidden");
+            };
+            if let Some(original) = original
+                && original != file.filename
+            {
+                markdown = rewrite_attachment_refs(&markdown, &original, &file.filename);
+            }
+        }
+        if !markdown.is_empty() {
+            self.snapshots.seed_markdown(&doc_id, &markdown).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::infrastructure::db::PgPool;
+    use crate::infrastructure::db::repositories::document_repository_sqlx::SqlxDocumentRepository;
+    use crate::infrastructure::db::repositories::document_snapshot_archive_repository_sqlx::SqlxDocumentSnapshotArchiveRepository;
+    use crate::infrastructure::db::repositories::files_repository_sqlx::SqlxFilesRepository;
+    use crate::infrastructure::db::repositories::linkgraph_repository_sqlx::SqlxLinkGraphRepository;
+    use crate::infrastructure::db::repositories::property_repository_sqlx::SqlxPropertyRepository;
+    use crate::infrastructure::db::repositories::search_index_repository_sqlx::SqlxSearchIndexRepository;
+    use crate::infrastructure::db::repositories::tagging_repository_sqlx::SqlxTaggingRepository;
+    use crate::infrastructure::db::test_support::{insert_user, insert_workspace, test_pool};
+    use crate::infrastructure::realtime::{SqlxDocPersistenceAdapter, SqlxDocStateReader};
+    use crate::infrastructure::storage::port_impl::FsStoragePort;
+
+    fn snapshots(pool: &PgPool, storage: Arc<FsStoragePort>) -> SnapshotService {
+        SnapshotService::new(
+            Arc::new(SqlxDocStateReader::new(pool.clone())),
+            Arc::new(SqlxDocPersistenceAdapter::new(pool.clone())),
+            storage,
+            Arc::new(SqlxLinkGraphRepository::new(pool.clone())),
+            Arc::new(SqlxTaggingRepository::new(pool.clone())),
+            Arc::new(SqlxSearchIndexRepository::new(pool.clone())),
+            Arc::new(SqlxPropertyRepository::new(pool.clone())),
+            Arc::new(SqlxDocumentSnapshotArchiveRepository::new(pool.clone())),
+        )
+    }
+
+    async fn create(
+        pool: &PgPool,
+        uploads_root: &Path,
+        doc_type: &str,
+        initial: InitialContent,
+    ) -> (Uuid, anyhow::Result<DomainDocument>) {
+        let owner = insert_user(pool).await;
+        let ws = insert_workspace(pool, owner).await;
+        let storage = Arc::new(FsStoragePort {
+            pool: pool.clone(),
+            uploads_root: uploads_root.to_path_buf(),
+        });
+        let repo = SqlxDocumentRepository::new(pool.clone());
+        let files = SqlxFilesRepository::new(pool.clone());
+        let snapshots = snapshots(pool, storage.clone());
+        let uc = CreateDocument {
+            repo: &repo,
+            files: &files,
+            storage: storage.as_ref(),
+            snapshots: &snapshots,
+            public_base_url: None,
+        };
+        let result = uc
+            .execute(owner, ws, "Notes", None, doc_type, initial)
+            .await;
+        (ws, result)
+    }
+
+    fn with_attachment(markdown: &str) -> InitialContent {
+        InitialContent {
+            markdown: Some(markdown.to_string()),
+            attachments: vec![InitialAttachment {
+                filename: Some("diagram.png".to_string()),
+                content_type: Some("image/png".to_string()),
+                bytes: vec![1, 2, 3],
+            }],
+        }
+    }
+
+    async fn document_count(pool: &PgPool, ws: Uuid) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM documents WHERE workspace_id = $1")
+            .bind(ws)
+            .fetch_one(pool)
             .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn seeds_markdown_and_points_links_at_stored_attachments() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let root = tempfile::tempdir().unwrap();
+        let (_, result) = create(
+            &pool,
+            root.path(),
+            "document",
+            with_attachment("# Plan\n\n![](./attachments/diagram.png)\n"),
+        )
+        .await;
+        let doc = result.unwrap();
+
+        let stored: String =
+            sqlx::query_scalar("SELECT filename FROM files WHERE document_id = $1")
+                .bind(doc.id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_ne!(stored, "diagram.png");
+        let storage = FsStoragePort {
+            pool: pool.clone(),
+            uploads_root: root.path().to_path_buf(),
+        };
+        let path = storage.build_doc_file_path(doc.id).await.unwrap();
+        let written = tokio::fs::read_to_string(path).await.unwrap();
+        assert!(written.contains(&format!("![](./attachments/{stored})")));
+        assert!(written.contains("# Plan"));
+    }
+
+    #[tokio::test]
+    async fn folders_take_no_content_and_failed_seeds_leave_nothing_behind() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let root = tempfile::tempdir().unwrap();
+        let (ws, result) = create(&pool, root.path(), "folder", with_attachment("x")).await;
+        assert_eq!(result.unwrap_err().to_string(), "bad_request");
+        assert_eq!(document_count(&pool, ws).await, 0);
+
+        // A file where the uploads directory should be makes the attachment write fail
+        let blocked = root.path().join("not-a-dir");
+        std::fs::write(&blocked, b"").unwrap();
+        let (ws, result) = create(&pool, &blocked, "document", with_attachment("x")).await;
+        assert!(result.is_err());
+        assert_eq!(document_count(&pool, ws).await, 0);
     }
 }
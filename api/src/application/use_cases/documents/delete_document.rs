@@ -1,32 +1,40 @@
 use uuid::Uuid;
 
 use crate::application::ports::document_repository::DocumentRepository;
-use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::realtime_port::RealtimeEngine;
 
-pub struct DeleteDocument<'a, R, S>
+/// Moves a document or folder subtree to the trash; see `use_cases::trash` for the rest.
+pub struct DeleteDocument<'a, R, RT>
 where
     R: DocumentRepository + ?Sized,
-    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
 {
     pub repo: &'a R,
-    pub storage: &'a S,
+    pub realtime: &'a RT,
 }
 
-impl<'a, R, S> DeleteDocument<'a, R, S>
+impl<'a, R, RT> DeleteDocument<'a, R, RT>
 where
     R: DocumentRepository + ?Sized,
-    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
 {
     pub async fn execute(&self, id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
-        if let Some(dtype) = self.repo.delete_owned(id, user_id).await? {
-            if dtype == "folder" {
-                let _ = self.storage.delete_folder_physical(id).await;
-            } else {
-                let _ = self.storage.delete_doc_physical(id).await;
+        if self.repo.get_meta_for_owner(id, user_id).await?.is_none() {
+            return Ok(false);
+        }
+        let subtree = self.repo.list_owned_subtree_documents(user_id, id).await?;
+        for node in &subtree {
+            if node.doc_type != "folder" {
+                self.realtime.force_persist(&node.id.to_string()).await?;
             }
-            Ok(true)
-        } else {
-            Ok(false)
         }
+
+        let trashed = self.repo.trash_subtree(id, user_id).await?;
+        for node in &trashed {
+            self.realtime
+                .set_document_editable(&node.id.to_string(), false)
+                .await?;
+        }
+        Ok(!trashed.is_empty())
     }
 }
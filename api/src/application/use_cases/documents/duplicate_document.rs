@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::application::linkgraph::rewrite_wiki_links;
+use crate::application::ports::document_repository::{DocumentRepository, SubtreeNode};
+use crate::application::ports::files_repository::FilesRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::services::markdown::attachments::{
+    original_attachment_name, rewrite_attachment_refs,
+};
+use crate::application::services::realtime::snapshot::SnapshotService;
+use crate::application::services::templates::render_template;
+use crate::domain::documents::document::Document as DomainDocument;
+
+pub struct DuplicateOptions {
+    pub workspace_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    /// Defaults to "<title> (copy)", or the rendered title when instantiating
+    pub title: Option<String>,
+    /// Point `[[...]]` links between documents of the subtree at their copies
+    pub rewrite_links: bool,
+    /// Substituted into `{{name}}` placeholders of titles and content (template instantiation)
+    pub variables: Option<HashMap<String, String>>,
+}
+
+impl DuplicateOptions {
+    fn render(&self, text: &str) -> String {
+        match &self.variables {
+            Some(vars) => render_template(text, vars),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// Deep-copies a document or folder subtree: content, attachments and (via content) tags.
+pub struct DuplicateDocument<'a, R, F, S, RT>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub repo: &'a R,
+    pub files: &'a F,
+    pub storage: &'a S,
+    pub realtime: &'a RT,
+    pub snapshots: &'a SnapshotService,
+}
+
+impl<'a, R, F, S, RT> DuplicateDocument<'a, R, F, S, RT>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        source_id: Uuid,
+        options: DuplicateOptions,
+    ) -> anyhow::Result<DomainDocument> {
+        let nodes = self
+            .repo
+            .list_owned_subtree_nodes(user_id, source_id)
+            .await?;
+        let Some(root) = nodes.first() else {
+            anyhow::bail!("not_found");
+        };
+        if let Some(parent_id) = options.parent_id
+            && nodes.iter().any(|n| n.id == parent_id)
+        {
+            anyhow::bail!("bad_request");
+        }
+
+        let root_title = match (&options.title, &options.variables) {
+            (Some(title), _) => title.clone(),
+            (None, Some(_)) => options.render(&root.title),
+            (None, None) => format!("{} (copy)", root.title),
+        };
+        let copy = self
+            .repo
+            .create_for_user(
+                user_id,
+                options.workspace_id,
+                &root_title,
+                options.parent_id,
+                &root.doc_type,
+            )
+            .await?;
+        match self.copy_subtree(user_id, &nodes, copy.id, &options).await {
+            Ok(()) => Ok(copy),
+            Err(err) => {
+                // Children go with the root copy.
+                if let Ok(Some(dtype)) = self.repo.delete_owned(copy.id, user_id).await {
+                    let _ = if dtype == "folder" {
+                        self.storage
+                            .delete_folder_physical(copy.id)
+                            .await
+                            .map(|_| ())
+                    } else {
+                        self.storage.delete_doc_physical(copy.id).await
+                    };
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn copy_subtree(
+        &self,
+        user_id: Uuid,
+        nodes: &[SubtreeNode],
+        root_copy: Uuid,
+        options: &DuplicateOptions,
+    ) -> anyhow::Result<()> {
+        let mut ids: HashMap<Uuid, Uuid> = HashMap::with_capacity(nodes.len());
+        ids.insert(nodes[0].id, root_copy);
+        for node in &nodes[1..] {
+            let Some(parent) = node.parent_id.and_then(|p| ids.get(&p).copied()) else {
+                continue;
+            };
+            let copy = self
+                .repo
+                .create_for_user(
+                    user_id,
+                    options.workspace_id,
+                    &options.render(&node.title),
+                    Some(parent),
+                    &node.doc_type,
+                )
+                .await?;
+            ids.insert(node.id, copy.id);
+        }
+
+        let titles: HashMap<String, Uuid> = nodes
+            .iter()
+            .filter_map(|n| ids.get(&n.id).map(|copy| (n.title.to_lowercase(), *copy)))
+            .collect();
+        for node in nodes.iter().filter(|n| n.doc_type != "folder") {
+            let Some(&copy_id) = ids.get(&node.id) else {
+                continue;
+            };
+            let mut content = self
+                .realtime
+                .get_content(&node.id.to_string())
+                .await?
+                .unwrap_or_default();
+            for file in self.files.list_files_for_document(node.id).await? {
+                let abs = self.storage.absolute_from_relative(&file.storage_path);
+                let bytes = match self.storage.read_bytes(abs.as_path()).await {
+                    Ok(bytes) => bytes,
+                    Err(err) => {
+                        tracing::warn!(document_id = %node.id, path = file.storage_path.as_str(), error = ?err, "duplicate_attachment_missing");
+                        continue;
+                    }
+                };
+                let name = original_attachment_name(&file.filename);
+                let stored = self
+                    .storage
+                    .store_doc_attachment(copy_id, Some(&name), &bytes)
+                    .await?;
+                self.files
+                    .insert_file(
+                        copy_id,
+                        &stored.filename,
+                        file.content_type.as_deref(),
+                        stored.size,
+                        &stored.relative_path,
+                        &stored.content_hash,
+                    )
+                    .await?;
+                if stored.filename != file.filename {
+                    content = rewrite_attachment_refs(&content, &file.filename, &stored.filename);
+                }
+            }
+            if options.rewrite_links {
+                content = rewrite_wiki_links(&content, |target, alias| {
+                    // Title links become id links: the copies share their originals' titles.
+                    let copy = match Uuid::parse_str(target) {
+                        Ok(id) => ids.get(&id).copied(),
+                        Err(_) => titles.get(&target.to_lowercase()).copied(),
+                    }?;
+                    let label =
+                        alias.or_else(|| Uuid::parse_str(target).is_err().then_some(target));
+                    Some(match label {
+                        Some(label) => format!("{copy}|{label}"),
+                        None => copy.to_string(),
+                    })
+                });
+            }
+            if options.variables.is_some() {
+                content = options.render(&content);
+            }
+            if !content.is_empty() {
+                self.snapshots.seed_markdown(&copy_id, &content).await?;
+            }
+        }
+        Ok(())
+    }
+}
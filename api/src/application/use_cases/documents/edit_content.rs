@@ -0,0 +1,94 @@
+use uuid::Uuid;
+
+use crate::application::access::{self, Actor, Capability};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::realtime_types::{ContentEdit, ContentState};
+use crate::application::ports::share_access_port::ShareAccessPort;
+use crate::application::services::realtime::content_edit::content_hash;
+
+pub struct GetDocumentContent<'a, S, A, RT>
+where
+    S: ShareAccessPort + ?Sized,
+    A: AccessRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub shares: &'a S,
+    pub access: &'a A,
+    pub realtime: &'a RT,
+}
+
+impl<'a, S, A, RT> GetDocumentContent<'a, S, A, RT>
+where
+    S: ShareAccessPort + ?Sized,
+    A: AccessRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub async fn execute(&self, actor: &Actor, id: Uuid) -> anyhow::Result<ContentState> {
+        let cap = access::resolve_document(self.access, self.shares, actor, id).await;
+        if cap < Capability::View {
+            anyhow::bail!("not_found");
+        }
+        let content = self
+            .realtime
+            .get_content(&id.to_string())
+            .await?
+            .unwrap_or_default();
+        Ok(ContentState {
+            hash: content_hash(&content),
+            content,
+        })
+    }
+}
+
+/// Replaces or patches document text through the realtime engine.
+pub struct EditDocumentContent<'a, R, S, A, RT>
+where
+    R: DocumentRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    A: AccessRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub repo: &'a R,
+    pub shares: &'a S,
+    pub access: &'a A,
+    pub realtime: &'a RT,
+}
+
+impl<'a, R, S, A, RT> EditDocumentContent<'a, R, S, A, RT>
+where
+    R: DocumentRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    A: AccessRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        id: Uuid,
+        edit: &ContentEdit,
+        if_match: Option<&str>,
+    ) -> anyhow::Result<ContentState> {
+        let cap = access::resolve_document(self.access, self.shares, actor, id).await;
+        if cap < Capability::View {
+            anyhow::bail!("not_found");
+        }
+        if cap < Capability::Edit {
+            anyhow::bail!("forbidden");
+        }
+        let Some(doc) = self.repo.get_by_id(id).await? else {
+            anyhow::bail!("not_found");
+        };
+        if doc.doc_type == "folder" {
+            anyhow::bail!("bad_request");
+        }
+        // Archived documents are read-only for realtime sessions too.
+        if doc.archived_at.is_some() {
+            anyhow::bail!("conflict");
+        }
+        self.realtime
+            .edit_content(&id.to_string(), edit, if_match)
+            .await
+    }
+}
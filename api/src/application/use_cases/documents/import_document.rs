@@ -0,0 +1,320 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use pandoc::{self, InputFormat, InputKind, OutputFormat, OutputKind, PandocOption, PandocOutput};
+use tempfile::tempdir;
+use tokio::task;
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::files_repository::FilesRepository;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::services::realtime::snapshot::SnapshotService;
+use crate::application::use_cases::documents::create_document::{
+    CreateDocument, InitialAttachment, InitialContent,
+};
+use crate::domain::documents::document::Document as DomainDocument;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Docx,
+    Odt,
+    Html,
+    Rst,
+    Org,
+    Latex,
+    Epub,
+}
+
+impl ImportFormat {
+    pub fn from_filename(name: &str) -> Option<Self> {
+        let ext = Path::new(name).extension()?.to_str()?.to_ascii_lowercase();
+        Some(match ext.as_str() {
+            "docx" => Self::Docx,
+            "odt" => Self::Odt,
+            "html" | "htm" | "xhtml" => Self::Html,
+            "rst" => Self::Rst,
+            "org" => Self::Org,
+            "tex" | "latex" => Self::Latex,
+            "epub" => Self::Epub,
+            _ => return None,
+        })
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Docx => "docx",
+            Self::Odt => "odt",
+            Self::Html => "html",
+            Self::Rst => "rst",
+            Self::Org => "org",
+            Self::Latex => "tex",
+            Self::Epub => "epub",
+        }
+    }
+
+    fn input_format(&self) -> InputFormat {
+        match self {
+            Self::Docx => InputFormat::Docx,
+            Self::Odt => InputFormat::Other("odt".to_string()),
+            Self::Html => InputFormat::Html,
+            Self::Rst => InputFormat::Rst,
+            Self::Org => InputFormat::Org,
+            Self::Latex => InputFormat::Latex,
+            Self::Epub => InputFormat::Epub,
+        }
+    }
+}
+
+pub struct ImportSource {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Converts a document with pandoc and creates it; embedded media become attachments.
+pub struct ImportDocument<'a, R, F, S>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub repo: &'a R,
+    pub files: &'a F,
+    pub storage: &'a S,
+    pub snapshots: &'a SnapshotService,
+    pub public_base_url: Option<String>,
+}
+
+impl<'a, R, F, S> ImportDocument<'a, R, F, S>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+        parent_id: Option<Uuid>,
+        title: Option<String>,
+        source: ImportSource,
+    ) -> anyhow::Result<DomainDocument> {
+        let Some(format) = ImportFormat::from_filename(&source.filename) else {
+            anyhow::bail!("unsupported_format");
+        };
+        let title = title
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .or_else(|| {
+                Path::new(&source.filename)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| "Imported document".to_string());
+        let initial = convert_with_pandoc(format, source.bytes).await?;
+        let uc = CreateDocument {
+            repo: self.repo,
+            files: self.files,
+            storage: self.storage,
+            snapshots: self.snapshots,
+            public_base_url: self.public_base_url.clone(),
+        };
+        uc.execute(
+            user_id,
+            workspace_id,
+            &title,
+            parent_id,
+            "document",
+            initial,
+        )
+        .await
+    }
+}
+
+async fn convert_with_pandoc(
+    format: ImportFormat,
+    bytes: Vec<u8>,
+) -> anyhow::Result<InitialContent> {
+    task::spawn_blocking(move || -> anyhow::Result<InitialContent> {
+        let tmp_dir = tempdir().context("unable to create temporary directory for pandoc")?;
+        let input = tmp_dir.path().join(format!("input.{}", format.extension()));
+        std::fs::write(&input, &bytes).context("failed to write pandoc input")?;
+        let media_dir = tmp_dir.path().join("media");
+
+        let mut pandoc_cmd = pandoc::new();
+        pandoc_cmd.set_input(InputKind::Files(vec![input]));
+        pandoc_cmd.set_input_format(format.input_format(), Vec::new());
+        pandoc_cmd.set_output_format(OutputFormat::Other("gfm".to_string()), Vec::new());
+        pandoc_cmd.set_output(OutputKind::Pipe);
+        pandoc_cmd.add_option(PandocOption::ExtractMedia(media_dir.clone()));
+        pandoc_cmd.add_option(PandocOption::NoWrap);
+        // Uploads are untrusted: no `\input`, `.. include::`, `#+INCLUDE:` or remote fetches.
+        pandoc_cmd.add_option(PandocOption::Sandbox);
+
+        let output = pandoc_cmd.execute().map_err(|err| match err {
+            pandoc::PandocError::PandocNotFound => {
+                anyhow::anyhow!("pandoc executable not found in PATH; install pandoc to enable import")
+            }
+            pandoc::PandocError::Err(output) => {
+                // Almost always a malformed or mislabelled upload.
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tracing::warn!(status = %output.status, stderr = stderr.trim(), "pandoc_import_failed");
+                anyhow::anyhow!("bad_request")
+            }
+            pandoc::PandocError::IoErr(io_err) => anyhow::Error::new(io_err),
+            other => anyhow::Error::new(other),
+        })?;
+        let markdown = match output {
+            PandocOutput::ToBuffer(text) => text,
+            PandocOutput::ToBufferRaw(raw) => String::from_utf8_lossy(&raw).into_owned(),
+            PandocOutput::ToFile(path) => std::fs::read_to_string(&path)?,
+        };
+
+        let mut media = Vec::new();
+        collect_files(&media_dir, &mut media)?;
+        let (markdown, attachments) = relink_media(markdown, media)?;
+        Ok(InitialContent {
+            markdown: Some(markdown),
+            attachments,
+        })
+    })
+    .await?
+}
+
+fn collect_files(dir: &Path, out: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), out)?;
+        } else if file_type.is_file() {
+            out.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+/// Reads the extracted media and points pandoc's absolute references at `./attachments/<name>`.
+fn relink_media(
+    mut markdown: String,
+    mut media: Vec<PathBuf>,
+) -> anyhow::Result<(String, Vec<InitialAttachment>)> {
+    // Longest first so `image1.png` doesn't clobber part of `image10.png`.
+    media.sort_by_key(|p| std::cmp::Reverse(p.as_os_str().len()));
+    let mut used = HashSet::new();
+    let mut attachments = Vec::with_capacity(media.len());
+    for path in media {
+        let name = unique_name(&path, &mut used);
+        markdown = markdown.replace(
+            path.to_string_lossy().as_ref(),
+            &format!("./attachments/{name}"),
+        );
+        attachments.push(InitialAttachment {
+            content_type: Some(
+                mime_guess::from_path(&name)
+                    .first_or_octet_stream()
+                    .essence_str()
+                    .to_string(),
+            ),
+            filename: Some(name),
+            bytes: std::fs::read(&path)?,
+        });
+    }
+    Ok((markdown, attachments))
+}
+
+fn unique_name(path: &Path, used: &mut HashSet<String>) -> String {
+    let raw = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let safe: String = raw
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let safe = if safe.trim_matches('.').is_empty() {
+        "media".to_string()
+    } else {
+        safe
+    };
+    let (stem, ext) = match safe.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => (stem.to_string(), format!(".{ext}")),
+        _ => (safe.clone(), String::new()),
+    };
+    let mut name = safe;
+    let mut n = 1;
+    while !used.insert(name.clone()) {
+        n += 1;
+        name = format!("{stem}-{n}{ext}");
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_formats_and_relinks_media() {
+        assert_eq!(
+            ImportFormat::from_filename("Report.DOCX"),
+            Some(ImportFormat::Docx)
+        );
+        assert_eq!(
+            ImportFormat::from_filename("notes.tex"),
+            Some(ImportFormat::Latex)
+        );
+        assert_eq!(ImportFormat::from_filename("notes.md"), None);
+
+        let dir = tempdir().unwrap();
+        let media = dir.path().join("media");
+        std::fs::create_dir_all(media.join("a")).unwrap();
+        let one = media.join("image1.png");
+        let ten = media.join("image10.png");
+        let dup = media.join("a").join("image1.png");
+        for p in [&one, &ten, &dup] {
+            std::fs::write(p, b"png").unwrap();
+        }
+        let markdown = format!(
+            "![]({})\n![]({})\n![]({})\n",
+            one.display(),
+            ten.display(),
+            dup.display()
+        );
+        let (out, attachments) = relink_media(markdown, vec![one, ten, dup]).unwrap();
+        assert_eq!(attachments.len(), 3);
+        assert!(out.contains("](./attachments/image10.png)"));
+        assert!(out.contains("](./attachments/image1.png)"));
+        assert!(out.contains("](./attachments/image1-2.png)"));
+        assert!(!out.contains(&dir.path().display().to_string()));
+    }
+
+    #[tokio::test]
+    async fn sandbox_keeps_server_files_out_of_imports() {
+        let dir = tempdir().unwrap();
+        let secret = dir.path().join("secret.tex");
+        std::fs::write(&secret, "server-side secret").unwrap();
+        let tex = format!("\\input{{{}}}\n\nvisible text\n", secret.display());
+        match convert_with_pandoc(ImportFormat::Latex, tex.into_bytes()).await {
+            Ok(content) => {
+                let markdown = content.markdown.unwrap_or_default();
+                assert!(markdown.contains("visible text"));
+                assert!(!markdown.contains("server-side secret"));
+            }
+            // No pandoc on PATH: nothing to exercise.
+            Err(e) if e.to_string().starts_with("pandoc executable not found") => {}
+            Err(e) => panic!("conversion failed: {e:?}"),
+        }
+    }
+}
@@ -0,0 +1,612 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::io::Read;
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use tokio::task;
+use uuid::Uuid;
+
+use crate::application::linkgraph::rewrite_wiki_links;
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::files_repository::FilesRepository;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::services::realtime::snapshot::SnapshotService;
+
+// Guards against zip bombs on top of the request body limit.
+const MAX_VAULT_ENTRIES: usize = 10_000;
+
+static ASSET_EMBED_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"!\[\[([^\[\]|]+)(?:\|([^\[\]]*))?\]\]").unwrap());
+static MARKDOWN_LINK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(!?)\[([^\]\n]*)\]\(<?([^)\s>]+)>?(\s+"[^"]*")?\)"#).unwrap());
+
+pub struct ImportVaultOptions {
+    pub workspace_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    /// Only report what would be created
+    pub dry_run: bool,
+    /// Limit on the uncompressed size of the archive
+    pub max_bytes: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportCollision {
+    pub path: String,
+    pub title: String,
+    /// `existing`: an active document in the workspace has this title;
+    /// `duplicate`: several notes of the vault share it
+    pub kind: &'static str,
+    pub existing_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnresolvedLink {
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VaultImportReport {
+    pub dry_run: bool,
+    pub folders: usize,
+    pub documents: usize,
+    pub attachments: usize,
+    /// Top-level folders and documents created; empty for dry runs
+    pub created: Vec<Uuid>,
+    pub collisions: Vec<ImportCollision>,
+    pub unresolved_links: Vec<UnresolvedLink>,
+    /// Entries that were not imported: hidden files, oversized files and unreferenced assets
+    pub skipped: Vec<String>,
+}
+
+struct VaultNote {
+    path: String,
+    dir: String,
+    title: String,
+    content: String,
+}
+
+#[derive(Default)]
+struct Vault {
+    notes: Vec<VaultNote>,
+    assets: BTreeMap<String, Vec<u8>>,
+    folders: BTreeSet<String>,
+    skipped: Vec<String>,
+}
+
+struct RewrittenNote {
+    content: String,
+    assets: BTreeSet<String>,
+    unresolved: Vec<String>,
+}
+
+/// Imports a zip of a Markdown vault (Obsidian, Foam, ...): directories become folders, `.md`
+/// files documents, and referenced assets attachments of the documents using them.
+pub struct ImportVault<'a, R, F, S>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub repo: &'a R,
+    pub files: &'a F,
+    pub storage: &'a S,
+    pub snapshots: &'a SnapshotService,
+}
+
+impl<'a, R, F, S> ImportVault<'a, R, F, S>
+where
+    R: DocumentRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        archive: Vec<u8>,
+        options: ImportVaultOptions,
+    ) -> anyhow::Result<VaultImportReport> {
+        let max_bytes = options.max_bytes;
+        let vault = task::spawn_blocking(move || read_vault(&archive, max_bytes)).await??;
+        if vault.notes.is_empty() {
+            anyhow::bail!("bad_request");
+        }
+
+        let mut report = VaultImportReport {
+            dry_run: options.dry_run,
+            folders: vault.folders.len(),
+            documents: vault.notes.len(),
+            skipped: vault.skipped.clone(),
+            ..Default::default()
+        };
+        let index = VaultIndex::new(&vault);
+        let placeholder_ids = vec![Uuid::nil(); vault.notes.len()];
+        let mut used_assets = HashSet::new();
+        let mut unresolved = Vec::new();
+        for note in &vault.notes {
+            let rewritten = rewrite_note(note, &index, &placeholder_ids, &HashMap::new());
+            report.attachments += rewritten.assets.len();
+            used_assets.extend(rewritten.assets);
+            unresolved.extend(
+                rewritten
+                    .unresolved
+                    .into_iter()
+                    .map(|target| UnresolvedLink {
+                        source: note.path.clone(),
+                        target,
+                    }),
+            );
+        }
+        report.skipped.extend(
+            vault
+                .assets
+                .keys()
+                .filter(|path| !used_assets.contains(*path))
+                .cloned(),
+        );
+
+        // Titles that already exist satisfy `[[Title]]` links and may clash with imported ones.
+        let mut titles: Vec<String> = vault.notes.iter().map(|n| n.title.clone()).collect();
+        titles.extend(vault.folders.iter().map(|f| folder_title(f).to_string()));
+        titles.extend(
+            unresolved
+                .iter()
+                .map(|u| link_target_path(&u.target).to_string()),
+        );
+        let existing: HashMap<String, Uuid> = self
+            .repo
+            .find_active_by_titles(options.workspace_id, &titles)
+            .await?
+            .into_iter()
+            .map(|n| (n.title.to_lowercase(), n.id))
+            .collect();
+        report.unresolved_links = unresolved
+            .into_iter()
+            .filter(|u| !existing.contains_key(&link_target_path(&u.target).to_lowercase()))
+            .collect();
+        let mut seen_titles: HashMap<String, usize> = HashMap::new();
+        for note in &vault.notes {
+            *seen_titles.entry(note.title.to_lowercase()).or_default() += 1;
+        }
+        let entries = vault
+            .folders
+            .iter()
+            .map(|f| (f.as_str(), folder_title(f)))
+            .chain(
+                vault
+                    .notes
+                    .iter()
+                    .map(|n| (n.path.as_str(), n.title.as_str())),
+            );
+        for (path, title) in entries {
+            let key = title.to_lowercase();
+            if let Some(id) = existing.get(&key) {
+                report.collisions.push(ImportCollision {
+                    path: path.to_string(),
+                    title: title.to_string(),
+                    kind: "existing",
+                    existing_id: Some(*id),
+                });
+            } else if seen_titles.get(&key).is_some_and(|n| *n > 1) {
+                report.collisions.push(ImportCollision {
+                    path: path.to_string(),
+                    title: title.to_string(),
+                    kind: "duplicate",
+                    existing_id: None,
+                });
+            }
+        }
+        if options.dry_run {
+            return Ok(report);
+        }
+
+        match self
+            .create_tree(user_id, &vault, &index, &options, &mut report)
+            .await
+        {
+            Ok(()) => Ok(report),
+            Err(err) => {
+                // Children go with their top-level folder.
+                for id in &report.created {
+                    if let Ok(Some(dtype)) = self.repo.delete_owned(*id, user_id).await {
+                        let _ = if dtype == "folder" {
+                            self.storage.delete_folder_physical(*id).await.map(|_| ())
+                        } else {
+                            self.storage.delete_doc_physical(*id).await
+                        };
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn create_tree(
+        &self,
+        user_id: Uuid,
+        vault: &Vault,
+        index: &VaultIndex,
+        options: &ImportVaultOptions,
+        report: &mut VaultImportReport,
+    ) -> anyhow::Result<()> {
+        // BTreeSet order puts every folder after its ancestors.
+        let mut folder_ids: HashMap<&str, Uuid> = HashMap::new();
+        for folder in &vault.folders {
+            let parent = match parent_dir(folder) {
+                "" => options.parent_id,
+                dir => folder_ids.get(dir).copied(),
+            };
+            let doc = self
+                .repo
+                .create_for_user(
+                    user_id,
+                    options.workspace_id,
+                    folder_title(folder),
+                    parent,
+                    "folder",
+                )
+                .await?;
+            if parent == options.parent_id {
+                report.created.push(doc.id);
+            }
+            folder_ids.insert(folder.as_str(), doc.id);
+        }
+
+        let mut note_ids = Vec::with_capacity(vault.notes.len());
+        for note in &vault.notes {
+            let parent = match note.dir.as_str() {
+                "" => options.parent_id,
+                dir => folder_ids.get(dir).copied(),
+            };
+            let doc = self
+                .repo
+                .create_for_user(
+                    user_id,
+                    options.workspace_id,
+                    &note.title,
+                    parent,
+                    "document",
+                )
+                .await?;
+            if parent == options.parent_id {
+                report.created.push(doc.id);
+            }
+            note_ids.push(doc.id);
+        }
+
+        // Every document exists before any content is seeded, so id links resolve in the graph.
+        for (note, &doc_id) in vault.notes.iter().zip(&note_ids) {
+            let assets = rewrite_note(note, index, &note_ids, &HashMap::new()).assets;
+            let mut stored_names = HashMap::new();
+            for asset in assets {
+                let Some(bytes) = vault.assets.get(&asset) else {
+                    continue;
+                };
+                let name = asset.rsplit('/').next().unwrap_or(&asset);
+                let stored = self
+                    .storage
+                    .store_doc_attachment(doc_id, Some(name), bytes)
+                    .await?;
+                let content_type = mime_guess::from_path(name)
+                    .first()
+                    .map(|m| m.essence_str().to_string());
+                self.files
+                    .insert_file(
+                        doc_id,
+                        &stored.filename,
+                        content_type.as_deref(),
+                        stored.size,
+                        &stored.relative_path,
+                        &stored.content_hash,
+                    )
+                    .await?;
+                stored_names.insert(asset, stored.filename);
+            }
+            let content = rewrite_note(note, index, &note_ids, &stored_names).content;
+            if !content.is_empty() {
+                self.snapshots.seed_markdown(&doc_id, &content).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_vault(archive: &[u8], max_bytes: usize) -> anyhow::Result<Vault> {
+    let Ok(mut zip) = zip::ZipArchive::new(std::io::Cursor::new(archive)) else {
+        anyhow::bail!("bad_request");
+    };
+    if zip.len() > MAX_VAULT_ENTRIES {
+        anyhow::bail!("payload_too_large");
+    }
+    let mut vault = Vault::default();
+    let mut total = 0usize;
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i)?;
+        let Some(path) = entry.enclosed_name().map(|p| p.to_path_buf()) else {
+            continue;
+        };
+        let parts: Vec<String> = path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let path = parts.join("/");
+        if entry.is_dir() || path.is_empty() {
+            continue;
+        }
+        let is_symlink = entry
+            .unix_mode()
+            .is_some_and(|mode| (mode & 0o170000) == 0o120000);
+        // Editor state (.obsidian, .foam, .git) and macOS resource forks
+        let hidden = parts.iter().any(|p| p.starts_with('.') || p == "__MACOSX");
+        if hidden || is_symlink {
+            vault.skipped.push(path);
+            continue;
+        }
+        let mut bytes = Vec::new();
+        (&mut entry)
+            .take((max_bytes - total) as u64 + 1)
+            .read_to_end(&mut bytes)?;
+        total += bytes.len();
+        if total > max_bytes {
+            anyhow::bail!("payload_too_large");
+        }
+
+        let name = parts.last().map(String::as_str).unwrap_or_default();
+        match note_title(name) {
+            Some(title) => {
+                let dir = parts[..parts.len() - 1].join("/");
+                let mut ancestor = dir.as_str();
+                while !ancestor.is_empty() && vault.folders.insert(ancestor.to_string()) {
+                    ancestor = parent_dir(ancestor);
+                }
+                let content = String::from_utf8_lossy(&bytes);
+                vault.notes.push(VaultNote {
+                    path: path.clone(),
+                    dir,
+                    title: title.to_string(),
+                    content: content.trim_start_matches('\u{feff}').to_string(),
+                });
+            }
+            _ => {
+                vault.assets.insert(path, bytes);
+            }
+        }
+    }
+    vault.notes.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(vault)
+}
+
+/// Lookup tables following Obsidian's resolution: exact path first, then the file name.
+struct VaultIndex {
+    notes_by_path: HashMap<String, usize>,
+    notes_by_name: HashMap<String, usize>,
+    assets_by_path: HashMap<String, String>,
+    assets_by_name: HashMap<String, String>,
+}
+
+impl VaultIndex {
+    fn new(vault: &Vault) -> Self {
+        let mut index = VaultIndex {
+            notes_by_path: HashMap::new(),
+            notes_by_name: HashMap::new(),
+            assets_by_path: HashMap::new(),
+            assets_by_name: HashMap::new(),
+        };
+        // Shortest path wins for ambiguous names.
+        let mut notes: Vec<(usize, &VaultNote)> = vault.notes.iter().enumerate().collect();
+        notes.sort_by_key(|(_, n)| (n.path.matches('/').count(), n.path.clone()));
+        for (i, note) in notes {
+            let without_ext = if note.dir.is_empty() {
+                note.title.clone()
+            } else {
+                format!("{}/{}", note.dir, note.title)
+            };
+            index.notes_by_path.insert(without_ext.to_lowercase(), i);
+            index
+                .notes_by_name
+                .entry(note.title.to_lowercase())
+                .or_insert(i);
+        }
+        let mut assets: Vec<&String> = vault.assets.keys().collect();
+        assets.sort_by_key(|p| (p.matches('/').count(), (*p).clone()));
+        for path in assets {
+            index
+                .assets_by_path
+                .insert(path.to_lowercase(), path.clone());
+            let name = path.rsplit('/').next().unwrap_or(path).to_lowercase();
+            index.assets_by_name.entry(name).or_insert(path.clone());
+        }
+        index
+    }
+
+    fn note(&self, from_dir: &str, target: &str) -> Option<usize> {
+        let lower = target.to_lowercase();
+        let lower = lower
+            .strip_suffix(".md")
+            .or_else(|| lower.strip_suffix(".markdown"))
+            .unwrap_or(&lower);
+        let relative = join_path(&from_dir.to_lowercase(), lower);
+        relative
+            .and_then(|p| self.notes_by_path.get(&p))
+            .or_else(|| self.notes_by_path.get(lower.trim_start_matches('/')))
+            .or_else(|| {
+                (!lower.contains('/'))
+                    .then(|| self.notes_by_name.get(lower))
+                    .flatten()
+            })
+            .copied()
+    }
+
+    fn asset(&self, from_dir: &str, target: &str) -> Option<&String> {
+        let lower = target.to_lowercase();
+        join_path(&from_dir.to_lowercase(), &lower)
+            .and_then(|p| self.assets_by_path.get(&p))
+            .or_else(|| self.assets_by_path.get(lower.trim_start_matches('/')))
+            .or_else(|| {
+                let name = lower.rsplit('/').next().unwrap_or(&lower);
+                self.assets_by_name.get(name)
+            })
+    }
+}
+
+/// Rewrites links of a note: asset references point at `./attachments/<stored name>` and links
+/// to other notes become `[[<id>|label]]`. Also reports the assets used and unresolved targets.
+fn rewrite_note(
+    note: &VaultNote,
+    index: &VaultIndex,
+    note_ids: &[Uuid],
+    stored_names: &HashMap<String, String>,
+) -> RewrittenNote {
+    let mut assets = BTreeSet::new();
+    let mut unresolved = Vec::new();
+    let attachment_url = |asset: &String| {
+        let name = stored_names
+            .get(asset)
+            .map(String::as_str)
+            .unwrap_or_else(|| asset.rsplit('/').next().unwrap_or(asset));
+        format!("./attachments/{}", urlencoding::encode(name))
+    };
+
+    let content = MARKDOWN_LINK_RE.replace_all(&note.content, |cap: &Captures| {
+        let url = &cap[3];
+        if url.starts_with('#') || url.contains(':') {
+            return cap[0].to_string();
+        }
+        let decoded = urlencoding::decode(url)
+            .map(|d| d.into_owned())
+            .unwrap_or_else(|_| url.to_string());
+        let path = link_target_path(&decoded);
+        if let Some(asset) = index.asset(&note.dir, path) {
+            assets.insert(asset.clone());
+            let title = cap.get(4).map(|m| m.as_str()).unwrap_or_default();
+            return format!("{}[{}]({}{title})", &cap[1], &cap[2], attachment_url(asset));
+        }
+        if cap[1].is_empty()
+            && let Some(i) = index.note(&note.dir, path)
+        {
+            let label = cap[2].replace('|', " ");
+            return format!("[[{}|{}]]", note_ids[i], label.trim());
+        }
+        unresolved.push(decoded.clone());
+        cap[0].to_string()
+    });
+
+    let content = ASSET_EMBED_RE.replace_all(&content, |cap: &Captures| {
+        let target = cap[1].trim();
+        match index.asset(&note.dir, target) {
+            Some(asset) if index.note(&note.dir, target).is_none() => {
+                assets.insert(asset.clone());
+                let name = asset.rsplit('/').next().unwrap_or(asset);
+                // Obsidian uses the alias slot for image sizes; keep the file name as alt text.
+                format!("![{}]({})", name, attachment_url(asset))
+            }
+            _ => cap[0].to_string(),
+        }
+    });
+
+    let content = rewrite_wiki_links(&content, |target, alias| {
+        if Uuid::parse_str(target).is_ok() {
+            return None;
+        }
+        match index.note(&note.dir, link_target_path(target)) {
+            Some(i) => Some(format!("{}|{}", note_ids[i], alias.unwrap_or(target))),
+            None => {
+                unresolved.push(target.to_string());
+                None
+            }
+        }
+    });
+
+    RewrittenNote {
+        content,
+        assets,
+        unresolved,
+    }
+}
+
+// Drops `#heading`, `^block` and `?query` suffixes.
+fn link_target_path(target: &str) -> &str {
+    let end = target.find(['#', '^', '?']).unwrap_or(target.len());
+    target[..end].trim()
+}
+
+fn note_title(file_name: &str) -> Option<&str> {
+    [".md", ".markdown"].iter().find_map(|ext| {
+        let split = file_name.len().checked_sub(ext.len())?;
+        let (stem, suffix) = (file_name.get(..split)?, file_name.get(split..)?);
+        (!stem.is_empty() && suffix.eq_ignore_ascii_case(ext)).then_some(stem)
+    })
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+}
+
+fn folder_title(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn join_path(base: &str, relative: &str) -> Option<String> {
+    let mut parts: Vec<&str> = if relative.starts_with('/') {
+        Vec::new()
+    } else {
+        base.split('/').filter(|s| !s.is_empty()).collect()
+    };
+    for segment in relative.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            s => parts.push(s),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(path: &str, content: &str) -> VaultNote {
+        let (dir, name) = path.rsplit_once('/').unwrap_or(("", path));
+        VaultNote {
+            path: path.to_string(),
+            dir: dir.to_string(),
+            title: name.trim_end_matches(".md").to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn rewrites_vault_links() {
+        let mut vault = Vault::default();
+        vault.notes.push(note(
+            "Daily/Today.md",
+            "See [[Plan#Goals|goals]], [[Missing]] and [spec](../Projects/Plan.md).\n\
+             ![[diagram.png|300]] ![photo](../assets/photo%201.jpg)",
+        ));
+        vault.notes.push(note("Projects/Plan.md", "# Plan"));
+        vault.assets.insert("assets/diagram.png".into(), vec![1]);
+        vault.assets.insert("assets/photo 1.jpg".into(), vec![2]);
+        let index = VaultIndex::new(&vault);
+        let ids = vec![Uuid::from_u128(1), Uuid::from_u128(2)];
+        let stored = HashMap::from([(
+            "assets/diagram.png".to_string(),
+            "diagram_20250101-000000.png".to_string(),
+        )]);
+
+        let out = rewrite_note(&vault.notes[0], &index, &ids, &stored);
+        let plan = ids[1];
+        assert_eq!(
+            out.content,
+            format!(
+                "See [[{plan}|goals]], [[Missing]] and [[{plan}|spec]].\n\
+                 ![diagram.png](./attachments/diagram_20250101-000000.png) \
+                 ![photo](./attachments/photo%201.jpg)"
+            )
+        );
+        assert_eq!(out.unresolved, ["Missing"]);
+        assert_eq!(out.assets.len(), 2);
+    }
+}
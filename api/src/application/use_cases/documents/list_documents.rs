@@ -1,6 +1,8 @@
 use uuid::Uuid;
 
-use crate::application::ports::document_repository::{DocumentListState, DocumentRepository};
+use crate::application::ports::document_repository::{
+    DocumentListState, DocumentRepository, PropertyFilter,
+};
 use crate::domain::documents::document::Document as DomainDocument;
 
 pub struct ListDocuments<'a, R: DocumentRepository + ?Sized> {
@@ -10,11 +12,14 @@ pub struct ListDocuments<'a, R: DocumentRepository + ?Sized> {
 impl<'a, R: DocumentRepository + ?Sized> ListDocuments<'a, R> {
     pub async fn execute(
         &self,
-        user_id: Uuid,
+        workspace_id: Uuid,
         query: Option<String>,
         tag: Option<String>,
+        property: Option<PropertyFilter>,
         state: DocumentListState,
     ) -> anyhow::Result<Vec<DomainDocument>> {
-        self.repo.list_for_user(user_id, query, tag, state).await
+        self.repo
+            .list_for_workspace(workspace_id, query, tag, property, state)
+            .await
     }
 }
@@ -2,11 +2,18 @@ pub mod archive_document;
 pub mod create_document;
 pub mod delete_document;
 pub mod download_document;
+pub mod duplicate_document;
+pub mod edit_content;
 pub mod get_backlinks;
 pub mod get_document;
 pub mod get_outgoing_links;
+pub mod import_document;
+pub mod import_vault;
 pub mod list_documents;
 pub mod list_snapshots;
+pub mod quick_search;
+pub mod rename_links;
+pub mod reorder_documents;
 pub mod restore_snapshot;
 pub mod search_documents;
 pub mod snapshot_diff;
@@ -0,0 +1,27 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::domain::documents::document::QuickSearchHit;
+
+const MAX_QUICK_SEARCH_LIMIT: i64 = 50;
+
+pub struct QuickSearchDocuments<'a, R: DocumentRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: DocumentRepository + ?Sized> QuickSearchDocuments<'a, R> {
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        query: &str,
+        limit: i64,
+    ) -> anyhow::Result<Vec<QuickSearchHit>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.repo
+            .quick_search(user_id, query, limit.clamp(1, MAX_QUICK_SEARCH_LIMIT))
+            .await
+    }
+}
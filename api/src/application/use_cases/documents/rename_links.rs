@@ -0,0 +1,215 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::application::access::{self, Actor, Capability};
+use crate::application::linkgraph::rewrite_wiki_links;
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::realtime_types::ContentEdit;
+use crate::application::ports::share_access_port::ShareAccessPort;
+use crate::application::services::realtime::content_edit::content_hash;
+
+// Concurrent typing in a source moves its hash; re-read and try again this many times.
+const EDIT_ATTEMPTS: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct LinkRewrite {
+    pub document_id: Uuid,
+    pub title: String,
+    pub occurrences: usize,
+}
+
+/// Points `[[old_title]]` links (embeds and mentions too, any case) at `new_title`, keeping aliases.
+/// Falls back to an id link when the new title can't appear inside `[[...]]`.
+pub fn rename_title_links(
+    content: &str,
+    target_id: Uuid,
+    old_title: &str,
+    new_title: &str,
+) -> (String, usize) {
+    let old = old_title.trim().to_lowercase();
+    let new_title = new_title.trim();
+    let inner = if new_title.is_empty() || new_title.contains(['[', ']', '|']) {
+        target_id.to_string()
+    } else {
+        new_title.to_string()
+    };
+    let mut count = 0;
+    let out = rewrite_wiki_links(content, |target, alias| {
+        if target.to_lowercase() != old {
+            return None;
+        }
+        count += 1;
+        Some(match alias {
+            Some(alias) => format!("{inner}|{alias}"),
+            None => inner.clone(),
+        })
+    });
+    (out, count)
+}
+
+/// Rewrites inbound title links when a document is renamed, as CRDT edits in the sources' rooms.
+pub struct RenameLinks<'a, R, S, A, RT>
+where
+    R: DocumentRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    A: AccessRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub repo: &'a R,
+    pub shares: &'a S,
+    pub access: &'a A,
+    pub realtime: &'a RT,
+}
+
+impl<'a, R, S, A, RT> RenameLinks<'a, R, S, A, RT>
+where
+    R: DocumentRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+    A: AccessRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    /// Documents that `apply` would change, with their link counts.
+    pub async fn preview(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        old_title: &str,
+        new_title: &str,
+    ) -> anyhow::Result<Vec<LinkRewrite>> {
+        let mut out = Vec::new();
+        if !title_changes(old_title, new_title) {
+            return Ok(out);
+        }
+        for (source_id, title) in self.editable_sources(user_id, doc_id).await? {
+            let content = self
+                .realtime
+                .get_content(&source_id.to_string())
+                .await?
+                .unwrap_or_default();
+            let (_, occurrences) = rename_title_links(&content, doc_id, old_title, new_title);
+            if occurrences > 0 {
+                out.push(LinkRewrite {
+                    document_id: source_id,
+                    title,
+                    occurrences,
+                });
+            }
+        }
+        Ok(out)
+    }
+
+    /// Rewrites every editable source; a source that fails is logged and skipped.
+    pub async fn apply(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        old_title: &str,
+        new_title: &str,
+    ) -> anyhow::Result<Vec<LinkRewrite>> {
+        let mut out = Vec::new();
+        if !title_changes(old_title, new_title) {
+            return Ok(out);
+        }
+        for (source_id, title) in self.editable_sources(user_id, doc_id).await? {
+            match self
+                .rewrite_source(source_id, doc_id, old_title, new_title)
+                .await
+            {
+                Ok(0) => {}
+                Ok(occurrences) => out.push(LinkRewrite {
+                    document_id: source_id,
+                    title,
+                    occurrences,
+                }),
+                Err(e) => {
+                    tracing::warn!(error = ?e, document_id = %source_id, target_id = %doc_id, "rename_link_rewrite_failed");
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    async fn rewrite_source(
+        &self,
+        source_id: Uuid,
+        doc_id: Uuid,
+        old_title: &str,
+        new_title: &str,
+    ) -> anyhow::Result<usize> {
+        let key = source_id.to_string();
+        let mut attempt = 1;
+        loop {
+            let content = self.realtime.get_content(&key).await?.unwrap_or_default();
+            let (rewritten, occurrences) =
+                rename_title_links(&content, doc_id, old_title, new_title);
+            if occurrences == 0 {
+                return Ok(0);
+            }
+            let hash = content_hash(&content);
+            match self
+                .realtime
+                .edit_content(&key, &ContentEdit::Replace(rewritten), Some(&hash))
+                .await
+            {
+                Ok(_) => return Ok(occurrences),
+                Err(e) if e.to_string() == "precondition_failed" && attempt < EDIT_ATTEMPTS => {
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Backlink sources the user may edit: not folders, not archived.
+    async fn editable_sources(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+    ) -> anyhow::Result<Vec<(Uuid, String)>> {
+        let actor = Actor::User(user_id);
+        let mut seen = HashSet::new();
+        let mut out = Vec::new();
+        for link in self.repo.backlinks_for(user_id, doc_id).await? {
+            if link.document_type == "folder" || !seen.insert(link.document_id) {
+                continue;
+            }
+            let cap =
+                access::resolve_document(self.access, self.shares, &actor, link.document_id).await;
+            if cap < Capability::Edit {
+                continue;
+            }
+            // Edit capability already rules out archived sources.
+            out.push((link.document_id, link.title));
+        }
+        Ok(out)
+    }
+}
+
+// Title links resolve case-insensitively, so a case-only rename keeps them working.
+fn title_changes(old_title: &str, new_title: &str) -> bool {
+    old_title.trim().to_lowercase() != new_title.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_title_links_keeping_aliases() {
+        let id = Uuid::new_v4();
+        let content = "See [[Plan]], ![[plan|the plan]] and @[[ PLAN ]]; not [[Planning]].";
+        let (out, count) = rename_title_links(content, id, "Plan", "Roadmap");
+        assert_eq!(count, 3);
+        assert_eq!(
+            out,
+            "See [[Roadmap]], ![[Roadmap|the plan]] and @[[Roadmap]]; not [[Planning]]."
+        );
+
+        let (out, count) = rename_title_links("[[Plan|x]]", id, "Plan", "Q1 [draft]");
+        assert_eq!(count, 1);
+        assert_eq!(out, format!("[[{id}|x]]"));
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::use_cases::documents::update_document::UpdateDocument;
+use crate::domain::documents::document::Document as DomainDocument;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Before(Uuid),
+    After(Uuid),
+}
+
+impl Placement {
+    fn anchor(&self) -> Uuid {
+        match self {
+            Placement::Before(id) | Placement::After(id) => *id,
+        }
+    }
+}
+
+/// `siblings` with `moved` taken out and put next to the anchor; `None` when the anchor is missing.
+pub fn place(siblings: &[Uuid], moved: Uuid, placement: Placement) -> Option<Vec<Uuid>> {
+    let mut order: Vec<Uuid> = siblings.iter().copied().filter(|id| *id != moved).collect();
+    let at = order.iter().position(|id| *id == placement.anchor())?;
+    let at = match placement {
+        Placement::Before(_) => at,
+        Placement::After(_) => at + 1,
+    };
+    order.insert(at, moved);
+    Some(order)
+}
+
+/// Requested ids first, then the remaining siblings in their current order.
+pub fn merge_order(siblings: &[Uuid], requested: &[Uuid]) -> anyhow::Result<Vec<Uuid>> {
+    let known: HashSet<Uuid> = siblings.iter().copied().collect();
+    let mut seen = HashSet::with_capacity(requested.len());
+    for id in requested {
+        if !known.contains(id) || !seen.insert(*id) {
+            anyhow::bail!("bad_request");
+        }
+    }
+    let mut order = requested.to_vec();
+    order.extend(siblings.iter().filter(|id| !seen.contains(id)));
+    Ok(order)
+}
+
+/// Manual sibling order; moves across folders go through `UpdateDocument`.
+pub struct ReorderDocuments<'a, R, S, RT>
+where
+    R: DocumentRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub repo: &'a R,
+    pub storage: &'a S,
+    pub realtime: &'a RT,
+}
+
+impl<'a, R, S, RT> ReorderDocuments<'a, R, S, RT>
+where
+    R: DocumentRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    /// Arranges the children of `parent_id` (the workspace root when `None`).
+    pub async fn reorder(
+        &self,
+        workspace_id: Uuid,
+        parent_id: Option<Uuid>,
+        ids: &[Uuid],
+    ) -> anyhow::Result<Vec<Uuid>> {
+        let siblings = self.repo.list_child_ids(workspace_id, parent_id).await?;
+        let order = merge_order(&siblings, ids)?;
+        self.repo
+            .set_sort_keys(workspace_id, parent_id, &order)
+            .await?;
+        Ok(order)
+    }
+
+    /// Moves a document next to `placement`'s anchor, into the anchor's folder if needed.
+    pub async fn place(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        placement: Placement,
+    ) -> anyhow::Result<Option<DomainDocument>> {
+        let anchor_id = placement.anchor();
+        if anchor_id == doc_id {
+            anyhow::bail!("bad_request");
+        }
+        let Some(meta) = self.repo.get_meta_for_owner(doc_id, user_id).await? else {
+            return Ok(None);
+        };
+        let Some(anchor_meta) = self.repo.get_meta_for_owner(anchor_id, user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        if meta.archived_at.is_some() || anchor_meta.archived_at.is_some() {
+            anyhow::bail!("conflict");
+        }
+        if meta.workspace_id != anchor_meta.workspace_id {
+            anyhow::bail!("bad_request");
+        }
+        let (Some(doc), Some(anchor)) = (
+            self.repo.get_by_id(doc_id).await?,
+            self.repo.get_by_id(anchor_id).await?,
+        ) else {
+            return Ok(None);
+        };
+
+        if doc.parent_id != anchor.parent_id {
+            // A folder can't move next to one of its own descendants.
+            if doc.doc_type == "folder"
+                && self
+                    .repo
+                    .list_owned_subtree_nodes(user_id, doc_id)
+                    .await?
+                    .iter()
+                    .any(|n| n.id == anchor_id)
+            {
+                anyhow::bail!("bad_request");
+            }
+            let update = UpdateDocument {
+                repo: self.repo,
+                storage: self.storage,
+                realtime: self.realtime,
+            };
+            if update
+                .execute(doc_id, user_id, None, Some(anchor.parent_id))
+                .await?
+                .is_none()
+            {
+                return Ok(None);
+            }
+        }
+
+        let siblings = self
+            .repo
+            .list_child_ids(meta.workspace_id, anchor.parent_id)
+            .await?;
+        let Some(order) = place(&siblings, doc_id, placement) else {
+            anyhow::bail!("not_found");
+        };
+        self.repo
+            .set_sort_keys(meta.workspace_id, anchor.parent_id, &order)
+            .await?;
+        self.repo.get_by_id(doc_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn places_and_merges_sibling_order() {
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let [a, b, c, d] = [ids[0], ids[1], ids[2], ids[3]];
+
+        assert_eq!(
+            place(&ids, d, Placement::Before(b)).unwrap(),
+            vec![a, d, b, c]
+        );
+        assert_eq!(
+            place(&ids, a, Placement::After(c)).unwrap(),
+            vec![b, c, a, d]
+        );
+        // Moving in from another folder
+        let outsider = Uuid::new_v4();
+        assert_eq!(
+            place(&ids, outsider, Placement::After(d)).unwrap(),
+            vec![a, b, c, d, outsider]
+        );
+        assert!(place(&ids, a, Placement::Before(outsider)).is_none());
+
+        assert_eq!(merge_order(&ids, &[c, a]).unwrap(), vec![c, a, b, d]);
+        assert!(merge_order(&ids, &[c, c]).is_err());
+        assert!(merge_order(&ids, &[outsider]).is_err());
+    }
+}
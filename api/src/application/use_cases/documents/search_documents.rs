@@ -1,8 +1,11 @@
 use uuid::Uuid;
 
-use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::document_repository::{DocumentRepository, DocumentSearch};
+use crate::application::services::search::build_tsquery;
 use crate::domain::documents::document::SearchHit;
 
+pub const MAX_SEARCH_LIMIT: i64 = 100;
+
 pub struct SearchDocuments<'a, R: DocumentRepository + ?Sized> {
     pub repo: &'a R,
 }
@@ -11,9 +14,20 @@ impl<'a, R: DocumentRepository + ?Sized> SearchDocuments<'a, R> {
     pub async fn execute(
         &self,
         user_id: Uuid,
-        q: Option<String>,
-        limit: i64,
+        mut search: DocumentSearch,
     ) -> anyhow::Result<Vec<SearchHit>> {
-        self.repo.search_for_user(user_id, q, limit).await
+        if let (Some(after), Some(before)) = (search.updated_after, search.updated_before)
+            && after >= before
+        {
+            anyhow::bail!("bad_request");
+        }
+        search.text = search
+            .text
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty());
+        search.tsquery = search.text.as_deref().and_then(build_tsquery);
+        search.limit = search.limit.clamp(1, MAX_SEARCH_LIMIT);
+        search.offset = search.offset.max(0);
+        self.repo.search_for_user(user_id, &search).await
     }
 }
@@ -17,7 +17,7 @@ where
     S: StoragePort + ?Sized,
 {
     pub async fn execute(&self, owner_id: uuid::Uuid) -> anyhow::Result<Vec<String>> {
-        let dir = self.storage.user_repo_dir(owner_id);
+        let dir = self.storage.workspace_repo_dir(owner_id);
         let patterns = self.gitignore.read_gitignore_patterns(&dir).await?;
         Ok(patterns)
     }
@@ -46,7 +46,7 @@ where
         patterns: Vec<String>,
     ) -> anyhow::Result<usize> {
         self.workspace.ensure_repository(owner_id, "main").await?;
-        let dir = self.storage.user_repo_dir(owner_id);
+        let dir = self.storage.workspace_repo_dir(owner_id);
         let _ = self.gitignore.ensure_gitignore(&dir).await?;
         let added = self
             .gitignore
@@ -63,7 +63,7 @@ pub struct CheckPathIgnored<'a, G: GitignorePort + ?Sized, S: StoragePort + ?Siz
 
 impl<'a, G: GitignorePort + ?Sized, S: StoragePort + ?Sized> CheckPathIgnored<'a, G, S> {
     pub async fn execute(&self, owner_id: uuid::Uuid, rel_path: &str) -> anyhow::Result<bool> {
-        let dir = self.storage.user_repo_dir(owner_id);
+        let dir = self.storage.workspace_repo_dir(owner_id);
         let patterns = self.gitignore.read_gitignore_patterns(&dir).await?;
         let p = rel_path.trim_start_matches('/');
         let mut is_ignored = false;
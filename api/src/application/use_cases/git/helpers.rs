@@ -3,8 +3,8 @@ use crate::application::ports::files_repository::FilesRepository;
 use crate::application::ports::storage_port::StoragePort;
 use uuid::Uuid;
 
-fn strip_user_prefix(owner_id: Uuid, rel_from_uploads: &str) -> String {
-    let pfx = format!("{}/", owner_id);
+fn strip_workspace_prefix(workspace_id: Uuid, rel_from_uploads: &str) -> String {
+    let pfx = format!("{}/", workspace_id);
     if let Some(stripped) = rel_from_uploads.strip_prefix(&pfx) {
         stripped.to_string()
     } else {
@@ -14,7 +14,7 @@ fn strip_user_prefix(owner_id: Uuid, rel_from_uploads: &str) -> String {
 
 /// Compute .gitignore patterns for a document or folder.
 /// - For document: returns the markdown file path and attachment file paths
-///   relative to the user's repository root.
+///   relative to the workspace repository root.
 /// - For folder: returns a single directory pattern with trailing '/'
 pub async fn compute_doc_patterns_with<
     D: DocumentRepository + ?Sized,
@@ -25,20 +25,22 @@ pub async fn compute_doc_patterns_with<
     files: &F,
     storage: &S,
     node_id: Uuid,
-    owner_id: Uuid,
+    workspace_id: Uuid,
+    user_id: Uuid,
 ) -> anyhow::Result<Vec<String>> {
-    // Fetch document meta for owner
+    // Fetch document meta for an editor of the workspace's document
     let meta = docs
-        .get_meta_for_owner(node_id, owner_id)
+        .get_meta_for_owner(node_id, user_id)
         .await?
+        .filter(|m| m.workspace_id == workspace_id)
         .ok_or_else(|| anyhow::anyhow!("Document not found"))?;
     let dtype = meta.doc_type;
 
     // Folder: ignore the entire directory under the repo root
     if dtype == "folder" {
-        let dir_full = storage.build_doc_dir(node_id).await?; // .../uploads/<owner>/<folders>
+        let dir_full = storage.build_doc_dir(node_id).await?; // .../uploads/<workspace>/<folders>
         let rel_from_uploads = storage.relative_from_uploads(&dir_full);
-        let repo_rel = strip_user_prefix(owner_id, &rel_from_uploads);
+        let repo_rel = strip_workspace_prefix(workspace_id, &rel_from_uploads);
         let mut pat = repo_rel;
         if !pat.ends_with('/') {
             pat.push('/');
@@ -56,7 +58,7 @@ pub async fn compute_doc_patterns_with<
         let full = storage.build_doc_file_path(node_id).await?;
         storage.relative_from_uploads(&full)
     };
-    let file_repo_rel = strip_user_prefix(owner_id, &file_rel_from_uploads);
+    let file_repo_rel = strip_workspace_prefix(workspace_id, &file_rel_from_uploads);
     patterns.push(file_repo_rel);
 
     // 2) Attachment paths (exact files for the document)
@@ -64,7 +66,7 @@ pub async fn compute_doc_patterns_with<
     for storage_path in file_paths {
         let full = storage.absolute_from_relative(&storage_path);
         let rel_from_uploads = storage.relative_from_uploads(&full);
-        let repo_rel = strip_user_prefix(owner_id, &rel_from_uploads);
+        let repo_rel = strip_workspace_prefix(workspace_id, &rel_from_uploads);
         patterns.push(repo_rel);
     }
 
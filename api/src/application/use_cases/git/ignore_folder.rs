@@ -35,12 +35,25 @@ where
     D: DocumentRepository + ?Sized,
     W: GitWorkspacePort + ?Sized,
 {
-    pub async fn execute(&self, owner_id: Uuid, folder_id: Uuid) -> anyhow::Result<IgnoreResult> {
-        self.workspace.ensure_repository(owner_id, "main").await?;
-        let patterns =
-            compute_doc_patterns_with(self.docs, self.files, self.storage, folder_id, owner_id)
-                .await?;
-        let dir = self.storage.user_repo_dir(owner_id);
+    pub async fn execute(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        folder_id: Uuid,
+    ) -> anyhow::Result<IgnoreResult> {
+        self.workspace
+            .ensure_repository(workspace_id, "main")
+            .await?;
+        let patterns = compute_doc_patterns_with(
+            self.docs,
+            self.files,
+            self.storage,
+            folder_id,
+            workspace_id,
+            user_id,
+        )
+        .await?;
+        let dir = self.storage.workspace_repo_dir(workspace_id);
         let _ = self.gitignore.ensure_gitignore(&dir).await?;
         let added = self
             .gitignore
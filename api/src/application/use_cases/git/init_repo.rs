@@ -35,7 +35,7 @@ where
             .ensure_repository(user_id, &default_branch)
             .await?;
 
-        let dir = self.storage.user_repo_dir(user_id);
+        let dir = self.storage.workspace_repo_dir(user_id);
         let _ = self.gitignore.ensure_gitignore(&dir).await?;
         Ok(())
     }
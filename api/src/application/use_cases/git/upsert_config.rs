@@ -50,7 +50,7 @@ where
         self.workspace
             .ensure_repository(user_id, &branch_name)
             .await?;
-        let dir = self.storage.user_repo_dir(user_id);
+        let dir = self.storage.workspace_repo_dir(user_id);
         let _ = self.gitignore.ensure_gitignore(&dir).await?;
         Ok(GitConfigDto {
             id,
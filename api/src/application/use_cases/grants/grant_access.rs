@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::application::access;
+use crate::application::ports::document_grants_repository::{
+    DocumentGrantRow, DocumentGrantsRepository,
+};
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::user_repository::UserRepository;
+
+pub struct GrantAccess<'a, G, D, U>
+where
+    G: DocumentGrantsRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    pub grants: &'a G,
+    pub documents: &'a D,
+    pub users: &'a U,
+}
+
+impl<'a, G, D, U> GrantAccess<'a, G, D, U>
+where
+    G: DocumentGrantsRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    /// Grants (or updates) access for the account with `email`; folder grants cover the subtree.
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        doc_id: Uuid,
+        email: &str,
+        permission: &str,
+    ) -> anyhow::Result<DocumentGrantRow> {
+        if !access::is_valid_permission(permission) {
+            anyhow::bail!("bad_request");
+        }
+        if self
+            .documents
+            .get_meta_for_owner(doc_id, actor_id)
+            .await?
+            .is_none()
+        {
+            anyhow::bail!("not_found");
+        }
+        let user = match self.users.find_by_email(email.trim()).await? {
+            Some(u) => u,
+            None => anyhow::bail!("user_not_found"),
+        };
+        if user.id == actor_id {
+            anyhow::bail!("bad_request");
+        }
+        self.grants
+            .upsert(doc_id, user.id, permission, actor_id)
+            .await?;
+        self.grants
+            .list_for_document(doc_id)
+            .await?
+            .into_iter()
+            .find(|g| g.user_id == user.id)
+            .ok_or_else(|| anyhow::anyhow!("not_found"))
+    }
+}
@@ -0,0 +1,38 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_grants_repository::{
+    DocumentGrantRow, DocumentGrantsRepository,
+};
+use crate::application::ports::document_repository::DocumentRepository;
+
+pub struct ListGrants<'a, G, D>
+where
+    G: DocumentGrantsRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+{
+    pub grants: &'a G,
+    pub documents: &'a D,
+}
+
+impl<'a, G, D> ListGrants<'a, G, D>
+where
+    G: DocumentGrantsRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+{
+    // Only editors of the owning workspace may see who else has access.
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        doc_id: Uuid,
+    ) -> anyhow::Result<Option<Vec<DocumentGrantRow>>> {
+        if self
+            .documents
+            .get_meta_for_owner(doc_id, actor_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+        Ok(Some(self.grants.list_for_document(doc_id).await?))
+    }
+}
@@ -0,0 +1,15 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_grants_repository::{
+    DocumentGrantsRepository, SharedWithMeRow,
+};
+
+pub struct ListSharedWithMe<'a, G: DocumentGrantsRepository + ?Sized> {
+    pub grants: &'a G,
+}
+
+impl<'a, G: DocumentGrantsRepository + ?Sized> ListSharedWithMe<'a, G> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<Vec<SharedWithMeRow>> {
+        self.grants.list_shared_with_user(user_id).await
+    }
+}
@@ -0,0 +1,4 @@
+pub mod grant_access;
+pub mod list_grants;
+pub mod list_shared_with_me;
+pub mod revoke_access;
@@ -0,0 +1,38 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_grants_repository::DocumentGrantsRepository;
+use crate::application::ports::document_repository::DocumentRepository;
+
+pub struct RevokeAccess<'a, G, D>
+where
+    G: DocumentGrantsRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+{
+    pub grants: &'a G,
+    pub documents: &'a D,
+}
+
+impl<'a, G, D> RevokeAccess<'a, G, D>
+where
+    G: DocumentGrantsRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+{
+    /// Workspace editors may revoke any grant; grantees may drop their own.
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<bool> {
+        if actor_id != user_id
+            && self
+                .documents
+                .get_meta_for_owner(doc_id, actor_id)
+                .await?
+                .is_none()
+        {
+            anyhow::bail!("not_found");
+        }
+        self.grants.revoke(doc_id, user_id).await
+    }
+}
@@ -1,8 +1,16 @@
+pub mod admin;
 pub mod auth;
+pub mod comments;
 pub mod documents;
 pub mod files;
 pub mod git;
+pub mod grants;
 pub mod plugins;
 pub mod public;
 pub mod shares;
 pub mod tags;
+pub mod takeout;
+pub mod templates;
+pub mod trash;
+pub mod user_documents;
+pub mod workspaces;
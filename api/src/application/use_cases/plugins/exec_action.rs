@@ -1,11 +1,20 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use uuid::Uuid;
 
 use crate::application::dto::plugins::ExecResult;
 use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::files_repository::FilesRepository;
 use crate::application::ports::plugin_repository::PluginRepository;
 use crate::application::ports::plugin_runtime::PluginRuntime;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::template_repository::TemplateRepository;
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::services::realtime::snapshot::SnapshotService;
+use crate::application::use_cases::templates::instantiate_template::{
+    InstantiateOptions, InstantiateTemplate,
+};
 
 const PERMISSION_DOC_WRITE: &str = "doc.write";
 
@@ -20,26 +29,46 @@ impl From<anyhow::Error> for PluginEffectError {
     }
 }
 
-pub struct ExecutePluginAction<'a, RT, PR, DR>
+pub struct ExecutePluginAction<'a, RT, PR, DR, TR, UR, FR, ST, RE>
 where
     RT: PluginRuntime + ?Sized,
     PR: PluginRepository + ?Sized,
     DR: DocumentRepository + ?Sized,
+    TR: TemplateRepository + ?Sized,
+    UR: UserRepository + ?Sized,
+    FR: FilesRepository + ?Sized,
+    ST: StoragePort + ?Sized,
+    RE: RealtimeEngine + ?Sized,
 {
     pub runtime: &'a RT,
     pub plugin_repo: &'a PR,
     pub document_repo: &'a DR,
+    // Used by `createDocument` effects that name a `templateId`
+    pub template_repo: &'a TR,
+    pub user_repo: &'a UR,
+    pub files_repo: &'a FR,
+    pub storage: &'a ST,
+    pub realtime: &'a RE,
+    pub snapshots: &'a SnapshotService,
 }
 
-impl<'a, RT, PR, DR> ExecutePluginAction<'a, RT, PR, DR>
+impl<'a, RT, PR, DR, TR, UR, FR, ST, RE> ExecutePluginAction<'a, RT, PR, DR, TR, UR, FR, ST, RE>
 where
     RT: PluginRuntime + ?Sized,
     PR: PluginRepository + ?Sized,
     DR: DocumentRepository + ?Sized,
+    TR: TemplateRepository + ?Sized,
+    UR: UserRepository + ?Sized,
+    FR: FilesRepository + ?Sized,
+    ST: StoragePort + ?Sized,
+    RE: RealtimeEngine + ?Sized,
 {
+    // `workspace_id` scopes plugin installation and permissions; `author_id` is the
+    // user recorded as creator of documents produced by effects.
     pub async fn execute(
         &self,
-        user_id: Uuid,
+        workspace_id: Uuid,
+        author_id: Uuid,
         plugin: &str,
         action: &str,
         payload: Option<serde_json::Value>,
@@ -47,14 +76,14 @@ where
         let payload = payload.unwrap_or(serde_json::Value::Null);
         let permissions = self
             .runtime
-            .permissions(Some(user_id), plugin)
+            .permissions(Some(workspace_id), plugin)
             .await?
             .unwrap_or_default()
             .into_iter()
             .collect::<HashSet<String>>();
         let try_result = self
             .runtime
-            .execute(Some(user_id), plugin, action, &payload)
+            .execute(Some(workspace_id), plugin, action, &payload)
             .await?;
         let Some(res) = try_result else {
             return Ok(None);
@@ -62,7 +91,7 @@ where
 
         if !res.effects.is_empty() {
             match self
-                .apply_server_effects(user_id, plugin, &res.effects, &permissions)
+                .apply_server_effects(workspace_id, author_id, plugin, &res.effects, &permissions)
                 .await
             {
                 Ok(passthrough) => {
@@ -98,7 +127,8 @@ where
 
     async fn apply_server_effects(
         &self,
-        user_id: Uuid,
+        workspace_id: Uuid,
+        author_id: Uuid,
         plugin: &str,
         effects: &[serde_json::Value],
         permissions: &HashSet<String>,
@@ -118,23 +148,62 @@ where
                 }
                 "createDocument" => {
                     self.ensure_permission(permissions, PERMISSION_DOC_WRITE)?;
-                    let title = effect
-                        .get("title")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("Untitled");
-                    let doc_type = effect
-                        .get("docType")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("document");
                     let parent_id = effect
                         .get("parentId")
                         .and_then(|v| v.as_str())
                         .and_then(|s| Uuid::parse_str(s).ok());
-                    let doc = self
-                        .document_repo
-                        .create_for_user(user_id, title, parent_id, doc_type)
-                        .await
-                        .map_err(PluginEffectError::from)?;
+                    let template_id = effect
+                        .get("templateId")
+                        .and_then(|v| v.as_str())
+                        .and_then(|s| Uuid::parse_str(s).ok());
+                    let doc = if let Some(template_id) = template_id {
+                        let variables: HashMap<String, String> = effect
+                            .get("variables")
+                            .and_then(|v| v.as_object())
+                            .map(|vars| {
+                                vars.iter()
+                                    .filter_map(|(k, v)| {
+                                        v.as_str().map(|v| (k.clone(), v.to_string()))
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        let instantiate = InstantiateTemplate {
+                            repo: self.document_repo,
+                            templates: self.template_repo,
+                            users: self.user_repo,
+                            files: self.files_repo,
+                            storage: self.storage,
+                            realtime: self.realtime,
+                            snapshots: self.snapshots,
+                        };
+                        let options = InstantiateOptions {
+                            workspace_id,
+                            parent_id,
+                            title: effect
+                                .get("title")
+                                .and_then(|v| v.as_str())
+                                .map(str::to_string),
+                            variables,
+                        };
+                        instantiate
+                            .execute(author_id, template_id, options)
+                            .await
+                            .map_err(PluginEffectError::from)?
+                    } else {
+                        let title = effect
+                            .get("title")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("Untitled");
+                        let doc_type = effect
+                            .get("docType")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("document");
+                        self.document_repo
+                            .create_for_user(author_id, workspace_id, title, parent_id, doc_type)
+                            .await
+                            .map_err(PluginEffectError::from)?
+                    };
                     doc_id_created = Some(doc.id);
                 }
                 "putKv" => {
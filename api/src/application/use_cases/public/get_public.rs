@@ -24,6 +24,8 @@ impl<'a, R: PublicRepository + ?Sized> GetPublicByOwnerAndId<'a, R> {
             archived_at,
             archived_by,
             archived_parent_id,
+            properties,
+            sort_key,
         )) = self
             .repo
             .get_public_meta_by_owner_and_id(owner_name, doc_id)
@@ -40,6 +42,8 @@ impl<'a, R: PublicRepository + ?Sized> GetPublicByOwnerAndId<'a, R> {
                 archived_at,
                 archived_by,
                 archived_parent_id,
+                properties,
+                sort_key,
             }))
         } else {
             Ok(None)
@@ -1,5 +1,6 @@
 use uuid::Uuid;
 
+use crate::application::access;
 use crate::application::ports::shares_repository::SharesRepository;
 
 pub struct CreateShare<'a, R: SharesRepository + ?Sized> {
@@ -20,6 +21,9 @@ impl<'a, R: SharesRepository + ?Sized> CreateShare<'a, R> {
         permission: &str,
         expires_at: Option<chrono::DateTime<chrono::Utc>>,
     ) -> anyhow::Result<CreateShareResult> {
+        if !access::is_valid_permission(permission) {
+            anyhow::bail!("bad_request");
+        }
         let (token, _share_id, dtype) = self
             .repo
             .create_share(owner_id, document_id, permission, expires_at)
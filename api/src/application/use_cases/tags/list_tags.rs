@@ -10,10 +10,10 @@ pub struct ListTags<'a, R: TagRepository + ?Sized> {
 impl<'a, R: TagRepository + ?Sized> ListTags<'a, R> {
     pub async fn execute(
         &self,
-        owner_id: Uuid,
+        workspace_id: Uuid,
         filter: Option<String>,
     ) -> anyhow::Result<Vec<TagItemDto>> {
-        let rows = self.repo.list_tags(owner_id, filter).await?;
+        let rows = self.repo.list_tags(workspace_id, filter).await?;
         Ok(rows
             .into_iter()
             .map(|(name, count)| TagItemDto { name, count })
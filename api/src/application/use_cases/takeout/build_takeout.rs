@@ -0,0 +1,314 @@
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Component, Path};
+
+use serde_json::{Value as JsonValue, json};
+use tokio::task;
+use uuid::Uuid;
+
+use crate::application::ports::document_snapshot_archive_repository::DocumentSnapshotArchiveRepository;
+use crate::application::ports::files_repository::FilesRepository;
+use crate::application::ports::git_repository::GitRepository;
+use crate::application::ports::plugin_repository::PluginRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::shares_repository::SharesRepository;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::takeout_repository::{TakeoutJobRow, TakeoutRepository};
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::use_cases::documents::snapshot_download::SnapshotServiceProvider;
+
+const SNAPSHOT_PAGE: i64 = 100;
+
+/// Builds the archive for a claimed job and records the outcome on it.
+pub struct BuildTakeout<'a, T, U, F, S, RT, SA, SNAP, SH, P, G>
+where
+    T: TakeoutRepository + ?Sized,
+    U: UserRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+    SA: DocumentSnapshotArchiveRepository + ?Sized,
+    SNAP: SnapshotServiceProvider + ?Sized,
+    SH: SharesRepository + ?Sized,
+    P: PluginRepository + ?Sized,
+    G: GitRepository + ?Sized,
+{
+    pub takeouts: &'a T,
+    pub users: &'a U,
+    pub files: &'a F,
+    pub storage: &'a S,
+    pub realtime: &'a RT,
+    pub snapshot_archives: &'a SA,
+    pub snapshots: &'a SNAP,
+    pub shares: &'a SH,
+    pub plugins: &'a P,
+    pub git: &'a G,
+    pub retention: chrono::Duration,
+}
+
+impl<'a, T, U, F, S, RT, SA, SNAP, SH, P, G> BuildTakeout<'a, T, U, F, S, RT, SA, SNAP, SH, P, G>
+where
+    T: TakeoutRepository + ?Sized,
+    U: UserRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+    SA: DocumentSnapshotArchiveRepository + ?Sized,
+    SNAP: SnapshotServiceProvider + ?Sized,
+    SH: SharesRepository + ?Sized,
+    P: PluginRepository + ?Sized,
+    G: GitRepository + ?Sized,
+{
+    pub async fn execute(&self, job: &TakeoutJobRow) -> anyhow::Result<()> {
+        let result = self.build_and_store(job).await;
+        match result {
+            Ok((path, size)) => {
+                let expires_at = chrono::Utc::now() + self.retention;
+                self.takeouts
+                    .mark_completed(job.id, &path, size, expires_at)
+                    .await
+            }
+            Err(err) => {
+                self.takeouts
+                    .mark_failed(job.id, &format!("{err:#}"))
+                    .await?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn build_and_store(&self, job: &TakeoutJobRow) -> anyhow::Result<(String, i64)> {
+        let entries = self.collect_entries(job).await?;
+        let bytes = task::spawn_blocking(move || build_archive(entries))
+            .await
+            .map_err(|e| anyhow::anyhow!("takeout_archive_join: {e}"))??;
+        let rel = format!("takeouts/{}/{}.zip", job.user_id, job.id);
+        let abs = self.storage.absolute_from_relative(&rel);
+        self.storage.write_bytes(abs.as_path(), &bytes).await?;
+        Ok((rel, bytes.len() as i64))
+    }
+
+    async fn collect_entries(&self, job: &TakeoutJobRow) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+        let user_id = job.user_id;
+        let Some(user) = self.users.find_by_id(user_id).await? else {
+            anyhow::bail!("user not found");
+        };
+
+        let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut push = |entries: &mut Vec<(String, Vec<u8>)>, name: String, data: Vec<u8>| {
+            if seen.insert(name.clone()) {
+                entries.push((name, data));
+                true
+            } else {
+                false
+            }
+        };
+
+        let documents = self.takeouts.list_documents(user_id).await?;
+        let doc_ids: Vec<Uuid> = documents.iter().map(|d| d.id).collect();
+
+        let mut doc_manifest: Vec<JsonValue> = Vec::with_capacity(documents.len());
+        let mut snapshot_manifest: Vec<JsonValue> = Vec::new();
+        let mut share_manifest: Vec<JsonValue> = Vec::new();
+        for doc in &documents {
+            let mut path: Option<String> = None;
+            let mut attachments: Vec<String> = Vec::new();
+            if doc.doc_type != "folder" {
+                if let Err(err) = self.realtime.force_save_to_fs(&doc.id.to_string()).await {
+                    tracing::warn!(document_id = %doc.id, error = ?err, "takeout_force_save_failed");
+                }
+                match self.read_markdown(doc.id).await {
+                    Ok((entry, bytes)) => {
+                        if push(&mut entries, entry.clone(), bytes) {
+                            path = Some(entry);
+                        }
+                    }
+                    Err(err) => {
+                        tracing::warn!(document_id = %doc.id, error = ?err, "takeout_markdown_missing");
+                    }
+                }
+                for stored_path in self.files.list_storage_paths_for_document(doc.id).await? {
+                    let Some(entry) = document_entry(Path::new(&stored_path)) else {
+                        continue;
+                    };
+                    let abs = self.storage.absolute_from_relative(&stored_path);
+                    match self.storage.read_bytes(abs.as_path()).await {
+                        Ok(bytes) => {
+                            if push(&mut entries, entry.clone(), bytes) {
+                                attachments.push(entry);
+                            }
+                        }
+                        Err(err) => {
+                            tracing::warn!(document_id = %doc.id, path = stored_path.as_str(), error = ?err, "takeout_attachment_missing");
+                        }
+                    }
+                }
+            }
+
+            let mut offset = 0;
+            loop {
+                let page = self
+                    .snapshot_archives
+                    .list_for_document(doc.id, SNAPSHOT_PAGE, offset)
+                    .await?;
+                let page_len = page.len() as i64;
+                for record in page {
+                    let Some((_, markdown)) =
+                        self.snapshots.load_markdown_with_record(record.id).await?
+                    else {
+                        continue;
+                    };
+                    let entry = format!("snapshots/{}/{}-{}.md", doc.id, record.version, record.id);
+                    push(&mut entries, entry.clone(), markdown.into_bytes());
+                    snapshot_manifest.push(json!({
+                        "id": record.id,
+                        "document_id": record.document_id,
+                        "version": record.version,
+                        "label": record.label,
+                        "notes": record.notes,
+                        "kind": record.kind,
+                        "created_at": record.created_at,
+                        "path": entry,
+                    }));
+                }
+                if page_len < SNAPSHOT_PAGE {
+                    break;
+                }
+                offset += SNAPSHOT_PAGE;
+            }
+
+            // Share tokens are bearer credentials and stay out of the archive.
+            for share in self.shares.list_document_shares(user_id, doc.id).await? {
+                share_manifest.push(json!({
+                    "id": share.id,
+                    "document_id": share.document_id,
+                    "permission": share.permission,
+                    "expires_at": share.expires_at,
+                    "parent_share_id": share.parent_share_id,
+                    "created_at": share.created_at,
+                }));
+            }
+
+            doc_manifest.push(json!({
+                "id": doc.id,
+                "workspace_id": doc.workspace_id,
+                "parent_id": doc.parent_id,
+                "sort_key": doc.sort_key,
+                "title": doc.title,
+                "type": doc.doc_type,
+                "tags": doc.tags,
+                "created_at": doc.created_at,
+                "updated_at": doc.updated_at,
+                "archived_at": doc.archived_at,
+                "path": path,
+                "attachments": attachments,
+            }));
+        }
+
+        let mut plugin_kv = self.plugins.list_scoped_kv("user", &[user_id]).await?;
+        plugin_kv.extend(self.plugins.list_scoped_kv("doc", &doc_ids).await?);
+        let mut plugin_records = self.plugins.list_scoped_records("user", &[user_id]).await?;
+        plugin_records.extend(self.plugins.list_scoped_records("doc", &doc_ids).await?);
+
+        // Credentials (auth_data, URL userinfo) are never exported.
+        let git = self.git.load_user_git_cfg(user_id).await?.map(|cfg| {
+            json!({
+                "repository_url": redact_url_credentials(&cfg.repository_url),
+                "branch_name": cfg.branch_name,
+                "auth_type": cfg.auth_type,
+                "auto_sync": cfg.auto_sync,
+            })
+        });
+
+        let manifest = json!({
+            "format": "refmd-takeout",
+            "version": 1,
+            "job_id": job.id,
+            "generated_at": chrono::Utc::now(),
+            "user": {
+                "id": user.id,
+                "email": user.email,
+                "name": user.name,
+            },
+            "documents": doc_manifest,
+            "snapshots": snapshot_manifest,
+            "shares": share_manifest,
+            "plugins": {
+                "kv": plugin_kv.iter().map(|kv| json!({
+                    "plugin": kv.plugin,
+                    "scope": kv.scope,
+                    "scope_id": kv.scope_id,
+                    "key": kv.key,
+                    "value": kv.value,
+                    "updated_at": kv.updated_at,
+                })).collect::<Vec<_>>(),
+                "records": plugin_records.iter().map(|r| json!({
+                    "id": r.id,
+                    "plugin": r.plugin,
+                    "scope": r.scope,
+                    "scope_id": r.scope_id,
+                    "kind": r.kind,
+                    "data": r.data,
+                    "created_at": r.created_at,
+                    "updated_at": r.updated_at,
+                })).collect::<Vec<_>>(),
+            },
+            "git": git,
+        });
+        push(
+            &mut entries,
+            "manifest.json".to_string(),
+            serde_json::to_vec_pretty(&manifest)?,
+        );
+
+        Ok(entries)
+    }
+
+    async fn read_markdown(&self, doc_id: Uuid) -> anyhow::Result<(String, Vec<u8>)> {
+        let abs = self.storage.build_doc_file_path(doc_id).await?;
+        let rel = self.storage.relative_from_uploads(abs.as_path());
+        let entry = document_entry(Path::new(&rel))
+            .ok_or_else(|| anyhow::anyhow!("invalid document path {rel}"))?;
+        let bytes = self.storage.read_bytes(abs.as_path()).await?;
+        Ok((entry, bytes))
+    }
+}
+
+// Keeps the storage layout (<workspace>/<folders>/...) under documents/
+fn document_entry(rel: &Path) -> Option<String> {
+    if rel.as_os_str().is_empty() || rel.components().any(|c| !matches!(c, Component::Normal(_))) {
+        return None;
+    }
+    Some(format!(
+        "documents/{}",
+        rel.to_string_lossy().replace('\\', "/")
+    ))
+}
+
+fn redact_url_credentials(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    match rest[..authority_end].rfind('@') {
+        Some(at) => format!("{scheme}://{}", &rest[at + 1..]),
+        None => url.to_string(),
+    }
+}
+
+fn build_archive(entries: Vec<(String, Vec<u8>)>) -> anyhow::Result<Vec<u8>> {
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    {
+        let mut zip = zip::ZipWriter::new(&mut cursor);
+        let options = zip::write::FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .unix_permissions(0o644);
+        for (name, data) in entries {
+            zip.start_file(name, options)?;
+            zip.write_all(&data)?;
+        }
+        zip.finish()?;
+    }
+    Ok(cursor.into_inner())
+}
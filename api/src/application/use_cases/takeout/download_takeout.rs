@@ -0,0 +1,43 @@
+use uuid::Uuid;
+
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::takeout_repository::TakeoutRepository;
+
+pub struct TakeoutDownload {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+pub struct DownloadTakeout<'a, T, S>
+where
+    T: TakeoutRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub repo: &'a T,
+    pub storage: &'a S,
+}
+
+impl<'a, T, S> DownloadTakeout<'a, T, S>
+where
+    T: TakeoutRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub async fn execute(&self, user_id: Uuid, id: Uuid) -> anyhow::Result<TakeoutDownload> {
+        let Some(job) = self.repo.get_job(user_id, id).await? else {
+            anyhow::bail!("not_found");
+        };
+        if job.expires_at.is_some_and(|exp| exp <= chrono::Utc::now()) {
+            anyhow::bail!("gone");
+        }
+        let Some(path) = job.storage_path.filter(|_| job.status == "completed") else {
+            anyhow::bail!("not_ready");
+        };
+        let abs = self.storage.absolute_from_relative(&path);
+        let bytes = self.storage.read_bytes(abs.as_path()).await?;
+        let filename = format!(
+            "refmd-takeout-{}.zip",
+            job.created_at.format("%Y%m%d-%H%M%S")
+        );
+        Ok(TakeoutDownload { filename, bytes })
+    }
+}
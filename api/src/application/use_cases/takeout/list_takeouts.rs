@@ -0,0 +1,26 @@
+use uuid::Uuid;
+
+use crate::application::ports::takeout_repository::{TakeoutJobRow, TakeoutRepository};
+
+pub struct ListTakeouts<'a, T: TakeoutRepository + ?Sized> {
+    pub repo: &'a T,
+}
+
+impl<'a, T: TakeoutRepository + ?Sized> ListTakeouts<'a, T> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<Vec<TakeoutJobRow>> {
+        self.repo.list_jobs(user_id).await
+    }
+}
+
+pub struct GetTakeout<'a, T: TakeoutRepository + ?Sized> {
+    pub repo: &'a T,
+}
+
+impl<'a, T: TakeoutRepository + ?Sized> GetTakeout<'a, T> {
+    pub async fn execute(&self, user_id: Uuid, id: Uuid) -> anyhow::Result<TakeoutJobRow> {
+        match self.repo.get_job(user_id, id).await? {
+            Some(job) => Ok(job),
+            None => anyhow::bail!("not_found"),
+        }
+    }
+}
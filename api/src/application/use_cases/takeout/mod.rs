@@ -0,0 +1,5 @@
+pub mod build_takeout;
+pub mod download_takeout;
+pub mod list_takeouts;
+pub mod purge_takeouts;
+pub mod request_takeout;
@@ -0,0 +1,29 @@
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::takeout_repository::TakeoutRepository;
+
+/// Drops expired exports together with their archives.
+pub struct PurgeExpiredTakeouts<'a, T, S>
+where
+    T: TakeoutRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub repo: &'a T,
+    pub storage: &'a S,
+}
+
+impl<'a, T, S> PurgeExpiredTakeouts<'a, T, S>
+where
+    T: TakeoutRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub async fn execute(&self) -> anyhow::Result<usize> {
+        let paths = self.repo.delete_expired().await?;
+        for path in &paths {
+            let abs = self.storage.absolute_from_relative(path);
+            if let Err(err) = self.storage.delete_bytes(abs.as_path()).await {
+                tracing::warn!(path = path.as_str(), error = ?err, "takeout_archive_delete_failed");
+            }
+        }
+        Ok(paths.len())
+    }
+}
@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::application::ports::takeout_repository::{TakeoutJobRow, TakeoutRepository};
+
+pub struct RequestTakeout<'a, T: TakeoutRepository + ?Sized> {
+    pub repo: &'a T,
+}
+
+impl<'a, T: TakeoutRepository + ?Sized> RequestTakeout<'a, T> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<TakeoutJobRow> {
+        self.repo.create_job(user_id).await
+    }
+}
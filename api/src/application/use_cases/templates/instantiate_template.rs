@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::files_repository::FilesRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::template_repository::TemplateRepository;
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::services::realtime::snapshot::SnapshotService;
+use crate::application::use_cases::documents::duplicate_document::{
+    DuplicateDocument, DuplicateOptions,
+};
+use crate::domain::documents::document::Document as DomainDocument;
+
+pub struct InstantiateOptions {
+    pub workspace_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    /// Title of the new root document; defaults to the rendered template title
+    pub title: Option<String>,
+    /// Values for the template's prompts
+    pub variables: HashMap<String, String>,
+}
+
+/// Copies a template (document or folder subtree), substituting `{{date}}`, `{{time}}`,
+/// `{{title}}`, `{{user}}` and the template's prompts in titles and content.
+pub struct InstantiateTemplate<'a, R, T, U, F, S, RT>
+where
+    R: DocumentRepository + ?Sized,
+    T: TemplateRepository + ?Sized,
+    U: UserRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub repo: &'a R,
+    pub templates: &'a T,
+    pub users: &'a U,
+    pub files: &'a F,
+    pub storage: &'a S,
+    pub realtime: &'a RT,
+    pub snapshots: &'a SnapshotService,
+}
+
+impl<'a, R, T, U, F, S, RT> InstantiateTemplate<'a, R, T, U, F, S, RT>
+where
+    R: DocumentRepository + ?Sized,
+    T: TemplateRepository + ?Sized,
+    U: UserRepository + ?Sized,
+    F: FilesRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        template_id: Uuid,
+        options: InstantiateOptions,
+    ) -> anyhow::Result<DomainDocument> {
+        let Some(template) = self.templates.get(template_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        let Some(meta) = self.repo.get_meta_for_owner(template_id, user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        if meta.archived_at.is_some() {
+            anyhow::bail!("conflict");
+        }
+        if let Some(parent_id) = options.parent_id {
+            let Some(parent) = self.repo.get_meta_for_owner(parent_id, user_id).await? else {
+                anyhow::bail!("not_found");
+            };
+            if parent.archived_at.is_some() {
+                anyhow::bail!("conflict");
+            }
+            if parent.doc_type != "folder" || parent.workspace_id != options.workspace_id {
+                anyhow::bail!("bad_request");
+            }
+        }
+
+        let mut variables = HashMap::new();
+        for prompt in &template.prompts {
+            let value = options
+                .variables
+                .get(&prompt.name)
+                .cloned()
+                .or_else(|| prompt.default.clone());
+            let Some(value) = value else {
+                anyhow::bail!("bad_request");
+            };
+            variables.insert(prompt.name.clone(), value);
+        }
+        let now = Utc::now();
+        let user_name = self
+            .users
+            .find_by_id(user_id)
+            .await?
+            .map(|u| u.name)
+            .unwrap_or_default();
+        let title = options
+            .title
+            .clone()
+            .unwrap_or_else(|| template.name.clone());
+        variables.insert("date".into(), now.format("%Y-%m-%d").to_string());
+        variables.insert("time".into(), now.format("%H:%M").to_string());
+        variables.insert("title".into(), title);
+        variables.insert("user".into(), user_name);
+
+        let duplicate = DuplicateDocument {
+            repo: self.repo,
+            files: self.files,
+            storage: self.storage,
+            realtime: self.realtime,
+            snapshots: self.snapshots,
+        };
+        duplicate
+            .execute(
+                user_id,
+                template_id,
+                DuplicateOptions {
+                    workspace_id: options.workspace_id,
+                    parent_id: options.parent_id,
+                    title: options.title,
+                    rewrite_links: true,
+                    variables: Some(variables),
+                },
+            )
+            .await
+    }
+}
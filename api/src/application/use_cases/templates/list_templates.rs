@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::application::ports::template_repository::{TemplateRepository, TemplateRow};
+
+pub struct ListTemplates<'a, T: TemplateRepository + ?Sized> {
+    pub templates: &'a T,
+}
+
+impl<'a, T: TemplateRepository + ?Sized> ListTemplates<'a, T> {
+    pub async fn execute(&self, workspace_id: Uuid) -> anyhow::Result<Vec<TemplateRow>> {
+        self.templates.list_for_workspace(workspace_id).await
+    }
+}
@@ -0,0 +1,4 @@
+pub mod instantiate_template;
+pub mod list_templates;
+pub mod remove_template;
+pub mod set_template;
@@ -0,0 +1,32 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::template_repository::TemplateRepository;
+
+/// Unmarks a template; the document itself is kept.
+pub struct RemoveTemplate<'a, R, T>
+where
+    R: DocumentRepository + ?Sized,
+    T: TemplateRepository + ?Sized,
+{
+    pub repo: &'a R,
+    pub templates: &'a T,
+}
+
+impl<'a, R, T> RemoveTemplate<'a, R, T>
+where
+    R: DocumentRepository + ?Sized,
+    T: TemplateRepository + ?Sized,
+{
+    pub async fn execute(&self, user_id: Uuid, doc_id: Uuid) -> anyhow::Result<bool> {
+        if self
+            .repo
+            .get_meta_for_owner(doc_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Ok(false);
+        }
+        self.templates.delete(doc_id).await
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::template_repository::{
+    TemplatePrompt, TemplateRepository, TemplateRow,
+};
+
+const BUILTIN_VARIABLES: [&str; 4] = ["date", "time", "title", "user"];
+
+/// Marks a document or folder as a template, or updates its template settings.
+pub struct SetTemplate<'a, R, T>
+where
+    R: DocumentRepository + ?Sized,
+    T: TemplateRepository + ?Sized,
+{
+    pub repo: &'a R,
+    pub templates: &'a T,
+}
+
+impl<'a, R, T> SetTemplate<'a, R, T>
+where
+    R: DocumentRepository + ?Sized,
+    T: TemplateRepository + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        name: Option<&str>,
+        description: Option<&str>,
+        prompts: &[TemplatePrompt],
+    ) -> anyhow::Result<TemplateRow> {
+        let Some(meta) = self.repo.get_meta_for_owner(doc_id, user_id).await? else {
+            anyhow::bail!("not_found");
+        };
+        if meta.archived_at.is_some() {
+            anyhow::bail!("conflict");
+        }
+        let mut seen = HashSet::new();
+        for prompt in prompts {
+            let valid = !prompt.name.is_empty()
+                && prompt
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+            if !valid
+                || BUILTIN_VARIABLES.contains(&prompt.name.as_str())
+                || !seen.insert(prompt.name.as_str())
+            {
+                anyhow::bail!("bad_request");
+            }
+        }
+        let name = name
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .unwrap_or(&meta.title);
+        self.templates
+            .upsert(
+                doc_id,
+                meta.workspace_id,
+                name,
+                description,
+                prompts,
+                user_id,
+            )
+            .await
+    }
+}
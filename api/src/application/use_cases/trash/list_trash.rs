@@ -0,0 +1,19 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::{DocumentRepository, TrashedDocument};
+
+pub struct ListTrash<'a, R>
+where
+    R: DocumentRepository + ?Sized,
+{
+    pub repo: &'a R,
+}
+
+impl<'a, R> ListTrash<'a, R>
+where
+    R: DocumentRepository + ?Sized,
+{
+    pub async fn execute(&self, workspace_id: Uuid) -> anyhow::Result<Vec<TrashedDocument>> {
+        self.repo.list_trash(workspace_id).await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod list_trash;
+pub mod purge_trash;
+pub mod restore_document;
@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::storage_port::StoragePort;
+
+/// Permanently deletes trashed documents: a workspace's whole trash, or everything past retention.
+pub struct PurgeTrash<'a, R, S>
+where
+    R: DocumentRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub repo: &'a R,
+    pub storage: &'a S,
+}
+
+impl<'a, R, S> PurgeTrash<'a, R, S>
+where
+    R: DocumentRepository + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        workspace_id: Option<Uuid>,
+        deleted_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> anyhow::Result<u64> {
+        let nodes = self
+            .repo
+            .list_trashed_nodes(workspace_id, deleted_before)
+            .await?;
+        if nodes.is_empty() {
+            return Ok(0);
+        }
+        // Files first: the physical cleanup looks the paths up from the rows.
+        for node in nodes.iter().filter(|n| n.doc_type != "folder") {
+            if let Err(err) = self.storage.delete_doc_physical(node.id).await {
+                tracing::warn!(document_id = %node.id, error = ?err, "trash_physical_delete_failed");
+            }
+        }
+        // Then folders, deepest first (the repository returns them in that order).
+        for node in nodes.iter().filter(|n| n.doc_type == "folder") {
+            if let Err(err) = self.storage.delete_folder_physical(node.id).await {
+                tracing::warn!(document_id = %node.id, error = ?err, "trash_physical_delete_failed");
+            }
+        }
+        let ids: Vec<Uuid> = nodes.iter().map(|n| n.id).collect();
+        self.repo.purge_trashed(&ids).await
+    }
+}
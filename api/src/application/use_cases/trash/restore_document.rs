@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::realtime_port::RealtimeEngine;
+use crate::application::ports::storage_port::StoragePort;
+use crate::domain::documents::document::Document as DomainDocument;
+
+pub struct RestoreDocument<'a, R, RT, S>
+where
+    R: DocumentRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub repo: &'a R,
+    pub realtime: &'a RT,
+    pub storage: &'a S,
+}
+
+impl<'a, R, RT, S> RestoreDocument<'a, R, RT, S>
+where
+    R: DocumentRepository + ?Sized,
+    RT: RealtimeEngine + ?Sized,
+    S: StoragePort + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+    ) -> anyhow::Result<Option<DomainDocument>> {
+        let restored = self.repo.restore_subtree(doc_id, user_id).await?;
+        for node in &restored {
+            if node.doc_type == "folder" {
+                continue;
+            }
+            // The root may land somewhere else when its folder is gone.
+            self.storage.sync_doc_paths(node.id).await?;
+            if node.archived_at.is_none() {
+                self.realtime
+                    .set_document_editable(&node.id.to_string(), true)
+                    .await?;
+            }
+        }
+        Ok(restored.into_iter().find(|doc| doc.id == doc_id))
+    }
+}
@@ -0,0 +1,160 @@
+use uuid::Uuid;
+
+use crate::application::access::{self, Actor, Capability};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::share_access_port::ShareAccessPort;
+use crate::application::ports::user_documents_repository::{
+    FavoriteDocumentRow, UserDocumentsRepository,
+};
+use crate::application::use_cases::user_documents::still_reachable;
+
+pub struct ListFavorites<'a, U, A, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    pub repo: &'a U,
+    pub access: &'a A,
+    pub shares: &'a S,
+}
+
+impl<'a, U, A, S> ListFavorites<'a, U, A, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    /// Favorites the user can still view; the rest stay stored in case access returns.
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<Vec<FavoriteDocumentRow>> {
+        let mut out = Vec::new();
+        for mut row in self.repo.list_favorites(user_id).await? {
+            if still_reachable(
+                self.access,
+                self.shares,
+                user_id,
+                row.document_id,
+                &mut row.share_token,
+            )
+            .await
+            {
+                out.push(row);
+            }
+        }
+        Ok(out)
+    }
+}
+
+pub struct AddFavorite<'a, U, A, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    pub repo: &'a U,
+    pub access: &'a A,
+    pub shares: &'a S,
+}
+
+impl<'a, U, A, S> AddFavorite<'a, U, A, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    /// `share_token` stars a document the user only reaches through that share link.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_token: Option<&str>,
+    ) -> anyhow::Result<bool> {
+        let direct =
+            access::resolve_document(self.access, self.shares, &Actor::User(user_id), doc_id).await;
+        if direct >= Capability::View {
+            return self.repo.add_favorite(user_id, doc_id, None).await;
+        }
+        let Some(token) = share_token else {
+            anyhow::bail!("not_found");
+        };
+        let via_share = access::resolve_document(
+            self.access,
+            self.shares,
+            &Actor::ShareToken(token.to_string()),
+            doc_id,
+        )
+        .await;
+        if via_share < Capability::View {
+            anyhow::bail!("not_found");
+        }
+        let share_id = self
+            .shares
+            .resolve_share_by_token(token)
+            .await?
+            .map(|(share_id, ..)| share_id);
+        self.repo.add_favorite(user_id, doc_id, share_id).await
+    }
+}
+
+pub struct RemoveFavorite<'a, U: UserDocumentsRepository + ?Sized> {
+    pub repo: &'a U,
+}
+
+impl<'a, U: UserDocumentsRepository + ?Sized> RemoveFavorite<'a, U> {
+    pub async fn execute(&self, user_id: Uuid, doc_id: Uuid) -> anyhow::Result<bool> {
+        self.repo.remove_favorite(user_id, doc_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::access::testing::FakeAccess;
+    use crate::application::ports::workspace_repository::WorkspaceRole;
+    use crate::application::use_cases::user_documents::testing::FakeUserDocuments;
+
+    async fn list(
+        repo: &FakeUserDocuments,
+        access: &FakeAccess,
+        user: Uuid,
+    ) -> Vec<(Uuid, Option<String>)> {
+        let uc = ListFavorites {
+            repo,
+            access,
+            shares: access,
+        };
+        uc.execute(user)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|f| (f.document_id, f.share_token))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn favorites_need_access_and_remember_the_share_link() {
+        let user = Uuid::new_v4();
+        let [own, shared, hidden] = [(); 3].map(|_| Uuid::new_v4());
+        let mut access = FakeAccess::default()
+            .member(own, user, WorkspaceRole::Member)
+            .share("link", shared, "document", "comment");
+        let repo = FakeUserDocuments::sharing(&access);
+        let add = AddFavorite {
+            repo: &repo,
+            access: &access,
+            shares: &access,
+        };
+        assert!(add.execute(user, own, None).await.unwrap());
+        assert!(!add.execute(user, own, None).await.unwrap());
+        assert!(add.execute(user, shared, None).await.is_err());
+        assert!(add.execute(user, shared, Some("link")).await.unwrap());
+        assert!(add.execute(user, hidden, Some("link")).await.is_err());
+
+        assert_eq!(
+            list(&repo, &access, user).await,
+            vec![(shared, Some("link".to_string())), (own, None)]
+        );
+        access.shares.clear();
+        assert_eq!(list(&repo, &access, user).await, vec![(own, None)]);
+    }
+}
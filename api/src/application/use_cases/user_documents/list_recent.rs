@@ -0,0 +1,120 @@
+use uuid::Uuid;
+
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::share_access_port::ShareAccessPort;
+use crate::application::ports::user_documents_repository::{
+    RecentDocumentRow, UserDocumentsRepository,
+};
+use crate::application::use_cases::user_documents::still_reachable;
+
+pub struct ListRecentDocuments<'a, U, A, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    pub repo: &'a U,
+    pub access: &'a A,
+    pub shares: &'a S,
+}
+
+impl<'a, U, A, S> ListRecentDocuments<'a, U, A, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    /// Recently opened documents the user can still view.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        limit: usize,
+    ) -> anyhow::Result<Vec<RecentDocumentRow>> {
+        let mut out = Vec::new();
+        for mut row in self.repo.list_recent(user_id).await? {
+            if out.len() >= limit {
+                break;
+            }
+            if still_reachable(
+                self.access,
+                self.shares,
+                user_id,
+                row.document_id,
+                &mut row.share_token,
+            )
+            .await
+            {
+                out.push(row);
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::access::testing::FakeAccess;
+    use crate::application::ports::workspace_repository::WorkspaceRole;
+    use crate::application::use_cases::user_documents::record_view::RecordDocumentView;
+    use crate::application::use_cases::user_documents::testing::FakeUserDocuments;
+
+    #[tokio::test]
+    async fn recent_list_follows_current_access_including_share_links() {
+        let user = Uuid::new_v4();
+        let [member, granted, shared, revoked, joined] = [(); 5].map(|_| Uuid::new_v4());
+        let mut access = FakeAccess::default()
+            .member(member, user, WorkspaceRole::Guest)
+            .grant(granted, user, "view")
+            .share("shared-link", shared, "document", "view")
+            .share("revoked-link", revoked, "document", "edit")
+            .share("joined-link", joined, "document", "view");
+        let repo = FakeUserDocuments::sharing(&access);
+        let record = RecordDocumentView {
+            repo: &repo,
+            shares: &access,
+        };
+        record.execute(user, member, None).await.unwrap();
+        record.execute(user, granted, None).await.unwrap();
+        record
+            .execute(user, shared, Some("shared-link"))
+            .await
+            .unwrap();
+        record
+            .execute(user, revoked, Some("revoked-link"))
+            .await
+            .unwrap();
+        record
+            .execute(user, joined, Some("joined-link"))
+            .await
+            .unwrap();
+        // A later direct open keeps the link it was first reached through
+        record.execute(user, shared, None).await.unwrap();
+
+        access.shares.remove("revoked-link");
+        access.trashed.insert(granted);
+        access = access.member(joined, user, WorkspaceRole::Member);
+
+        let uc = ListRecentDocuments {
+            repo: &repo,
+            access: &access,
+            shares: &access,
+        };
+        let rows = uc.execute(user, 10).await.unwrap();
+        let seen: Vec<(Uuid, Option<&str>)> = rows
+            .iter()
+            .map(|r| (r.document_id, r.share_token.as_deref()))
+            .collect();
+        assert_eq!(
+            seen,
+            vec![
+                (shared, Some("shared-link")),
+                (joined, None),
+                (member, None)
+            ]
+        );
+        assert_eq!(uc.execute(user, 1).await.unwrap().len(), 1);
+        // Nothing leaks to another user
+        assert!(uc.execute(Uuid::new_v4(), 10).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+use uuid::Uuid;
+
+use crate::application::access::{self, Actor, Capability};
+use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::share_access_port::ShareAccessPort;
+
+pub mod favorites;
+pub mod list_recent;
+pub mod record_view;
+
+#[cfg(test)]
+pub(crate) mod testing;
+
+/// Whether the user can still open a stored document, directly or through the share link it
+/// was reached by; `share_token` is cleared when the link is no longer needed.
+pub(crate) async fn still_reachable<A, S>(
+    access: &A,
+    shares: &S,
+    user_id: Uuid,
+    doc_id: Uuid,
+    share_token: &mut Option<String>,
+) -> bool
+where
+    A: AccessRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    let direct = access::resolve_document(access, shares, &Actor::User(user_id), doc_id).await;
+    if direct >= Capability::View {
+        *share_token = None;
+        return true;
+    }
+    match share_token.clone() {
+        Some(token) => {
+            access::resolve_document(access, shares, &Actor::ShareToken(token), doc_id).await
+                >= Capability::View
+        }
+        None => false,
+    }
+}
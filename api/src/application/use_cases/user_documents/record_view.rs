@@ -0,0 +1,43 @@
+use uuid::Uuid;
+
+use crate::application::ports::share_access_port::ShareAccessPort;
+use crate::application::ports::user_documents_repository::UserDocumentsRepository;
+
+/// Views kept per user; older ones drop off the recent list.
+pub const RECENT_VIEWS_KEPT: i64 = 100;
+
+pub struct RecordDocumentView<'a, U, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    pub repo: &'a U,
+    pub shares: &'a S,
+}
+
+impl<'a, U, S> RecordDocumentView<'a, U, S>
+where
+    U: UserDocumentsRepository + ?Sized,
+    S: ShareAccessPort + ?Sized,
+{
+    /// Call once the user is known to see the document; `share_token` is the link that let
+    /// them in when their own access did not.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_token: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let share_id = match share_token {
+            Some(token) => self
+                .shares
+                .resolve_share_by_token(token)
+                .await?
+                .map(|(share_id, ..)| share_id),
+            None => None,
+        };
+        self.repo
+            .record_view(user_id, doc_id, share_id, RECENT_VIEWS_KEPT)
+            .await
+    }
+}
@@ -0,0 +1,120 @@
+//! In-memory user document state for use-case tests.
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::access::testing::FakeAccess;
+use crate::application::ports::user_documents_repository::{
+    FavoriteDocumentRow, RecentDocumentRow, UserDocumentsRepository,
+};
+
+type Entry = (Uuid, Uuid, Option<Uuid>);
+
+#[derive(Default)]
+pub(crate) struct FakeUserDocuments {
+    /// share id -> token, standing in for the join on `shares`
+    pub share_tokens: HashMap<Uuid, String>,
+    /// (user, document, share), oldest first
+    pub views: Mutex<Vec<Entry>>,
+    pub favorites: Mutex<Vec<Entry>>,
+}
+
+impl FakeUserDocuments {
+    pub fn sharing(access: &FakeAccess) -> Self {
+        Self {
+            share_tokens: access
+                .shares
+                .iter()
+                .map(|(token, share)| (share.id, token.clone()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn token(&self, share_id: Option<Uuid>) -> Option<String> {
+        share_id.and_then(|id| self.share_tokens.get(&id).cloned())
+    }
+}
+
+fn upsert(
+    entries: &Mutex<Vec<Entry>>,
+    user_id: Uuid,
+    doc_id: Uuid,
+    share_id: Option<Uuid>,
+) -> bool {
+    let mut entries = entries.lock().unwrap();
+    let previous = entries
+        .iter()
+        .position(|(u, d, _)| *u == user_id && *d == doc_id)
+        .map(|i| entries.remove(i));
+    let share_id = share_id.or(previous.and_then(|(_, _, s)| s));
+    entries.push((user_id, doc_id, share_id));
+    previous.is_none()
+}
+
+#[async_trait]
+impl UserDocumentsRepository for FakeUserDocuments {
+    async fn record_view(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_id: Option<Uuid>,
+        _keep: i64,
+    ) -> anyhow::Result<()> {
+        upsert(&self.views, user_id, doc_id, share_id);
+        Ok(())
+    }
+
+    async fn list_recent(&self, user_id: Uuid) -> anyhow::Result<Vec<RecentDocumentRow>> {
+        let views = self.views.lock().unwrap();
+        Ok(views
+            .iter()
+            .rev()
+            .filter(|(u, _, _)| *u == user_id)
+            .map(|(_, doc_id, share_id)| RecentDocumentRow {
+                document_id: *doc_id,
+                workspace_id: Uuid::nil(),
+                title: doc_id.to_string(),
+                doc_type: "document".into(),
+                view_count: 1,
+                last_viewed_at: chrono::Utc::now(),
+                share_token: self.token(*share_id),
+            })
+            .collect())
+    }
+
+    async fn add_favorite(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_id: Option<Uuid>,
+    ) -> anyhow::Result<bool> {
+        Ok(upsert(&self.favorites, user_id, doc_id, share_id))
+    }
+
+    async fn remove_favorite(&self, user_id: Uuid, doc_id: Uuid) -> anyhow::Result<bool> {
+        let mut favorites = self.favorites.lock().unwrap();
+        let before = favorites.len();
+        favorites.retain(|(u, d, _)| !(*u == user_id && *d == doc_id));
+        Ok(favorites.len() < before)
+    }
+
+    async fn list_favorites(&self, user_id: Uuid) -> anyhow::Result<Vec<FavoriteDocumentRow>> {
+        let favorites = self.favorites.lock().unwrap();
+        Ok(favorites
+            .iter()
+            .rev()
+            .filter(|(u, _, _)| *u == user_id)
+            .map(|(_, doc_id, share_id)| FavoriteDocumentRow {
+                document_id: *doc_id,
+                workspace_id: Uuid::nil(),
+                title: doc_id.to_string(),
+                doc_type: "document".into(),
+                created_at: chrono::Utc::now(),
+                share_token: self.token(*share_id),
+            })
+            .collect())
+    }
+}
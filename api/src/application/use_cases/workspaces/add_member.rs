@@ -0,0 +1,54 @@
+use uuid::Uuid;
+
+use crate::application::ports::user_repository::UserRepository;
+use crate::application::ports::workspace_repository::{
+    WorkspaceMemberRow, WorkspaceRepository, WorkspaceRole,
+};
+
+pub struct AddMember<'a, R, U>
+where
+    R: WorkspaceRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    pub repo: &'a R,
+    pub users: &'a U,
+}
+
+impl<'a, R, U> AddMember<'a, R, U>
+where
+    R: WorkspaceRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        workspace_id: Uuid,
+        email: &str,
+        role: WorkspaceRole,
+    ) -> anyhow::Result<WorkspaceMemberRow> {
+        let ws = match self.repo.get_for_user(workspace_id, actor_id).await? {
+            Some(ws) => ws,
+            None => anyhow::bail!("not_found"),
+        };
+        if !ws.role.can_manage() || ws.is_personal {
+            anyhow::bail!("forbidden");
+        }
+        // Only owners may hand out ownership.
+        if role == WorkspaceRole::Owner && ws.role != WorkspaceRole::Owner {
+            anyhow::bail!("forbidden");
+        }
+        let user = match self.users.find_by_email(email.trim()).await? {
+            Some(u) => u,
+            None => anyhow::bail!("user_not_found"),
+        };
+        if self.repo.get_role(workspace_id, user.id).await?.is_some() {
+            anyhow::bail!("conflict");
+        }
+        self.repo.upsert_member(workspace_id, user.id, role).await?;
+        let members = self.repo.list_members(workspace_id).await?;
+        members
+            .into_iter()
+            .find(|m| m.user_id == user.id)
+            .ok_or_else(|| anyhow::anyhow!("not_found"))
+    }
+}
@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{WorkspaceRepository, WorkspaceRow};
+
+pub struct CreateWorkspace<'a, R: WorkspaceRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: WorkspaceRepository + ?Sized> CreateWorkspace<'a, R> {
+    pub async fn execute(&self, user_id: Uuid, name: &str) -> anyhow::Result<WorkspaceRow> {
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("bad_request");
+        }
+        self.repo.create(name, user_id).await
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use tokio::task;
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::DocumentRepository;
+use crate::application::ports::git_repository::GitRepository;
+use crate::application::ports::git_workspace::GitWorkspacePort;
+use crate::application::ports::plugin_asset_store::PluginAssetStore;
+use crate::application::ports::plugin_installation_repository::PluginInstallationRepository;
+use crate::application::ports::storage_port::StoragePort;
+use crate::application::ports::workspace_repository::{WorkspaceRepository, WorkspaceRole};
+
+pub struct DeleteWorkspace<'a, R, D, S, PIR, GR, GW>
+where
+    R: WorkspaceRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    PIR: PluginInstallationRepository + ?Sized,
+    GR: GitRepository + ?Sized,
+    GW: GitWorkspacePort + ?Sized,
+{
+    pub repo: &'a R,
+    pub documents: &'a D,
+    pub storage: &'a S,
+    pub plugin_installations: &'a PIR,
+    pub plugin_assets: Arc<dyn PluginAssetStore>,
+    pub git_repo: &'a GR,
+    pub git_workspace: &'a GW,
+}
+
+impl<'a, R, D, S, PIR, GR, GW> DeleteWorkspace<'a, R, D, S, PIR, GR, GW>
+where
+    R: WorkspaceRepository + ?Sized,
+    D: DocumentRepository + ?Sized,
+    S: StoragePort + ?Sized,
+    PIR: PluginInstallationRepository + ?Sized,
+    GR: GitRepository + ?Sized,
+    GW: GitWorkspacePort + ?Sized,
+{
+    pub async fn execute(&self, user_id: Uuid, workspace_id: Uuid) -> anyhow::Result<()> {
+        let ws = match self.repo.get_for_user(workspace_id, user_id).await? {
+            Some(ws) => ws,
+            None => anyhow::bail!("not_found"),
+        };
+        if ws.role != WorkspaceRole::Owner || ws.is_personal {
+            anyhow::bail!("forbidden");
+        }
+
+        // Physical artifacts are resolved from document rows, so remove them before
+        // the rows cascade away with the workspace.
+        for root in self.documents.list_workspace_roots(workspace_id).await? {
+            let res = if root.doc_type == "folder" {
+                self.storage
+                    .delete_folder_physical(root.id)
+                    .await
+                    .map(|_| ())
+            } else {
+                self.storage.delete_doc_physical(root.id).await
+            };
+            if let Err(err) = res {
+                tracing::warn!(workspace_id = %workspace_id, document_id = %root.id, error = ?err, "failed to remove document artifacts during workspace deletion");
+            }
+        }
+
+        let installations = self
+            .plugin_installations
+            .list_for_user(workspace_id)
+            .await?;
+        for inst in &installations {
+            let plugin_id = inst.plugin_id.clone();
+            let assets = Arc::clone(&self.plugin_assets);
+            match task::spawn_blocking(move || {
+                assets.remove_user_plugin_dir(&workspace_id, &plugin_id)
+            })
+            .await
+            {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => {
+                    tracing::warn!(workspace_id = %workspace_id, plugin_id = %inst.plugin_id, error = ?err, "failed to remove plugin assets for workspace");
+                }
+                Err(err) => {
+                    tracing::warn!(workspace_id = %workspace_id, plugin_id = %inst.plugin_id, error = ?err, "failed to join plugin asset removal task");
+                }
+            }
+        }
+        self.plugin_installations
+            .remove_all_for_user(workspace_id)
+            .await?;
+
+        self.git_repo.delete_sync_logs(workspace_id).await?;
+        if let Err(err) = self.git_workspace.remove_repository(workspace_id).await {
+            tracing::warn!(workspace_id = %workspace_id, error = ?err, "failed to remove git workspace during workspace deletion");
+        }
+        let _ = self.git_repo.delete_config(workspace_id).await?;
+        self.git_repo.delete_repository_state(workspace_id).await?;
+
+        if !self.repo.delete(workspace_id).await? {
+            anyhow::bail!("not_found");
+        }
+        Ok(())
+    }
+}
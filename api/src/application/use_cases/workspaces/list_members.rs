@@ -0,0 +1,20 @@
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{WorkspaceMemberRow, WorkspaceRepository};
+
+pub struct ListMembers<'a, R: WorkspaceRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: WorkspaceRepository + ?Sized> ListMembers<'a, R> {
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+    ) -> anyhow::Result<Option<Vec<WorkspaceMemberRow>>> {
+        if self.repo.get_role(workspace_id, user_id).await?.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(self.repo.list_members(workspace_id).await?))
+    }
+}
@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{WorkspaceRepository, WorkspaceRow};
+
+pub struct ListWorkspaces<'a, R: WorkspaceRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: WorkspaceRepository + ?Sized> ListWorkspaces<'a, R> {
+    pub async fn execute(&self, user_id: Uuid) -> anyhow::Result<Vec<WorkspaceRow>> {
+        self.repo.list_for_user(user_id).await
+    }
+}
@@ -0,0 +1,9 @@
+pub mod add_member;
+pub mod create_workspace;
+pub mod delete_workspace;
+pub mod list_members;
+pub mod list_workspaces;
+pub mod remove_member;
+pub mod resolve_workspace;
+pub mod update_member;
+pub mod update_workspace;
@@ -0,0 +1,40 @@
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{WorkspaceRepository, WorkspaceRole};
+
+pub struct RemoveMember<'a, R: WorkspaceRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: WorkspaceRepository + ?Sized> RemoveMember<'a, R> {
+    /// Removes a member; members may always remove themselves (leave).
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        workspace_id: Uuid,
+        member_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let actor_role = match self.repo.get_role(workspace_id, actor_id).await? {
+            Some(r) => r,
+            None => anyhow::bail!("not_found"),
+        };
+        if actor_id != member_id && !actor_role.can_manage() {
+            anyhow::bail!("forbidden");
+        }
+        let current = match self.repo.get_role(workspace_id, member_id).await? {
+            Some(r) => r,
+            None => anyhow::bail!("not_found"),
+        };
+        if current == WorkspaceRole::Owner {
+            if actor_id != member_id && actor_role != WorkspaceRole::Owner {
+                anyhow::bail!("forbidden");
+            }
+            // Also protects the personal workspace, which has a single owner.
+            if self.repo.count_owners(workspace_id).await? <= 1 {
+                anyhow::bail!("conflict");
+            }
+        }
+        self.repo.remove_member(workspace_id, member_id).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{WorkspaceRepository, WorkspaceRow};
+
+/// Resolves the workspace a request operates on. Without an explicit id the
+/// user's personal workspace (which shares the user's id) is used.
+pub struct ResolveWorkspace<'a, R: WorkspaceRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: WorkspaceRepository + ?Sized> ResolveWorkspace<'a, R> {
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        requested: Option<Uuid>,
+    ) -> anyhow::Result<Option<WorkspaceRow>> {
+        let workspace_id = requested.unwrap_or(user_id);
+        self.repo.get_for_user(workspace_id, user_id).await
+    }
+}
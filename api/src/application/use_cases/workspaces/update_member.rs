@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{WorkspaceRepository, WorkspaceRole};
+
+pub struct UpdateMember<'a, R: WorkspaceRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: WorkspaceRepository + ?Sized> UpdateMember<'a, R> {
+    pub async fn execute(
+        &self,
+        actor_id: Uuid,
+        workspace_id: Uuid,
+        member_id: Uuid,
+        role: WorkspaceRole,
+    ) -> anyhow::Result<()> {
+        let actor_role = match self.repo.get_role(workspace_id, actor_id).await? {
+            Some(r) => r,
+            None => anyhow::bail!("not_found"),
+        };
+        if !actor_role.can_manage() {
+            anyhow::bail!("forbidden");
+        }
+        let current = match self.repo.get_role(workspace_id, member_id).await? {
+            Some(r) => r,
+            None => anyhow::bail!("not_found"),
+        };
+        // Promoting to or demoting from owner is reserved to owners.
+        if (role == WorkspaceRole::Owner || current == WorkspaceRole::Owner)
+            && actor_role != WorkspaceRole::Owner
+        {
+            anyhow::bail!("forbidden");
+        }
+        if current == WorkspaceRole::Owner
+            && role != WorkspaceRole::Owner
+            && self.repo.count_owners(workspace_id).await? <= 1
+        {
+            anyhow::bail!("conflict");
+        }
+        self.repo.upsert_member(workspace_id, member_id, role).await
+    }
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{WorkspaceRepository, WorkspaceRow};
+
+pub struct UpdateWorkspace<'a, R: WorkspaceRepository + ?Sized> {
+    pub repo: &'a R,
+}
+
+impl<'a, R: WorkspaceRepository + ?Sized> UpdateWorkspace<'a, R> {
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        workspace_id: Uuid,
+        name: &str,
+    ) -> anyhow::Result<WorkspaceRow> {
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("bad_request");
+        }
+        let ws = match self.repo.get_for_user(workspace_id, user_id).await? {
+            Some(ws) => ws,
+            None => anyhow::bail!("not_found"),
+        };
+        if !ws.role.can_manage() {
+            anyhow::bail!("forbidden");
+        }
+        self.repo.rename(workspace_id, name).await?;
+        match self.repo.get_for_user(workspace_id, user_id).await? {
+            Some(ws) => Ok(ws),
+            None => anyhow::bail!("not_found"),
+        }
+    }
+}
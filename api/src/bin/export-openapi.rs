@@ -1,5 +1,9 @@
 use api::presentation::{
-    http::{auth, documents, files, git, health, markdown, plugins, public, shares, tags},
+    http::{
+        admin, api_tokens, auth, comments, documents, files, git, grants, health, imports,
+        markdown, plugins, public, shares, tags, takeout, templates, trash, user_documents,
+        workspaces,
+    },
     ws,
 };
 use utoipa::OpenApi;
@@ -9,17 +13,79 @@ use utoipa::OpenApi;
     paths(
         auth::register,
         auth::login,
+        auth::refresh,
         auth::logout,
         auth::me,
         auth::delete_account,
+        auth::list_sessions,
+        auth::revoke_session,
+        auth::revoke_all_sessions,
+        auth::providers,
+        auth::oidc_login,
+        auth::oidc_callback,
+        auth::login_mfa,
+        auth::mfa_status,
+        auth::setup_totp,
+        auth::enable_totp,
+        auth::disable_totp,
+        auth::forgot_password,
+        auth::reset_password,
+        auth::verify_email,
+        auth::resend_verification,
+        auth::update_profile,
+        auth::change_email,
+        auth::change_password,
+        admin::list_users,
+        admin::get_user,
+        admin::disable_user,
+        admin::enable_user,
+        admin::update_user_role,
+        admin::send_password_reset,
+        admin::instance_stats,
+        admin::delete_document,
+        admin::install_global_plugin,
+        admin::list_audit,
+        api_tokens::list_tokens,
+        api_tokens::create_token,
+        api_tokens::delete_token,
+        takeout::list_takeouts,
+        takeout::request_takeout,
+        takeout::get_takeout,
+        takeout::download_takeout,
+        templates::list_templates,
+        templates::set_template,
+        templates::remove_template,
+        templates::instantiate_template,
+        trash::list_trash,
+        trash::empty_trash,
+        trash::restore_document,
+        user_documents::list_recent,
+        user_documents::list_favorites,
+        user_documents::add_favorite,
+        user_documents::remove_favorite,
         ws::axum_ws_entry,
         tags::list_tags,
+        workspaces::list_workspaces,
+        workspaces::create_workspace,
+        workspaces::update_workspace,
+        workspaces::delete_workspace,
+        workspaces::list_members,
+        workspaces::add_member,
+        workspaces::update_member,
+        workspaces::remove_member,
         documents::list_documents,
         documents::create_document,
         documents::get_document,
         documents::update_document,
         documents::delete_document,
+        documents::duplicate_document,
+        documents::move_document,
+        documents::reorder_documents,
+        imports::import_vault,
+        imports::import_document,
         documents::get_document_content,
+        documents::replace_document_content,
+        documents::patch_document_content,
         documents::archive_document,
         documents::unarchive_document,
         documents::download_document,
@@ -28,7 +94,9 @@ use utoipa::OpenApi;
         documents::restore_document_snapshot,
         documents::download_document_snapshot,
         documents::search_documents,
+        documents::quick_search_documents,
         documents::get_backlinks,
+        documents::preview_rename,
         documents::get_outgoing_links,
         files::upload_file,
         files::get_file,
@@ -41,6 +109,19 @@ use utoipa::OpenApi;
         shares::list_active_shares,
         shares::list_applicable_shares,
         shares::materialize_folder_share,
+        grants::list_grants,
+        grants::create_grant,
+        grants::delete_grant,
+        grants::list_shared_with_me,
+        comments::list_threads,
+        comments::create_thread,
+        comments::add_reply,
+        comments::resolve_thread,
+        comments::reopen_thread,
+        comments::delete_thread,
+        comments::update_comment,
+        comments::delete_comment,
+        comments::list_mentions,
         public::publish_document,
         public::unpublish_document,
         public::get_publish_status,
@@ -83,14 +164,67 @@ use utoipa::OpenApi;
         auth::LoginRequest,
         auth::LoginResponse,
         auth::UserResponse,
+        auth::RefreshRequest,
+        auth::SessionItem,
+        auth::AuthProvidersResponse,
+        auth::OidcProviderInfo,
+        auth::MfaRequiredResponse,
+        auth::LoginMfaRequest,
+        auth::MfaStatusResponse,
+        auth::TotpSetupResponse,
+        auth::TotpCodeRequest,
+        auth::RecoveryCodesResponse,
+        auth::DisableTotpRequest,
+        auth::EmailRequest,
+        auth::ResetPasswordRequest,
+        auth::VerifyEmailRequest,
+        auth::UpdateProfileRequest,
+        auth::ChangeEmailRequest,
+        auth::ChangePasswordRequest,
+        admin::AdminUserItem,
+        admin::InstanceStatsResponse,
+        admin::AdminAuditItem,
+        admin::UpdateUserRoleRequest,
+        admin::InstallGlobalPluginRequest,
+        admin::InstallGlobalPluginResponse,
+        api_tokens::ApiTokenItem,
+        api_tokens::CreateApiTokenRequest,
+        api_tokens::CreateApiTokenResponse,
+        takeout::TakeoutJob,
         tags::TagItem,
+        templates::Template,
+        templates::TemplatePromptItem,
+        templates::SetTemplateRequest,
+        templates::InstantiateTemplateRequest,
+        trash::TrashItem,
+        trash::EmptyTrashResponse,
+        user_documents::RecentDocumentItem,
+        user_documents::FavoriteDocumentItem,
+        workspaces::WorkspaceResponse,
+        workspaces::WorkspaceMemberResponse,
+        workspaces::CreateWorkspaceRequest,
+        workspaces::UpdateWorkspaceRequest,
+        workspaces::AddMemberRequest,
+        workspaces::UpdateMemberRequest,
         documents::Document,
         documents::DocumentListResponse,
         documents::CreateDocumentRequest,
         documents::UpdateDocumentRequest,
+        documents::DuplicateDocumentRequest,
+        documents::MoveDocumentRequest,
+        documents::ReorderDocumentsRequest,
+        documents::ReorderDocumentsResponse,
+        documents::DocumentContentResponse,
+        documents::ReplaceDocumentContentRequest,
+        documents::PatchDocumentContentRequest,
+        documents::TextOperationRequest,
         documents::SearchResult,
+        documents::QuickSearchResult,
+        documents::BreadcrumbItem,
         documents::BacklinkInfo,
         documents::BacklinksResponse,
+        documents::LinkRewriteItem,
+        documents::RenamePreviewResponse,
         documents::OutgoingLink,
         documents::OutgoingLinksResponse,
         documents::DocumentDownloadBinary,
@@ -106,6 +240,11 @@ use utoipa::OpenApi;
         documents::SnapshotRestoreResponse,
         files::UploadFileResponse,
         files::UploadFileMultipart,
+        imports::VaultImportMultipart,
+        imports::DocumentImportMultipart,
+        imports::VaultImportResponse,
+        imports::ImportCollisionItem,
+        imports::UnresolvedLinkItem,
         shares::CreateShareRequest,
         shares::CreateShareResponse,
         shares::ShareItem,
@@ -115,6 +254,14 @@ use utoipa::OpenApi;
         shares::ApplicableShareItem,
         shares::ActiveShareItem,
         shares::MaterializeResponse,
+        grants::DocumentGrantItem,
+        grants::CreateGrantRequest,
+        grants::SharedWithMeItem,
+        comments::CommentItem,
+        comments::CommentThreadItem,
+        comments::CreateCommentThreadRequest,
+        comments::CommentBodyRequest,
+        comments::MentionItem,
         public::PublishResponse,
         public::PublicDocumentSummary,
         git::GitConfigResponse,
@@ -153,9 +300,14 @@ use utoipa::OpenApi;
     )),
     tags(
         (name = "Auth", description = "Authentication"),
+        (name = "Admin", description = "Instance administration"),
+        (name = "Workspaces", description = "Team workspaces and membership"),
         (name = "Documents", description = "Documents management"),
         (name = "Files", description = "File management"),
         (name = "Sharing", description = "Document sharing"),
+        (name = "Comments", description = "Document comment threads"),
+        (name = "Templates", description = "Document templates"),
+        (name = "Trash", description = "Deleted documents"),
         (name = "Public Documents", description = "Public pages"),
         (name = "Realtime", description = "Yjs WebSocket endpoint (/yjs/:id)"),
         (name = "Git", description = "Git integration"),
@@ -1,6 +1,11 @@
 use std::sync::Arc;
 
 use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::admin_repository::AdminRepository;
+use crate::application::ports::api_token_repository::ApiTokenRepository;
+use crate::application::ports::auth_link_repository::AuthLinkRepository;
+use crate::application::ports::comment_repository::CommentRepository;
+use crate::application::ports::document_grants_repository::DocumentGrantsRepository;
 use crate::application::ports::document_repository::DocumentRepository;
 use crate::application::ports::document_snapshot_archive_repository::DocumentSnapshotArchiveRepository;
 use crate::application::ports::files_repository::FilesRepository;
@@ -8,6 +13,10 @@ use crate::application::ports::git_repository::GitRepository;
 use crate::application::ports::git_storage::GitStorage;
 use crate::application::ports::git_workspace::GitWorkspacePort;
 use crate::application::ports::gitignore_port::GitignorePort;
+use crate::application::ports::mailer::Mailer;
+use crate::application::ports::mfa_repository::MfaRepository;
+use crate::application::ports::oidc_provider::OidcProvider;
+use crate::application::ports::oidc_repository::OidcRepository;
 use crate::application::ports::plugin_asset_store::PluginAssetStore;
 use crate::application::ports::plugin_event_publisher::{PluginEventPublisher, PluginScopedEvent};
 use crate::application::ports::plugin_installation_repository::PluginInstallationRepository;
@@ -17,12 +26,19 @@ use crate::application::ports::plugin_repository::PluginRepository;
 use crate::application::ports::plugin_runtime::PluginRuntime;
 use crate::application::ports::public_repository::PublicRepository;
 use crate::application::ports::realtime_port::RealtimeEngine;
-pub use crate::application::ports::realtime_types::{DynRealtimeSink, DynRealtimeStream};
+pub use crate::application::ports::realtime_types::{
+    DynRealtimeSink, DynRealtimeStream, RealtimeAccess,
+};
+use crate::application::ports::session_repository::SessionRepository;
 use crate::application::ports::share_access_port::ShareAccessPort;
 use crate::application::ports::shares_repository::SharesRepository;
 use crate::application::ports::storage_port::StoragePort;
 use crate::application::ports::tag_repository::TagRepository;
+use crate::application::ports::takeout_repository::TakeoutRepository;
+use crate::application::ports::template_repository::TemplateRepository;
+use crate::application::ports::user_documents_repository::UserDocumentsRepository;
 use crate::application::ports::user_repository::UserRepository;
+use crate::application::ports::workspace_repository::WorkspaceRepository;
 use crate::application::services::plugins::asset_signer::AssetSigner;
 use crate::application::services::realtime::snapshot::SnapshotService;
 use crate::bootstrap::config::Config;
@@ -42,10 +58,24 @@ pub struct AppServices {
     shares_repo: Arc<dyn SharesRepository>,
     share_access_port: Arc<dyn ShareAccessPort>,
     access_repo: Arc<dyn AccessRepository>,
+    document_grants_repo: Arc<dyn DocumentGrantsRepository>,
+    comment_repo: Arc<dyn CommentRepository>,
     files_repo: Arc<dyn FilesRepository>,
     public_repo: Arc<dyn PublicRepository>,
     user_repo: Arc<dyn UserRepository>,
+    admin_repo: Arc<dyn AdminRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    api_token_repo: Arc<dyn ApiTokenRepository>,
+    mfa_repo: Arc<dyn MfaRepository>,
+    auth_link_repo: Arc<dyn AuthLinkRepository>,
+    mailer: Arc<dyn Mailer>,
+    oidc_repo: Arc<dyn OidcRepository>,
+    oidc_provider: Option<Arc<dyn OidcProvider>>,
     tag_repo: Arc<dyn TagRepository>,
+    takeout_repo: Arc<dyn TakeoutRepository>,
+    template_repo: Arc<dyn TemplateRepository>,
+    user_documents_repo: Arc<dyn UserDocumentsRepository>,
+    workspace_repo: Arc<dyn WorkspaceRepository>,
     git_repo: Arc<dyn GitRepository>,
     git_storage: Arc<dyn GitStorage>,
     gitignore_port: Arc<dyn GitignorePort>,
@@ -72,10 +102,24 @@ impl AppServices {
         shares_repo: Arc<dyn SharesRepository>,
         share_access_port: Arc<dyn ShareAccessPort>,
         access_repo: Arc<dyn AccessRepository>,
+        document_grants_repo: Arc<dyn DocumentGrantsRepository>,
+        comment_repo: Arc<dyn CommentRepository>,
         files_repo: Arc<dyn FilesRepository>,
         public_repo: Arc<dyn PublicRepository>,
         user_repo: Arc<dyn UserRepository>,
+        admin_repo: Arc<dyn AdminRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        api_token_repo: Arc<dyn ApiTokenRepository>,
+        mfa_repo: Arc<dyn MfaRepository>,
+        auth_link_repo: Arc<dyn AuthLinkRepository>,
+        mailer: Arc<dyn Mailer>,
+        oidc_repo: Arc<dyn OidcRepository>,
+        oidc_provider: Option<Arc<dyn OidcProvider>>,
         tag_repo: Arc<dyn TagRepository>,
+        takeout_repo: Arc<dyn TakeoutRepository>,
+        template_repo: Arc<dyn TemplateRepository>,
+        user_documents_repo: Arc<dyn UserDocumentsRepository>,
+        workspace_repo: Arc<dyn WorkspaceRepository>,
         git_repo: Arc<dyn GitRepository>,
         git_storage: Arc<dyn GitStorage>,
         gitignore_port: Arc<dyn GitignorePort>,
@@ -99,10 +143,24 @@ impl AppServices {
             shares_repo,
             share_access_port,
             access_repo,
+            document_grants_repo,
+            comment_repo,
             files_repo,
             public_repo,
             user_repo,
+            admin_repo,
+            session_repo,
+            api_token_repo,
+            mfa_repo,
+            auth_link_repo,
+            mailer,
+            oidc_repo,
+            oidc_provider,
             tag_repo,
+            takeout_repo,
+            template_repo,
+            user_documents_repo,
+            workspace_repo,
             git_repo,
             git_storage,
             gitignore_port,
@@ -148,6 +206,14 @@ impl AppContext {
         self.services.access_repo.clone()
     }
 
+    pub fn document_grants_repo(&self) -> Arc<dyn DocumentGrantsRepository> {
+        self.services.document_grants_repo.clone()
+    }
+
+    pub fn comment_repo(&self) -> Arc<dyn CommentRepository> {
+        self.services.comment_repo.clone()
+    }
+
     pub fn files_repo(&self) -> Arc<dyn FilesRepository> {
         self.services.files_repo.clone()
     }
@@ -160,10 +226,59 @@ impl AppContext {
         self.services.user_repo.clone()
     }
 
+    pub fn admin_repo(&self) -> Arc<dyn AdminRepository> {
+        self.services.admin_repo.clone()
+    }
+
+    pub fn session_repo(&self) -> Arc<dyn SessionRepository> {
+        self.services.session_repo.clone()
+    }
+
+    pub fn api_token_repo(&self) -> Arc<dyn ApiTokenRepository> {
+        self.services.api_token_repo.clone()
+    }
+
+    pub fn mfa_repo(&self) -> Arc<dyn MfaRepository> {
+        self.services.mfa_repo.clone()
+    }
+
+    pub fn auth_link_repo(&self) -> Arc<dyn AuthLinkRepository> {
+        self.services.auth_link_repo.clone()
+    }
+
+    pub fn mailer(&self) -> Arc<dyn Mailer> {
+        self.services.mailer.clone()
+    }
+
+    pub fn oidc_repo(&self) -> Arc<dyn OidcRepository> {
+        self.services.oidc_repo.clone()
+    }
+
+    /// Configured single sign-on provider, if any
+    pub fn oidc_provider(&self) -> Option<Arc<dyn OidcProvider>> {
+        self.services.oidc_provider.clone()
+    }
+
     pub fn tag_repo(&self) -> Arc<dyn TagRepository> {
         self.services.tag_repo.clone()
     }
 
+    pub fn takeout_repo(&self) -> Arc<dyn TakeoutRepository> {
+        self.services.takeout_repo.clone()
+    }
+
+    pub fn template_repo(&self) -> Arc<dyn TemplateRepository> {
+        self.services.template_repo.clone()
+    }
+
+    pub fn user_documents_repo(&self) -> Arc<dyn UserDocumentsRepository> {
+        self.services.user_documents_repo.clone()
+    }
+
+    pub fn workspace_repo(&self) -> Arc<dyn WorkspaceRepository> {
+        self.services.workspace_repo.clone()
+    }
+
     pub fn git_repo(&self) -> Arc<dyn GitRepository> {
         self.services.git_repo.clone()
     }
@@ -243,11 +358,11 @@ impl AppContext {
         doc_id: &str,
         sink: DynRealtimeSink,
         stream: DynRealtimeStream,
-        can_edit: bool,
+        access: RealtimeAccess,
     ) -> anyhow::Result<()> {
         self.services
             .realtime_engine
-            .subscribe(doc_id, sink, stream, can_edit)
+            .subscribe(doc_id, sink, stream, access)
             .await
     }
 }
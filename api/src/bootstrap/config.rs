@@ -37,6 +37,14 @@ pub struct Config {
     pub database_url: String,
     pub jwt_secret_pem: String,
     pub jwt_expires_secs: i64,
+    pub refresh_token_ttl_secs: i64,
+    pub password_signup_enabled: bool,
+    /// Reject password logins until the address is confirmed
+    pub require_email_verification: bool,
+    /// Accounts promoted to the admin role at startup
+    pub admin_emails: Vec<String>,
+    pub oidc: Option<OidcConfig>,
+    pub mail: MailConfig,
     pub snapshot_interval_secs: u64,
     pub snapshot_keep_versions: i64,
     pub updates_keep_window: i64,
@@ -66,6 +74,51 @@ pub struct Config {
     pub redis_awareness_ttl_ms: u64,
     pub redis_stream_max_len: usize,
     pub snapshot_archive_interval_secs: u64,
+    /// How long finished account exports stay downloadable
+    pub takeout_retention_secs: i64,
+    /// How long deleted documents stay in the trash; 0 keeps them until emptied
+    pub trash_retention_secs: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct OidcConfig {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub redirect_url: String,
+    pub scopes: String,
+    pub provider_name: String,
+    /// Lower-cased email domains allowed to sign in; empty allows any
+    pub allowed_email_domains: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// Upgrade with STARTTLS (typically port 587)
+    StartTls,
+    /// TLS from the first byte (typically port 465)
+    Implicit,
+    /// Plain text, for local catch-all servers only
+    None,
+}
+
+#[derive(Clone, Debug)]
+pub enum MailBackend {
+    Smtp {
+        host: String,
+        port: u16,
+        username: Option<String>,
+        password: Option<String>,
+        tls: SmtpTls,
+    },
+    /// Writes messages as .eml files instead of sending them
+    Outbox { dir: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct MailConfig {
+    pub from: String,
+    pub backend: MailBackend,
 }
 
 impl Config {
@@ -79,9 +132,69 @@ impl Config {
         // HS256 secret in PEM or bare string (we'll accept either)
         let jwt_secret_pem =
             env_var(&["JWT_SECRET"]).unwrap_or_else(|| "development-secret-change-me".into());
+        // Access tokens are short-lived; sessions are kept alive with rotating refresh tokens
         let jwt_expires_secs = env_var(&["JWT_EXPIRES_SECS"])
             .and_then(|s| s.parse().ok())
-            .unwrap_or(60 * 60);
+            .unwrap_or(15 * 60);
+        let refresh_token_ttl_secs = env_var(&["REFRESH_TOKEN_TTL_SECS"])
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(30 * 24 * 60 * 60);
+        let password_signup_enabled = env_var(&["PASSWORD_SIGNUP_ENABLED"])
+            .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(true);
+        let require_email_verification = env_var(&["REQUIRE_EMAIL_VERIFICATION"])
+            .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        let admin_emails = env_var(&["ADMIN_EMAILS"])
+            .map(|v| {
+                v.split(',')
+                    .map(|e| e.trim().to_lowercase())
+                    .filter(|e| !e.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mail_from =
+            env_var(&["MAIL_FROM"]).unwrap_or_else(|| "RefMD <no-reply@localhost>".into());
+        let mail_backend = match env_var(&["MAIL_BACKEND"])
+            .as_deref()
+            .map(|v| v.trim().to_lowercase())
+            .as_deref()
+            .unwrap_or("outbox")
+        {
+            "smtp" => {
+                let tls = match env_var(&["SMTP_TLS"])
+                    .as_deref()
+                    .map(|v| v.trim().to_lowercase())
+                    .as_deref()
+                    .unwrap_or("starttls")
+                {
+                    "starttls" => SmtpTls::StartTls,
+                    "tls" | "implicit" => SmtpTls::Implicit,
+                    "none" | "off" => SmtpTls::None,
+                    other => anyhow::bail!("unsupported SMTP_TLS mode: {}", other),
+                };
+                let default_port = match tls {
+                    SmtpTls::Implicit => 465,
+                    SmtpTls::StartTls => 587,
+                    SmtpTls::None => 25,
+                };
+                MailBackend::Smtp {
+                    host: env_var(&["SMTP_HOST"]).ok_or_else(|| {
+                        anyhow::anyhow!("SMTP_HOST must be set when MAIL_BACKEND=smtp")
+                    })?,
+                    port: env_var(&["SMTP_PORT"])
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(default_port),
+                    username: env_var(&["SMTP_USERNAME"]),
+                    password: env_var(&["SMTP_PASSWORD"]),
+                    tls,
+                }
+            }
+            "outbox" | "file" => MailBackend::Outbox {
+                dir: env_var(&["MAIL_OUTBOX_DIR"]).unwrap_or_else(|| "./mail-outbox".into()),
+            },
+            other => anyhow::bail!("unsupported MAIL_BACKEND: {}", other),
+        };
         let snapshot_interval_secs = env_var(&["SNAPSHOT_INTERVAL_SECS"])
             .and_then(|s| s.parse().ok())
             .unwrap_or(300);
@@ -142,6 +255,39 @@ impl Config {
                     }
                 })
                 .or_else(|| frontend_url.clone());
+        let oidc = match (env_var(&["OIDC_ISSUER_URL"]), env_var(&["OIDC_CLIENT_ID"])) {
+            (Some(issuer_url), Some(client_id)) => {
+                let redirect_url = env_var(&["OIDC_REDIRECT_URL"])
+                    .or_else(|| {
+                        public_base_url
+                            .as_ref()
+                            .map(|base| format!("{base}/api/auth/oidc/callback"))
+                    })
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("OIDC_REDIRECT_URL or BACKEND_URL must be set for OIDC")
+                    })?;
+                Some(OidcConfig {
+                    issuer_url: issuer_url.trim().trim_end_matches('/').to_string(),
+                    client_id: client_id.trim().to_string(),
+                    client_secret: env_var(&["OIDC_CLIENT_SECRET"]),
+                    redirect_url,
+                    scopes: env_var(&["OIDC_SCOPES"])
+                        .unwrap_or_else(|| "openid email profile".into()),
+                    provider_name: env_var(&["OIDC_PROVIDER_NAME"])
+                        .unwrap_or_else(|| "Single sign-on".into()),
+                    allowed_email_domains: env_var(&["OIDC_ALLOWED_EMAIL_DOMAINS"])
+                        .map(|v| {
+                            v.split(',')
+                                .map(|d| d.trim().trim_start_matches('@').to_lowercase())
+                                .filter(|d| !d.is_empty())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                })
+            }
+            (None, None) => None,
+            _ => anyhow::bail!("OIDC_ISSUER_URL and OIDC_CLIENT_ID must be set together"),
+        };
         let runtime_env = env_var(&["RUST_ENV", "APP_ENV"]).unwrap_or_else(|| "production".into());
         let is_production = matches!(runtime_env.as_str(), "production" | "prod" | "release");
 
@@ -165,6 +311,12 @@ impl Config {
         let snapshot_archive_interval_secs = env_var(&["SNAPSHOT_ARCHIVE_INTERVAL_SECS"])
             .and_then(|s| s.parse().ok())
             .unwrap_or(900);
+        let takeout_retention_secs = env_var(&["TAKEOUT_RETENTION_SECS"])
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(7 * 24 * 3600);
+        let trash_retention_secs = env_var(&["TRASH_RETENTION_SECS"])
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(30 * 24 * 3600);
 
         // Production hardening: require proper FRONTEND_URL and robust secrets
         if is_production {
@@ -215,6 +367,15 @@ impl Config {
             database_url,
             jwt_secret_pem,
             jwt_expires_secs,
+            refresh_token_ttl_secs,
+            password_signup_enabled,
+            require_email_verification,
+            admin_emails,
+            oidc,
+            mail: MailConfig {
+                from: mail_from,
+                backend: mail_backend,
+            },
             snapshot_interval_secs,
             snapshot_keep_versions,
             updates_keep_window,
@@ -244,6 +405,8 @@ impl Config {
             redis_awareness_ttl_ms,
             redis_stream_max_len,
             snapshot_archive_interval_secs,
+            takeout_retention_secs,
+            trash_retention_secs,
         })
     }
 }
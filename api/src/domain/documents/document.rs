@@ -12,6 +12,10 @@ pub struct Document {
     pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
     pub archived_by: Option<Uuid>,
     pub archived_parent_id: Option<Uuid>,
+    // Front matter of the last persisted content (JSON object)
+    pub properties: serde_json::Value,
+    // Position among siblings when arranged manually
+    pub sort_key: Option<i32>,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +25,28 @@ pub struct SearchHit {
     pub doc_type: String,
     pub path: Option<String>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    // Present only for text queries
+    pub rank: Option<f32>,
+    // HTML-escaped excerpt with matches wrapped in <mark>
+    pub snippet: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Breadcrumb {
+    pub id: Uuid,
+    pub title: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuickSearchHit {
+    pub id: Uuid,
+    pub title: String,
+    pub doc_type: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub score: f32,
+    // Ancestor folders, root first
+    pub breadcrumbs: Vec<Breadcrumb>,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1 @@
+pub mod oidc_provider_reqwest;
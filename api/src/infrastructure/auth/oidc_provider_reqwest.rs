@@ -0,0 +1,239 @@
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use tokio::sync::RwLock;
+
+use crate::application::ports::oidc_provider::{OidcIdentity, OidcProvider};
+use crate::bootstrap::config::OidcConfig;
+
+#[derive(Debug, Clone, Deserialize)]
+struct Discovery {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    iss: String,
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    // Some providers send "true"/"false" strings
+    #[serde(default)]
+    email_verified: Option<serde_json::Value>,
+    name: Option<String>,
+    preferred_username: Option<String>,
+}
+
+/// OpenID Connect relying party using discovery; metadata and signing keys are
+/// fetched lazily and keys are refetched when an unknown `kid` shows up.
+pub struct ReqwestOidcProvider {
+    cfg: OidcConfig,
+    client: reqwest::Client,
+    discovery: RwLock<Option<Discovery>>,
+    jwks: RwLock<Option<JwkSet>>,
+}
+
+impl ReqwestOidcProvider {
+    pub fn new(cfg: OidcConfig) -> Self {
+        Self {
+            cfg,
+            client: reqwest::Client::new(),
+            discovery: RwLock::new(None),
+            jwks: RwLock::new(None),
+        }
+    }
+
+    async fn discovery(&self) -> anyhow::Result<Discovery> {
+        if let Some(d) = self.discovery.read().await.as_ref() {
+            return Ok(d.clone());
+        }
+        let url = format!("{}/.well-known/openid-configuration", self.cfg.issuer_url);
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("oidc discovery failed: {e}"))?;
+        if !resp.status().is_success() {
+            anyhow::bail!("oidc discovery returned status {}", resp.status());
+        }
+        let doc: Discovery = resp.json().await?;
+        if doc.issuer.trim_end_matches('/') != self.cfg.issuer_url {
+            anyhow::bail!("oidc discovery issuer mismatch: {}", doc.issuer);
+        }
+        *self.discovery.write().await = Some(doc.clone());
+        Ok(doc)
+    }
+
+    async fn fetch_jwks(&self, jwks_uri: &str) -> anyhow::Result<JwkSet> {
+        let resp = self
+            .client
+            .get(jwks_uri)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("oidc jwks request failed: {e}"))?;
+        if !resp.status().is_success() {
+            anyhow::bail!("oidc jwks returned status {}", resp.status());
+        }
+        let set: JwkSet = resp.json().await?;
+        *self.jwks.write().await = Some(set.clone());
+        Ok(set)
+    }
+
+    async fn decoding_key(&self, jwks_uri: &str, kid: Option<&str>) -> anyhow::Result<DecodingKey> {
+        let cached = self.jwks.read().await.clone();
+        let find = |set: &JwkSet| match kid {
+            Some(kid) => set.find(kid).cloned(),
+            None if set.keys.len() == 1 => set.keys.first().cloned(),
+            None => None,
+        };
+        let jwk = match cached.as_ref().and_then(find) {
+            Some(jwk) => jwk,
+            None => find(&self.fetch_jwks(jwks_uri).await?)
+                .ok_or_else(|| anyhow::anyhow!("oidc signing key not found"))?,
+        };
+        Ok(DecodingKey::from_jwk(&jwk)?)
+    }
+
+    async fn verify_id_token(
+        &self,
+        discovery: &Discovery,
+        id_token: &str,
+        nonce: &str,
+    ) -> anyhow::Result<IdTokenClaims> {
+        let header = jsonwebtoken::decode_header(id_token)?;
+        // Only asymmetric signatures; an HMAC "key" from the JWKS would be public
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            anyhow::bail!("oidc id_token uses unsupported algorithm");
+        }
+        let key = self
+            .decoding_key(&discovery.jwks_uri, header.kid.as_deref())
+            .await?;
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[discovery.issuer.as_str()]);
+        validation.set_audience(&[self.cfg.client_id.as_str()]);
+        let claims = jsonwebtoken::decode::<IdTokenClaims>(id_token, &key, &validation)?.claims;
+        if claims.nonce.as_deref() != Some(nonce) {
+            anyhow::bail!("oidc nonce mismatch");
+        }
+        Ok(claims)
+    }
+}
+
+#[async_trait]
+impl OidcProvider for ReqwestOidcProvider {
+    fn name(&self) -> &str {
+        &self.cfg.provider_name
+    }
+
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> anyhow::Result<String> {
+        let discovery = self.discovery().await?;
+        let mut url = reqwest::Url::parse(&discovery.authorization_endpoint)?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.cfg.client_id)
+            .append_pair("redirect_uri", &self.cfg.redirect_url)
+            .append_pair("scope", &self.cfg.scopes)
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("code_challenge", code_challenge)
+            .append_pair("code_challenge_method", "S256");
+        Ok(url.into())
+    }
+
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> anyhow::Result<OidcIdentity> {
+        let discovery = self.discovery().await?;
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.cfg.redirect_url.as_str()),
+            ("client_id", self.cfg.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(secret) = self.cfg.client_secret.as_deref() {
+            form.push(("client_secret", secret));
+        }
+        let resp = self
+            .client
+            .post(&discovery.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("oidc token request failed: {e}"))?;
+        if !resp.status().is_success() {
+            anyhow::bail!("oidc token endpoint returned status {}", resp.status());
+        }
+        let tokens: TokenResponse = resp.json().await?;
+        let id_token = tokens
+            .id_token
+            .ok_or_else(|| anyhow::anyhow!("oidc token response without id_token"))?;
+        let claims = self.verify_id_token(&discovery, &id_token, nonce).await?;
+        Ok(OidcIdentity {
+            email_verified: email_verified(claims.email_verified.as_ref()),
+            issuer: claims.iss,
+            subject: claims.sub,
+            email: claims.email,
+            name: claims.name.or(claims.preferred_username),
+        })
+    }
+}
+
+// Anything but a boolean true or a "true" string (any case) counts as unverified.
+fn email_verified(claim: Option<&serde_json::Value>) -> bool {
+    match claim {
+        Some(serde_json::Value::Bool(b)) => *b,
+        Some(serde_json::Value::String(s)) => s.trim().eq_ignore_ascii_case("true"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn email_verified_accepts_booleans_and_true_strings_only() {
+        let claims = |json: &str| -> IdTokenClaims {
+            serde_json::from_str(&format!(r#"{{"iss":"i","sub":"s"{json}}}"#)).unwrap()
+        };
+        for (json, expected) in [
+            (r#","email_verified":true"#, true),
+            (r#","email_verified":"true""#, true),
+            (r#","email_verified":"TRUE""#, true),
+            (r#","email_verified":false"#, false),
+            (r#","email_verified":"false""#, false),
+            (r#","email_verified":"yes""#, false),
+            (r#","email_verified":1"#, false),
+            (r#","email_verified":null"#, false),
+            ("", false),
+        ] {
+            assert_eq!(
+                email_verified(claims(json).email_verified.as_ref()),
+                expected,
+                "{json}"
+            );
+        }
+    }
+}
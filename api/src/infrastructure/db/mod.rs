@@ -18,3 +18,5 @@ pub async fn migrate(pool: &PgPool) -> anyhow::Result<()> {
 
 pub mod advisory_lock;
 pub mod repositories;
+#[cfg(test)]
+pub(crate) mod test_support;
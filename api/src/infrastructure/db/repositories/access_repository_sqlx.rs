@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::application::ports::access_repository::AccessRepository;
+use crate::application::ports::workspace_repository::WorkspaceRole;
 use crate::infrastructure::db::PgPool;
 
 pub struct SqlxAccessRepository {
@@ -16,15 +17,47 @@ impl SqlxAccessRepository {
 
 #[async_trait]
 impl AccessRepository for SqlxAccessRepository {
-    async fn user_owns_document(&self, doc_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
-        let count = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(1) FROM documents WHERE id = $1 AND owner_id = $2",
+    async fn workspace_role_for_document(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<WorkspaceRole>> {
+        let role = sqlx::query_scalar::<_, String>(
+            r#"SELECT wm.role FROM documents d
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id
+               WHERE d.id = $1 AND wm.user_id = $2"#,
         )
         .bind(doc_id)
         .bind(user_id)
-        .fetch_one(&self.pool)
+        .fetch_optional(&self.pool)
         .await?;
-        Ok(count > 0)
+        Ok(role.as_deref().and_then(WorkspaceRole::parse))
+    }
+
+    async fn grant_permission_for_document(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<String>> {
+        let permission = sqlx::query_scalar::<_, String>(
+            r#"
+            WITH RECURSIVE ancestors AS (
+              SELECT id, parent_id FROM documents WHERE id = $1
+              UNION ALL
+              SELECT d.id, d.parent_id FROM documents d JOIN ancestors a ON d.id = a.parent_id
+            )
+            SELECT g.permission FROM document_grants g
+            JOIN ancestors a ON a.id = g.document_id
+            WHERE g.user_id = $2
+            ORDER BY CASE g.permission WHEN 'edit' THEN 3 WHEN 'comment' THEN 2 ELSE 1 END DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(doc_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(permission)
     }
 
     async fn is_document_public(&self, doc_id: Uuid) -> anyhow::Result<bool> {
@@ -47,4 +80,98 @@ impl AccessRepository for SqlxAccessRepository {
         .unwrap_or(false);
         Ok(archived)
     }
+
+    async fn is_document_trashed(&self, doc_id: Uuid) -> anyhow::Result<bool> {
+        let trashed = sqlx::query_scalar::<_, bool>(
+            "SELECT deleted_at IS NOT NULL FROM documents WHERE id = $1",
+        )
+        .bind(doc_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .unwrap_or(false);
+        Ok(trashed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::db::test_support::{
+        insert_document, insert_user, insert_workspace, test_pool,
+    };
+
+    async fn grant(pool: &PgPool, doc_id: Uuid, user_id: Uuid, permission: &str) {
+        sqlx::query(
+            "INSERT INTO document_grants (document_id, user_id, permission) VALUES ($1, $2, $3)",
+        )
+        .bind(doc_id)
+        .bind(user_id)
+        .bind(permission)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn grants_are_inherited_from_ancestor_folders_strongest_first() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let owner = insert_user(&pool).await;
+        let guest = insert_user(&pool).await;
+        let ws = insert_workspace(&pool, owner).await;
+        let top = insert_document(&pool, ws, owner, None, "folder", "Top").await;
+        let mid = insert_document(&pool, ws, owner, Some(top), "folder", "Mid").await;
+        let doc = insert_document(&pool, ws, owner, Some(mid), "document", "Leaf").await;
+        let sibling = insert_document(&pool, ws, owner, None, "document", "Sibling").await;
+        let repo = SqlxAccessRepository::new(pool.clone());
+
+        assert_eq!(
+            repo.grant_permission_for_document(doc, guest)
+                .await
+                .unwrap(),
+            None
+        );
+        grant(&pool, top, guest, "comment").await;
+        assert_eq!(
+            repo.grant_permission_for_document(doc, guest)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("comment")
+        );
+        grant(&pool, doc, guest, "view").await;
+        grant(&pool, mid, guest, "edit").await;
+        assert_eq!(
+            repo.grant_permission_for_document(doc, guest)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("edit")
+        );
+        // Grants never leak to siblings or up the tree
+        assert_eq!(
+            repo.grant_permission_for_document(sibling, guest)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            repo.grant_permission_for_document(top, guest)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("comment")
+        );
+        assert!(
+            repo.workspace_role_for_document(doc, guest)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            repo.workspace_role_for_document(doc, owner).await.unwrap(),
+            Some(WorkspaceRole::Owner)
+        );
+    }
 }
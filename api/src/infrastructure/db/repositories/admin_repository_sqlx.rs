@@ -0,0 +1,196 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::{
+    AdminAuditRow, AdminRepository, AdminUserRow, InstanceStats,
+};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxAdminRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxAdminRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const ADMIN_USER_SELECT: &str = r#"SELECT u.id, u.email, u.name, u.role, u.email_verified_at, u.disabled_at, u.created_at,
+       (SELECT COUNT(*) FROM documents d WHERE d.owner_id = u.id) AS document_count,
+       (SELECT COALESCE(SUM(f.size), 0)::BIGINT FROM files f
+          JOIN documents d ON d.id = f.document_id
+         WHERE d.owner_id = u.id) AS storage_bytes
+  FROM users u"#;
+
+fn admin_user_from_row(r: &PgRow) -> AdminUserRow {
+    AdminUserRow {
+        id: r.get("id"),
+        email: r.get("email"),
+        name: r.get("name"),
+        role: r.get("role"),
+        email_verified_at: r.try_get("email_verified_at").ok().flatten(),
+        disabled_at: r.try_get("disabled_at").ok().flatten(),
+        created_at: r.get("created_at"),
+        document_count: r.get("document_count"),
+        storage_bytes: r.get("storage_bytes"),
+    }
+}
+
+#[async_trait]
+impl AdminRepository for SqlxAdminRepository {
+    async fn list_users(
+        &self,
+        query: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> anyhow::Result<Vec<AdminUserRow>> {
+        let sql = format!(
+            r#"{ADMIN_USER_SELECT}
+               WHERE $1::text IS NULL OR u.email ILIKE $1 OR u.name ILIKE $1
+               ORDER BY u.created_at DESC
+               LIMIT $2 OFFSET $3"#
+        );
+        let pattern = query.map(|q| {
+            let escaped = q
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            format!("%{escaped}%")
+        });
+        let rows = sqlx::query(&sql)
+            .bind(pattern)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(admin_user_from_row).collect())
+    }
+
+    async fn get_user(&self, id: Uuid) -> anyhow::Result<Option<AdminUserRow>> {
+        let sql = format!("{ADMIN_USER_SELECT} WHERE u.id = $1");
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(admin_user_from_row))
+    }
+
+    async fn set_disabled(&self, id: Uuid, disabled: bool) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            r#"UPDATE users
+               SET disabled_at = CASE WHEN $2 THEN COALESCE(disabled_at, now()) ELSE NULL END,
+                   updated_at = now()
+               WHERE id = $1"#,
+        )
+        .bind(id)
+        .bind(disabled)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn set_role(&self, id: Uuid, role: &str) -> anyhow::Result<bool> {
+        let res = sqlx::query("UPDATE users SET role = $2, updated_at = now() WHERE id = $1")
+            .bind(id)
+            .bind(role)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn promote_admins(&self, emails: &[String]) -> anyhow::Result<u64> {
+        let res = sqlx::query(
+            "UPDATE users SET role = 'admin', updated_at = now() WHERE role <> 'admin' AND lower(email::text) = ANY($1)",
+        )
+        .bind(emails)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+
+    async fn instance_stats(&self) -> anyhow::Result<InstanceStats> {
+        let row = sqlx::query(
+            r#"SELECT
+                 (SELECT COUNT(*) FROM users) AS users,
+                 (SELECT COUNT(*) FROM users WHERE role = 'admin') AS admins,
+                 (SELECT COUNT(*) FROM users WHERE disabled_at IS NOT NULL) AS disabled_users,
+                 (SELECT COUNT(*) FROM workspaces) AS workspaces,
+                 (SELECT COUNT(*) FROM documents) AS documents,
+                 (SELECT COUNT(*) FROM files) AS files,
+                 (SELECT COALESCE(SUM(size), 0)::BIGINT FROM files) AS storage_bytes"#,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(InstanceStats {
+            users: row.get("users"),
+            admins: row.get("admins"),
+            disabled_users: row.get("disabled_users"),
+            workspaces: row.get("workspaces"),
+            documents: row.get("documents"),
+            files: row.get("files"),
+            storage_bytes: row.get("storage_bytes"),
+        })
+    }
+
+    async fn delete_document(&self, id: Uuid) -> anyhow::Result<Option<String>> {
+        let dtype =
+            sqlx::query_scalar::<_, String>("DELETE FROM documents WHERE id = $1 RETURNING type")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(dtype)
+    }
+
+    async fn record_audit(
+        &self,
+        actor_id: Uuid,
+        action: &str,
+        target_type: &str,
+        target_id: Option<&str>,
+        details: &serde_json::Value,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"INSERT INTO admin_audit_log (actor_id, action, target_type, target_id, details)
+               VALUES ($1, $2, $3, $4, $5)"#,
+        )
+        .bind(actor_id)
+        .bind(action)
+        .bind(target_type)
+        .bind(target_id)
+        .bind(details)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn list_audit(&self, limit: i64, offset: i64) -> anyhow::Result<Vec<AdminAuditRow>> {
+        let rows = sqlx::query(
+            r#"SELECT a.id, a.actor_id, u.email AS actor_email, a.action, a.target_type,
+                      a.target_id, a.details, a.created_at
+               FROM admin_audit_log a
+               LEFT JOIN users u ON u.id = a.actor_id
+               ORDER BY a.created_at DESC
+               LIMIT $1 OFFSET $2"#,
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| AdminAuditRow {
+                id: r.get("id"),
+                actor_id: r.try_get("actor_id").ok().flatten(),
+                actor_email: r.try_get("actor_email").ok().flatten(),
+                action: r.get("action"),
+                target_type: r.get("target_type"),
+                target_id: r.try_get("target_id").ok().flatten(),
+                details: r.get("details"),
+                created_at: r.get("created_at"),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::application::ports::api_token_repository::{ApiTokenRepository, ApiTokenRow};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxApiTokenRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxApiTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const TOKEN_COLUMNS: &str =
+    "id, user_id, name, token_prefix, scopes, expires_at, last_used_at, created_at";
+
+fn token_from_row(r: &PgRow) -> ApiTokenRow {
+    ApiTokenRow {
+        id: r.get("id"),
+        user_id: r.get("user_id"),
+        name: r.get("name"),
+        token_prefix: r.get("token_prefix"),
+        scopes: r.get("scopes"),
+        expires_at: r.try_get("expires_at").ok().flatten(),
+        last_used_at: r.try_get("last_used_at").ok().flatten(),
+        created_at: r.get("created_at"),
+    }
+}
+
+#[async_trait]
+impl ApiTokenRepository for SqlxApiTokenRepository {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        token_hash: &str,
+        token_prefix: &str,
+        scopes: &[String],
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> anyhow::Result<ApiTokenRow> {
+        let sql = format!(
+            "INSERT INTO api_tokens (user_id, name, token_hash, token_prefix, scopes, expires_at) VALUES ($1, $2, $3, $4, $5, $6) RETURNING {TOKEN_COLUMNS}"
+        );
+        let row = sqlx::query(&sql)
+            .bind(user_id)
+            .bind(name)
+            .bind(token_hash)
+            .bind(token_prefix)
+            .bind(scopes)
+            .bind(expires_at)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(token_from_row(&row))
+    }
+
+    async fn list_for_user(&self, user_id: Uuid) -> anyhow::Result<Vec<ApiTokenRow>> {
+        let sql = format!(
+            "SELECT {TOKEN_COLUMNS} FROM api_tokens WHERE user_id = $1 ORDER BY created_at DESC"
+        );
+        let rows = sqlx::query(&sql)
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(token_from_row).collect())
+    }
+
+    async fn delete(&self, token_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query("DELETE FROM api_tokens WHERE id = $1 AND user_id = $2")
+            .bind(token_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn authenticate(&self, token_hash: &str) -> anyhow::Result<Option<ApiTokenRow>> {
+        let sql = format!(
+            "SELECT {TOKEN_COLUMNS} FROM api_tokens t
+             WHERE token_hash = $1 AND (expires_at IS NULL OR expires_at > now())
+               AND NOT EXISTS (SELECT 1 FROM users u WHERE u.id = t.user_id AND u.disabled_at IS NOT NULL)"
+        );
+        let row = sqlx::query(&sql)
+            .bind(token_hash)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some(row) = row.as_ref().map(token_from_row) else {
+            return Ok(None);
+        };
+        let stale = row
+            .last_used_at
+            .is_none_or(|t| t < chrono::Utc::now() - chrono::Duration::seconds(60));
+        if stale {
+            sqlx::query("UPDATE api_tokens SET last_used_at = now() WHERE id = $1")
+                .bind(row.id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(Some(row))
+    }
+}
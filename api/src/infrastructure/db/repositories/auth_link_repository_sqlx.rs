@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::auth_link_repository::AuthLinkRepository;
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxAuthLinkRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxAuthLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuthLinkRepository for SqlxAuthLinkRepository {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        purpose: &str,
+        nonce_hash: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO auth_links (user_id, purpose, nonce_hash, expires_at) VALUES ($1, $2, $3, $4)",
+        )
+        .bind(user_id)
+        .bind(purpose)
+        .bind(nonce_hash)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn consume(
+        &self,
+        user_id: Uuid,
+        purpose: &str,
+        nonce_hash: &str,
+    ) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            r#"UPDATE auth_links SET used_at = now()
+               WHERE nonce_hash = $3 AND user_id = $1 AND purpose = $2
+                 AND used_at IS NULL AND expires_at > now()"#,
+        )
+        .bind(user_id)
+        .bind(purpose)
+        .bind(nonce_hash)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn invalidate_all(&self, user_id: Uuid, purpose: &str) -> anyhow::Result<()> {
+        sqlx::query(
+            "UPDATE auth_links SET used_at = now() WHERE user_id = $1 AND purpose = $2 AND used_at IS NULL",
+        )
+        .bind(user_id)
+        .bind(purpose)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
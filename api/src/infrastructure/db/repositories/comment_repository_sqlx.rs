@@ -0,0 +1,277 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::application::ports::comment_repository::{
+    CommentRepository, CommentRow, CommentThreadRow, MentionRow,
+};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxCommentRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxCommentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const THREAD_COLUMNS: &str = "id, document_id, anchor_start, anchor_end, quote, status, created_by, resolved_by, resolved_at, created_at, updated_at";
+
+const COMMENT_SELECT: &str = r#"SELECT c.id, c.thread_id, c.author_id, u.name AS author_name, c.body,
+       COALESCE((SELECT array_agg(m.user_id) FROM comment_mentions m WHERE m.comment_id = c.id), '{}') AS mentions,
+       c.created_at, c.updated_at
+  FROM comments c LEFT JOIN users u ON u.id = c.author_id"#;
+
+fn thread_from_row(r: &PgRow) -> CommentThreadRow {
+    CommentThreadRow {
+        id: r.get("id"),
+        document_id: r.get("document_id"),
+        anchor_start: r.try_get("anchor_start").ok().flatten(),
+        anchor_end: r.try_get("anchor_end").ok().flatten(),
+        quote: r.try_get("quote").ok().flatten(),
+        status: r.get("status"),
+        created_by: r.try_get("created_by").ok().flatten(),
+        resolved_by: r.try_get("resolved_by").ok().flatten(),
+        resolved_at: r.try_get("resolved_at").ok().flatten(),
+        created_at: r.get("created_at"),
+        updated_at: r.get("updated_at"),
+    }
+}
+
+fn comment_from_row(r: &PgRow) -> CommentRow {
+    CommentRow {
+        id: r.get("id"),
+        thread_id: r.get("thread_id"),
+        author_id: r.try_get("author_id").ok().flatten(),
+        author_name: r.try_get("author_name").ok().flatten(),
+        body: r.get("body"),
+        mentions: r.try_get("mentions").unwrap_or_default(),
+        created_at: r.get("created_at"),
+        updated_at: r.get("updated_at"),
+    }
+}
+
+#[async_trait]
+impl CommentRepository for SqlxCommentRepository {
+    async fn list_threads(&self, doc_id: Uuid) -> anyhow::Result<Vec<CommentThreadRow>> {
+        let sql = format!(
+            "SELECT {THREAD_COLUMNS} FROM comment_threads WHERE document_id = $1 ORDER BY created_at ASC"
+        );
+        let rows = sqlx::query(&sql).bind(doc_id).fetch_all(&self.pool).await?;
+        Ok(rows.iter().map(thread_from_row).collect())
+    }
+
+    async fn list_comments(&self, doc_id: Uuid) -> anyhow::Result<Vec<CommentRow>> {
+        let sql = format!(
+            "{COMMENT_SELECT} JOIN comment_threads t ON t.id = c.thread_id WHERE t.document_id = $1 ORDER BY c.created_at ASC"
+        );
+        let rows = sqlx::query(&sql).bind(doc_id).fetch_all(&self.pool).await?;
+        Ok(rows.iter().map(comment_from_row).collect())
+    }
+
+    async fn get_thread(&self, thread_id: Uuid) -> anyhow::Result<Option<CommentThreadRow>> {
+        let sql = format!("SELECT {THREAD_COLUMNS} FROM comment_threads WHERE id = $1");
+        let row = sqlx::query(&sql)
+            .bind(thread_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(thread_from_row))
+    }
+
+    async fn create_thread(
+        &self,
+        doc_id: Uuid,
+        created_by: Option<Uuid>,
+        anchor_start: Option<&[u8]>,
+        anchor_end: Option<&[u8]>,
+        quote: Option<&str>,
+    ) -> anyhow::Result<CommentThreadRow> {
+        let sql = format!(
+            "INSERT INTO comment_threads (document_id, created_by, anchor_start, anchor_end, quote) VALUES ($1, $2, $3, $4, $5) RETURNING {THREAD_COLUMNS}"
+        );
+        let row = sqlx::query(&sql)
+            .bind(doc_id)
+            .bind(created_by)
+            .bind(anchor_start)
+            .bind(anchor_end)
+            .bind(quote)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(thread_from_row(&row))
+    }
+
+    async fn set_thread_status(
+        &self,
+        thread_id: Uuid,
+        status: &str,
+        actor: Option<Uuid>,
+    ) -> anyhow::Result<Option<CommentThreadRow>> {
+        let sql = format!(
+            r#"UPDATE comment_threads
+               SET status = $2,
+                   resolved_by = CASE WHEN $2 = 'resolved' THEN $3 ELSE NULL END,
+                   resolved_at = CASE WHEN $2 = 'resolved' THEN now() ELSE NULL END,
+                   updated_at = now()
+               WHERE id = $1
+               RETURNING {THREAD_COLUMNS}"#
+        );
+        let row = sqlx::query(&sql)
+            .bind(thread_id)
+            .bind(status)
+            .bind(actor)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(thread_from_row))
+    }
+
+    async fn delete_thread(&self, thread_id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query("DELETE FROM comment_threads WHERE id = $1")
+            .bind(thread_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn delete_thread_if_empty(&self, thread_id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            "DELETE FROM comment_threads WHERE id = $1 AND NOT EXISTS (SELECT 1 FROM comments WHERE thread_id = $1)",
+        )
+        .bind(thread_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn resolve_open_for_documents(&self, doc_ids: &[Uuid]) -> anyhow::Result<u64> {
+        if doc_ids.is_empty() {
+            return Ok(0);
+        }
+        let res = sqlx::query(
+            r#"UPDATE comment_threads
+               SET status = 'resolved', resolved_at = now(), updated_at = now()
+               WHERE document_id = ANY($1) AND status = 'open'"#,
+        )
+        .bind(doc_ids)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+
+    async fn get_comment(&self, comment_id: Uuid) -> anyhow::Result<Option<CommentRow>> {
+        let sql = format!("{COMMENT_SELECT} WHERE c.id = $1");
+        let row = sqlx::query(&sql)
+            .bind(comment_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(comment_from_row))
+    }
+
+    async fn add_comment(
+        &self,
+        thread_id: Uuid,
+        author_id: Option<Uuid>,
+        body: &str,
+        mentions: &[Uuid],
+    ) -> anyhow::Result<CommentRow> {
+        let mut tx = self.pool.begin().await?;
+        let comment_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO comments (thread_id, author_id, body) VALUES ($1, $2, $3) RETURNING id",
+        )
+        .bind(thread_id)
+        .bind(author_id)
+        .bind(body)
+        .fetch_one(&mut *tx)
+        .await?;
+        sqlx::query(
+            "INSERT INTO comment_mentions (comment_id, user_id) SELECT $1, UNNEST($2::uuid[]) ON CONFLICT DO NOTHING",
+        )
+        .bind(comment_id)
+        .bind(mentions)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE comment_threads SET updated_at = now() WHERE id = $1")
+            .bind(thread_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        self.get_comment(comment_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("not_found"))
+    }
+
+    async fn update_comment(
+        &self,
+        comment_id: Uuid,
+        body: &str,
+        mentions: &[Uuid],
+    ) -> anyhow::Result<Option<CommentRow>> {
+        let mut tx = self.pool.begin().await?;
+        let res = sqlx::query("UPDATE comments SET body = $2, updated_at = now() WHERE id = $1")
+            .bind(comment_id)
+            .bind(body)
+            .execute(&mut *tx)
+            .await?;
+        if res.rows_affected() == 0 {
+            return Ok(None);
+        }
+        sqlx::query("DELETE FROM comment_mentions WHERE comment_id = $1")
+            .bind(comment_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO comment_mentions (comment_id, user_id) SELECT $1, UNNEST($2::uuid[]) ON CONFLICT DO NOTHING",
+        )
+        .bind(comment_id)
+        .bind(mentions)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        self.get_comment(comment_id).await
+    }
+
+    async fn delete_comment(&self, comment_id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query("DELETE FROM comments WHERE id = $1")
+            .bind(comment_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn list_mentions_for_user(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+    ) -> anyhow::Result<Vec<MentionRow>> {
+        let rows = sqlx::query(
+            r#"SELECT c.id AS comment_id, c.thread_id, t.document_id, d.title AS document_title,
+                      u.name AS author_name, c.body, c.created_at
+               FROM comment_mentions m
+               JOIN comments c ON c.id = m.comment_id
+               JOIN comment_threads t ON t.id = c.thread_id
+               JOIN documents d ON d.id = t.document_id
+               LEFT JOIN users u ON u.id = c.author_id
+               WHERE m.user_id = $1 AND d.archived_at IS NULL AND d.deleted_at IS NULL
+               ORDER BY c.created_at DESC
+               LIMIT $2"#,
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| MentionRow {
+                comment_id: r.get("comment_id"),
+                thread_id: r.get("thread_id"),
+                document_id: r.get("document_id"),
+                document_title: r.get("document_title"),
+                author_name: r.try_get("author_name").ok().flatten(),
+                body: r.get("body"),
+                created_at: r.get("created_at"),
+            })
+            .collect())
+    }
+}
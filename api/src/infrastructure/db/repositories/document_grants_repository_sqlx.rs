@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::application::ports::document_grants_repository::{
+    DocumentGrantRow, DocumentGrantsRepository, SharedWithMeRow,
+};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxDocumentGrantsRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxDocumentGrantsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DocumentGrantsRepository for SqlxDocumentGrantsRepository {
+    async fn list_for_document(&self, doc_id: Uuid) -> anyhow::Result<Vec<DocumentGrantRow>> {
+        let rows = sqlx::query(
+            r#"SELECT g.user_id, u.email, u.name, g.permission, g.granted_by, g.created_at
+               FROM document_grants g JOIN users u ON u.id = g.user_id
+               WHERE g.document_id = $1
+               ORDER BY g.created_at ASC"#,
+        )
+        .bind(doc_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| DocumentGrantRow {
+                user_id: r.get("user_id"),
+                email: r.get("email"),
+                name: r.get("name"),
+                permission: r.get("permission"),
+                granted_by: r.try_get("granted_by").ok(),
+                created_at: r.get("created_at"),
+            })
+            .collect())
+    }
+
+    async fn upsert(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+        permission: &str,
+        granted_by: Uuid,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"INSERT INTO document_grants (document_id, user_id, permission, granted_by)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT (document_id, user_id)
+               DO UPDATE SET permission = EXCLUDED.permission, granted_by = EXCLUDED.granted_by"#,
+        )
+        .bind(doc_id)
+        .bind(user_id)
+        .bind(permission)
+        .bind(granted_by)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn revoke(&self, doc_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
+        let res =
+            sqlx::query("DELETE FROM document_grants WHERE document_id = $1 AND user_id = $2")
+                .bind(doc_id)
+                .bind(user_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn list_shared_with_user(&self, user_id: Uuid) -> anyhow::Result<Vec<SharedWithMeRow>> {
+        let rows = sqlx::query(
+            r#"SELECT d.id, d.title, d.type, g.permission, gb.name AS granted_by_name, g.created_at
+               FROM document_grants g
+               JOIN documents d ON d.id = g.document_id
+               LEFT JOIN users gb ON gb.id = g.granted_by
+               WHERE g.user_id = $1 AND d.archived_at IS NULL AND d.deleted_at IS NULL
+               ORDER BY g.created_at DESC"#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| SharedWithMeRow {
+                document_id: r.get("id"),
+                title: r.get("title"),
+                doc_type: r.get("type"),
+                permission: r.get("permission"),
+                granted_by_name: r.try_get("granted_by_name").ok(),
+                created_at: r.get("created_at"),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::document_repository::DocumentRepository;
+    use crate::infrastructure::db::repositories::document_repository_sqlx::SqlxDocumentRepository;
+    use crate::infrastructure::db::test_support::{
+        insert_document, insert_user, insert_workspace, test_pool,
+    };
+
+    #[tokio::test]
+    async fn trashed_documents_leave_shared_with_me() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let owner = insert_user(&pool).await;
+        let grantee = insert_user(&pool).await;
+        let ws = insert_workspace(&pool, owner).await;
+        let kept = insert_document(&pool, ws, owner, None, "document", "Kept").await;
+        let trashed = insert_document(&pool, ws, owner, None, "document", "Trashed").await;
+        let repo = SqlxDocumentGrantsRepository::new(pool.clone());
+        repo.upsert(kept, grantee, "view", owner).await.unwrap();
+        repo.upsert(trashed, grantee, "edit", owner).await.unwrap();
+
+        SqlxDocumentRepository::new(pool.clone())
+            .trash_subtree(trashed, owner)
+            .await
+            .unwrap();
+
+        let ids: Vec<Uuid> = repo
+            .list_shared_with_user(grantee)
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| r.document_id)
+            .collect();
+        assert_eq!(ids, vec![kept]);
+    }
+}
@@ -1,16 +1,60 @@
 use async_trait::async_trait;
 use sqlx::Row;
+use sqlx::postgres::{PgArguments, Postgres};
+use sqlx::query::Query;
 use uuid::Uuid;
 
 use crate::application::ports::document_repository::{
-    DocMeta, DocumentListState, DocumentRepository, SubtreeDocument,
+    DocMeta, DocumentListState, DocumentRepository, DocumentSearch, PropertyFilter,
+    SubtreeDocument, SubtreeNode, TrashedDocument,
 };
 use crate::domain::documents::document::{
-    BacklinkInfo as DomBacklinkInfo, Document as DomainDocument, OutgoingLink as DomOutgoingLink,
-    SearchHit,
+    BacklinkInfo as DomBacklinkInfo, Breadcrumb, Document as DomainDocument,
+    OutgoingLink as DomOutgoingLink, QuickSearchHit, SearchHit,
 };
 use crate::infrastructure::db::PgPool;
 
+// Minimum word_similarity for a fuzzy title hit
+const QUICK_SEARCH_THRESHOLD: f32 = 0.3;
+
+// Sibling order: manually arranged first, then folders before documents, then by title
+pub(crate) const SIBLING_ORDER: &str =
+    "d.sort_key NULLS LAST, (d.type = 'folder') DESC, lower(d.title), d.created_at";
+
+// `AND ...` clause for a property filter; the key binds to `$first`, the value to `$first + 1`.
+fn property_condition(filter: Option<&PropertyFilter>, first: usize) -> String {
+    match filter {
+        None => String::new(),
+        Some(PropertyFilter { value: None, .. }) => format!("AND d.properties ? ${first}"),
+        Some(PropertyFilter { value: Some(_), .. }) => {
+            let value = first + 1;
+            format!(
+                r#"AND (d.properties ->> ${first} = ${value}
+                        OR EXISTS (SELECT 1 FROM jsonb_array_elements_text(
+                                       CASE WHEN jsonb_typeof(d.properties -> ${first}) = 'array'
+                                            THEN d.properties -> ${first} ELSE '[]'::jsonb END) e
+                                   WHERE e = ${value}))"#
+            )
+        }
+    }
+}
+
+fn bind_property<'q>(
+    query: Query<'q, Postgres, PgArguments>,
+    filter: Option<&'q PropertyFilter>,
+) -> Query<'q, Postgres, PgArguments> {
+    match filter {
+        None => query,
+        Some(f) => {
+            let query = query.bind(f.key.as_str());
+            match f.value.as_deref() {
+                Some(value) => query.bind(value),
+                None => query,
+            }
+        }
+    }
+}
+
 pub struct SqlxDocumentRepository {
     pub pool: PgPool,
 }
@@ -23,61 +67,72 @@ impl SqlxDocumentRepository {
 
 #[async_trait]
 impl DocumentRepository for SqlxDocumentRepository {
-    async fn list_for_user(
+    async fn list_for_workspace(
         &self,
-        user_id: Uuid,
+        workspace_id: Uuid,
         query: Option<String>,
         tag: Option<String>,
+        property: Option<PropertyFilter>,
         state: DocumentListState,
     ) -> anyhow::Result<Vec<DomainDocument>> {
         let archived_condition = match state {
-            DocumentListState::Active => "d.archived_at IS NULL",
-            DocumentListState::Archived => "d.archived_at IS NOT NULL",
-            DocumentListState::All => "TRUE",
+            DocumentListState::Active => "d.deleted_at IS NULL AND d.archived_at IS NULL",
+            DocumentListState::Archived => "d.deleted_at IS NULL AND d.archived_at IS NOT NULL",
+            DocumentListState::All => "d.deleted_at IS NULL",
         };
+        let property = property.filter(|p| !p.key.trim().is_empty());
 
         let rows = if let Some(t) = tag.as_ref().filter(|s| !s.trim().is_empty()) {
             let sql = format!(
                 r#"SELECT d.id, d.title, d.parent_id, d.type, d.created_at, d.updated_at, d.path,
-                          d.archived_at, d.archived_by, d.archived_parent_id
+                          d.archived_at, d.archived_by, d.archived_parent_id, d.properties, d.sort_key
                    FROM document_tags dt
                    JOIN tags t ON t.id = dt.tag_id
                    JOIN documents d ON d.id = dt.document_id
-                   WHERE d.owner_id = $1 AND {archived_condition} AND t.name ILIKE $2
+                   WHERE d.workspace_id = $1 AND {archived_condition} AND t.name ILIKE $2
+                         {property_condition}
                    ORDER BY d.updated_at DESC LIMIT 100"#,
                 archived_condition = archived_condition,
+                property_condition = property_condition(property.as_ref(), 3),
             );
-            sqlx::query(&sql)
-                .bind(user_id)
-                .bind(t)
+            let q = sqlx::query(&sql).bind(workspace_id).bind(t);
+            bind_property(q, property.as_ref())
                 .fetch_all(&self.pool)
                 .await?
         } else if let Some(ref qq) = query.as_ref().filter(|s| !s.trim().is_empty()) {
             let like = format!("%{}%", qq);
             let sql = format!(
                 r#"SELECT d.id, d.title, d.parent_id, d.type, d.created_at, d.updated_at, d.path,
-                          d.archived_at, d.archived_by, d.archived_parent_id
+                          d.archived_at, d.archived_by, d.archived_parent_id, d.properties, d.sort_key
                    FROM documents d
-                   WHERE d.owner_id = $1 AND {archived_condition} AND d.title ILIKE $2
+                   WHERE d.workspace_id = $1 AND {archived_condition} AND d.title ILIKE $2
+                         {property_condition}
                    ORDER BY d.updated_at DESC LIMIT 100"#,
                 archived_condition = archived_condition,
+                property_condition = property_condition(property.as_ref(), 3),
             );
-            sqlx::query(&sql)
-                .bind(user_id)
-                .bind(like)
+            let q = sqlx::query(&sql).bind(workspace_id).bind(like);
+            bind_property(q, property.as_ref())
                 .fetch_all(&self.pool)
                 .await?
         } else {
+            // Tree listing: the most recent documents, returned in sibling order
             let sql = format!(
-                r#"SELECT d.id, d.title, d.parent_id, d.type, d.created_at, d.updated_at, d.path,
-                          d.archived_at, d.archived_by, d.archived_parent_id
-                   FROM documents d
-                   WHERE d.owner_id = $1 AND {archived_condition}
-                   ORDER BY d.updated_at DESC LIMIT 100"#,
+                r#"SELECT * FROM (
+                       SELECT d.id, d.title, d.parent_id, d.type, d.created_at, d.updated_at, d.path,
+                              d.archived_at, d.archived_by, d.archived_parent_id, d.properties, d.sort_key
+                       FROM documents d
+                       WHERE d.workspace_id = $1 AND {archived_condition}
+                             {property_condition}
+                       ORDER BY d.updated_at DESC LIMIT 100
+                   ) d
+                   ORDER BY {sibling_order}"#,
                 archived_condition = archived_condition,
+                property_condition = property_condition(property.as_ref(), 2),
+                sibling_order = SIBLING_ORDER,
             );
-            sqlx::query(&sql)
-                .bind(user_id)
+            let q = sqlx::query(&sql).bind(workspace_id);
+            bind_property(q, property.as_ref())
                 .fetch_all(&self.pool)
                 .await?
         };
@@ -95,6 +150,8 @@ impl DocumentRepository for SqlxDocumentRepository {
                 archived_at: r.try_get("archived_at").ok(),
                 archived_by: r.try_get("archived_by").ok(),
                 archived_parent_id: r.try_get("archived_parent_id").ok(),
+                properties: r.try_get("properties").unwrap_or_default(),
+                sort_key: r.try_get("sort_key").ok(),
             })
             .collect();
         Ok(items)
@@ -108,10 +165,29 @@ impl DocumentRepository for SqlxDocumentRepository {
         Ok(rows.into_iter().map(|r| r.get("id")).collect())
     }
 
+    async fn list_workspace_roots(
+        &self,
+        workspace_id: Uuid,
+    ) -> anyhow::Result<Vec<SubtreeDocument>> {
+        let rows = sqlx::query(
+            "SELECT id, type FROM documents WHERE workspace_id = $1 AND parent_id IS NULL",
+        )
+        .bind(workspace_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| SubtreeDocument {
+                id: r.get("id"),
+                doc_type: r.get("type"),
+            })
+            .collect())
+    }
+
     async fn get_by_id(&self, id: Uuid) -> anyhow::Result<Option<DomainDocument>> {
         let row = sqlx::query(
             r#"SELECT id, title, parent_id, type, created_at, updated_at, path,
-                      archived_at, archived_by, archived_parent_id
+                      archived_at, archived_by, archived_parent_id, properties, sort_key
                FROM documents WHERE id = $1"#,
         )
         .bind(id)
@@ -128,44 +204,86 @@ impl DocumentRepository for SqlxDocumentRepository {
             archived_at: r.try_get("archived_at").ok(),
             archived_by: r.try_get("archived_by").ok(),
             archived_parent_id: r.try_get("archived_parent_id").ok(),
+            properties: r.try_get("properties").unwrap_or_default(),
+            sort_key: r.try_get("sort_key").ok(),
         }))
     }
 
     async fn search_for_user(
         &self,
         user_id: Uuid,
-        query: Option<String>,
-        limit: i64,
+        search: &DocumentSearch,
     ) -> anyhow::Result<Vec<SearchHit>> {
-        let q = query.unwrap_or_default();
-        let like = format!("%{}%", q);
-        let rows = if q.trim().is_empty() {
-            sqlx::query(
-                r#"SELECT id, title, type, path, updated_at, archived_at
-                   FROM documents WHERE owner_id = $1
-                   AND archived_at IS NULL
-                   ORDER BY updated_at DESC
-                   LIMIT $2"#,
-            )
-            .bind(user_id)
-            .bind(limit)
-            .fetch_all(&self.pool)
-            .await?
-        } else {
-            sqlx::query(
-                r#"SELECT id, title, type, path, updated_at, archived_at FROM documents
-                   WHERE owner_id = $1 AND archived_at IS NULL
-                     AND (LOWER(title) LIKE LOWER($2) OR title ILIKE $2)
-                   ORDER BY CASE WHEN LOWER(title) = LOWER($3) THEN 0 ELSE 1 END, LENGTH(title), updated_at DESC
-                   LIMIT $4"#
-            )
-                .bind(user_id)
-                .bind(like)
-                .bind(&q)
-                .bind(limit)
-                .fetch_all(&self.pool)
-                .await?
+        let like = search
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|t| {
+                let escaped = t
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+                format!("%{escaped}%")
+            });
+        let state = match search.state {
+            DocumentListState::Active => "active",
+            DocumentListState::Archived => "archived",
+            DocumentListState::All => "all",
         };
+        let rows = sqlx::query(
+            r#"WITH RECURSIVE subtree AS (
+                   SELECT id FROM documents WHERE id = $5
+                   UNION
+                   SELECT c.id FROM documents c
+                   JOIN subtree s ON c.parent_id = s.id OR c.archived_parent_id = s.id
+               ), q AS (
+                   SELECT CASE WHEN $2::text IS NULL THEN NULL ELSE to_tsquery('simple', $2) END AS query
+               )
+               SELECT d.id, d.title, d.type, d.path, d.updated_at, d.archived_at,
+                      CASE WHEN $3::text IS NULL THEN NULL ELSE (
+                          COALESCE(ts_rank_cd(
+                              setweight(to_tsvector('simple', d.title), 'A') || COALESCE(si.tsv, ''::tsvector),
+                              q.query), 0)
+                          + CASE WHEN d.title ILIKE $3 THEN 1 ELSE 0 END
+                      )::real END AS rank,
+                      CASE WHEN q.query IS NULL OR si.content IS NULL THEN NULL ELSE ts_headline(
+                          'simple',
+                          replace(replace(replace(si.content, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'),
+                          q.query,
+                          'StartSel=<mark>, StopSel=</mark>, MaxWords=35, MinWords=15, MaxFragments=2, FragmentDelimiter=" … "'
+                      ) END AS snippet
+               FROM documents d
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id AND wm.user_id = $1
+               CROSS JOIN q
+               LEFT JOIN document_search_index si ON si.document_id = d.id
+               WHERE ($3::text IS NULL
+                      OR d.title ILIKE $3
+                      OR si.tsv @@ q.query
+                      OR to_tsvector('simple', d.title) @@ q.query)
+                 AND d.deleted_at IS NULL
+                 AND ($4 = 'all' OR ($4 = 'archived') = (d.archived_at IS NOT NULL))
+                 AND ($5::uuid IS NULL OR d.id IN (SELECT id FROM subtree))
+                 AND ($6::text IS NULL OR EXISTS (
+                        SELECT 1 FROM document_tags dt JOIN tags t ON t.id = dt.tag_id
+                        WHERE dt.document_id = d.id AND t.name = $6::citext))
+                 AND ($7::timestamptz IS NULL OR d.updated_at >= $7)
+                 AND ($8::timestamptz IS NULL OR d.updated_at < $8)
+               ORDER BY rank DESC NULLS LAST, d.updated_at DESC
+               LIMIT $9 OFFSET $10"#,
+        )
+        .bind(user_id)
+        .bind(search.tsquery.as_deref())
+        .bind(like)
+        .bind(state)
+        .bind(search.folder_id)
+        .bind(search.tag.as_deref())
+        .bind(search.updated_after)
+        .bind(search.updated_before)
+        .bind(search.limit)
+        .bind(search.offset)
+        .fetch_all(&self.pool)
+        .await?;
         let out = rows
             .into_iter()
             .map(|r| SearchHit {
@@ -174,30 +292,117 @@ impl DocumentRepository for SqlxDocumentRepository {
                 doc_type: r.get::<String, _>("type"),
                 path: r.try_get("path").ok(),
                 updated_at: r.get("updated_at"),
+                archived_at: r.try_get("archived_at").ok().flatten(),
+                rank: r.try_get("rank").ok().flatten(),
+                snippet: r.try_get("snippet").ok().flatten(),
             })
             .collect();
         Ok(out)
     }
 
+    async fn quick_search(
+        &self,
+        user_id: Uuid,
+        query: &str,
+        limit: i64,
+    ) -> anyhow::Result<Vec<QuickSearchHit>> {
+        let q = query.trim().to_lowercase();
+        let prefix = format!(
+            "{}%",
+            q.replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+        let mut tx = self.pool.begin().await?;
+        // `<%` uses this threshold and can be served by idx_documents_title_trgm
+        sqlx::query("SELECT set_config('pg_trgm.word_similarity_threshold', $1, true)")
+            .bind(QUICK_SEARCH_THRESHOLD.to_string())
+            .execute(&mut *tx)
+            .await?;
+        let rows = sqlx::query(
+            r#"WITH hits AS (
+                   SELECT d.id, d.title, d.type, d.parent_id, d.updated_at,
+                          (0.6 * word_similarity($2, lower(d.title))
+                           + 0.4 * similarity(lower(d.title), $2)
+                           + CASE WHEN lower(d.title) LIKE $3 THEN 0.2 ELSE 0 END
+                           + 0.15 * exp(-EXTRACT(EPOCH FROM (now() - d.updated_at)) / 2592000.0)
+                          )::real AS score
+                   FROM documents d
+                   JOIN workspace_members wm ON wm.workspace_id = d.workspace_id AND wm.user_id = $1
+                   WHERE d.archived_at IS NULL AND d.deleted_at IS NULL
+                     AND ($2 <% lower(d.title) OR lower(d.title) LIKE $3)
+                   ORDER BY score DESC, d.updated_at DESC
+                   LIMIT $4
+               )
+               SELECT h.id, h.title, h.type, h.updated_at, h.score,
+                      COALESCE(b.ids, '{}') AS crumb_ids, COALESCE(b.titles, '{}') AS crumb_titles
+               FROM hits h
+               LEFT JOIN LATERAL (
+                   WITH RECURSIVE anc(id, title, parent_id, depth) AS (
+                       SELECT p.id, p.title, p.parent_id, 1 FROM documents p WHERE p.id = h.parent_id
+                       UNION ALL
+                       SELECT p.id, p.title, p.parent_id, a.depth + 1
+                       FROM documents p JOIN anc a ON p.id = a.parent_id
+                       WHERE a.depth < 64
+                   )
+                   SELECT array_agg(id ORDER BY depth DESC) AS ids,
+                          array_agg(title ORDER BY depth DESC) AS titles
+                   FROM anc
+               ) b ON true
+               ORDER BY h.score DESC, h.updated_at DESC"#,
+        )
+        .bind(user_id)
+        .bind(&q)
+        .bind(prefix)
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| {
+                let ids: Vec<Uuid> = r.get("crumb_ids");
+                let titles: Vec<String> = r.get("crumb_titles");
+                QuickSearchHit {
+                    id: r.get("id"),
+                    title: r.get("title"),
+                    doc_type: r.get("type"),
+                    updated_at: r.get("updated_at"),
+                    score: r.get("score"),
+                    breadcrumbs: ids
+                        .into_iter()
+                        .zip(titles)
+                        .map(|(id, title)| Breadcrumb { id, title })
+                        .collect(),
+                }
+            })
+            .collect())
+    }
+
     async fn create_for_user(
         &self,
         user_id: Uuid,
+        workspace_id: Uuid,
         title: &str,
         parent_id: Option<Uuid>,
         doc_type: &str,
     ) -> anyhow::Result<DomainDocument> {
         let row = sqlx::query(
-            r#"INSERT INTO documents (title, owner_id, parent_id, type, path)
-               VALUES ($1, $2, $3, $4, NULL)
+            r#"INSERT INTO documents (title, owner_id, workspace_id, parent_id, type, path)
+               SELECT $1, $2, $3, $4, $5, NULL
+               WHERE $4::uuid IS NULL OR EXISTS (
+                   SELECT 1 FROM documents p WHERE p.id = $4 AND p.workspace_id = $3)
                RETURNING id, title, parent_id, type, created_at, updated_at, path,
-                         archived_at, archived_by, archived_parent_id"#,
+                         archived_at, archived_by, archived_parent_id, properties, sort_key"#,
         )
         .bind(title)
         .bind(user_id)
+        .bind(workspace_id)
         .bind(parent_id)
         .bind(doc_type)
-        .fetch_one(&self.pool)
-        .await?;
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("parent_not_in_workspace"))?;
         Ok(DomainDocument {
             id: row.get("id"),
             title: row.get("title"),
@@ -209,6 +414,8 @@ impl DocumentRepository for SqlxDocumentRepository {
             archived_at: row.try_get("archived_at").ok(),
             archived_by: row.try_get("archived_by").ok(),
             archived_parent_id: row.try_get("archived_parent_id").ok(),
+            properties: row.try_get("properties").unwrap_or_default(),
+            sort_key: row.try_get("sort_key").ok(),
         })
     }
 
@@ -225,9 +432,12 @@ impl DocumentRepository for SqlxDocumentRepository {
                     r#"UPDATE documents SET
                             title = COALESCE($1, title),
                             updated_at = now()
-                        WHERE id = $2 AND owner_id = $3
+                        WHERE id = $2
+                          AND EXISTS (SELECT 1 FROM workspace_members wm
+                                     WHERE wm.workspace_id = documents.workspace_id
+                                       AND wm.user_id = $3 AND wm.role <> 'guest')
                         RETURNING id, title, parent_id, type, created_at, updated_at, path,
-                                  archived_at, archived_by, archived_parent_id"#,
+                                  archived_at, archived_by, archived_parent_id, properties, sort_key"#,
                 )
                 .bind(title)
                 .bind(id)
@@ -240,10 +450,17 @@ impl DocumentRepository for SqlxDocumentRepository {
                     r#"UPDATE documents SET
                             title = COALESCE($1, title),
                             parent_id = $2,
+                            sort_key = CASE WHEN parent_id IS DISTINCT FROM $2 THEN NULL ELSE sort_key END,
                             updated_at = now()
-                        WHERE id = $3 AND owner_id = $4
+                        WHERE id = $3
+                          AND EXISTS (SELECT 1 FROM workspace_members wm
+                                     WHERE wm.workspace_id = documents.workspace_id
+                                       AND wm.user_id = $4 AND wm.role <> 'guest')
+                          AND ($2::uuid IS NULL OR EXISTS (
+                                SELECT 1 FROM documents p
+                                WHERE p.id = $2 AND p.workspace_id = documents.workspace_id))
                         RETURNING id, title, parent_id, type, created_at, updated_at, path,
-                                  archived_at, archived_by, archived_parent_id"#,
+                                  archived_at, archived_by, archived_parent_id, properties, sort_key"#,
                 )
                 .bind(title)
                 .bind(newp)
@@ -264,23 +481,28 @@ impl DocumentRepository for SqlxDocumentRepository {
             archived_at: r.try_get("archived_at").ok(),
             archived_by: r.try_get("archived_by").ok(),
             archived_parent_id: r.try_get("archived_parent_id").ok(),
+            properties: r.try_get("properties").unwrap_or_default(),
+            sort_key: r.try_get("sort_key").ok(),
         }))
     }
 
     async fn delete_owned(&self, id: Uuid, user_id: Uuid) -> anyhow::Result<Option<String>> {
         // fetch type
-        let row = sqlx::query(r#"SELECT type FROM documents WHERE id = $1 AND owner_id = $2"#)
-            .bind(id)
-            .bind(user_id)
-            .fetch_optional(&self.pool)
-            .await?;
+        let row = sqlx::query(
+            r#"SELECT d.type FROM documents d
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id
+               WHERE d.id = $1 AND wm.user_id = $2 AND wm.role <> 'guest'"#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
         let dtype: String = match row {
             Some(r) => r.get("type"),
             None => return Ok(None),
         };
-        let res = sqlx::query(r#"DELETE FROM documents WHERE id = $1 AND owner_id = $2"#)
+        let res = sqlx::query(r#"DELETE FROM documents WHERE id = $1"#)
             .bind(id)
-            .bind(user_id)
             .execute(&self.pool)
             .await?;
         if res.rows_affected() > 0 {
@@ -300,7 +522,8 @@ impl DocumentRepository for SqlxDocumentRepository {
                       dl.link_type, dl.link_text, COUNT(*)::BIGINT as link_count
                FROM document_links dl
                JOIN documents d ON d.id = dl.source_document_id
-               WHERE dl.target_document_id = $1 AND d.owner_id = $2
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id AND wm.user_id = $2
+               WHERE dl.target_document_id = $1 AND d.deleted_at IS NULL
                GROUP BY d.id, d.title, d.type, d.path, dl.link_type, dl.link_text
                ORDER BY link_count DESC, d.title"#,
         )
@@ -333,7 +556,8 @@ impl DocumentRepository for SqlxDocumentRepository {
                       dl.link_type, dl.link_text, dl.position_start, dl.position_end
                FROM document_links dl
                JOIN documents d ON d.id = dl.target_document_id
-               WHERE dl.source_document_id = $1 AND d.owner_id = $2
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id AND wm.user_id = $2
+               WHERE dl.source_document_id = $1 AND d.deleted_at IS NULL
                ORDER BY dl.position_start"#,
         )
         .bind(source_id)
@@ -362,7 +586,11 @@ impl DocumentRepository for SqlxDocumentRepository {
         owner_id: Uuid,
     ) -> anyhow::Result<Option<DocMeta>> {
         let row = sqlx::query(
-            "SELECT type, path, title, archived_at FROM documents WHERE id = $1 AND owner_id = $2",
+            r#"SELECT d.type, d.path, d.title, d.archived_at, d.workspace_id
+               FROM documents d
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id
+               WHERE d.id = $1 AND wm.user_id = $2 AND wm.role <> 'guest'
+                 AND d.deleted_at IS NULL"#,
         )
         .bind(doc_id)
         .bind(owner_id)
@@ -373,6 +601,7 @@ impl DocumentRepository for SqlxDocumentRepository {
             path: r.try_get("path").ok(),
             title: r.get("title"),
             archived_at: r.try_get("archived_at").ok(),
+            workspace_id: r.get("workspace_id"),
         }))
     }
 
@@ -387,18 +616,20 @@ impl DocumentRepository for SqlxDocumentRepository {
         let updated = sqlx::query_scalar::<_, Uuid>(
             r#"
             WITH RECURSIVE subtree AS (
-                SELECT id FROM documents WHERE id = $1 AND owner_id = $2
+                SELECT id, workspace_id FROM documents
+                WHERE id = $1
+                  AND EXISTS (SELECT 1 FROM workspace_members wm
+                              WHERE wm.workspace_id = documents.workspace_id
+                                AND wm.user_id = $2 AND wm.role <> 'guest')
                 UNION ALL
-                SELECT d.id
+                SELECT d.id, d.workspace_id
                 FROM documents d
-                JOIN subtree sb ON d.parent_id = sb.id
-                WHERE d.owner_id = $2
+                JOIN subtree sb ON d.parent_id = sb.id AND d.workspace_id = sb.workspace_id
             ),
             removed_shares AS (
                 DELETE FROM shares s
                 USING subtree sb
                 WHERE s.document_id = sb.id
-                  AND s.created_by = $2
                 RETURNING 1
             ),
             updated AS (
@@ -424,7 +655,7 @@ impl DocumentRepository for SqlxDocumentRepository {
         let root = if let Some(root_id) = updated {
             sqlx::query(
                 r#"SELECT id, title, parent_id, type, created_at, updated_at, path,
-                          archived_at, archived_by, archived_parent_id
+                          archived_at, archived_by, archived_parent_id, properties, sort_key
                    FROM documents WHERE id = $1"#,
             )
             .bind(root_id)
@@ -441,6 +672,8 @@ impl DocumentRepository for SqlxDocumentRepository {
                 archived_at: r.try_get("archived_at").ok(),
                 archived_by: r.try_get("archived_by").ok(),
                 archived_parent_id: r.try_get("archived_parent_id").ok(),
+                properties: r.try_get("properties").unwrap_or_default(),
+                sort_key: r.try_get("sort_key").ok(),
             })
         } else {
             None
@@ -460,12 +693,15 @@ impl DocumentRepository for SqlxDocumentRepository {
         let updated = sqlx::query_scalar::<_, Uuid>(
             r#"
             WITH RECURSIVE subtree AS (
-                SELECT id FROM documents WHERE id = $1 AND owner_id = $2
+                SELECT id, workspace_id FROM documents
+                WHERE id = $1
+                  AND EXISTS (SELECT 1 FROM workspace_members wm
+                              WHERE wm.workspace_id = documents.workspace_id
+                                AND wm.user_id = $2 AND wm.role <> 'guest')
                 UNION ALL
-                SELECT d.id
+                SELECT d.id, d.workspace_id
                 FROM documents d
-                JOIN subtree sb ON d.archived_parent_id = sb.id
-                WHERE d.owner_id = $2
+                JOIN subtree sb ON d.archived_parent_id = sb.id AND d.workspace_id = sb.workspace_id
             ),
             updated AS (
                 UPDATE documents AS d
@@ -489,7 +725,7 @@ impl DocumentRepository for SqlxDocumentRepository {
         let root = if let Some(root_id) = updated {
             sqlx::query(
                 r#"SELECT id, title, parent_id, type, created_at, updated_at, path,
-                          archived_at, archived_by, archived_parent_id
+                          archived_at, archived_by, archived_parent_id, properties, sort_key
                    FROM documents WHERE id = $1"#,
             )
             .bind(root_id)
@@ -506,6 +742,8 @@ impl DocumentRepository for SqlxDocumentRepository {
                 archived_at: r.try_get("archived_at").ok(),
                 archived_by: r.try_get("archived_by").ok(),
                 archived_parent_id: r.try_get("archived_parent_id").ok(),
+                properties: r.try_get("properties").unwrap_or_default(),
+                sort_key: r.try_get("sort_key").ok(),
             })
         } else {
             None
@@ -523,12 +761,16 @@ impl DocumentRepository for SqlxDocumentRepository {
         let rows = sqlx::query(
             r#"
             WITH RECURSIVE subtree AS (
-                SELECT id, type FROM documents WHERE id = $1 AND owner_id = $2
+                SELECT id, type, workspace_id FROM documents
+                WHERE id = $1
+                  AND EXISTS (SELECT 1 FROM workspace_members wm
+                              WHERE wm.workspace_id = documents.workspace_id
+                                AND wm.user_id = $2 AND wm.role <> 'guest')
                 UNION ALL
-                SELECT d.id, d.type
+                SELECT d.id, d.type, d.workspace_id
                 FROM documents d
                 JOIN subtree sb ON COALESCE(d.parent_id, d.archived_parent_id) = sb.id
-                WHERE d.owner_id = $2
+                              AND d.workspace_id = sb.workspace_id
             )
             SELECT id, type FROM subtree
             "#,
@@ -545,4 +787,354 @@ impl DocumentRepository for SqlxDocumentRepository {
             })
             .collect())
     }
+
+    async fn list_owned_subtree_nodes(
+        &self,
+        owner_id: Uuid,
+        root_id: Uuid,
+    ) -> anyhow::Result<Vec<SubtreeNode>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id, parent_id, title, type, workspace_id, created_at, sort_key, 0 AS depth
+                FROM documents
+                WHERE id = $1 AND archived_at IS NULL AND deleted_at IS NULL
+                  AND EXISTS (SELECT 1 FROM workspace_members wm
+                              WHERE wm.workspace_id = documents.workspace_id
+                                AND wm.user_id = $2 AND wm.role <> 'guest')
+                UNION ALL
+                SELECT d.id, d.parent_id, d.title, d.type, d.workspace_id, d.created_at, d.sort_key,
+                       sb.depth + 1
+                FROM documents d
+                JOIN subtree sb ON d.parent_id = sb.id AND d.workspace_id = sb.workspace_id
+                WHERE d.archived_at IS NULL
+            )
+            SELECT id, parent_id, title, type FROM subtree d ORDER BY depth, {SIBLING_ORDER}
+            "#,
+        ))
+        .bind(root_id)
+        .bind(owner_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| SubtreeNode {
+                id: r.get("id"),
+                parent_id: r.get("parent_id"),
+                title: r.get("title"),
+                doc_type: r.get("type"),
+            })
+            .collect())
+    }
+
+    async fn find_active_by_titles(
+        &self,
+        workspace_id: Uuid,
+        titles: &[String],
+    ) -> anyhow::Result<Vec<SubtreeNode>> {
+        let lowered: Vec<String> = titles.iter().map(|t| t.to_lowercase()).collect();
+        let rows = sqlx::query(
+            r#"SELECT id, parent_id, title, type FROM documents
+               WHERE workspace_id = $1 AND archived_at IS NULL AND deleted_at IS NULL
+                 AND LOWER(title) = ANY($2)
+               ORDER BY created_at"#,
+        )
+        .bind(workspace_id)
+        .bind(&lowered)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| SubtreeNode {
+                id: r.get("id"),
+                parent_id: r.get("parent_id"),
+                title: r.get("title"),
+                doc_type: r.get("type"),
+            })
+            .collect())
+    }
+
+    async fn trash_subtree(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Vec<SubtreeDocument>> {
+        // Only the root is detached; descendants keep their links so the subtree restores whole.
+        let rows = sqlx::query(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id, workspace_id FROM documents
+                WHERE id = $1 AND deleted_at IS NULL
+                  AND EXISTS (SELECT 1 FROM workspace_members wm
+                              WHERE wm.workspace_id = documents.workspace_id
+                                AND wm.user_id = $2 AND wm.role <> 'guest')
+                UNION ALL
+                SELECT d.id, d.workspace_id
+                FROM documents d
+                JOIN subtree sb ON COALESCE(d.parent_id, d.archived_parent_id) = sb.id
+                              AND d.workspace_id = sb.workspace_id
+                WHERE d.deleted_at IS NULL
+            )
+            UPDATE documents AS d
+            SET deleted_at = now(),
+                deleted_by = $2,
+                deleted_parent_id = CASE WHEN d.id = $1 THEN d.parent_id ELSE NULL END,
+                parent_id = CASE WHEN d.id = $1 THEN NULL ELSE d.parent_id END,
+                updated_at = now()
+            FROM subtree sb
+            WHERE d.id = sb.id
+            RETURNING d.id, d.type
+            "#,
+        )
+        .bind(doc_id)
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| SubtreeDocument {
+                id: r.get("id"),
+                doc_type: r.get("type"),
+            })
+            .collect())
+    }
+
+    async fn restore_subtree(
+        &self,
+        doc_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Vec<DomainDocument>> {
+        // Nodes trashed together share `deleted_at` (the transaction timestamp).
+        let rows = sqlx::query(
+            r#"
+            WITH RECURSIVE root AS (
+                SELECT id, workspace_id, deleted_at, deleted_parent_id FROM documents
+                WHERE id = $1 AND deleted_at IS NOT NULL
+                  AND EXISTS (SELECT 1 FROM workspace_members wm
+                              WHERE wm.workspace_id = documents.workspace_id
+                                AND wm.user_id = $2 AND wm.role <> 'guest')
+            ),
+            subtree AS (
+                SELECT id FROM root
+                UNION ALL
+                SELECT d.id
+                FROM documents d
+                JOIN subtree sb ON COALESCE(d.parent_id, d.archived_parent_id) = sb.id
+                WHERE d.deleted_at = (SELECT deleted_at FROM root)
+            ),
+            target AS (
+                SELECT p.id FROM documents p
+                JOIN root r ON p.id = r.deleted_parent_id AND p.workspace_id = r.workspace_id
+                WHERE p.deleted_at IS NULL AND p.archived_at IS NULL AND p.type = 'folder'
+            )
+            UPDATE documents AS d
+            SET parent_id = CASE WHEN d.id = $1 THEN (SELECT id FROM target) ELSE d.parent_id END,
+                deleted_at = NULL,
+                deleted_by = NULL,
+                deleted_parent_id = NULL,
+                updated_at = now()
+            FROM subtree sb
+            WHERE d.id = sb.id
+            RETURNING d.id, d.title, d.parent_id, d.type, d.created_at, d.updated_at, d.path,
+                      d.archived_at, d.archived_by, d.archived_parent_id, d.properties, d.sort_key
+            "#,
+        )
+        .bind(doc_id)
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| DomainDocument {
+                id: r.get("id"),
+                title: r.get("title"),
+                parent_id: r.get("parent_id"),
+                doc_type: r.get("type"),
+                created_at: r.get("created_at"),
+                updated_at: r.get("updated_at"),
+                path: r.try_get("path").ok(),
+                archived_at: r.try_get("archived_at").ok(),
+                archived_by: r.try_get("archived_by").ok(),
+                archived_parent_id: r.try_get("archived_parent_id").ok(),
+                properties: r.try_get("properties").unwrap_or_default(),
+                sort_key: r.try_get("sort_key").ok(),
+            })
+            .collect())
+    }
+
+    async fn list_trash(&self, workspace_id: Uuid) -> anyhow::Result<Vec<TrashedDocument>> {
+        let rows = sqlx::query(
+            r#"SELECT d.id, d.title, d.type, d.deleted_at, d.deleted_by, d.deleted_parent_id,
+                      p.title AS parent_title
+               FROM documents d
+               LEFT JOIN documents p ON p.id = d.deleted_parent_id
+               WHERE d.workspace_id = $1 AND d.deleted_at IS NOT NULL
+                 AND NOT EXISTS (SELECT 1 FROM documents a
+                                 WHERE a.id = COALESCE(d.parent_id, d.archived_parent_id)
+                                   AND a.deleted_at = d.deleted_at)
+               ORDER BY d.deleted_at DESC"#,
+        )
+        .bind(workspace_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| TrashedDocument {
+                id: r.get("id"),
+                title: r.get("title"),
+                doc_type: r.get("type"),
+                deleted_at: r.get("deleted_at"),
+                deleted_by: r.try_get("deleted_by").ok().flatten(),
+                original_parent_id: r.try_get("deleted_parent_id").ok().flatten(),
+                original_parent_title: r.try_get("parent_title").ok().flatten(),
+            })
+            .collect())
+    }
+
+    async fn list_trashed_nodes(
+        &self,
+        workspace_id: Option<Uuid>,
+        deleted_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> anyhow::Result<Vec<SubtreeDocument>> {
+        let rows = sqlx::query(
+            r#"WITH RECURSIVE nodes AS (
+                   SELECT id, type, parent_id FROM documents
+                   WHERE deleted_at IS NOT NULL
+                     AND ($1::uuid IS NULL OR workspace_id = $1)
+                     AND ($2::timestamptz IS NULL OR deleted_at < $2)
+               ), up(id, ancestor) AS (
+                   SELECT id, parent_id FROM nodes
+                   UNION ALL
+                   SELECT up.id, d.parent_id FROM up JOIN documents d ON d.id = up.ancestor
+               )
+               SELECT n.id, n.type, COUNT(up.ancestor) AS depth
+               FROM nodes n JOIN up ON up.id = n.id
+               GROUP BY n.id, n.type
+               ORDER BY depth DESC"#,
+        )
+        .bind(workspace_id)
+        .bind(deleted_before)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| SubtreeDocument {
+                id: r.get("id"),
+                doc_type: r.get("type"),
+            })
+            .collect())
+    }
+
+    async fn purge_trashed(&self, ids: &[Uuid]) -> anyhow::Result<u64> {
+        let res =
+            sqlx::query("DELETE FROM documents WHERE id = ANY($1) AND deleted_at IS NOT NULL")
+                .bind(ids)
+                .execute(&self.pool)
+                .await?;
+        Ok(res.rows_affected())
+    }
+
+    async fn list_child_ids(
+        &self,
+        workspace_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> anyhow::Result<Vec<Uuid>> {
+        let sql = format!(
+            r#"SELECT d.id FROM documents d
+               WHERE d.workspace_id = $1 AND d.parent_id IS NOT DISTINCT FROM $2
+                 AND d.archived_at IS NULL AND d.deleted_at IS NULL
+               ORDER BY {SIBLING_ORDER}"#
+        );
+        let ids = sqlx::query_scalar::<_, Uuid>(&sql)
+            .bind(workspace_id)
+            .bind(parent_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(ids)
+    }
+
+    async fn set_sort_keys(
+        &self,
+        workspace_id: Uuid,
+        parent_id: Option<Uuid>,
+        ordered: &[Uuid],
+    ) -> anyhow::Result<u64> {
+        let res = sqlx::query(
+            r#"UPDATE documents AS d
+               SET sort_key = o.position::int - 1
+               FROM UNNEST($3::uuid[]) WITH ORDINALITY AS o(id, position)
+               WHERE d.id = o.id AND d.workspace_id = $1
+                 AND d.parent_id IS NOT DISTINCT FROM $2"#,
+        )
+        .bind(workspace_id)
+        .bind(parent_id)
+        .bind(ordered)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::db::test_support::{
+        insert_document, insert_user, insert_workspace, test_pool,
+    };
+
+    #[tokio::test]
+    async fn trashed_subtree_restores_and_purges_as_a_unit() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let owner = insert_user(&pool).await;
+        let ws = insert_workspace(&pool, owner).await;
+        let top = insert_document(&pool, ws, owner, None, "folder", "Top").await;
+        let mid = insert_document(&pool, ws, owner, Some(top), "folder", "Mid").await;
+        let leaf = insert_document(&pool, ws, owner, Some(mid), "document", "Leaf").await;
+        let other = insert_document(&pool, ws, owner, None, "document", "Other").await;
+        let repo = SqlxDocumentRepository::new(pool.clone());
+
+        let trashed = repo.trash_subtree(mid, owner).await.unwrap();
+        let mut ids: Vec<Uuid> = trashed.iter().map(|n| n.id).collect();
+        ids.sort();
+        let mut expected = vec![mid, leaf];
+        expected.sort();
+        assert_eq!(ids, expected);
+        // Only the subtree root is a trash item; it remembers where it came from
+        let items = repo.list_trash(ws).await.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, mid);
+        assert_eq!(items[0].original_parent_id, Some(top));
+        let nodes: Vec<Uuid> = repo
+            .list_trashed_nodes(Some(ws), None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(nodes, vec![leaf, mid]);
+
+        let restored = repo.restore_subtree(mid, owner).await.unwrap();
+        assert_eq!(restored.len(), 2);
+        let back = restored.iter().find(|d| d.id == mid).unwrap();
+        assert_eq!(back.parent_id, Some(top));
+        assert!(repo.list_trash(ws).await.unwrap().is_empty());
+
+        // Trashing the parent too and purging removes the whole subtree, nothing else
+        let trashed = repo.trash_subtree(top, owner).await.unwrap();
+        assert_eq!(trashed.len(), 3);
+        let nodes = repo.list_trashed_nodes(Some(ws), None).await.unwrap();
+        assert_eq!(nodes.first().map(|n| n.id), Some(leaf));
+        assert_eq!(nodes.last().map(|n| n.id), Some(top));
+        let ids: Vec<Uuid> = nodes.iter().map(|n| n.id).collect();
+        assert_eq!(repo.purge_trashed(&ids).await.unwrap(), 3);
+        let left: Vec<Uuid> =
+            sqlx::query_scalar("SELECT id FROM documents WHERE workspace_id = $1")
+                .bind(ws)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(left, vec![other]);
+    }
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use sqlx::Row;
 use uuid::Uuid;
 
-use crate::application::ports::files_repository::FilesRepository;
+use crate::application::ports::files_repository::{FilesRepository, StoredFileRow};
 use crate::infrastructure::db::PgPool;
 
 pub struct SqlxFilesRepository {
@@ -19,7 +19,10 @@ impl SqlxFilesRepository {
 impl FilesRepository for SqlxFilesRepository {
     async fn is_owner_document(&self, doc_id: Uuid, owner_id: Uuid) -> anyhow::Result<bool> {
         let n = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(1) FROM documents WHERE id = $1 AND owner_id = $2",
+            r#"SELECT COUNT(1) FROM documents d
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id
+               WHERE d.id = $1 AND wm.user_id = $2 AND wm.role <> 'guest'
+                 AND d.deleted_at IS NULL"#,
         )
         .bind(doc_id)
         .bind(owner_id)
@@ -58,9 +61,7 @@ impl FilesRepository for SqlxFilesRepository {
         file_id: Uuid,
     ) -> anyhow::Result<Option<(String, Option<String>, Uuid)>> {
         let row = sqlx::query(
-            r#"SELECT f.storage_path, f.content_type, d.owner_id
-               FROM files f JOIN documents d ON f.document_id = d.id
-               WHERE f.id = $1"#,
+            r#"SELECT storage_path, content_type, document_id FROM files WHERE id = $1"#,
         )
         .bind(file_id)
         .fetch_optional(&self.pool)
@@ -69,7 +70,7 @@ impl FilesRepository for SqlxFilesRepository {
             (
                 r.get("storage_path"),
                 r.try_get("content_type").ok(),
-                r.get("owner_id"),
+                r.get("document_id"),
             )
         }))
     }
@@ -99,4 +100,22 @@ impl FilesRepository for SqlxFilesRepository {
             .filter_map(|r| r.try_get::<String, _>("storage_path").ok())
             .collect())
     }
+
+    async fn list_files_for_document(&self, doc_id: Uuid) -> anyhow::Result<Vec<StoredFileRow>> {
+        let rows = sqlx::query(
+            r#"SELECT filename, content_type, storage_path FROM files
+               WHERE document_id = $1 ORDER BY created_at"#,
+        )
+        .bind(doc_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| StoredFileRow {
+                filename: r.get("filename"),
+                content_type: r.get("content_type"),
+                storage_path: r.get("storage_path"),
+            })
+            .collect())
+    }
 }
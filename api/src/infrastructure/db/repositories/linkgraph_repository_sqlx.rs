@@ -25,28 +25,32 @@ impl LinkGraphRepository for SqlxLinkGraphRepository {
         Ok(())
     }
 
-    async fn exists_doc_for_owner(&self, doc_id: Uuid, owner_id: Uuid) -> anyhow::Result<bool> {
+    async fn exists_doc_in_workspace(
+        &self,
+        doc_id: Uuid,
+        workspace_id: Uuid,
+    ) -> anyhow::Result<bool> {
         let n = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(1) FROM documents WHERE id = $1 AND owner_id = $2",
+            "SELECT COUNT(1) FROM documents WHERE id = $1 AND workspace_id = $2",
         )
         .bind(doc_id)
-        .bind(owner_id)
+        .bind(workspace_id)
         .fetch_one(&self.pool)
         .await?;
         Ok(n > 0)
     }
 
-    async fn find_doc_id_by_owner_and_title(
+    async fn find_doc_id_by_workspace_and_title(
         &self,
-        owner_id: Uuid,
+        workspace_id: Uuid,
         title: &str,
     ) -> anyhow::Result<Option<Uuid>> {
         let row = sqlx::query(
             r#"SELECT id FROM documents 
-               WHERE owner_id = $1 AND LOWER(title) = LOWER($2)
+               WHERE workspace_id = $1 AND LOWER(title) = LOWER($2) AND deleted_at IS NULL
                ORDER BY updated_at DESC LIMIT 1"#,
         )
-        .bind(owner_id)
+        .bind(workspace_id)
         .bind(title)
         .fetch_optional(&self.pool)
         .await?;
@@ -0,0 +1,235 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::application::ports::mfa_repository::{MfaChallengeRow, MfaRepository, TotpRow};
+use crate::infrastructure::crypto;
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxMfaRepository {
+    pub pool: PgPool,
+    encryption_key: String,
+}
+
+impl SqlxMfaRepository {
+    pub fn new(pool: PgPool, encryption_key: impl Into<String>) -> Self {
+        Self {
+            pool,
+            encryption_key: encryption_key.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl MfaRepository for SqlxMfaRepository {
+    async fn get_totp(&self, user_id: Uuid) -> anyhow::Result<Option<TotpRow>> {
+        let row = sqlx::query(
+            "SELECT user_id, secret_encrypted, enabled_at, last_used_step FROM user_totp WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        let Some(r) = row else {
+            return Ok(None);
+        };
+        let encrypted: String = r.get("secret_encrypted");
+        Ok(Some(TotpRow {
+            user_id: r.get("user_id"),
+            secret: crypto::decrypt_string(&self.encryption_key, &encrypted)?,
+            enabled_at: r.try_get("enabled_at").ok().flatten(),
+            last_used_step: r.try_get("last_used_step").ok().flatten(),
+        }))
+    }
+
+    async fn upsert_pending_totp(&self, user_id: Uuid, secret: &str) -> anyhow::Result<bool> {
+        let encrypted = crypto::encrypt_string(&self.encryption_key, secret)?;
+        let res = sqlx::query(
+            r#"INSERT INTO user_totp (user_id, secret_encrypted) VALUES ($1, $2)
+               ON CONFLICT (user_id) DO UPDATE
+                 SET secret_encrypted = EXCLUDED.secret_encrypted,
+                     last_used_step = NULL,
+                     created_at = now()
+                 WHERE user_totp.enabled_at IS NULL"#,
+        )
+        .bind(user_id)
+        .bind(encrypted)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn enable_totp(
+        &self,
+        user_id: Uuid,
+        step: i64,
+        recovery_code_hashes: &[String],
+    ) -> anyhow::Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let res = sqlx::query(
+            "UPDATE user_totp SET enabled_at = now(), last_used_step = $2 WHERE user_id = $1 AND enabled_at IS NULL",
+        )
+        .bind(user_id)
+        .bind(step)
+        .execute(&mut *tx)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO user_recovery_codes (user_id, code_hash) SELECT $1, unnest($2::text[])",
+        )
+        .bind(user_id)
+        .bind(recovery_code_hashes)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            "UPDATE user_totp SET last_used_step = $2 WHERE user_id = $1 AND (last_used_step IS NULL OR last_used_step < $2)",
+        )
+        .bind(user_id)
+        .bind(step)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn consume_recovery_code(&self, user_id: Uuid, code_hash: &str) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            r#"UPDATE user_recovery_codes SET used_at = now()
+               WHERE id = (
+                 SELECT id FROM user_recovery_codes
+                 WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+                 LIMIT 1
+               )"#,
+        )
+        .bind(user_id)
+        .bind(code_hash)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn count_recovery_codes(&self, user_id: Uuid) -> anyhow::Result<i64> {
+        let n: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM user_recovery_codes WHERE user_id = $1 AND used_at IS NULL",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(n)
+    }
+
+    async fn disable_totp(&self, user_id: Uuid) -> anyhow::Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let res = sqlx::query("DELETE FROM user_totp WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM mfa_challenges WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn create_challenge(
+        &self,
+        user_id: Uuid,
+        token_hash: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM mfa_challenges WHERE expires_at <= now()")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query(
+            "INSERT INTO mfa_challenges (user_id, token_hash, expires_at) VALUES ($1, $2, $3)",
+        )
+        .bind(user_id)
+        .bind(token_hash)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn find_challenge(&self, token_hash: &str) -> anyhow::Result<Option<MfaChallengeRow>> {
+        let row = sqlx::query(
+            "SELECT id, user_id, attempts FROM mfa_challenges WHERE token_hash = $1 AND expires_at > now()",
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|r| MfaChallengeRow {
+            id: r.get("id"),
+            user_id: r.get("user_id"),
+            attempts: r.get("attempts"),
+        }))
+    }
+
+    async fn count_challenge_attempt(&self, challenge_id: Uuid) -> anyhow::Result<Option<i32>> {
+        let attempts = sqlx::query_scalar(
+            "UPDATE mfa_challenges SET attempts = attempts + 1 WHERE id = $1 RETURNING attempts",
+        )
+        .bind(challenge_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(attempts)
+    }
+
+    async fn delete_challenge(&self, challenge_id: Uuid) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM mfa_challenges WHERE id = $1")
+            .bind(challenge_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn begin_second_factor_attempt(
+        &self,
+        user_id: Uuid,
+        max_failures: i32,
+        lock_secs: i64,
+    ) -> anyhow::Result<Option<i32>> {
+        let attempts = sqlx::query_scalar(
+            r#"UPDATE user_totp
+               SET failed_attempts = failed_attempts + 1,
+                   locked_until = CASE
+                     WHEN failed_attempts + 1 >= $2 THEN now() + make_interval(secs => $3)
+                     ELSE locked_until
+                   END
+               WHERE user_id = $1
+                 AND enabled_at IS NOT NULL
+                 AND (locked_until IS NULL OR locked_until <= now())
+               RETURNING failed_attempts"#,
+        )
+        .bind(user_id)
+        .bind(max_failures)
+        .bind(lock_secs as f64)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(attempts)
+    }
+
+    async fn reset_second_factor_failures(&self, user_id: Uuid) -> anyhow::Result<()> {
+        sqlx::query(
+            "UPDATE user_totp SET failed_attempts = 0, locked_until = NULL WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -1,13 +1,27 @@
 pub mod access_repository_sqlx;
+pub mod admin_repository_sqlx;
+pub mod api_token_repository_sqlx;
+pub mod auth_link_repository_sqlx;
+pub mod comment_repository_sqlx;
+pub mod document_grants_repository_sqlx;
 pub mod document_repository_sqlx;
 pub mod document_snapshot_archive_repository_sqlx;
 pub mod files_repository_sqlx;
 pub mod git_repository_sqlx;
 pub mod linkgraph_repository_sqlx;
+pub mod mfa_repository_sqlx;
+pub mod oidc_repository_sqlx;
 pub mod plugin_installation_repository_sqlx;
 pub mod plugin_repository_sqlx;
+pub mod property_repository_sqlx;
 pub mod public_repository_sqlx;
+pub mod search_index_repository_sqlx;
+pub mod session_repository_sqlx;
 pub mod shares_repository_sqlx;
 pub mod tag_repository_sqlx;
 pub mod tagging_repository_sqlx;
+pub mod takeout_repository_sqlx;
+pub mod template_repository_sqlx;
+pub mod user_documents_repository_sqlx;
 pub mod user_repository_sqlx;
+pub mod workspace_repository_sqlx;
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::application::ports::oidc_repository::{OidcLoginStateRow, OidcRepository};
+use crate::application::ports::user_repository::UserRow;
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxOidcRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxOidcRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OidcRepository for SqlxOidcRepository {
+    async fn create_login_state(
+        &self,
+        row: &OidcLoginStateRow,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO oidc_login_states (state, nonce, code_verifier, redirect_to, expires_at) VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(&row.state)
+        .bind(&row.nonce)
+        .bind(&row.code_verifier)
+        .bind(row.redirect_to.as_deref())
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn consume_login_state(&self, state: &str) -> anyhow::Result<Option<OidcLoginStateRow>> {
+        sqlx::query("DELETE FROM oidc_login_states WHERE expires_at <= now()")
+            .execute(&self.pool)
+            .await?;
+        let row = sqlx::query(
+            "DELETE FROM oidc_login_states WHERE state = $1 RETURNING state, nonce, code_verifier, redirect_to",
+        )
+        .bind(state)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|r| OidcLoginStateRow {
+            state: r.get("state"),
+            nonce: r.get("nonce"),
+            code_verifier: r.get("code_verifier"),
+            redirect_to: r.try_get("redirect_to").ok().flatten(),
+        }))
+    }
+
+    async fn find_user_by_identity(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> anyhow::Result<Option<UserRow>> {
+        let row = sqlx::query(
+            r#"WITH hit AS (
+                 UPDATE user_identities SET last_login_at = now()
+                 WHERE issuer = $1 AND subject = $2
+                 RETURNING user_id
+               )
+               SELECT u.id, u.email, u.name, u.email_verified_at, u.role, u.disabled_at FROM users u JOIN hit ON hit.user_id = u.id"#,
+        )
+        .bind(issuer)
+        .bind(subject)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|r| UserRow {
+            id: r.get("id"),
+            email: r.get("email"),
+            name: r.get("name"),
+            password_hash: None,
+            email_verified_at: r.try_get("email_verified_at").ok().flatten(),
+            role: r.get("role"),
+            disabled_at: r.try_get("disabled_at").ok().flatten(),
+        }))
+    }
+
+    async fn link_identity(
+        &self,
+        user_id: Uuid,
+        issuer: &str,
+        subject: &str,
+        email: Option<&str>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO user_identities (user_id, issuer, subject, email) VALUES ($1, $2, $3, $4)",
+        )
+        .bind(user_id)
+        .bind(issuer)
+        .bind(subject)
+        .bind(email)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
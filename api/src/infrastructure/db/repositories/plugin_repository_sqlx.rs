@@ -3,7 +3,7 @@ use serde_json::Value as JsonValue;
 use sqlx::Row;
 use uuid::Uuid;
 
-use crate::application::ports::plugin_repository::{PluginRecord, PluginRepository};
+use crate::application::ports::plugin_repository::{PluginKvEntry, PluginRecord, PluginRepository};
 use crate::infrastructure::db::PgPool;
 
 pub struct SqlxPluginRepository {
@@ -211,4 +211,66 @@ impl PluginRepository for SqlxPluginRepository {
             .await?;
         Ok(())
     }
+
+    async fn list_scoped_kv(
+        &self,
+        scope: &str,
+        scope_ids: &[Uuid],
+    ) -> anyhow::Result<Vec<PluginKvEntry>> {
+        if scope_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let rows = sqlx::query(
+            r#"SELECT plugin, scope, scope_id, key, value, updated_at FROM plugin_kv
+               WHERE scope = $1 AND scope_id = ANY($2)
+               ORDER BY plugin, scope_id, key"#,
+        )
+        .bind(scope)
+        .bind(scope_ids)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| PluginKvEntry {
+                plugin: r.get("plugin"),
+                scope: r.get("scope"),
+                scope_id: r.try_get("scope_id").ok().flatten(),
+                key: r.get("key"),
+                value: r.get("value"),
+                updated_at: r.get("updated_at"),
+            })
+            .collect())
+    }
+
+    async fn list_scoped_records(
+        &self,
+        scope: &str,
+        scope_ids: &[Uuid],
+    ) -> anyhow::Result<Vec<PluginRecord>> {
+        if scope_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let rows = sqlx::query(
+            r#"SELECT id, plugin, scope, scope_id, kind, data, created_at, updated_at FROM plugin_records
+               WHERE scope = $1 AND scope_id = ANY($2)
+               ORDER BY plugin, scope_id, kind, created_at"#,
+        )
+        .bind(scope)
+        .bind(scope_ids)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| PluginRecord {
+                id: r.get("id"),
+                plugin: r.get("plugin"),
+                scope: r.get("scope"),
+                scope_id: r.get("scope_id"),
+                kind: r.get("kind"),
+                data: r.get("data"),
+                created_at: r.get("created_at"),
+                updated_at: r.get("updated_at"),
+            })
+            .collect())
+    }
 }
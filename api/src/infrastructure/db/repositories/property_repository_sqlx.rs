@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::ports::property_repository::PropertyRepository;
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxPropertyRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxPropertyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PropertyRepository for SqlxPropertyRepository {
+    async fn set_document_properties(
+        &self,
+        doc_id: Uuid,
+        properties: &serde_json::Value,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"UPDATE documents SET properties = $2
+               WHERE id = $1 AND properties IS DISTINCT FROM $2"#,
+        )
+        .bind(doc_id)
+        .bind(properties)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
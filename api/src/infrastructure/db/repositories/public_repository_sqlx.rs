@@ -22,11 +22,17 @@ impl PublicRepository for SqlxPublicRepository {
         doc_id: Uuid,
         owner_id: Uuid,
     ) -> anyhow::Result<Option<(String, String)>> {
-        let row = sqlx::query("SELECT d.title, u.name as owner_name FROM documents d JOIN users u ON d.owner_id = u.id WHERE d.id = $1 AND d.owner_id = $2")
-            .bind(doc_id)
-            .bind(owner_id)
-            .fetch_optional(&self.pool)
-            .await?;
+        let row = sqlx::query(
+            r#"SELECT d.title, u.name as owner_name
+               FROM documents d
+               JOIN users u ON d.owner_id = u.id
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id
+               WHERE d.id = $1 AND wm.user_id = $2 AND wm.role <> 'guest'"#,
+        )
+        .bind(doc_id)
+        .bind(owner_id)
+        .fetch_optional(&self.pool)
+        .await?;
         Ok(row.map(|r| (r.get("title"), r.get("owner_name"))))
     }
 
@@ -50,7 +56,9 @@ impl PublicRepository for SqlxPublicRepository {
 
     async fn is_owner_document(&self, doc_id: Uuid, owner_id: Uuid) -> anyhow::Result<bool> {
         let n = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(1) FROM documents WHERE id = $1 AND owner_id = $2",
+            r#"SELECT COUNT(1) FROM documents d
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id
+               WHERE d.id = $1 AND wm.user_id = $2 AND wm.role <> 'guest'"#,
         )
         .bind(doc_id)
         .bind(owner_id)
@@ -77,7 +85,8 @@ impl PublicRepository for SqlxPublicRepository {
                FROM public_documents p
                JOIN documents d ON p.document_id = d.id
                JOIN users u ON d.owner_id = u.id
-               WHERE p.document_id = $1 AND d.owner_id = $2"#,
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id AND wm.user_id = $2
+               WHERE p.document_id = $1"#,
         )
         .bind(doc_id)
         .bind(owner_id)
@@ -102,7 +111,7 @@ impl PublicRepository for SqlxPublicRepository {
                FROM public_documents p
                JOIN documents d ON p.document_id = d.id
                JOIN users u ON d.owner_id = u.id
-               WHERE u.name = $1
+               WHERE u.name = $1 AND d.deleted_at IS NULL
                ORDER BY d.updated_at DESC LIMIT 200"#,
         )
         .bind(owner_name)
@@ -137,15 +146,17 @@ impl PublicRepository for SqlxPublicRepository {
             Option<chrono::DateTime<chrono::Utc>>,
             Option<Uuid>,
             Option<Uuid>,
+            serde_json::Value,
+            Option<i32>,
         )>,
     > {
         let row = sqlx::query(
             r#"SELECT d.id, d.title, d.parent_id, d.type, d.created_at, d.updated_at, d.path,
-                      d.archived_at, d.archived_by, d.archived_parent_id
+                      d.archived_at, d.archived_by, d.archived_parent_id, d.properties, d.sort_key
                FROM public_documents p
                JOIN documents d ON p.document_id = d.id
                JOIN users u ON d.owner_id = u.id
-               WHERE u.name = $1 AND d.id = $2"#,
+               WHERE u.name = $1 AND d.id = $2 AND d.deleted_at IS NULL"#,
         )
         .bind(owner_name)
         .bind(doc_id)
@@ -163,6 +174,8 @@ impl PublicRepository for SqlxPublicRepository {
                 r.try_get("archived_at").ok(),
                 r.try_get("archived_by").ok(),
                 r.try_get("archived_parent_id").ok(),
+                r.try_get("properties").unwrap_or_default(),
+                r.try_get("sort_key").ok(),
             )
         }))
     }
@@ -177,7 +190,7 @@ impl PublicRepository for SqlxPublicRepository {
                FROM public_documents p
                JOIN documents d ON p.document_id = d.id
                JOIN users u ON d.owner_id = u.id
-               WHERE u.name = $1 AND d.id = $2"#,
+               WHERE u.name = $1 AND d.id = $2 AND d.deleted_at IS NULL"#,
         )
         .bind(owner_name)
         .bind(doc_id)
@@ -185,4 +198,18 @@ impl PublicRepository for SqlxPublicRepository {
         .await?;
         Ok(n > 0)
     }
+
+    async fn resolve_renamed_owner(&self, name: &str) -> anyhow::Result<Option<String>> {
+        let row = sqlx::query_scalar::<_, String>(
+            r#"SELECT u.name
+               FROM user_name_history h
+               JOIN users u ON u.id = h.user_id
+               WHERE h.name = $1::citext
+                 AND NOT EXISTS (SELECT 1 FROM users x WHERE x.name = $1)"#,
+        )
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
 }
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::application::ports::search_index_repository::SearchIndexRepository;
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxSearchIndexRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxSearchIndexRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SearchIndexRepository for SqlxSearchIndexRepository {
+    async fn upsert_document_content(&self, doc_id: Uuid, content: &str) -> anyhow::Result<()> {
+        // The default parser treats `<...>` as markup and skips <script>/<style> bodies
+        sqlx::query(
+            r#"INSERT INTO document_search_index (document_id, content, tsv, updated_at)
+               SELECT d.id, $2, to_tsvector('simple', replace($2, '<', ' ')), now()
+               FROM documents d WHERE d.id = $1
+               ON CONFLICT (document_id) DO UPDATE
+                 SET content = EXCLUDED.content, tsv = EXCLUDED.tsv, updated_at = now()
+                 WHERE document_search_index.content IS DISTINCT FROM EXCLUDED.content"#,
+        )
+        .bind(doc_id)
+        .bind(content)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn list_unindexed(&self, after: Option<Uuid>, limit: i64) -> anyhow::Result<Vec<Uuid>> {
+        let rows = sqlx::query(
+            r#"SELECT d.id FROM documents d
+               WHERE d.type <> 'folder'
+                 AND ($1::uuid IS NULL OR d.id > $1)
+                 AND NOT EXISTS (SELECT 1 FROM document_search_index si WHERE si.document_id = d.id)
+               ORDER BY d.id
+               LIMIT $2"#,
+        )
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|r| r.get("id")).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::db::test_support::{
+        insert_document, insert_user, insert_workspace, test_pool,
+    };
+
+    #[tokio::test]
+    async fn unindexed_documents_page_past_the_cursor() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let owner = insert_user(&pool).await;
+        let ws = insert_workspace(&pool, owner).await;
+        let mut ids = [
+            insert_document(&pool, ws, owner, None, "document", "A").await,
+            insert_document(&pool, ws, owner, None, "document", "B").await,
+        ];
+        ids.sort();
+        let (first, second) = (ids[0], ids[1]);
+        let folder = insert_document(&pool, ws, owner, None, "folder", "F").await;
+        let repo = SqlxSearchIndexRepository::new(pool.clone());
+
+        // A document that stays unindexed must not stop the scan from moving on.
+        let page = repo.list_unindexed(Some(first), i64::MAX).await.unwrap();
+        assert!(page.windows(2).all(|w| w[0] < w[1]));
+        assert!(page.iter().all(|id| *id > first));
+        assert!(page.contains(&second));
+        assert!(!page.contains(&folder));
+
+        repo.upsert_document_content(second, "indexed")
+            .await
+            .unwrap();
+        let page = repo.list_unindexed(Some(first), i64::MAX).await.unwrap();
+        assert!(!page.contains(&second));
+    }
+}
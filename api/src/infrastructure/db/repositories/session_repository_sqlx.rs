@@ -0,0 +1,176 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::application::ports::session_repository::{SessionRepository, SessionRow};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxSessionRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const SESSION_COLUMNS: &str =
+    "id, user_id, user_agent, ip_address, created_at, last_seen_at, expires_at, revoked_at";
+
+fn session_from_row(r: &PgRow) -> SessionRow {
+    SessionRow {
+        id: r.get("id"),
+        user_id: r.get("user_id"),
+        user_agent: r.try_get("user_agent").ok().flatten(),
+        ip_address: r.try_get("ip_address").ok().flatten(),
+        created_at: r.get("created_at"),
+        last_seen_at: r.get("last_seen_at"),
+        expires_at: r.get("expires_at"),
+        revoked_at: r.try_get("revoked_at").ok().flatten(),
+    }
+}
+
+#[async_trait]
+impl SessionRepository for SqlxSessionRepository {
+    async fn create(
+        &self,
+        user_id: Uuid,
+        refresh_token_hash: &str,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<SessionRow> {
+        let sql = format!(
+            "INSERT INTO user_sessions (user_id, refresh_token_hash, user_agent, ip_address, expires_at) VALUES ($1, $2, $3, $4, $5) RETURNING {SESSION_COLUMNS}"
+        );
+        let row = sqlx::query(&sql)
+            .bind(user_id)
+            .bind(refresh_token_hash)
+            .bind(user_agent)
+            .bind(ip_address)
+            .bind(expires_at)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(session_from_row(&row))
+    }
+
+    async fn find_by_refresh_hash(
+        &self,
+        refresh_token_hash: &str,
+    ) -> anyhow::Result<Option<SessionRow>> {
+        let sql = format!(
+            "SELECT {SESSION_COLUMNS} FROM user_sessions WHERE refresh_token_hash = $1 AND revoked_at IS NULL AND expires_at > now()"
+        );
+        let row = sqlx::query(&sql)
+            .bind(refresh_token_hash)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(session_from_row))
+    }
+
+    async fn find_by_previous_hash(
+        &self,
+        refresh_token_hash: &str,
+    ) -> anyhow::Result<Option<SessionRow>> {
+        let sql =
+            format!("SELECT {SESSION_COLUMNS} FROM user_sessions WHERE previous_refresh_hash = $1");
+        let row = sqlx::query(&sql)
+            .bind(refresh_token_hash)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(session_from_row))
+    }
+
+    async fn rotate(
+        &self,
+        session_id: Uuid,
+        old_hash: &str,
+        new_hash: &str,
+        user_agent: Option<&str>,
+        ip_address: Option<&str>,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            r#"UPDATE user_sessions
+               SET refresh_token_hash = $3,
+                   previous_refresh_hash = $2,
+                   user_agent = COALESCE($4, user_agent),
+                   ip_address = COALESCE($5, ip_address),
+                   expires_at = $6,
+                   last_seen_at = now()
+               WHERE id = $1 AND refresh_token_hash = $2 AND revoked_at IS NULL"#,
+        )
+        .bind(session_id)
+        .bind(old_hash)
+        .bind(new_hash)
+        .bind(user_agent)
+        .bind(ip_address)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn touch(&self, session_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
+        let active: bool = sqlx::query_scalar(
+            r#"WITH s AS (
+                 SELECT id, last_seen_at FROM user_sessions
+                 WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL AND expires_at > now()
+               ), touched AS (
+                 UPDATE user_sessions SET last_seen_at = now()
+                 WHERE id IN (SELECT id FROM s WHERE last_seen_at < now() - interval '60 seconds')
+               )
+               SELECT EXISTS (SELECT 1 FROM s)"#,
+        )
+        .bind(session_id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(active)
+    }
+
+    async fn list_active(&self, user_id: Uuid) -> anyhow::Result<Vec<SessionRow>> {
+        let sql = format!(
+            "SELECT {SESSION_COLUMNS} FROM user_sessions WHERE user_id = $1 AND revoked_at IS NULL AND expires_at > now() ORDER BY last_seen_at DESC"
+        );
+        let rows = sqlx::query(&sql)
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(session_from_row).collect())
+    }
+
+    async fn revoke(&self, session_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            "UPDATE user_sessions SET revoked_at = now() WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL",
+        )
+        .bind(session_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn revoke_all(&self, user_id: Uuid) -> anyhow::Result<u64> {
+        let res = sqlx::query(
+            "UPDATE user_sessions SET revoked_at = now() WHERE user_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+
+    async fn revoke_others(&self, user_id: Uuid, keep_session_id: Uuid) -> anyhow::Result<u64> {
+        let res = sqlx::query(
+            "UPDATE user_sessions SET revoked_at = now() WHERE user_id = $1 AND id <> $2 AND revoked_at IS NULL",
+        )
+        .bind(user_id)
+        .bind(keep_session_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+}
@@ -57,14 +57,17 @@ impl SharesRepository for SqlxSharesRepository {
         permission: &str,
         expires_at: Option<chrono::DateTime<chrono::Utc>>,
     ) -> anyhow::Result<(String, Uuid, String)> {
-        // Verify ownership and type
-        let dtype: String =
-            sqlx::query_scalar("SELECT type FROM documents WHERE id = $1 AND owner_id = $2")
-                .bind(document_id)
-                .bind(owner_id)
-                .fetch_optional(&self.pool)
-                .await?
-                .ok_or_else(|| anyhow::anyhow!("forbidden"))?;
+        // Verify edit membership in the owning workspace and type
+        let dtype: String = sqlx::query_scalar(
+            r#"SELECT d.type FROM documents d
+               JOIN workspace_members wm ON wm.workspace_id = d.workspace_id
+               WHERE d.id = $1 AND wm.user_id = $2 AND wm.role <> 'guest'"#,
+        )
+        .bind(document_id)
+        .bind(owner_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("forbidden"))?;
         let token = Uuid::new_v4().to_string();
         let row = sqlx::query("INSERT INTO shares (document_id, token, permission, created_by, expires_at) VALUES ($1, $2, $3, $4, $5) RETURNING id, token")
             .bind(document_id)
@@ -118,7 +121,10 @@ impl SharesRepository for SqlxSharesRepository {
             r#"SELECT s.id, s.token, s.permission, s.expires_at, s.parent_share_id, s.created_at,
                       d.id as document_id, d.title as document_title, d.type as document_type
                FROM shares s JOIN documents d ON d.id = s.document_id
-               WHERE s.document_id = $1 AND d.owner_id = $2
+               WHERE s.document_id = $1
+                 AND EXISTS (SELECT 1 FROM workspace_members wm
+                                 WHERE wm.workspace_id = d.workspace_id AND wm.user_id = $2
+                                   AND wm.role <> 'guest')
                ORDER BY s.created_at DESC"#,
         )
         .bind(document_id)
@@ -143,11 +149,17 @@ impl SharesRepository for SqlxSharesRepository {
     }
 
     async fn delete_share(&self, owner_id: Uuid, token: &str) -> anyhow::Result<bool> {
-        let res = sqlx::query("DELETE FROM shares s USING documents d WHERE s.token = $1 AND s.document_id = d.id AND d.owner_id = $2")
-            .bind(token)
-            .bind(owner_id)
-            .execute(&self.pool)
-            .await?;
+        let res = sqlx::query(
+            r#"DELETE FROM shares s USING documents d
+               WHERE s.token = $1 AND s.document_id = d.id
+                 AND EXISTS (SELECT 1 FROM workspace_members wm
+                                 WHERE wm.workspace_id = d.workspace_id AND wm.user_id = $2
+                                   AND wm.role <> 'guest')"#,
+        )
+        .bind(token)
+        .bind(owner_id)
+        .execute(&self.pool)
+        .await?;
         Ok(res.rows_affected() > 0)
     }
 
@@ -182,7 +194,10 @@ impl SharesRepository for SqlxSharesRepository {
             r#"SELECT s.token, s.permission, s.expires_at
                FROM shares s
                JOIN documents d ON d.id = s.document_id
-               WHERE s.document_id = $1 AND d.owner_id = $2 AND s.created_by = $2"#,
+               WHERE s.document_id = $1
+                 AND EXISTS (SELECT 1 FROM workspace_members wm
+                                 WHERE wm.workspace_id = d.workspace_id AND wm.user_id = $2
+                                   AND wm.role <> 'guest')"#,
         )
         .bind(doc_id)
         .bind(owner_id)
@@ -206,7 +221,11 @@ impl SharesRepository for SqlxSharesRepository {
                       d.id as document_id, d.title as document_title, d.type as document_type
                FROM shares s
                JOIN documents d ON d.id = s.document_id
-               WHERE d.owner_id = $1 AND (s.expires_at IS NULL OR s.expires_at > now())
+               WHERE (s.expires_at IS NULL OR s.expires_at > now())
+                 AND d.deleted_at IS NULL
+                 AND EXISTS (SELECT 1 FROM workspace_members wm
+                                 WHERE wm.workspace_id = d.workspace_id AND wm.user_id = $1
+                                   AND wm.role <> 'guest')
                ORDER BY s.created_at DESC"#,
         )
         .bind(owner_id)
@@ -254,6 +273,16 @@ impl SharesRepository for SqlxSharesRepository {
         Ok(owner)
     }
 
+    async fn get_document_workspace_by_token(&self, token: &str) -> anyhow::Result<Option<Uuid>> {
+        let workspace = sqlx::query_scalar::<_, Uuid>(
+            "SELECT d.workspace_id FROM shares s JOIN documents d ON d.id = s.document_id WHERE s.token = $1",
+        )
+        .bind(token)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(workspace)
+    }
+
     async fn list_subtree_nodes(
         &self,
         root_id: Uuid,
@@ -270,13 +299,16 @@ impl SharesRepository for SqlxSharesRepository {
         let rows = sqlx::query(
             r#"
             WITH RECURSIVE subtree AS (
-                SELECT id, title, type, parent_id, created_at, updated_at FROM documents WHERE id = $1
+                SELECT id, title, type, parent_id, created_at, updated_at, sort_key, 0 AS depth
+                FROM documents WHERE id = $1
                 UNION ALL
-                SELECT d.id, d.title, d.type, d.parent_id, d.created_at, d.updated_at
+                SELECT d.id, d.title, d.type, d.parent_id, d.created_at, d.updated_at, d.sort_key,
+                       s.depth + 1
                 FROM documents d JOIN subtree s ON d.parent_id = s.id
             )
             SELECT id, title, type, parent_id, created_at, updated_at FROM subtree
-            "#
+            ORDER BY depth, sort_key NULLS LAST, (type = 'folder') DESC, lower(title), created_at
+            "#,
         )
         .bind(root_id)
         .fetch_all(&self.pool)
@@ -306,19 +338,23 @@ impl SharesRepository for SqlxSharesRepository {
 
     async fn materialize_folder_share(&self, owner_id: Uuid, token: &str) -> anyhow::Result<i64> {
         let row = sqlx::query(
-            r#"SELECT s.id as share_id, s.permission, s.expires_at, d.id as folder_id, d.owner_id, d.type
+            r#"SELECT s.id as share_id, s.permission, s.expires_at, d.id as folder_id, d.type,
+                      EXISTS (SELECT 1 FROM workspace_members wm
+                                 WHERE wm.workspace_id = d.workspace_id AND wm.user_id = $2
+                                   AND wm.role <> 'guest') AS can_edit
                FROM shares s JOIN documents d ON d.id = s.document_id
-               WHERE s.token = $1"#
+               WHERE s.token = $1"#,
         )
         .bind(token)
+        .bind(owner_id)
         .fetch_optional(&self.pool)
         .await?;
         let row = match row {
             Some(r) => r,
             None => anyhow::bail!("not_found"),
         };
-        let owner: Uuid = row.get("owner_id");
-        if owner != owner_id {
+        let can_edit: bool = row.get("can_edit");
+        if !can_edit {
             anyhow::bail!("forbidden");
         }
         let dtype: String = row.get("type");
@@ -364,18 +400,20 @@ impl SharesRepository for SqlxSharesRepository {
         let deleted = sqlx::query_scalar::<_, i64>(
             r#"
             WITH RECURSIVE subtree AS (
-                SELECT id FROM documents WHERE id = $1 AND owner_id = $2
+                SELECT d.id, d.workspace_id FROM documents d
+                WHERE d.id = $1
+                  AND EXISTS (SELECT 1 FROM workspace_members wm
+                                 WHERE wm.workspace_id = d.workspace_id AND wm.user_id = $2
+                                   AND wm.role <> 'guest')
                 UNION ALL
-                SELECT d.id
+                SELECT d.id, d.workspace_id
                 FROM documents d
-                JOIN subtree sb ON d.parent_id = sb.id
-                WHERE d.owner_id = $2
+                JOIN subtree sb ON d.parent_id = sb.id AND d.workspace_id = sb.workspace_id
             ),
             removed AS (
                 DELETE FROM shares s
                 USING subtree sb
                 WHERE s.document_id = sb.id
-                  AND s.created_by = $2
                 RETURNING 1
             )
             SELECT COALESCE(COUNT(*), 0) FROM removed
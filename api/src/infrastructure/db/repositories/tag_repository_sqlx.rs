@@ -19,7 +19,7 @@ impl SqlxTagRepository {
 impl TagRepository for SqlxTagRepository {
     async fn list_tags(
         &self,
-        owner_id: Uuid,
+        workspace_id: Uuid,
         filter: Option<String>,
     ) -> anyhow::Result<Vec<(String, i64)>> {
         let rows = if let Some(f) = filter.filter(|s| !s.trim().is_empty()) {
@@ -28,12 +28,13 @@ impl TagRepository for SqlxTagRepository {
                 r#"SELECT t.name, COUNT(*)::BIGINT AS count
                    FROM document_tags dt
                    JOIN tags t ON t.id = dt.tag_id
-                   JOIN documents d ON d.id = dt.document_id AND d.owner_id = $1
+                   JOIN documents d ON d.id = dt.document_id AND d.workspace_id = $1
+                                   AND d.deleted_at IS NULL
                    WHERE t.name ILIKE $2
                    GROUP BY t.name
                    ORDER BY count DESC, t.name ASC"#,
             )
-            .bind(owner_id)
+            .bind(workspace_id)
             .bind(like)
             .fetch_all(&self.pool)
             .await?
@@ -42,11 +43,12 @@ impl TagRepository for SqlxTagRepository {
                 r#"SELECT t.name, COUNT(*)::BIGINT AS count
                    FROM document_tags dt
                    JOIN tags t ON t.id = dt.tag_id
-                   JOIN documents d ON d.id = dt.document_id AND d.owner_id = $1
+                   JOIN documents d ON d.id = dt.document_id AND d.workspace_id = $1
+                                   AND d.deleted_at IS NULL
                    GROUP BY t.name
                    ORDER BY count DESC, t.name ASC"#,
             )
-            .bind(owner_id)
+            .bind(workspace_id)
             .fetch_all(&self.pool)
             .await?
         };
@@ -33,12 +33,12 @@ impl TaggingRepository for SqlxTaggingRepository {
         Ok(row.get("id"))
     }
 
-    async fn owner_doc_exists(&self, doc_id: Uuid, owner_id: Uuid) -> anyhow::Result<bool> {
+    async fn workspace_doc_exists(&self, doc_id: Uuid, workspace_id: Uuid) -> anyhow::Result<bool> {
         let n = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(1) FROM documents WHERE id = $1 AND owner_id = $2",
+            "SELECT COUNT(1) FROM documents WHERE id = $1 AND workspace_id = $2",
         )
         .bind(doc_id)
-        .bind(owner_id)
+        .bind(workspace_id)
         .fetch_one(&self.pool)
         .await?;
         Ok(n > 0)
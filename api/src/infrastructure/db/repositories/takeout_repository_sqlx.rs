@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::application::ports::takeout_repository::{
+    TakeoutDocumentRow, TakeoutJobRow, TakeoutRepository,
+};
+use crate::infrastructure::db::PgPool;
+use crate::infrastructure::db::repositories::document_repository_sqlx::SIBLING_ORDER;
+
+pub struct SqlxTakeoutRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxTakeoutRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const JOB_COLUMNS: &str = "id, user_id, status, error, storage_path, size_bytes, created_at, started_at, completed_at, expires_at";
+
+fn job_from_row(r: &PgRow) -> TakeoutJobRow {
+    TakeoutJobRow {
+        id: r.get("id"),
+        user_id: r.get("user_id"),
+        status: r.get("status"),
+        error: r.try_get("error").ok().flatten(),
+        storage_path: r.try_get("storage_path").ok().flatten(),
+        size_bytes: r.try_get("size_bytes").ok().flatten(),
+        created_at: r.get("created_at"),
+        started_at: r.try_get("started_at").ok().flatten(),
+        completed_at: r.try_get("completed_at").ok().flatten(),
+        expires_at: r.try_get("expires_at").ok().flatten(),
+    }
+}
+
+#[async_trait]
+impl TakeoutRepository for SqlxTakeoutRepository {
+    async fn create_job(&self, user_id: Uuid) -> anyhow::Result<TakeoutJobRow> {
+        let sql = format!("INSERT INTO takeout_jobs (user_id) VALUES ($1) RETURNING {JOB_COLUMNS}");
+        let res = sqlx::query(&sql).bind(user_id).fetch_one(&self.pool).await;
+        match res {
+            Ok(row) => Ok(job_from_row(&row)),
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                anyhow::bail!("conflict")
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn list_jobs(&self, user_id: Uuid) -> anyhow::Result<Vec<TakeoutJobRow>> {
+        let sql = format!(
+            "SELECT {JOB_COLUMNS} FROM takeout_jobs WHERE user_id = $1 ORDER BY created_at DESC"
+        );
+        let rows = sqlx::query(&sql)
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(job_from_row).collect())
+    }
+
+    async fn get_job(&self, user_id: Uuid, id: Uuid) -> anyhow::Result<Option<TakeoutJobRow>> {
+        let sql = format!("SELECT {JOB_COLUMNS} FROM takeout_jobs WHERE id = $1 AND user_id = $2");
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(job_from_row))
+    }
+
+    async fn claim_next(&self) -> anyhow::Result<Option<TakeoutJobRow>> {
+        let sql = format!(
+            r#"UPDATE takeout_jobs SET status = 'running', started_at = now()
+               WHERE id = (
+                   SELECT id FROM takeout_jobs WHERE status = 'pending'
+                   ORDER BY created_at
+                   FOR UPDATE SKIP LOCKED
+                   LIMIT 1
+               )
+               RETURNING {JOB_COLUMNS}"#
+        );
+        let row = sqlx::query(&sql).fetch_optional(&self.pool).await?;
+        Ok(row.as_ref().map(job_from_row))
+    }
+
+    async fn mark_completed(
+        &self,
+        id: Uuid,
+        storage_path: &str,
+        size_bytes: i64,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"UPDATE takeout_jobs
+               SET status = 'completed', storage_path = $2, size_bytes = $3,
+                   completed_at = now(), expires_at = $4, error = NULL
+               WHERE id = $1"#,
+        )
+        .bind(id)
+        .bind(storage_path)
+        .bind(size_bytes)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_failed(&self, id: Uuid, error: &str) -> anyhow::Result<()> {
+        sqlx::query(
+            "UPDATE takeout_jobs SET status = 'failed', error = $2, completed_at = now() WHERE id = $1",
+        )
+        .bind(id)
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn requeue_stale(&self, cutoff: chrono::DateTime<chrono::Utc>) -> anyhow::Result<u64> {
+        let res = sqlx::query(
+            "UPDATE takeout_jobs SET status = 'pending', started_at = NULL WHERE status = 'running' AND started_at < $1",
+        )
+        .bind(cutoff)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+
+    async fn delete_expired(&self) -> anyhow::Result<Vec<String>> {
+        let rows = sqlx::query(
+            "DELETE FROM takeout_jobs WHERE expires_at IS NOT NULL AND expires_at < now() RETURNING storage_path",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|r| {
+                r.try_get::<Option<String>, _>("storage_path")
+                    .ok()
+                    .flatten()
+            })
+            .collect())
+    }
+
+    async fn list_documents(&self, user_id: Uuid) -> anyhow::Result<Vec<TakeoutDocumentRow>> {
+        // Siblings grouped under their parent, in the order the tree shows them.
+        let rows = sqlx::query(&format!(
+            r#"SELECT d.id, d.workspace_id, d.parent_id, d.title, d.type, d.created_at, d.updated_at,
+                      d.archived_at, d.sort_key,
+                      COALESCE(ARRAY(
+                          SELECT t.name::text FROM document_tags dt
+                          JOIN tags t ON t.id = dt.tag_id
+                          WHERE dt.document_id = d.id
+                          ORDER BY t.name
+                      ), '{{}}') AS tags
+               FROM documents d
+               WHERE d.owner_id = $1
+               ORDER BY d.workspace_id, d.parent_id NULLS FIRST, {SIBLING_ORDER}"#
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| TakeoutDocumentRow {
+                id: r.get("id"),
+                workspace_id: r.get("workspace_id"),
+                parent_id: r.try_get("parent_id").ok().flatten(),
+                title: r.get("title"),
+                doc_type: r.get("type"),
+                tags: r.get("tags"),
+                created_at: r.get("created_at"),
+                updated_at: r.get("updated_at"),
+                archived_at: r.try_get("archived_at").ok().flatten(),
+                sort_key: r.try_get("sort_key").ok().flatten(),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::db::test_support::{
+        insert_document, insert_user, insert_workspace, test_pool,
+    };
+
+    #[tokio::test]
+    async fn documents_are_listed_by_parent_in_sibling_order() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let owner = insert_user(&pool).await;
+        let ws = insert_workspace(&pool, owner).await;
+        let b = insert_document(&pool, ws, owner, None, "document", "B").await;
+        let a = insert_document(&pool, ws, owner, None, "document", "A").await;
+        let folder = insert_document(&pool, ws, owner, None, "folder", "Z folder").await;
+        let child = insert_document(&pool, ws, owner, Some(folder), "document", "Child").await;
+        sqlx::query("UPDATE documents SET sort_key = 1 WHERE id = $1")
+            .bind(b)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let repo = SqlxTakeoutRepository::new(pool.clone());
+
+        let rows = repo.list_documents(owner).await.unwrap();
+        let ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![b, folder, a, child]);
+        assert_eq!(rows[0].sort_key, Some(1));
+        assert_eq!(rows[1].sort_key, None);
+    }
+}
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::application::ports::template_repository::{
+    TemplatePrompt, TemplateRepository, TemplateRow,
+};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxTemplateRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxTemplateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+// Expects `document_templates t JOIN documents d`.
+const TEMPLATE_COLUMNS: &str = "t.document_id, t.workspace_id, t.name, t.description, t.prompts, t.created_by, t.created_at, t.updated_at, d.type AS doc_type";
+
+fn template_from_row(r: &PgRow) -> TemplateRow {
+    let prompts: serde_json::Value = r.get("prompts");
+    TemplateRow {
+        document_id: r.get("document_id"),
+        workspace_id: r.get("workspace_id"),
+        name: r.get("name"),
+        description: r.try_get("description").ok().flatten(),
+        prompts: serde_json::from_value(prompts).unwrap_or_default(),
+        doc_type: r.get("doc_type"),
+        created_by: r.try_get("created_by").ok().flatten(),
+        created_at: r.get("created_at"),
+        updated_at: r.get("updated_at"),
+    }
+}
+
+#[async_trait]
+impl TemplateRepository for SqlxTemplateRepository {
+    async fn upsert(
+        &self,
+        document_id: Uuid,
+        workspace_id: Uuid,
+        name: &str,
+        description: Option<&str>,
+        prompts: &[TemplatePrompt],
+        created_by: Uuid,
+    ) -> anyhow::Result<TemplateRow> {
+        let sql = format!(
+            r#"WITH t AS (
+                   INSERT INTO document_templates
+                       (document_id, workspace_id, name, description, prompts, created_by)
+                   VALUES ($1, $2, $3, $4, $5, $6)
+                   ON CONFLICT (document_id) DO UPDATE
+                   SET name = EXCLUDED.name,
+                       description = EXCLUDED.description,
+                       prompts = EXCLUDED.prompts,
+                       updated_at = now()
+                   RETURNING *
+               )
+               SELECT {TEMPLATE_COLUMNS} FROM t JOIN documents d ON d.id = t.document_id"#
+        );
+        let row = sqlx::query(&sql)
+            .bind(document_id)
+            .bind(workspace_id)
+            .bind(name)
+            .bind(description)
+            .bind(serde_json::to_value(prompts)?)
+            .bind(created_by)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(template_from_row(&row))
+    }
+
+    async fn list_for_workspace(&self, workspace_id: Uuid) -> anyhow::Result<Vec<TemplateRow>> {
+        let sql = format!(
+            r#"SELECT {TEMPLATE_COLUMNS}
+               FROM document_templates t JOIN documents d ON d.id = t.document_id
+               WHERE t.workspace_id = $1 AND d.archived_at IS NULL AND d.deleted_at IS NULL
+               ORDER BY LOWER(t.name)"#
+        );
+        let rows = sqlx::query(&sql)
+            .bind(workspace_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(template_from_row).collect())
+    }
+
+    async fn get(&self, document_id: Uuid) -> anyhow::Result<Option<TemplateRow>> {
+        let sql = format!(
+            r#"SELECT {TEMPLATE_COLUMNS}
+               FROM document_templates t JOIN documents d ON d.id = t.document_id
+               WHERE t.document_id = $1"#
+        );
+        let row = sqlx::query(&sql)
+            .bind(document_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(template_from_row))
+    }
+
+    async fn delete(&self, document_id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query("DELETE FROM document_templates WHERE document_id = $1")
+            .bind(document_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::document_repository::DocumentRepository;
+    use crate::infrastructure::db::repositories::document_repository_sqlx::SqlxDocumentRepository;
+    use crate::infrastructure::db::test_support::{
+        insert_document, insert_user, insert_workspace, test_pool,
+    };
+
+    #[tokio::test]
+    async fn trashed_templates_are_not_listed() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let owner = insert_user(&pool).await;
+        let ws = insert_workspace(&pool, owner).await;
+        let kept = insert_document(&pool, ws, owner, None, "document", "Kept").await;
+        let trashed = insert_document(&pool, ws, owner, None, "document", "Trashed").await;
+        let repo = SqlxTemplateRepository::new(pool.clone());
+        repo.upsert(kept, ws, "Kept", None, &[], owner)
+            .await
+            .unwrap();
+        repo.upsert(trashed, ws, "Trashed", None, &[], owner)
+            .await
+            .unwrap();
+
+        SqlxDocumentRepository::new(pool.clone())
+            .trash_subtree(trashed, owner)
+            .await
+            .unwrap();
+
+        let ids: Vec<Uuid> = repo
+            .list_for_workspace(ws)
+            .await
+            .unwrap()
+            .iter()
+            .map(|t| t.document_id)
+            .collect();
+        assert_eq!(ids, vec![kept]);
+    }
+}
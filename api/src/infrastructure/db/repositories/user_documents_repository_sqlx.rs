@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::application::ports::user_documents_repository::{
+    FavoriteDocumentRow, RecentDocumentRow, UserDocumentsRepository,
+};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxUserDocumentsRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxUserDocumentsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserDocumentsRepository for SqlxUserDocumentsRepository {
+    async fn record_view(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_id: Option<Uuid>,
+        keep: i64,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"INSERT INTO document_views (user_id, document_id, share_id)
+               VALUES ($1, $2, $3)
+               ON CONFLICT (user_id, document_id)
+               DO UPDATE SET view_count = document_views.view_count + 1, last_viewed_at = now(),
+                             share_id = COALESCE(EXCLUDED.share_id, document_views.share_id)"#,
+        )
+        .bind(user_id)
+        .bind(doc_id)
+        .bind(share_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            r#"DELETE FROM document_views
+               WHERE user_id = $1 AND document_id NOT IN (
+                 SELECT document_id FROM document_views
+                 WHERE user_id = $1
+                 ORDER BY last_viewed_at DESC
+                 LIMIT $2
+               )"#,
+        )
+        .bind(user_id)
+        .bind(keep)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn list_recent(&self, user_id: Uuid) -> anyhow::Result<Vec<RecentDocumentRow>> {
+        let rows = sqlx::query(
+            r#"SELECT d.id, d.workspace_id, d.title, d.type, v.view_count, v.last_viewed_at,
+                      s.token AS share_token
+               FROM document_views v
+               JOIN documents d ON d.id = v.document_id
+               LEFT JOIN shares s ON s.id = v.share_id
+               WHERE v.user_id = $1 AND d.deleted_at IS NULL
+               ORDER BY v.last_viewed_at DESC"#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| RecentDocumentRow {
+                document_id: r.get("id"),
+                workspace_id: r.get("workspace_id"),
+                title: r.get("title"),
+                doc_type: r.get("type"),
+                view_count: r.get("view_count"),
+                last_viewed_at: r.get("last_viewed_at"),
+                share_token: r.try_get("share_token").ok().flatten(),
+            })
+            .collect())
+    }
+
+    async fn add_favorite(
+        &self,
+        user_id: Uuid,
+        doc_id: Uuid,
+        share_id: Option<Uuid>,
+    ) -> anyhow::Result<bool> {
+        let inserted = sqlx::query_scalar::<_, bool>(
+            r#"INSERT INTO document_favorites (user_id, document_id, share_id)
+               VALUES ($1, $2, $3)
+               ON CONFLICT (user_id, document_id)
+               DO UPDATE SET share_id = COALESCE(EXCLUDED.share_id, document_favorites.share_id)
+               RETURNING (xmax = 0)"#,
+        )
+        .bind(user_id)
+        .bind(doc_id)
+        .bind(share_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(inserted)
+    }
+
+    async fn remove_favorite(&self, user_id: Uuid, doc_id: Uuid) -> anyhow::Result<bool> {
+        let res =
+            sqlx::query("DELETE FROM document_favorites WHERE user_id = $1 AND document_id = $2")
+                .bind(user_id)
+                .bind(doc_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn list_favorites(&self, user_id: Uuid) -> anyhow::Result<Vec<FavoriteDocumentRow>> {
+        let rows = sqlx::query(
+            r#"SELECT d.id, d.workspace_id, d.title, d.type, f.created_at, s.token AS share_token
+               FROM document_favorites f
+               JOIN documents d ON d.id = f.document_id
+               LEFT JOIN shares s ON s.id = f.share_id
+               WHERE f.user_id = $1 AND d.deleted_at IS NULL
+               ORDER BY f.created_at DESC"#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| FavoriteDocumentRow {
+                document_id: r.get("id"),
+                workspace_id: r.get("workspace_id"),
+                title: r.get("title"),
+                doc_type: r.get("type"),
+                created_at: r.get("created_at"),
+                share_token: r.try_get("share_token").ok().flatten(),
+            })
+            .collect())
+    }
+}
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use sqlx::Row;
+use sqlx::postgres::PgRow;
 use uuid::Uuid;
 
 use crate::application::ports::user_repository::{UserRepository, UserRow};
@@ -15,56 +16,73 @@ impl SqlxUserRepository {
     }
 }
 
+const USER_COLUMNS: &str = "id, email, name, password_hash, email_verified_at, role, disabled_at";
+
+fn user_from_row(r: &PgRow) -> UserRow {
+    UserRow {
+        id: r.get("id"),
+        email: r.get("email"),
+        name: r.get("name"),
+        password_hash: r.try_get("password_hash").ok().flatten(),
+        email_verified_at: r.try_get("email_verified_at").ok().flatten(),
+        role: r.get("role"),
+        disabled_at: r.try_get("disabled_at").ok().flatten(),
+    }
+}
+
 #[async_trait]
 impl UserRepository for SqlxUserRepository {
     async fn create_user(
         &self,
         email: &str,
         name: &str,
-        password_hash: &str,
+        password_hash: Option<&str>,
     ) -> anyhow::Result<UserRow> {
-        let row = sqlx::query(
-            r#"INSERT INTO users (email, name, password_hash) VALUES ($1, $2, $3)
-               RETURNING id, email, name, password_hash"#,
+        let mut tx = self.pool.begin().await?;
+        let sql = format!(
+            "INSERT INTO users (email, name, password_hash) VALUES ($1, $2, $3) RETURNING {USER_COLUMNS}"
+        );
+        let row = sqlx::query(&sql)
+            .bind(email)
+            .bind(name)
+            .bind(password_hash)
+            .fetch_one(&mut *tx)
+            .await?;
+        // Personal workspace shares the user's id (see create_workspaces migration)
+        let user_id: Uuid = row.get("id");
+        sqlx::query(
+            "INSERT INTO workspaces (id, name, is_personal, created_by) VALUES ($1, $2, true, $1)",
         )
-        .bind(email)
+        .bind(user_id)
         .bind(name)
-        .bind(password_hash)
-        .fetch_one(&self.pool)
+        .execute(&mut *tx)
         .await?;
-        Ok(UserRow {
-            id: row.get("id"),
-            email: row.get("email"),
-            name: row.get("name"),
-            password_hash: row.try_get("password_hash").ok(),
-        })
+        sqlx::query(
+            "INSERT INTO workspace_members (workspace_id, user_id, role) VALUES ($1, $1, 'owner')",
+        )
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(user_from_row(&row))
     }
 
     async fn find_by_email(&self, email: &str) -> anyhow::Result<Option<UserRow>> {
-        let row =
-            sqlx::query(r#"SELECT id, email, name, password_hash FROM users WHERE email = $1"#)
-                .bind(email)
-                .fetch_optional(&self.pool)
-                .await?;
-        Ok(row.map(|r| UserRow {
-            id: r.get("id"),
-            email: r.get("email"),
-            name: r.get("name"),
-            password_hash: r.try_get("password_hash").ok(),
-        }))
+        let sql = format!("SELECT {USER_COLUMNS} FROM users WHERE email = $1");
+        let row = sqlx::query(&sql)
+            .bind(email)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(user_from_row))
     }
 
     async fn find_by_id(&self, id: Uuid) -> anyhow::Result<Option<UserRow>> {
-        let row = sqlx::query(r#"SELECT id, email, name FROM users WHERE id = $1"#)
+        let sql = format!("SELECT {USER_COLUMNS} FROM users WHERE id = $1");
+        let row = sqlx::query(&sql)
             .bind(id)
             .fetch_optional(&self.pool)
             .await?;
-        Ok(row.map(|r| UserRow {
-            id: r.get("id"),
-            email: r.get("email"),
-            name: r.get("name"),
-            password_hash: None,
-        }))
+        Ok(row.as_ref().map(user_from_row))
     }
 
     async fn delete_user(&self, id: Uuid) -> anyhow::Result<bool> {
@@ -74,4 +92,101 @@ impl UserRepository for SqlxUserRepository {
             .await?;
         Ok(res.rows_affected() > 0)
     }
+
+    async fn set_password(&self, id: Uuid, password_hash: &str) -> anyhow::Result<bool> {
+        let res =
+            sqlx::query("UPDATE users SET password_hash = $2, updated_at = now() WHERE id = $1")
+                .bind(id)
+                .bind(password_hash)
+                .execute(&self.pool)
+                .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn mark_email_verified(&self, id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query(
+            "UPDATE users SET email_verified_at = now(), updated_at = now() WHERE id = $1 AND email_verified_at IS NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn find_by_name(&self, name: &str) -> anyhow::Result<Option<UserRow>> {
+        let sql = format!(
+            "SELECT {USER_COLUMNS} FROM users WHERE lower(name) = lower($1) ORDER BY created_at LIMIT 1"
+        );
+        let row = sqlx::query(&sql)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(user_from_row))
+    }
+
+    async fn update_name(&self, id: Uuid, name: &str) -> anyhow::Result<Option<UserRow>> {
+        let mut tx = self.pool.begin().await?;
+        let Some(old_name) =
+            sqlx::query_scalar::<_, String>("SELECT name FROM users WHERE id = $1 FOR UPDATE")
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await?
+        else {
+            return Ok(None);
+        };
+        let sql = format!(
+            "UPDATE users SET name = $2, updated_at = now() WHERE id = $1 RETURNING {USER_COLUMNS}"
+        );
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .bind(name)
+            .fetch_one(&mut *tx)
+            .await?;
+        if old_name != name {
+            // Reclaiming an earlier name drops its redirect
+            sqlx::query("DELETE FROM user_name_history WHERE name = $1::citext")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+            if !old_name.eq_ignore_ascii_case(name) {
+                sqlx::query(
+                    r#"INSERT INTO user_name_history (name, user_id) VALUES ($1, $2)
+                       ON CONFLICT (name) DO UPDATE SET user_id = EXCLUDED.user_id, changed_at = now()"#,
+                )
+                .bind(&old_name)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            sqlx::query(
+                "UPDATE workspaces SET name = $3, updated_at = now() WHERE id = $1 AND is_personal AND name = $2",
+            )
+            .bind(id)
+            .bind(&old_name)
+            .bind(name)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(Some(user_from_row(&row)))
+    }
+
+    async fn update_email(&self, id: Uuid, email: &str) -> anyhow::Result<Option<UserRow>> {
+        let sql = format!(
+            "UPDATE users SET email = $2, email_verified_at = NULL, updated_at = now() WHERE id = $1 RETURNING {USER_COLUMNS}"
+        );
+        let res = sqlx::query(&sql)
+            .bind(id)
+            .bind(email)
+            .fetch_optional(&self.pool)
+            .await;
+        let row = match res {
+            Ok(row) => row,
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                anyhow::bail!("conflict")
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Ok(row.as_ref().map(user_from_row))
+    }
 }
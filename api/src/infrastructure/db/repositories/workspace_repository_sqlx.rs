@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{
+    WorkspaceMemberRow, WorkspaceRepository, WorkspaceRole, WorkspaceRow,
+};
+use crate::infrastructure::db::PgPool;
+
+pub struct SqlxWorkspaceRepository {
+    pub pool: PgPool,
+}
+
+impl SqlxWorkspaceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn parse_role(raw: &str) -> anyhow::Result<WorkspaceRole> {
+    WorkspaceRole::parse(raw).ok_or_else(|| anyhow::anyhow!("invalid workspace role: {raw}"))
+}
+
+#[async_trait]
+impl WorkspaceRepository for SqlxWorkspaceRepository {
+    async fn list_for_user(&self, user_id: Uuid) -> anyhow::Result<Vec<WorkspaceRow>> {
+        let rows = sqlx::query(
+            r#"SELECT w.id, w.name, w.is_personal, wm.role, w.created_at, w.updated_at
+               FROM workspace_members wm
+               JOIN workspaces w ON w.id = wm.workspace_id
+               WHERE wm.user_id = $1
+               ORDER BY w.is_personal DESC, LOWER(w.name), w.created_at"#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            let role: String = r.get("role");
+            out.push(WorkspaceRow {
+                id: r.get("id"),
+                name: r.get("name"),
+                is_personal: r.get("is_personal"),
+                role: parse_role(&role)?,
+                created_at: r.get("created_at"),
+                updated_at: r.get("updated_at"),
+            });
+        }
+        Ok(out)
+    }
+
+    async fn get_for_user(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<WorkspaceRow>> {
+        let row = sqlx::query(
+            r#"SELECT w.id, w.name, w.is_personal, wm.role, w.created_at, w.updated_at
+               FROM workspace_members wm
+               JOIN workspaces w ON w.id = wm.workspace_id
+               WHERE wm.workspace_id = $1 AND wm.user_id = $2"#,
+        )
+        .bind(workspace_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        match row {
+            Some(r) => {
+                let role: String = r.get("role");
+                Ok(Some(WorkspaceRow {
+                    id: r.get("id"),
+                    name: r.get("name"),
+                    is_personal: r.get("is_personal"),
+                    role: parse_role(&role)?,
+                    created_at: r.get("created_at"),
+                    updated_at: r.get("updated_at"),
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, name: &str, owner_id: Uuid) -> anyhow::Result<WorkspaceRow> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query(
+            r#"INSERT INTO workspaces (name, is_personal, created_by)
+               VALUES ($1, false, $2)
+               RETURNING id, name, is_personal, created_at, updated_at"#,
+        )
+        .bind(name)
+        .bind(owner_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        let id: Uuid = row.get("id");
+        sqlx::query(
+            "INSERT INTO workspace_members (workspace_id, user_id, role) VALUES ($1, $2, 'owner')",
+        )
+        .bind(id)
+        .bind(owner_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(WorkspaceRow {
+            id,
+            name: row.get("name"),
+            is_personal: row.get("is_personal"),
+            role: WorkspaceRole::Owner,
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+
+    async fn rename(&self, workspace_id: Uuid, name: &str) -> anyhow::Result<bool> {
+        let res = sqlx::query("UPDATE workspaces SET name = $2, updated_at = now() WHERE id = $1")
+            .bind(workspace_id)
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn delete(&self, workspace_id: Uuid) -> anyhow::Result<bool> {
+        let res = sqlx::query("DELETE FROM workspaces WHERE id = $1 AND is_personal = false")
+            .bind(workspace_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn get_role(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<Option<WorkspaceRole>> {
+        let role = sqlx::query_scalar::<_, String>(
+            "SELECT role FROM workspace_members WHERE workspace_id = $1 AND user_id = $2",
+        )
+        .bind(workspace_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        role.as_deref().map(parse_role).transpose()
+    }
+
+    async fn list_members(&self, workspace_id: Uuid) -> anyhow::Result<Vec<WorkspaceMemberRow>> {
+        let rows = sqlx::query(
+            r#"SELECT u.id, u.email, u.name, wm.role, wm.created_at
+               FROM workspace_members wm
+               JOIN users u ON u.id = wm.user_id
+               WHERE wm.workspace_id = $1
+               ORDER BY wm.created_at, u.email"#,
+        )
+        .bind(workspace_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            let role: String = r.get("role");
+            out.push(WorkspaceMemberRow {
+                user_id: r.get("id"),
+                email: r.get("email"),
+                name: r.get("name"),
+                role: parse_role(&role)?,
+                created_at: r.get("created_at"),
+            });
+        }
+        Ok(out)
+    }
+
+    async fn upsert_member(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        role: WorkspaceRole,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"INSERT INTO workspace_members (workspace_id, user_id, role)
+               VALUES ($1, $2, $3)
+               ON CONFLICT (workspace_id, user_id) DO UPDATE SET role = EXCLUDED.role"#,
+        )
+        .bind(workspace_id)
+        .bind(user_id)
+        .bind(role.as_str())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_member(&self, workspace_id: Uuid, user_id: Uuid) -> anyhow::Result<bool> {
+        let res =
+            sqlx::query("DELETE FROM workspace_members WHERE workspace_id = $1 AND user_id = $2")
+                .bind(workspace_id)
+                .bind(user_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    async fn count_owners(&self, workspace_id: Uuid) -> anyhow::Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(1) FROM workspace_members WHERE workspace_id = $1 AND role = 'owner'",
+        )
+        .bind(workspace_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+
+    async fn release_user(&self, user_id: Uuid) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        // Pick a successor per shared workspace: highest role first, then seniority.
+        let rows = sqlx::query(
+            r#"SELECT DISTINCT ON (wm.workspace_id) wm.workspace_id, wm.user_id, wm.role
+               FROM workspace_members wm
+               JOIN workspace_members me
+                 ON me.workspace_id = wm.workspace_id AND me.user_id = $1
+               WHERE wm.user_id <> $1
+               ORDER BY wm.workspace_id,
+                        CASE wm.role WHEN 'owner' THEN 0 WHEN 'admin' THEN 1
+                                     WHEN 'member' THEN 2 ELSE 3 END,
+                        wm.created_at"#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *tx)
+        .await?;
+        for r in rows {
+            let workspace_id: Uuid = r.get("workspace_id");
+            let successor: Uuid = r.get("user_id");
+            let role: String = r.get("role");
+            if role != "owner" {
+                sqlx::query(
+                    "UPDATE workspace_members SET role = 'owner' WHERE workspace_id = $1 AND user_id = $2",
+                )
+                .bind(workspace_id)
+                .bind(successor)
+                .execute(&mut *tx)
+                .await?;
+            }
+            sqlx::query(
+                "UPDATE documents SET owner_id = $3 WHERE workspace_id = $1 AND owner_id = $2",
+            )
+            .bind(workspace_id)
+            .bind(user_id)
+            .bind(successor)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_sole_member_workspaces(&self, user_id: Uuid) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"DELETE FROM workspaces w
+               WHERE w.id = $1
+                  OR (EXISTS (SELECT 1 FROM workspace_members wm
+                              WHERE wm.workspace_id = w.id AND wm.user_id = $1)
+                      AND NOT EXISTS (SELECT 1 FROM workspace_members wm
+                                      WHERE wm.workspace_id = w.id AND wm.user_id <> $1))"#,
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! Fixtures for repository tests against a real Postgres.
+//!
+//! Tests are skipped unless `TEST_DATABASE_URL` points at a disposable database; it is
+//! migrated on first use and rows are created with fresh ids, so tests may share it.
+use uuid::Uuid;
+
+use super::{PgPool, connect_pool, migrate};
+
+pub(crate) async fn test_pool() -> Option<PgPool> {
+    let url = std::env::var("TEST_DATABASE_URL").ok()?;
+    let pool = connect_pool(&url).await.expect("connect TEST_DATABASE_URL");
+    migrate(&pool).await.expect("migrate test database");
+    Some(pool)
+}
+
+pub(crate) async fn insert_user(pool: &PgPool) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query("INSERT INTO users (id, email, name) VALUES ($1, $2, 'Test user')")
+        .bind(id)
+        .bind(format!("{id}@example.test"))
+        .execute(pool)
+        .await
+        .expect("insert user");
+    id
+}
+
+/// Workspace with `owner` as its owner.
+pub(crate) async fn insert_workspace(pool: &PgPool, owner: Uuid) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query("INSERT INTO workspaces (id, name) VALUES ($1, 'Test workspace')")
+        .bind(id)
+        .execute(pool)
+        .await
+        .expect("insert workspace");
+    add_member(pool, id, owner, "owner").await;
+    id
+}
+
+pub(crate) async fn add_member(pool: &PgPool, workspace_id: Uuid, user_id: Uuid, role: &str) {
+    sqlx::query("INSERT INTO workspace_members (workspace_id, user_id, role) VALUES ($1, $2, $3)")
+        .bind(workspace_id)
+        .bind(user_id)
+        .bind(role)
+        .execute(pool)
+        .await
+        .expect("insert workspace member");
+}
+
+pub(crate) async fn insert_document(
+    pool: &PgPool,
+    workspace_id: Uuid,
+    owner_id: Uuid,
+    parent_id: Option<Uuid>,
+    doc_type: &str,
+    title: &str,
+) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        r#"INSERT INTO documents (id, title, owner_id, workspace_id, parent_id, type)
+           VALUES ($1, $2, $3, $4, $5, $6)"#,
+    )
+    .bind(id)
+    .bind(title)
+    .bind(owner_id)
+    .bind(workspace_id)
+    .bind(parent_id)
+    .bind(doc_type)
+    .execute(pool)
+    .await
+    .expect("insert document");
+    id
+}
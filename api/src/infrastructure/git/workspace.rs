@@ -430,7 +430,7 @@ impl GitWorkspaceService {
         let mut state: HashMap<String, FileSnapshot> = HashMap::new();
 
         let doc_rows =
-            sqlx::query("SELECT id FROM documents WHERE owner_id = $1 AND type <> 'folder'")
+            sqlx::query("SELECT id FROM documents WHERE workspace_id = $1 AND type <> 'folder' AND deleted_at IS NULL")
                 .bind(user_id)
                 .fetch_all(&self.pool)
                 .await?;
@@ -469,7 +469,7 @@ impl GitWorkspaceService {
             r#"SELECT f.storage_path, f.content_hash
                FROM files f
                JOIN documents d ON d.id = f.document_id
-               WHERE d.owner_id = $1"#,
+               WHERE d.workspace_id = $1 AND d.deleted_at IS NULL"#,
         )
         .bind(user_id)
         .fetch_all(&self.pool)
@@ -0,0 +1,52 @@
+pub mod outbox_mailer;
+pub mod smtp_mailer;
+
+use base64::Engine as _;
+
+use crate::application::ports::mailer::MailMessage;
+
+/// Bare address from `Name <addr>` or `addr`.
+pub(crate) fn envelope_address(mailbox: &str) -> &str {
+    match (mailbox.rfind('<'), mailbox.rfind('>')) {
+        (Some(start), Some(end)) if start < end => mailbox[start + 1..end].trim(),
+        _ => mailbox.trim(),
+    }
+}
+
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!(
+            "=?UTF-8?B?{}?=",
+            base64::engine::general_purpose::STANDARD.encode(value)
+        )
+    }
+}
+
+/// RFC 5322 message with a base64 text/plain body, CRLF line endings.
+pub(crate) fn format_message(from: &str, message: &MailMessage) -> String {
+    let domain = envelope_address(from)
+        .rsplit_once('@')
+        .map(|(_, d)| d)
+        .unwrap_or("localhost");
+    let body = base64::engine::general_purpose::STANDARD.encode(message.body.as_bytes());
+    let mut out = String::new();
+    out.push_str(&format!("From: {}\r\n", encode_header(from)));
+    out.push_str(&format!("To: {}\r\n", message.to));
+    out.push_str(&format!("Subject: {}\r\n", encode_header(&message.subject)));
+    out.push_str(&format!("Date: {}\r\n", chrono::Utc::now().to_rfc2822()));
+    out.push_str(&format!(
+        "Message-ID: <{}@{}>\r\n",
+        uuid::Uuid::new_v4(),
+        domain
+    ));
+    out.push_str("MIME-Version: 1.0\r\n");
+    out.push_str("Content-Type: text/plain; charset=utf-8\r\n");
+    out.push_str("Content-Transfer-Encoding: base64\r\n\r\n");
+    for chunk in body.as_bytes().chunks(76) {
+        out.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+        out.push_str("\r\n");
+    }
+    out
+}
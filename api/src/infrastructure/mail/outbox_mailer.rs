@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::application::ports::mailer::{MailMessage, Mailer};
+use crate::infrastructure::mail::format_message;
+
+/// Development mailer: every message lands in `dir` as an .eml file.
+pub struct OutboxMailer {
+    dir: PathBuf,
+    from: String,
+}
+
+impl OutboxMailer {
+    pub fn new(dir: impl Into<PathBuf>, from: impl Into<String>) -> Self {
+        Self {
+            dir: dir.into(),
+            from: from.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for OutboxMailer {
+    async fn send(&self, message: &MailMessage) -> anyhow::Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let name = format!(
+            "{}-{}.eml",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S%.3f"),
+            uuid::Uuid::new_v4()
+        );
+        let path = self.dir.join(name);
+        tokio::fs::write(&path, format_message(&self.from, message)).await?;
+        tracing::info!(to = %message.to, subject = %message.subject, path = %path.display(), "mail_written_to_outbox");
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::Engine as _;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::{self, OwnedTrustAnchor, RootCertStore, ServerName};
+
+use crate::application::ports::mailer::{MailMessage, Mailer};
+use crate::bootstrap::config::SmtpTls;
+use crate::infrastructure::mail::{envelope_address, format_message};
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Minimal SMTP submission client (EHLO, optional STARTTLS/implicit TLS, AUTH PLAIN).
+pub struct SmtpMailer {
+    host: String,
+    port: u16,
+    credentials: Option<(String, String)>,
+    tls: SmtpTls,
+    from: String,
+    connector: TlsConnector,
+}
+
+impl SmtpMailer {
+    pub fn new(
+        host: impl Into<String>,
+        port: u16,
+        username: Option<String>,
+        password: Option<String>,
+        tls: SmtpTls,
+        from: impl Into<String>,
+    ) -> Self {
+        let mut roots = RootCertStore::empty();
+        roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|ta| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                ta.subject,
+                ta.spki,
+                ta.name_constraints,
+            )
+        }));
+        let config = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        Self {
+            host: host.into(),
+            port,
+            credentials: username.map(|u| (u, password.unwrap_or_default())),
+            tls,
+            from: from.into(),
+            connector: TlsConnector::from(Arc::new(config)),
+        }
+    }
+
+    async fn deliver(&self, message: &MailMessage) -> anyhow::Result<()> {
+        let tcp = TcpStream::connect((self.host.as_str(), self.port)).await?;
+        let server_name = ServerName::try_from(self.host.as_str())
+            .map_err(|_| anyhow::anyhow!("invalid SMTP host name"))?;
+        match self.tls {
+            SmtpTls::Implicit => {
+                let tls = self.connector.connect(server_name, tcp).await?;
+                let mut stream = BufReader::new(tls);
+                expect_reply(&mut stream, &[220]).await?;
+                self.ehlo(&mut stream).await?;
+                self.transact(&mut stream, message).await
+            }
+            SmtpTls::StartTls => {
+                let mut stream = BufReader::new(tcp);
+                expect_reply(&mut stream, &[220]).await?;
+                self.ehlo(&mut stream).await?;
+                command(&mut stream, "STARTTLS", &[220]).await?;
+                let tls = self
+                    .connector
+                    .connect(server_name, stream.into_inner())
+                    .await?;
+                let mut stream = BufReader::new(tls);
+                self.ehlo(&mut stream).await?;
+                self.transact(&mut stream, message).await
+            }
+            SmtpTls::None => {
+                let mut stream = BufReader::new(tcp);
+                expect_reply(&mut stream, &[220]).await?;
+                self.ehlo(&mut stream).await?;
+                self.transact(&mut stream, message).await
+            }
+        }
+    }
+
+    async fn ehlo<S>(&self, stream: &mut BufReader<S>) -> anyhow::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        command(stream, "EHLO refmd", &[250]).await.map(|_| ())
+    }
+
+    async fn transact<S>(
+        &self,
+        stream: &mut BufReader<S>,
+        message: &MailMessage,
+    ) -> anyhow::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        if let Some((user, pass)) = &self.credentials {
+            let plain =
+                base64::engine::general_purpose::STANDARD.encode(format!("\0{user}\0{pass}"));
+            command(stream, &format!("AUTH PLAIN {plain}"), &[235]).await?;
+        }
+        let from = envelope_address(&self.from);
+        let to = envelope_address(&message.to);
+        if [from, to]
+            .iter()
+            .any(|a| a.contains(['\r', '\n', '<', '>']))
+        {
+            anyhow::bail!("invalid mail address");
+        }
+        command(stream, &format!("MAIL FROM:<{from}>"), &[250]).await?;
+        command(stream, &format!("RCPT TO:<{to}>"), &[250, 251]).await?;
+        command(stream, "DATA", &[354]).await?;
+        let mut data = String::new();
+        for line in format_message(&self.from, message).split("\r\n") {
+            // Dot-stuffing (RFC 5321 4.5.2)
+            if line.starts_with('.') {
+                data.push('.');
+            }
+            data.push_str(line);
+            data.push_str("\r\n");
+        }
+        data.push_str(".\r\n");
+        stream.get_mut().write_all(data.as_bytes()).await?;
+        stream.get_mut().flush().await?;
+        expect_reply(stream, &[250]).await?;
+        let _ = command(stream, "QUIT", &[221]).await;
+        Ok(())
+    }
+}
+
+async fn command<S>(stream: &mut BufReader<S>, line: &str, expect: &[u16]) -> anyhow::Result<String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    stream.get_mut().write_all(line.as_bytes()).await?;
+    stream.get_mut().write_all(b"\r\n").await?;
+    stream.get_mut().flush().await?;
+    expect_reply(stream, expect).await
+}
+
+/// Reads a (possibly multi-line) reply and checks its code.
+async fn expect_reply<S>(stream: &mut BufReader<S>, expect: &[u16]) -> anyhow::Result<String>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut text = String::new();
+    loop {
+        let mut line = String::new();
+        if stream.read_line(&mut line).await? == 0 {
+            anyhow::bail!("smtp connection closed");
+        }
+        let line = line.trim_end();
+        let code: u16 = line
+            .get(..3)
+            .and_then(|c| c.parse().ok())
+            .ok_or_else(|| anyhow::anyhow!("malformed smtp reply: {line}"))?;
+        text.push_str(line.get(4..).unwrap_or_default());
+        text.push('\n');
+        if line.as_bytes().get(3) != Some(&b'-') {
+            if !expect.contains(&code) {
+                anyhow::bail!("smtp error {code}: {}", text.trim());
+            }
+            return Ok(text);
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &MailMessage) -> anyhow::Result<()> {
+        tokio::time::timeout(SMTP_TIMEOUT, self.deliver(message))
+            .await
+            .map_err(|_| anyhow::anyhow!("smtp timed out"))?
+    }
+}
@@ -1,6 +1,8 @@
+pub mod auth;
 pub mod crypto;
 pub mod db;
 pub mod git;
+pub mod mail;
 pub mod plugins;
 pub mod realtime;
 pub mod storage;
@@ -532,14 +532,26 @@ impl PluginInstaller for FilesystemPluginStore {
         &self,
         user_id: Uuid,
         archive: &[u8],
+    ) -> Result<InstalledPlugin, PluginInstallError> {
+        self.install_into(self.user_root(&user_id), archive).await
+    }
+
+    async fn install_global(&self, archive: &[u8]) -> Result<InstalledPlugin, PluginInstallError> {
+        self.install_into(self.global_root(), archive).await
+    }
+}
+
+impl FilesystemPluginStore {
+    /// Extracts the package to `<base>/<id>/<version>`, replacing an existing copy.
+    async fn install_into(
+        &self,
+        base: PathBuf,
+        archive: &[u8],
     ) -> Result<InstalledPlugin, PluginInstallError> {
         let archive_vec = archive.to_vec();
         let (_manifest, installed) = Self::read_manifest_from_archive(&archive_vec)?;
 
-        let dest_root = self
-            .user_root(&user_id)
-            .join(&installed.id)
-            .join(&installed.version);
+        let dest_root = base.join(&installed.id).join(&installed.version);
 
         match tokio::fs::metadata(&dest_root).await {
             Ok(_) => {
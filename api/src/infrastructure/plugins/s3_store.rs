@@ -576,6 +576,20 @@ impl PluginInstaller for S3BackedPluginStore {
         self.global_cache.invalidate();
         Ok(installed)
     }
+
+    async fn install_global(&self, archive: &[u8]) -> Result<InstalledPlugin, PluginInstallError> {
+        let installed = self.local.install_global(archive).await?;
+        let install_dir = self
+            .local
+            .global_root()
+            .join(&installed.id)
+            .join(&installed.version);
+        upload_directory(&self.client, &self.bucket, self.local.root(), &install_dir)
+            .await
+            .map_err(PluginInstallError::Storage)?;
+        self.global_cache.invalidate();
+        Ok(installed)
+    }
 }
 
 #[async_trait]
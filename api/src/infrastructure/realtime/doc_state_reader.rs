@@ -64,16 +64,19 @@ impl DocStateReader for SqlxDocStateReader {
     }
 
     async fn document_record(&self, doc_id: &Uuid) -> anyhow::Result<Option<DocumentRecord>> {
-        let row = sqlx::query("SELECT type, path, title, owner_id FROM documents WHERE id = $1")
-            .bind(doc_id)
-            .fetch_optional(&self.pool)
-            .await?;
+        let row = sqlx::query(
+            "SELECT type, path, title, owner_id, workspace_id FROM documents WHERE id = $1",
+        )
+        .bind(doc_id)
+        .fetch_optional(&self.pool)
+        .await?;
 
         Ok(row.map(|row| DocumentRecord {
             doc_type: row.get("type"),
             path: row.try_get("path").ok(),
             title: row.get("title"),
             owner_id: row.try_get("owner_id").ok(),
+            workspace_id: row.try_get("workspace_id").ok(),
         }))
     }
 }
@@ -7,7 +7,7 @@ use anyhow::Context;
 use chrono::Utc;
 use futures_util::SinkExt;
 use tokio::sync::mpsc;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{Mutex, RwLock, watch};
 use tokio::time::{Duration, Instant, sleep};
 use uuid::Uuid;
 use yrs::GetString;
@@ -17,15 +17,18 @@ use yrs::sync::awareness::AwarenessUpdate;
 use yrs::sync::protocol::{MSG_SYNC, MSG_SYNC_UPDATE};
 use yrs::sync::{DefaultProtocol, Error as SyncError, Protocol};
 use yrs::updates::decoder::Decode;
-use yrs::updates::encoder::{Encoder, EncoderV1};
+use yrs::updates::encoder::{Encode, Encoder, EncoderV1};
 use yrs::{Doc, ReadTxn, StateVector, Text, Transact, Update};
 use yrs_warp::AwarenessRef;
 use yrs_warp::broadcast::BroadcastGroup;
 
 use crate::application::ports::document_snapshot_archive_repository::DocumentSnapshotArchiveRepository;
 use crate::application::ports::linkgraph_repository::LinkGraphRepository;
+use crate::application::ports::property_repository::PropertyRepository;
 use crate::application::ports::realtime_hydration_port::{DocStateReader, RealtimeBacklogReader};
 use crate::application::ports::realtime_persistence_port::DocPersistencePort;
+use crate::application::ports::realtime_types::{ContentEdit, ContentState, RealtimeAccess};
+use crate::application::ports::search_index_repository::SearchIndexRepository;
 use crate::application::ports::storage_port::StoragePort;
 use crate::application::ports::tagging_repository::TaggingRepository;
 use crate::application::services::realtime::doc_hydration::{
@@ -36,8 +39,13 @@ use crate::application::services::realtime::snapshot::{
 };
 use crate::infrastructure::db::PgPool;
 use crate::infrastructure::db::repositories::linkgraph_repository_sqlx::SqlxLinkGraphRepository;
+use crate::infrastructure::db::repositories::property_repository_sqlx::SqlxPropertyRepository;
+use crate::infrastructure::db::repositories::search_index_repository_sqlx::SqlxSearchIndexRepository;
 use crate::infrastructure::db::repositories::tagging_repository_sqlx::SqlxTaggingRepository;
-use crate::infrastructure::realtime::utils::wrap_stream_with_edit_guard;
+use crate::infrastructure::realtime::utils::{
+    apply_content_edit, encode_event_frame, resolve_sticky_offsets, update_writes_only_comments,
+    wrap_stream_with_edit_guard,
+};
 use crate::infrastructure::realtime::{
     DynRealtimeSink, DynRealtimeStream, NoopBacklogReader, SqlxDocPersistenceAdapter,
     SqlxDocStateReader,
@@ -51,6 +59,8 @@ pub struct DocumentRoom {
     #[allow(dead_code)]
     persist_sub: yrs::Subscription,
     pub seq: Arc<Mutex<i64>>, // latest persisted seq
+    /// Flips to true once background hydration has been applied; closed if it failed.
+    pub hydrated: watch::Receiver<bool>,
 }
 
 #[derive(Clone)]
@@ -84,13 +94,20 @@ impl Hub {
             Arc::new(SqlxDocPersistenceAdapter::new(pool.clone()));
         let linkgraph_repo: Arc<dyn LinkGraphRepository> =
             Arc::new(SqlxLinkGraphRepository::new(pool.clone()));
-        let tagging_repo: Arc<dyn TaggingRepository> = Arc::new(SqlxTaggingRepository::new(pool));
+        let tagging_repo: Arc<dyn TaggingRepository> =
+            Arc::new(SqlxTaggingRepository::new(pool.clone()));
+        let search_index_repo: Arc<dyn SearchIndexRepository> =
+            Arc::new(SqlxSearchIndexRepository::new(pool.clone()));
+        let property_repo: Arc<dyn PropertyRepository> =
+            Arc::new(SqlxPropertyRepository::new(pool));
         let snapshot_service = Arc::new(SnapshotService::new(
             doc_state_reader,
             persistence.clone(),
             storage,
             linkgraph_repo,
             tagging_repo,
+            search_index_repo,
+            property_repo,
             archives,
         ));
 
@@ -273,12 +290,14 @@ impl Hub {
             })
             .unwrap();
 
+        let (hydrated_tx, hydrated_rx) = watch::channel(false);
         let room = Arc::new(DocumentRoom {
             doc: doc.clone(),
             awareness: awareness.clone(),
             broadcast: bcast.clone(),
             persist_sub,
             seq: seq.clone(),
+            hydrated: hydrated_rx,
         });
         self.inner
             .write()
@@ -329,6 +348,7 @@ impl Hub {
                             "hydrate:broadcast_failed"
                         );
                     }
+                    let _ = hydrated_tx.send(true);
                     tracing::debug!(document_id = %doc_uuid, "hydrate:complete");
                 }
                 Err(e) => {
@@ -381,6 +401,37 @@ impl Hub {
         Ok(())
     }
 
+    pub async fn edit_content(
+        &self,
+        doc_id: &str,
+        edit: &ContentEdit,
+        if_match: Option<&str>,
+    ) -> anyhow::Result<ContentState> {
+        let room = self.get_or_create(doc_id).await?;
+        // Editing before hydration lands would diff against an empty doc.
+        room.hydrated
+            .clone()
+            .wait_for(|ready| *ready)
+            .await
+            .map_err(|_| anyhow::anyhow!("document_hydration_failed"))?;
+
+        let (state, update_bytes) = apply_content_edit(&room.doc, edit, if_match)?;
+        if update_bytes.is_empty() {
+            return Ok(state);
+        }
+
+        let mut encoder = EncoderV1::new();
+        encoder.write_var(MSG_SYNC);
+        encoder.write_var(MSG_SYNC_UPDATE);
+        encoder.write_buf(&update_bytes);
+        // Persistence runs off the update observer; a room without sessions has nobody to notify.
+        if let Err(e) = room.broadcast.broadcast(encoder.to_vec()) {
+            tracing::debug!(document_id = %doc_id, error = %e, "content_edit_broadcast_skipped");
+        }
+
+        Ok(state)
+    }
+
     pub async fn get_content(&self, doc_id: &str) -> anyhow::Result<Option<String>> {
         if let Some(room) = self.inner.read().await.get(doc_id).cloned() {
             let txt = room.doc.get_or_insert_text("content");
@@ -456,37 +507,84 @@ impl Hub {
         Ok(())
     }
 
+    pub async fn publish_event(
+        &self,
+        doc_id: &str,
+        payload: &serde_json::Value,
+    ) -> anyhow::Result<()> {
+        // Only rooms with live sessions need the event; others pick state up over REST.
+        if let Some(room) = self.inner.read().await.get(doc_id).cloned() {
+            let frame = encode_event_frame(payload)?;
+            let _ = room.broadcast.broadcast(frame);
+        }
+        Ok(())
+    }
+
+    pub async fn resolve_anchors(
+        &self,
+        doc_id: &str,
+        anchors: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<Option<u32>>> {
+        if let Some(room) = self.inner.read().await.get(doc_id).cloned() {
+            return Ok(resolve_sticky_offsets(&room.doc, anchors));
+        }
+        let uuid = Uuid::parse_str(doc_id)?;
+        let hydrated = self
+            .hydration_service
+            .hydrate(&uuid, HydrationOptions::default())
+            .await?;
+        Ok(resolve_sticky_offsets(&hydrated.doc, anchors))
+    }
+
     pub async fn subscribe(
         &self,
         doc_id: &str,
         sink: DynRealtimeSink,
         stream: DynRealtimeStream,
-        can_edit: bool,
+        access: RealtimeAccess,
     ) -> anyhow::Result<()> {
         let room = self.get_or_create(doc_id).await?;
         let edit_flag = self.ensure_edit_flag(doc_id).await;
-        let effective_can_edit = can_edit && edit_flag.load(Ordering::Relaxed);
+        let effective_access = if edit_flag.load(Ordering::Relaxed) {
+            access
+        } else {
+            RealtimeAccess::ReadOnly
+        };
         let guarded_stream =
             wrap_stream_with_edit_guard(stream, doc_id.to_string(), edit_flag.clone());
         let tracked_clients: Arc<StdMutex<HashMap<ClientID, u32>>> =
             Arc::new(StdMutex::new(HashMap::new()));
         let awareness = room.awareness.clone();
-        let result = if effective_can_edit {
-            let subscription = room.broadcast.subscribe_with(
-                sink.clone(),
-                guarded_stream,
-                TrackingProtocol::new(DefaultProtocol, tracked_clients.clone()),
-            );
-            Self::send_protocol_start(sink.clone(), awareness.clone(), DefaultProtocol).await?;
-            subscription.completed().await
-        } else {
-            let subscription = room.broadcast.subscribe_with(
-                sink.clone(),
-                guarded_stream,
-                TrackingProtocol::new(ReadOnlyProtocol, tracked_clients.clone()),
-            );
-            Self::send_protocol_start(sink.clone(), awareness.clone(), ReadOnlyProtocol).await?;
-            subscription.completed().await
+        let result = match effective_access {
+            RealtimeAccess::Edit => {
+                let subscription = room.broadcast.subscribe_with(
+                    sink.clone(),
+                    guarded_stream,
+                    TrackingProtocol::new(DefaultProtocol, tracked_clients.clone()),
+                );
+                Self::send_protocol_start(sink.clone(), awareness.clone(), DefaultProtocol).await?;
+                subscription.completed().await
+            }
+            RealtimeAccess::Comment => {
+                let subscription = room.broadcast.subscribe_with(
+                    sink.clone(),
+                    guarded_stream,
+                    TrackingProtocol::new(CommentOnlyProtocol, tracked_clients.clone()),
+                );
+                Self::send_protocol_start(sink.clone(), awareness.clone(), CommentOnlyProtocol)
+                    .await?;
+                subscription.completed().await
+            }
+            RealtimeAccess::ReadOnly => {
+                let subscription = room.broadcast.subscribe_with(
+                    sink.clone(),
+                    guarded_stream,
+                    TrackingProtocol::new(ReadOnlyProtocol, tracked_clients.clone()),
+                );
+                Self::send_protocol_start(sink.clone(), awareness.clone(), ReadOnlyProtocol)
+                    .await?;
+                subscription.completed().await
+            }
         };
 
         Self::cleanup_tracked_clients(awareness, tracked_clients);
@@ -560,6 +658,33 @@ impl yrs::sync::Protocol for ReadOnlyProtocol {
     }
 }
 
+/// Accepts only updates that write to the comment threads root.
+#[derive(Debug, Clone, Copy)]
+struct CommentOnlyProtocol;
+
+impl yrs::sync::Protocol for CommentOnlyProtocol {
+    fn handle_sync_step2(
+        &self,
+        awareness: &yrs::sync::Awareness,
+        update: yrs::Update,
+    ) -> Result<Option<yrs::sync::Message>, yrs::sync::Error> {
+        self.handle_update(awareness, update)
+    }
+
+    fn handle_update(
+        &self,
+        awareness: &yrs::sync::Awareness,
+        update: yrs::Update,
+    ) -> Result<Option<yrs::sync::Message>, yrs::sync::Error> {
+        if update_writes_only_comments(awareness.doc(), &update.encode_v1()) {
+            DefaultProtocol.handle_update(awareness, update)
+        } else {
+            tracing::warn!("ignored_non_comment_update_from_comment_client");
+            Ok(None)
+        }
+    }
+}
+
 struct TrackingProtocol<P> {
     inner: P,
     tracked: Arc<StdMutex<HashMap<ClientID, u32>>>,
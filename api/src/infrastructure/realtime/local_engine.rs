@@ -1,5 +1,7 @@
 use crate::application::ports::realtime_port::RealtimeEngine;
-use crate::application::ports::realtime_types::{DynRealtimeSink, DynRealtimeStream};
+use crate::application::ports::realtime_types::{
+    ContentEdit, ContentState, DynRealtimeSink, DynRealtimeStream, RealtimeAccess,
+};
 use yrs::Doc;
 
 pub struct LocalRealtimeEngine {
@@ -13,9 +15,9 @@ impl RealtimeEngine for LocalRealtimeEngine {
         doc_id: &str,
         sink: DynRealtimeSink,
         stream: DynRealtimeStream,
-        can_edit: bool,
+        access: RealtimeAccess,
     ) -> anyhow::Result<()> {
-        self.hub.subscribe(doc_id, sink, stream, can_edit).await
+        self.hub.subscribe(doc_id, sink, stream, access).await
     }
 
     async fn get_content(&self, doc_id: &str) -> anyhow::Result<Option<String>> {
@@ -30,7 +32,28 @@ impl RealtimeEngine for LocalRealtimeEngine {
         self.hub.apply_snapshot(doc_id, doc).await
     }
 
+    async fn edit_content(
+        &self,
+        doc_id: &str,
+        edit: &ContentEdit,
+        if_match: Option<&str>,
+    ) -> anyhow::Result<ContentState> {
+        self.hub.edit_content(doc_id, edit, if_match).await
+    }
+
     async fn set_document_editable(&self, doc_id: &str, editable: bool) -> anyhow::Result<()> {
         self.hub.set_document_editable(doc_id, editable).await
     }
+
+    async fn publish_event(&self, doc_id: &str, payload: &serde_json::Value) -> anyhow::Result<()> {
+        self.hub.publish_event(doc_id, payload).await
+    }
+
+    async fn resolve_anchors(
+        &self,
+        doc_id: &str,
+        anchors: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<Option<u32>>> {
+        self.hub.resolve_anchors(doc_id, anchors).await
+    }
 }
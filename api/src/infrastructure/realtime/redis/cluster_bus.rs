@@ -13,7 +13,29 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 
 const FIELD_FRAME: &str = "frame";
 const FIELD_AWARENESS: &str = "awareness";
+const FIELD_EVENT: &str = "event";
+// Events are never replayed, so their stream stays capped even without a configured max length.
+const EVENT_STREAM_MAX_LEN: usize = 1024;
 const FIELD_TASK_DOC: &str = "doc";
+const EDIT_LOCK_RETRY: Duration = Duration::from_millis(50);
+
+// Appends to the updates stream only while its newest entry is still ARGV[1] ("" when empty).
+const APPEND_IF_TAIL_SCRIPT: &str = r#"
+local last = redis.call('XREVRANGE', KEYS[1], '+', '-', 'COUNT', 1)
+local tail = ''
+if #last > 0 then tail = last[1][1] end
+if tail ~= ARGV[1] then return false end
+if ARGV[2] ~= '' then
+  return redis.call('XADD', KEYS[1], 'MAXLEN', '~', ARGV[2], '*', ARGV[3], ARGV[4])
+end
+return redis.call('XADD', KEYS[1], '*', ARGV[3], ARGV[4])
+"#;
+
+// Deletes the lock only if it still holds the caller's token.
+const RELEASE_LOCK_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then return redis.call('DEL', KEYS[1]) end
+return 0
+"#;
 
 #[derive(Clone)]
 pub struct RedisClusterBus {
@@ -50,10 +72,18 @@ impl RedisClusterBus {
         format!("{}:{}:awareness", self.stream_prefix, doc_id)
     }
 
+    fn events_key(&self, doc_id: &str) -> String {
+        format!("{}:{}:events", self.stream_prefix, doc_id)
+    }
+
     fn tasks_key(&self) -> String {
         format!("{}:tasks", self.stream_prefix)
     }
 
+    fn edit_lock_key(&self, doc_id: &str) -> String {
+        format!("{}:{}:edit_lock", self.stream_prefix, doc_id)
+    }
+
     pub async fn publish_update(&self, doc_id: &str, frame: Vec<u8>) -> anyhow::Result<String> {
         let mut conn = self
             .client
@@ -74,7 +104,95 @@ impl RedisClusterBus {
             .await
             .context("redis_xadd_update")?;
 
-        // Schedule a background persistence task (best effort)
+        self.schedule_persist(&mut conn, doc_id).await;
+        Ok(id)
+    }
+
+    /// Publishes `frame` only if the newest update is still `expected_tail` (`None`: no updates),
+    /// so a server-side edit never lands on top of updates it did not see. `None` when the tail moved.
+    pub async fn publish_update_if_tail(
+        &self,
+        doc_id: &str,
+        expected_tail: Option<&str>,
+        frame: Vec<u8>,
+    ) -> anyhow::Result<Option<String>> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .context("redis_get_async_connection")?;
+        let max_len = self
+            .stream_max_len
+            .map(|len| len.to_string())
+            .unwrap_or_default();
+        let id: Option<String> = redis::Script::new(APPEND_IF_TAIL_SCRIPT)
+            .key(self.updates_key(doc_id))
+            .arg(expected_tail.unwrap_or(""))
+            .arg(max_len)
+            .arg(FIELD_FRAME)
+            .arg(frame)
+            .invoke_async(&mut conn)
+            .await
+            .context("redis_xadd_update_if_tail")?;
+        if id.is_some() {
+            self.schedule_persist(&mut conn, doc_id).await;
+        }
+        Ok(id)
+    }
+
+    /// Takes the document's edit lock, waiting up to `wait`; returns the token to release it with,
+    /// `None` when it stayed taken. The lock expires after `ttl` so a crashed holder cannot block edits for long.
+    pub async fn acquire_edit_lock(
+        &self,
+        doc_id: &str,
+        ttl: Duration,
+        wait: Duration,
+    ) -> anyhow::Result<Option<String>> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .context("redis_get_async_connection")?;
+        let key = self.edit_lock_key(doc_id);
+        let token = uuid::Uuid::new_v4().to_string();
+        let deadline = tokio::time::Instant::now() + wait;
+        loop {
+            let acquired: Option<String> = redis::cmd("SET")
+                .arg(&key)
+                .arg(&token)
+                .arg("NX")
+                .arg("PX")
+                .arg(ttl.as_millis() as u64)
+                .query_async(&mut conn)
+                .await
+                .context("redis_set_edit_lock")?;
+            if acquired.is_some() {
+                return Ok(Some(token));
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Ok(None);
+            }
+            sleep(EDIT_LOCK_RETRY).await;
+        }
+    }
+
+    pub async fn release_edit_lock(&self, doc_id: &str, token: &str) -> anyhow::Result<()> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .context("redis_get_async_connection")?;
+        let _: i64 = redis::Script::new(RELEASE_LOCK_SCRIPT)
+            .key(self.edit_lock_key(doc_id))
+            .arg(token)
+            .invoke_async(&mut conn)
+            .await
+            .context("redis_release_edit_lock")?;
+        Ok(())
+    }
+
+    // Schedule a background persistence task (best effort)
+    async fn schedule_persist(&self, conn: &mut redis::aio::MultiplexedConnection, doc_id: &str) {
         let mut task_cmd = redis::cmd("XADD");
         task_cmd.arg(self.tasks_key());
         if let Some(max_len) = self.stream_max_len {
@@ -84,9 +202,8 @@ impl RedisClusterBus {
             .arg("*")
             .arg("doc")
             .arg(doc_id)
-            .query_async::<()>(&mut conn)
+            .query_async::<()>(conn)
             .await;
-        Ok(id)
     }
 
     pub async fn publish_awareness(
@@ -115,6 +232,28 @@ impl RedisClusterBus {
         Ok(id)
     }
 
+    pub async fn publish_event(&self, doc_id: &str, frame: Vec<u8>) -> anyhow::Result<String> {
+        let mut conn = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .context("redis_get_async_connection")?;
+        let key = self.events_key(doc_id);
+        let max_len = self.stream_max_len.unwrap_or(EVENT_STREAM_MAX_LEN);
+        let id: String = redis::cmd("XADD")
+            .arg(&key)
+            .arg("MAXLEN")
+            .arg("~")
+            .arg(max_len as i64)
+            .arg("*")
+            .arg(FIELD_EVENT)
+            .arg(frame)
+            .query_async(&mut conn)
+            .await
+            .context("redis_xadd_event")?;
+        Ok(id)
+    }
+
     pub async fn read_update_backlog(
         &self,
         doc_id: &str,
@@ -191,6 +330,15 @@ impl RedisClusterBus {
         Ok(self.spawn_stream_reader_bytes(key, FIELD_AWARENESS, start_id))
     }
 
+    // Sessions only follow events added after they join.
+    pub async fn subscribe_events(
+        &self,
+        doc_id: &str,
+    ) -> anyhow::Result<UnboundedReceiverStream<anyhow::Result<StreamItem>>> {
+        let key = self.events_key(doc_id);
+        Ok(self.spawn_stream_reader_bytes(key, FIELD_EVENT, None))
+    }
+
     pub async fn subscribe_tasks(
         &self,
         start_id: Option<String>,
@@ -21,10 +21,14 @@ use yrs::{Doc, GetString, ReadTxn, StateVector, Text, Transact};
 use crate::application::ports::awareness_port::AwarenessPublisher;
 use crate::application::ports::document_snapshot_archive_repository::DocumentSnapshotArchiveRepository;
 use crate::application::ports::linkgraph_repository::LinkGraphRepository;
+use crate::application::ports::property_repository::PropertyRepository;
 use crate::application::ports::realtime_hydration_port::{DocStateReader, RealtimeBacklogReader};
 use crate::application::ports::realtime_persistence_port::DocPersistencePort;
 use crate::application::ports::realtime_port::RealtimeEngine as RealtimeEngineTrait;
-use crate::application::ports::realtime_types::{DynRealtimeSink, DynRealtimeStream};
+use crate::application::ports::realtime_types::{
+    ContentEdit, ContentState, DynRealtimeSink, DynRealtimeStream, RealtimeAccess,
+};
+use crate::application::ports::search_index_repository::SearchIndexRepository;
 use crate::application::ports::storage_port::StoragePort;
 use crate::application::ports::tagging_repository::TaggingRepository;
 use crate::application::services::realtime::awareness::{AwarenessService, encode_awareness_state};
@@ -38,12 +42,21 @@ use crate::bootstrap::config::Config;
 use crate::infrastructure::db::PgPool;
 use crate::infrastructure::db::repositories::document_snapshot_archive_repository_sqlx::SqlxDocumentSnapshotArchiveRepository;
 use crate::infrastructure::db::repositories::linkgraph_repository_sqlx::SqlxLinkGraphRepository;
+use crate::infrastructure::db::repositories::property_repository_sqlx::SqlxPropertyRepository;
+use crate::infrastructure::db::repositories::search_index_repository_sqlx::SqlxSearchIndexRepository;
 use crate::infrastructure::db::repositories::tagging_repository_sqlx::SqlxTaggingRepository;
-use crate::infrastructure::realtime::utils::{analyse_frame, wrap_stream_with_edit_guard};
+use crate::infrastructure::realtime::utils::{
+    analyse_frame, apply_content_edit, apply_frame_updates, encode_event_frame,
+    frame_writes_only_comments, resolve_sticky_offsets, wrap_stream_with_edit_guard,
+};
 use crate::infrastructure::realtime::{SqlxDocPersistenceAdapter, SqlxDocStateReader};
 
 use super::cluster_bus::{RedisClusterBus, StreamItem};
 
+const EDIT_LOCK_TTL: Duration = Duration::from_secs(10);
+const EDIT_LOCK_WAIT: Duration = Duration::from_secs(5);
+const EDIT_TAIL_ATTEMPTS: usize = 5;
+
 pub struct RedisRealtimeEngine {
     bus: Arc<RedisClusterBus>,
     hydration_service: Arc<DocHydrationService>,
@@ -86,6 +99,10 @@ impl RedisRealtimeEngine {
             Arc::new(SqlxLinkGraphRepository::new(pool.clone()));
         let tagging_repo: Arc<dyn TaggingRepository> =
             Arc::new(SqlxTaggingRepository::new(pool.clone()));
+        let search_index_repo: Arc<dyn SearchIndexRepository> =
+            Arc::new(SqlxSearchIndexRepository::new(pool.clone()));
+        let property_repo: Arc<dyn PropertyRepository> =
+            Arc::new(SqlxPropertyRepository::new(pool.clone()));
         let archive_repo: Arc<dyn DocumentSnapshotArchiveRepository> =
             Arc::new(SqlxDocumentSnapshotArchiveRepository::new(pool.clone()));
         let snapshot_service = Arc::new(SnapshotService::new(
@@ -94,6 +111,8 @@ impl RedisRealtimeEngine {
             storage.clone(),
             linkgraph_repo,
             tagging_repo,
+            search_index_repo,
+            property_repo,
             archive_repo,
         ));
         let auto_archive_interval = Duration::from_secs(cfg.snapshot_archive_interval_secs);
@@ -172,17 +191,30 @@ impl RedisRealtimeEngine {
         Ok(())
     }
 
+    /// Forwards bus frames to the client; update frames are first applied to `mirror`, if any,
+    /// so it is never behind what the client has seen.
     fn spawn_forward_task(
         mut stream: UnboundedReceiverStream<anyhow::Result<StreamItem>>,
         sink: DynRealtimeSink,
         doc_id: String,
         channel: &'static str,
         awareness_manager: Option<AwarenessService>,
+        mirror: Option<Doc>,
     ) -> JoinHandle<()> {
         tokio::spawn(async move {
             while let Some(item) = stream.next().await {
                 match item {
                     Ok((_id, frame)) => {
+                        if let Some(doc) = &mirror
+                            && let Err(e) = apply_frame_updates(doc, &frame)
+                        {
+                            tracing::debug!(
+                                document_id = %doc_id,
+                                channel,
+                                error = ?e,
+                                "redis_cluster_mirror_apply_failed"
+                            );
+                        }
                         if let Some(manager) = &awareness_manager {
                             if let Err(e) = manager.apply_remote_frame(&frame).await {
                                 tracing::debug!(
@@ -223,7 +255,7 @@ impl RealtimeEngineTrait for RedisRealtimeEngine {
         doc_id: &str,
         sink: DynRealtimeSink,
         stream: DynRealtimeStream,
-        can_edit: bool,
+        access: RealtimeAccess,
     ) -> anyhow::Result<()> {
         let doc_uuid = Uuid::parse_str(doc_id)?;
         let hydrated = self
@@ -240,10 +272,12 @@ impl RealtimeEngineTrait for RedisRealtimeEngine {
         let ttl_handle = awareness_service.spawn_ttl_task();
         let mut updates_handle: Option<JoinHandle<()>> = None;
         let mut awareness_handle: Option<JoinHandle<()>> = None;
+        let mut events_handle: Option<JoinHandle<()>> = None;
 
         let result: anyhow::Result<()> = async {
             let edit_flag = self.ensure_edit_flag(doc_id).await;
-            let session_can_edit = can_edit && edit_flag.load(Ordering::Relaxed);
+            let session_can_edit =
+                access == RealtimeAccess::Edit && edit_flag.load(Ordering::Relaxed);
             let mut guarded_stream =
                 wrap_stream_with_edit_guard(stream, doc_id.to_string(), edit_flag.clone());
 
@@ -276,12 +310,16 @@ impl RealtimeEngineTrait for RedisRealtimeEngine {
                 .subscribe_awareness(doc_id, hydrated.last_awareness_stream_id.clone())
                 .await?;
 
+            // Comment sessions check each frame against the document; keep the hydrated copy
+            // current from the bus instead of rehydrating per frame.
+            let comment_doc = (access == RealtimeAccess::Comment).then(|| hydrated.doc.clone());
             updates_handle = Some(Self::spawn_forward_task(
                 updates_stream,
                 sink.clone(),
                 doc_id.to_string(),
                 "updates",
                 None,
+                comment_doc.clone(),
             ));
             awareness_handle = Some(Self::spawn_forward_task(
                 awareness_stream,
@@ -289,6 +327,16 @@ impl RealtimeEngineTrait for RedisRealtimeEngine {
                 doc_id.to_string(),
                 "awareness",
                 Some(awareness_service.clone()),
+                None,
+            ));
+            let events_stream = self.bus.subscribe_events(doc_id).await?;
+            events_handle = Some(Self::spawn_forward_task(
+                events_stream,
+                sink.clone(),
+                doc_id.to_string(),
+                "events",
+                None,
+                None,
             ));
 
             while let Some(frame) = guarded_stream.next().await {
@@ -296,7 +344,14 @@ impl RealtimeEngineTrait for RedisRealtimeEngine {
                     Ok(bytes) => match analyse_frame(&bytes) {
                         Ok(summary) => {
                             if summary.has_update {
-                                let allow_edit = can_edit && edit_flag.load(Ordering::Relaxed);
+                                let allow_edit = match access {
+                                    _ if !edit_flag.load(Ordering::Relaxed) => false,
+                                    RealtimeAccess::Edit => true,
+                                    RealtimeAccess::Comment => comment_doc
+                                        .as_ref()
+                                        .is_some_and(|doc| comment_update_allowed(doc, &bytes)),
+                                    RealtimeAccess::ReadOnly => false,
+                                };
                                 if !allow_edit {
                                     tracing::warn!(
                                         document_id = %doc_id,
@@ -361,6 +416,9 @@ impl RealtimeEngineTrait for RedisRealtimeEngine {
         if let Some(handle) = awareness_handle {
             handle.abort();
         }
+        if let Some(handle) = events_handle {
+            handle.abort();
+        }
         if let Err(err) = awareness_service.clear_local_clients().await {
             tracing::debug!(document_id = %doc_id, error = ?err, "redis_cluster_awareness_clear_failed");
         }
@@ -437,11 +495,54 @@ impl RealtimeEngineTrait for RedisRealtimeEngine {
         Ok(())
     }
 
+    async fn edit_content(
+        &self,
+        doc_id: &str,
+        edit: &ContentEdit,
+        if_match: Option<&str>,
+    ) -> anyhow::Result<ContentState> {
+        let uuid = Uuid::parse_str(doc_id)?;
+        // Serialises server-side edits across nodes; client updates are caught by the tail check.
+        let Some(token) = self
+            .bus
+            .acquire_edit_lock(doc_id, EDIT_LOCK_TTL, EDIT_LOCK_WAIT)
+            .await?
+        else {
+            anyhow::bail!("conflict");
+        };
+        let result = self
+            .edit_content_locked(&uuid, doc_id, edit, if_match)
+            .await;
+        if let Err(err) = self.bus.release_edit_lock(doc_id, &token).await {
+            tracing::warn!(document_id = %doc_id, error = ?err, "redis_edit_lock_release_failed");
+        }
+        result
+    }
+
     async fn set_document_editable(&self, doc_id: &str, editable: bool) -> anyhow::Result<()> {
         let flag = self.ensure_edit_flag(doc_id).await;
         flag.store(editable, Ordering::SeqCst);
         Ok(())
     }
+
+    async fn publish_event(&self, doc_id: &str, payload: &serde_json::Value) -> anyhow::Result<()> {
+        let frame = encode_event_frame(payload)?;
+        self.bus.publish_event(doc_id, frame).await?;
+        Ok(())
+    }
+
+    async fn resolve_anchors(
+        &self,
+        doc_id: &str,
+        anchors: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<Option<u32>>> {
+        let uuid = Uuid::parse_str(doc_id)?;
+        let hydrated = self
+            .hydration_service
+            .hydrate(&uuid, HydrationOptions::default())
+            .await?;
+        Ok(resolve_sticky_offsets(&hydrated.doc, anchors))
+    }
 }
 
 fn spawn_persistence_worker(
@@ -648,6 +749,53 @@ impl RedisRealtimeEngine {
         guard.send(frame).await.map_err(|err| anyhow!(err))?;
         Ok(())
     }
+
+    /// Hydrates, checks and diffs, then publishes only if no update landed since the hydrate;
+    /// otherwise starts over on the newer state, so `if_match` is always checked against it.
+    async fn edit_content_locked(
+        &self,
+        uuid: &Uuid,
+        doc_id: &str,
+        edit: &ContentEdit,
+        if_match: Option<&str>,
+    ) -> anyhow::Result<ContentState> {
+        for _ in 0..EDIT_TAIL_ATTEMPTS {
+            let hydrated = self
+                .hydration_service
+                .hydrate(uuid, HydrationOptions::default())
+                .await?;
+            let (state, update_bytes) = apply_content_edit(&hydrated.doc, edit, if_match)?;
+            if update_bytes.is_empty() {
+                return Ok(state);
+            }
+            let mut encoder = EncoderV1::new();
+            encoder.write_var(MSG_SYNC);
+            encoder.write_var(MSG_SYNC_UPDATE);
+            encoder.write_buf(&update_bytes);
+            let published = self
+                .bus
+                .publish_update_if_tail(
+                    doc_id,
+                    hydrated.last_update_stream_id.as_deref(),
+                    encoder.to_vec(),
+                )
+                .await?;
+            if published.is_some() {
+                return Ok(state);
+            }
+        }
+        // Clients kept typing through every attempt; the caller can retry.
+        anyhow::bail!("conflict")
+    }
+}
+
+/// Admits a comment session's frame when it writes only comment threads, and applies it to
+/// the session's copy right away so the client's next frame can build on it.
+fn comment_update_allowed(doc: &Doc, frame: &[u8]) -> bool {
+    if !frame_writes_only_comments(doc, frame).unwrap_or(false) {
+        return false;
+    }
+    apply_frame_updates(doc, frame).is_ok()
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -670,3 +818,52 @@ impl yrs::sync::Protocol for ReadOnlyProtocol {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yrs::updates::decoder::Decode;
+    use yrs::{Map, WriteTxn};
+
+    fn update_frame(update: Vec<u8>) -> Vec<u8> {
+        let mut encoder = EncoderV1::new();
+        encoder.write_var(MSG_SYNC);
+        encoder.write_var(MSG_SYNC_UPDATE);
+        encoder.write_buf(&update);
+        encoder.to_vec()
+    }
+
+    #[test]
+    fn comment_frames_build_on_each_other_without_rehydrating() {
+        let server = Doc::new();
+        server.get_or_insert_text("content");
+        let client = Doc::new();
+        {
+            let state = server
+                .transact()
+                .encode_state_as_update_v1(&StateVector::default());
+            let mut txn = client.transact_mut();
+            txn.apply_update(yrs::Update::decode_v1(&state).unwrap())
+                .unwrap();
+        }
+        let comments = client.transact_mut().get_or_insert_map("comments");
+        let mut frames = Vec::new();
+        for key in ["a", "b"] {
+            let before = client.transact().state_vector();
+            comments.insert(&mut client.transact_mut(), key, "note");
+            frames.push(update_frame(client.transact().encode_diff_v1(&before)));
+        }
+
+        // The second frame depends on the first, which the mirror has taken in
+        assert!(comment_update_allowed(&server, &frames[0]));
+        assert!(comment_update_allowed(&server, &frames[1]));
+
+        let before = client.transact().state_vector();
+        let content = client.transact_mut().get_or_insert_text("content");
+        content.insert(&mut client.transact_mut(), 0, "rewritten");
+        let frame = update_frame(client.transact().encode_diff_v1(&before));
+        assert!(!comment_update_allowed(&server, &frame));
+        let txt = server.get_or_insert_text("content");
+        assert_eq!(txt.get_string(&server.transact()), "");
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::ops::Range;
 use std::pin::Pin;
 use std::result::Result as StdResult;
 use std::sync::Arc;
@@ -7,12 +9,64 @@ use std::task::{Context, Poll};
 use anyhow::Result;
 use futures_util::Stream;
 use tracing::{debug, warn};
-use yrs::encoding::read::Cursor;
+use yrs::block::{
+    BLOCK_GC_REF_NUMBER, BLOCK_SKIP_REF_NUMBER, ClientID, HAS_ORIGIN, HAS_PARENT_SUB,
+    HAS_RIGHT_ORIGIN, ItemContent,
+};
+use yrs::encoding::read::{Cursor, Read};
 use yrs::sync::{Message, MessageReader, SyncMessage};
-use yrs::updates::decoder::DecoderV1;
+use yrs::updates::decoder::{Decode, Decoder, DecoderV1};
+use yrs::updates::encoder::Encode;
+use yrs::{
+    Assoc, BranchID, DeleteSet, Doc, GetString, ID, OffsetKind, Options, ReadTxn, StateVector,
+    StickyIndex, Text, Transact, Update,
+};
 
 use crate::application::ports::realtime_port::RealtimeError;
-use crate::application::ports::realtime_types::DynRealtimeStream;
+use crate::application::ports::realtime_types::{ContentEdit, ContentState, DynRealtimeStream};
+use crate::application::services::realtime::content_edit::{
+    apply_edit, content_hash, minimal_splices,
+};
+
+/// Custom y-sync message tag carrying JSON application events (e.g. comment changes).
+pub const MSG_APP_EVENT: u8 = 100;
+
+/// Root type holding comment threads; the only one comment-only sessions may write.
+pub const COMMENTS_ROOT: &str = "comments";
+
+pub fn encode_event_frame(payload: &serde_json::Value) -> Result<Vec<u8>> {
+    let data = serde_json::to_vec(payload)?;
+    Ok(Message::Custom(MSG_APP_EVENT, data).encode_v1())
+}
+
+/// Resolves encoded sticky indexes (Y.RelativePosition) against `doc`, counting UTF-16 code
+/// units like Yjs clients do. Undecodable or dangling anchors resolve to `None`.
+pub fn resolve_sticky_offsets(doc: &Doc, anchors: &[Vec<u8>]) -> Vec<Option<u32>> {
+    let state = doc
+        .transact()
+        .encode_state_as_update_v1(&StateVector::default());
+    let scratch = Doc::with_options(Options {
+        offset_kind: OffsetKind::Utf16,
+        ..Options::default()
+    });
+    scratch.get_or_insert_text("content");
+    if let Ok(update) = Update::decode_v1(&state) {
+        let mut txn = scratch.transact_mut();
+        if txn.apply_update(update).is_err() {
+            return vec![None; anchors.len()];
+        }
+    }
+    let txn = scratch.transact();
+    anchors
+        .iter()
+        .map(|bytes| {
+            StickyIndex::decode_v1(bytes)
+                .ok()
+                .and_then(|idx| idx.get_offset(&txn))
+                .map(|offset| offset.index)
+        })
+        .collect()
+}
 
 pub fn analyse_frame(frame: &[u8]) -> Result<FrameSummary> {
     let mut decoder = DecoderV1::new(Cursor::new(frame));
@@ -32,6 +86,320 @@ pub fn analyse_frame(frame: &[u8]) -> Result<FrameSummary> {
     Ok(summary)
 }
 
+/// Returns true when every update carried by `frame` writes only to the [`COMMENTS_ROOT`] of
+/// `doc`. Used to admit comment-only sessions.
+pub fn frame_writes_only_comments(doc: &Doc, frame: &[u8]) -> Result<bool> {
+    let mut decoder = DecoderV1::new(Cursor::new(frame));
+    let reader = MessageReader::new(&mut decoder);
+    for message in reader {
+        match message? {
+            Message::Sync(SyncMessage::Update(update))
+            | Message::Sync(SyncMessage::SyncStep2(update))
+                if !update_writes_only_comments(doc, &update) =>
+            {
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+    Ok(true)
+}
+
+/// Applies the document updates carried by a sync frame to `doc`; other messages are skipped.
+pub fn apply_frame_updates(doc: &Doc, frame: &[u8]) -> Result<()> {
+    let mut decoder = DecoderV1::new(Cursor::new(frame));
+    let reader = MessageReader::new(&mut decoder);
+    for message in reader {
+        if let Message::Sync(SyncMessage::Update(update))
+        | Message::Sync(SyncMessage::SyncStep2(update)) = message?
+        {
+            let update = Update::decode_v1(&update)?;
+            doc.transact_mut().apply_update(update)?;
+        }
+    }
+    Ok(())
+}
+
+/// Traces every item a v1 `update` inserts or deletes to the root type it lives under, without
+/// applying it. Only updates that delete existing items consult `doc`'s delete set. Items that
+/// cannot be traced through the update or `doc` are rejected, since their pending parts could
+/// later land anywhere.
+pub fn update_writes_only_comments(doc: &Doc, update: &[u8]) -> bool {
+    let Some(scan) = UpdateScan::decode(update) else {
+        return false;
+    };
+    let txn = doc.transact();
+    scan.writes_only(&txn, COMMENTS_ROOT)
+}
+
+// Where an item hangs: under a named root, or beside/inside another item.
+enum Anchor {
+    Root(Arc<str>),
+    Item(ID),
+    Gc,
+}
+
+struct ScannedBlock {
+    client: ClientID,
+    clocks: Range<u32>,
+    anchor: Anchor,
+}
+
+struct UpdateScan {
+    blocks: Vec<ScannedBlock>,
+    // Block indexes per client, in clock order
+    by_client: HashMap<ClientID, Vec<usize>>,
+    deletes: DeleteSet,
+}
+
+impl UpdateScan {
+    // Mirrors yrs' v1 update decoding, keeping only what locates each block.
+    fn decode(update: &[u8]) -> Option<Self> {
+        let mut decoder = DecoderV1::new(Cursor::new(update));
+        let clients: u32 = decoder.read_var().ok()?;
+        let mut blocks = Vec::new();
+        for _ in 0..clients {
+            let len: u32 = decoder.read_var().ok()?;
+            let client = decoder.read_client().ok()?;
+            let mut clock: u32 = decoder.read_var().ok()?;
+            for _ in 0..len {
+                let info = decoder.read_info().ok()?;
+                let (block_len, anchor) = match info {
+                    BLOCK_SKIP_REF_NUMBER => {
+                        clock = clock.checked_add(decoder.read_var().ok()?)?;
+                        continue;
+                    }
+                    BLOCK_GC_REF_NUMBER => (decoder.read_len().ok()?, Anchor::Gc),
+                    info => {
+                        let origin = if info & HAS_ORIGIN != 0 {
+                            Some(decoder.read_left_id().ok()?)
+                        } else {
+                            None
+                        };
+                        let right_origin = if info & HAS_RIGHT_ORIGIN != 0 {
+                            Some(decoder.read_right_id().ok()?)
+                        } else {
+                            None
+                        };
+                        // Integration takes the parent from the left origin, then the right one.
+                        let anchor = match origin.or(right_origin) {
+                            Some(id) => Anchor::Item(id),
+                            None => {
+                                let anchor = if decoder.read_parent_info().ok()? {
+                                    Anchor::Root(decoder.read_string().ok()?.into())
+                                } else {
+                                    Anchor::Item(decoder.read_left_id().ok()?)
+                                };
+                                if info & HAS_PARENT_SUB != 0 {
+                                    decoder.read_string().ok()?;
+                                }
+                                anchor
+                            }
+                        };
+                        let content = ItemContent::decode(&mut decoder, info).ok()?;
+                        // Moves can pull ranges out of other types.
+                        if matches!(content, ItemContent::Move(_)) {
+                            return None;
+                        }
+                        (content.len(OffsetKind::Utf16), anchor)
+                    }
+                };
+                let end = clock.checked_add(block_len)?;
+                if block_len > 0 {
+                    blocks.push(ScannedBlock {
+                        client,
+                        clocks: clock..end,
+                        anchor,
+                    });
+                }
+                clock = end;
+            }
+        }
+        let deletes = DeleteSet::decode(&mut decoder).ok()?;
+        let mut by_client: HashMap<ClientID, Vec<usize>> = HashMap::new();
+        for (idx, block) in blocks.iter().enumerate() {
+            by_client.entry(block.client).or_default().push(idx);
+        }
+        for idxs in by_client.values_mut() {
+            idxs.sort_by_key(|&idx| blocks[idx].clocks.start);
+        }
+        Some(Self {
+            blocks,
+            by_client,
+            deletes,
+        })
+    }
+
+    fn writes_only<T: ReadTxn>(&self, txn: &T, root: &str) -> bool {
+        let mut roots = vec![None; self.blocks.len()];
+        for idx in 0..self.blocks.len() {
+            if matches!(self.blocks[idx].anchor, Anchor::Gc) {
+                continue;
+            }
+            if self.block_root(idx, txn, &mut roots).as_deref() != Some(root) {
+                return false;
+            }
+        }
+
+        // Deleting items this update inserts is covered by the checks above.
+        let mut pending: Vec<(ClientID, Vec<Range<u32>>)> = Vec::new();
+        for (client, ranges) in self.deletes.iter() {
+            let mut ranges: Vec<Range<u32>> = ranges.iter().cloned().collect();
+            ranges.sort_by_key(|r| r.start);
+            let own: Vec<Range<u32>> = self
+                .by_client
+                .get(client)
+                .into_iter()
+                .flatten()
+                .map(|&idx| self.blocks[idx].clocks.clone())
+                .collect();
+            let rest = uncovered(&ranges, &own);
+            if !rest.is_empty() {
+                pending.push((*client, rest));
+            }
+        }
+        if pending.is_empty() {
+            return true;
+        }
+
+        // Clients resend deletes the document already has (e.g. in sync step 2).
+        let snapshot = txn.snapshot();
+        let mut deleted: HashMap<ClientID, Vec<Range<u32>>> = HashMap::new();
+        for (client, ranges) in snapshot.delete_set.iter() {
+            let mut ranges: Vec<Range<u32>> = ranges.iter().cloned().collect();
+            ranges.sort_by_key(|r| r.start);
+            deleted.insert(*client, ranges);
+        }
+        for (client, ranges) in pending {
+            if ranges
+                .last()
+                .is_some_and(|r| r.end > snapshot.state_map.get(&client))
+            {
+                return false;
+            }
+            let live = uncovered(&ranges, deleted.get(&client).map_or(&[], Vec::as_slice));
+            for clock in live.into_iter().flatten() {
+                if doc_item_root(txn, ID::new(client, clock)).as_deref() != Some(root) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    // Follows anchors through this update until a root or an item already in the doc.
+    fn block_root<T: ReadTxn>(
+        &self,
+        start: usize,
+        txn: &T,
+        roots: &mut [Option<Option<Arc<str>>>],
+    ) -> Option<Arc<str>> {
+        let mut path = Vec::new();
+        let mut idx = start;
+        let root = loop {
+            if let Some(root) = &roots[idx] {
+                // Also ends cycles, as blocks on the path are marked unresolved below.
+                break root.clone();
+            }
+            roots[idx] = Some(None);
+            path.push(idx);
+            match &self.blocks[idx].anchor {
+                Anchor::Root(name) => break Some(name.clone()),
+                Anchor::Gc => break None,
+                Anchor::Item(id) => match self.find(id) {
+                    Some(next) => idx = next,
+                    None => break doc_item_root(txn, *id),
+                },
+            }
+        };
+        for idx in path {
+            roots[idx] = Some(root.clone());
+        }
+        root
+    }
+
+    fn find(&self, id: &ID) -> Option<usize> {
+        let idxs = self.by_client.get(&id.client)?;
+        let at = idxs.partition_point(|&idx| self.blocks[idx].clocks.end <= id.clock);
+        idxs.get(at)
+            .copied()
+            .filter(|&idx| self.blocks[idx].clocks.contains(&id.clock))
+    }
+}
+
+// Root type holding an item of `txn`, climbing out of nested types.
+fn doc_item_root<T: ReadTxn>(txn: &T, mut id: ID) -> Option<Arc<str>> {
+    loop {
+        let offset = StickyIndex::from_id(id, Assoc::After).get_offset(txn)?;
+        match offset.branch.id() {
+            BranchID::Root(name) => return Some(name),
+            BranchID::Nested(parent) => id = parent,
+        }
+    }
+}
+
+// Parts of `ranges` outside `covered`; both sorted by start.
+fn uncovered(ranges: &[Range<u32>], covered: &[Range<u32>]) -> Vec<Range<u32>> {
+    let mut out = Vec::new();
+    for range in ranges {
+        let mut start = range.start;
+        for c in covered
+            .iter()
+            .skip_while(|c| c.end <= range.start)
+            .take_while(|c| c.start < range.end)
+        {
+            if c.start > start {
+                out.push(start..c.start);
+            }
+            start = start.max(c.end);
+        }
+        if start < range.end {
+            out.push(start..range.end);
+        }
+    }
+    out
+}
+
+/// Applies `edit` to the `content` text of `doc` as minimal splices within one transaction,
+/// failing with `precondition_failed` when `if_match` is not the current content hash.
+/// Returns the new state and the encoded update (empty when nothing changed).
+pub fn apply_content_edit(
+    doc: &Doc,
+    edit: &ContentEdit,
+    if_match: Option<&str>,
+) -> Result<(ContentState, Vec<u8>)> {
+    let text = doc.get_or_insert_text("content");
+    let mut txn = doc.transact_mut();
+    let current = text.get_string(&txn);
+    if let Some(expected) = if_match
+        && expected != content_hash(&current)
+    {
+        anyhow::bail!("precondition_failed");
+    }
+    let next = apply_edit(&current, edit)?;
+    let splices = minimal_splices(&current, &next);
+    // Room docs count offsets in bytes, matching the splice indexes.
+    for splice in &splices {
+        let index = splice.index as u32;
+        if splice.delete > 0 {
+            text.remove_range(&mut txn, index, splice.delete as u32);
+        }
+        if !splice.insert.is_empty() {
+            text.insert(&mut txn, index, &splice.insert);
+        }
+    }
+    let update = if splices.is_empty() {
+        Vec::new()
+    } else {
+        txn.encode_update_v1()
+    };
+    let state = ContentState {
+        hash: content_hash(&next),
+        content: next,
+    };
+    Ok((state, update))
+}
+
 #[derive(Default, Clone, Copy, Debug)]
 pub struct FrameSummary {
     pub has_update: bool,
@@ -92,3 +460,119 @@ impl Stream for GuardedStream {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yrs::{Map, TextPrelim, WriteTxn};
+
+    fn synced(server: &Doc) -> Doc {
+        let client = Doc::new();
+        let state = server
+            .transact()
+            .encode_state_as_update_v1(&StateVector::default());
+        client
+            .transact_mut()
+            .apply_update(Update::decode_v1(&state).unwrap())
+            .unwrap();
+        client
+    }
+
+    // Runs `change` on `client` and returns the update it produced.
+    fn change(client: &Doc, change: impl FnOnce(&mut yrs::TransactionMut)) -> Vec<u8> {
+        let mut txn = client.transact_mut();
+        change(&mut txn);
+        txn.encode_update_v1()
+    }
+
+    fn apply(doc: &Doc, update: &[u8]) {
+        doc.transact_mut()
+            .apply_update(Update::decode_v1(update).unwrap())
+            .unwrap();
+    }
+
+    fn server_with_content(text: &str) -> Doc {
+        let server = Doc::new();
+        let content = server.get_or_insert_text("content");
+        content.insert(&mut server.transact_mut(), 0, text);
+        server
+    }
+
+    #[test]
+    fn comment_threads_may_be_written_and_removed() {
+        let server = server_with_content("hello");
+        let client = synced(&server);
+
+        let created = change(&client, |txn| {
+            let comments = txn.get_or_insert_map(COMMENTS_ROOT);
+            comments.insert(txn, "t1", TextPrelim::new("looks"));
+        });
+        assert!(update_writes_only_comments(&server, &created));
+        apply(&server, &created);
+
+        let extended = change(&client, |txn| {
+            let comments = txn.get_or_insert_map(COMMENTS_ROOT);
+            let thread: yrs::TextRef = comments.get(txn, "t1").unwrap().cast().unwrap();
+            thread.push(txn, " good");
+        });
+        assert!(update_writes_only_comments(&server, &extended));
+        apply(&server, &extended);
+
+        let removed = change(&client, |txn| {
+            let comments = txn.get_or_insert_map(COMMENTS_ROOT);
+            let thread: yrs::TextRef = comments.get(txn, "t1").unwrap().cast().unwrap();
+            thread.remove_range(txn, 0, 5);
+            comments.remove(txn, "t1");
+        });
+        assert!(update_writes_only_comments(&server, &removed));
+    }
+
+    #[test]
+    fn writes_outside_the_comments_root_are_refused() {
+        let server = server_with_content("hello");
+        let client = synced(&server);
+
+        let appended = change(&client, |txn| {
+            let content = txn.get_or_insert_text("content");
+            content.push(txn, " world");
+        });
+        assert!(!update_writes_only_comments(&server, &appended));
+
+        let deleted = change(&client, |txn| {
+            let content = txn.get_or_insert_text("content");
+            content.remove_range(txn, 0, 2);
+        });
+        assert!(!update_writes_only_comments(&server, &deleted));
+
+        let other = change(&client, |txn| {
+            let other = txn.get_or_insert_map("other");
+            other.insert(txn, "k", "v");
+        });
+        assert!(!update_writes_only_comments(&server, &other));
+    }
+
+    #[test]
+    fn resent_deletes_are_accepted_but_unknown_origins_are_not() {
+        let server = server_with_content("hello");
+        let content = server.get_or_insert_text("content");
+        content.remove_range(&mut server.transact_mut(), 0, 2);
+        let client = synced(&server);
+
+        // Sync step 2 carries the client's whole delete set alongside its own changes.
+        let server_state = server.transact().state_vector();
+        change(&client, |txn| {
+            let comments = txn.get_or_insert_map(COMMENTS_ROOT);
+            comments.insert(txn, "t1", TextPrelim::new("note"));
+        });
+        let step2 = client.transact().encode_diff_v1(&server_state);
+        assert!(update_writes_only_comments(&server, &step2));
+
+        // Builds on the thread above, which the server has not seen.
+        let dangling = change(&client, |txn| {
+            let comments = txn.get_or_insert_map(COMMENTS_ROOT);
+            let thread: yrs::TextRef = comments.get(txn, "t1").unwrap().cast().unwrap();
+            thread.push(txn, "!");
+        });
+        assert!(!update_writes_only_comments(&server, &dangling));
+    }
+}
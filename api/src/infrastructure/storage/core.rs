@@ -27,20 +27,20 @@ pub async fn build_doc_dir(
 ) -> anyhow::Result<PathBuf> {
     // Fetch basic document info first
     let row = sqlx::query(
-        "SELECT owner_id, parent_id, archived_at, archived_parent_id FROM documents WHERE id = $1",
+        "SELECT workspace_id, parent_id, archived_at, archived_parent_id FROM documents WHERE id = $1",
     )
     .bind(doc_id)
     .fetch_optional(pool)
     .await?;
     let row = row.ok_or_else(|| anyhow::anyhow!("Document not found"))?;
 
-    let owner_id: Uuid = row.get("owner_id");
+    let workspace_id: Uuid = row.get("workspace_id");
     let archived_at: Option<chrono::DateTime<chrono::Utc>> = row
         .try_get::<Option<chrono::DateTime<chrono::Utc>>, _>("archived_at")
         .ok()
         .flatten();
     let mut dir = uploads_root.to_path_buf();
-    dir.push(owner_id.to_string());
+    dir.push(workspace_id.to_string());
 
     let mut current_parent: Option<Uuid> = if archived_at.is_some() {
         dir.push("Archives");
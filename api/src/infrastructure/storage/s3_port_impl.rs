@@ -314,8 +314,8 @@ impl StoragePort for S3StoragePort {
         crate::infrastructure::storage::relative_from_uploads(&self.root, abs).replace('\\', "/")
     }
 
-    fn user_repo_dir(&self, user_id: Uuid) -> String {
-        let rel = format!("{}", user_id);
+    fn workspace_repo_dir(&self, workspace_id: Uuid) -> String {
+        let rel = format!("{}", workspace_id);
         if self.root_prefix.is_empty() {
             rel
         } else {
@@ -396,6 +396,11 @@ impl StoragePort for S3StoragePort {
         Ok(())
     }
 
+    async fn delete_bytes(&self, abs_path: &Path) -> anyhow::Result<()> {
+        let key = self.key_from_path(abs_path);
+        self.delete_object(&key).await
+    }
+
     async fn store_doc_attachment(
         &self,
         doc_id: Uuid,
@@ -61,8 +61,8 @@ impl StoragePort for FsStoragePort {
         crate::infrastructure::storage::relative_from_uploads(self.uploads_root.as_path(), abs)
     }
 
-    fn user_repo_dir(&self, user_id: Uuid) -> String {
-        let path = self.uploads_root.join(user_id.to_string());
+    fn workspace_repo_dir(&self, workspace_id: Uuid) -> String {
+        let path = self.uploads_root.join(workspace_id.to_string());
         path.to_string_lossy().to_string()
     }
 
@@ -135,6 +135,14 @@ impl StoragePort for FsStoragePort {
         Ok(())
     }
 
+    async fn delete_bytes(&self, abs_path: &Path) -> anyhow::Result<()> {
+        match tokio::fs::remove_file(abs_path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     async fn store_doc_attachment(
         &self,
         doc_id: Uuid,
@@ -12,6 +12,7 @@ use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
 use tracing::{error, info};
 
+use api::application::ports::admin_repository::AdminRepository;
 use api::application::ports::plugin_asset_store::PluginAssetStore;
 use api::application::ports::plugin_event_publisher::PluginEventPublisher;
 use api::application::ports::plugin_installation_repository::PluginInstallationRepository;
@@ -19,7 +20,7 @@ use api::application::ports::plugin_installer::PluginInstaller;
 use api::application::ports::plugin_runtime::PluginRuntime;
 use api::application::services::plugins::asset_signer::AssetSigner;
 use api::bootstrap::app_context::{AppContext, AppServices};
-use api::bootstrap::config::{Config, StorageBackend};
+use api::bootstrap::config::{Config, MailBackend, StorageBackend};
 use api::infrastructure::db::advisory_lock::AdvisoryLock;
 use api::infrastructure::plugins::filesystem_store::PluginExecutionLimits;
 use utoipa::OpenApi;
@@ -30,23 +31,87 @@ use utoipa_swagger_ui::SwaggerUi;
         paths(
             api::presentation::http::auth::register,
             api::presentation::http::auth::login,
+            api::presentation::http::auth::refresh,
             api::presentation::http::auth::logout,
             api::presentation::http::auth::me,
+            api::presentation::http::auth::list_sessions,
+            api::presentation::http::auth::revoke_session,
+            api::presentation::http::auth::revoke_all_sessions,
+            api::presentation::http::auth::providers,
+            api::presentation::http::auth::oidc_login,
+            api::presentation::http::auth::oidc_callback,
+            api::presentation::http::auth::login_mfa,
+            api::presentation::http::auth::mfa_status,
+            api::presentation::http::auth::setup_totp,
+            api::presentation::http::auth::enable_totp,
+            api::presentation::http::auth::disable_totp,
+            api::presentation::http::auth::forgot_password,
+            api::presentation::http::auth::reset_password,
+            api::presentation::http::auth::verify_email,
+            api::presentation::http::auth::resend_verification,
+            api::presentation::http::auth::update_profile,
+            api::presentation::http::auth::change_email,
+            api::presentation::http::auth::change_password,
+            api::presentation::http::admin::list_users,
+            api::presentation::http::admin::get_user,
+            api::presentation::http::admin::disable_user,
+            api::presentation::http::admin::enable_user,
+            api::presentation::http::admin::update_user_role,
+            api::presentation::http::admin::send_password_reset,
+            api::presentation::http::admin::instance_stats,
+            api::presentation::http::admin::delete_document,
+            api::presentation::http::admin::install_global_plugin,
+            api::presentation::http::admin::list_audit,
+            api::presentation::http::api_tokens::list_tokens,
+            api::presentation::http::api_tokens::create_token,
+            api::presentation::http::api_tokens::delete_token,
+            api::presentation::http::takeout::list_takeouts,
+            api::presentation::http::takeout::request_takeout,
+            api::presentation::http::takeout::get_takeout,
+            api::presentation::http::takeout::download_takeout,
+            api::presentation::http::templates::list_templates,
+            api::presentation::http::templates::set_template,
+            api::presentation::http::templates::remove_template,
+            api::presentation::http::templates::instantiate_template,
+            api::presentation::http::trash::list_trash,
+            api::presentation::http::trash::empty_trash,
+            api::presentation::http::trash::restore_document,
+            api::presentation::http::user_documents::list_recent,
+            api::presentation::http::user_documents::list_favorites,
+            api::presentation::http::user_documents::add_favorite,
+            api::presentation::http::user_documents::remove_favorite,
             api::presentation::http::tags::list_tags,
+            api::presentation::http::workspaces::list_workspaces,
+            api::presentation::http::workspaces::create_workspace,
+            api::presentation::http::workspaces::update_workspace,
+            api::presentation::http::workspaces::delete_workspace,
+            api::presentation::http::workspaces::list_members,
+            api::presentation::http::workspaces::add_member,
+            api::presentation::http::workspaces::update_member,
+            api::presentation::http::workspaces::remove_member,
             api::presentation::ws::axum_ws_entry,
             api::presentation::http::documents::list_documents,
             api::presentation::http::documents::create_document,
             api::presentation::http::documents::get_document,
             api::presentation::http::documents::update_document,
             api::presentation::http::documents::delete_document,
+            api::presentation::http::documents::duplicate_document,
+            api::presentation::http::documents::move_document,
+            api::presentation::http::documents::reorder_documents,
+            api::presentation::http::imports::import_vault,
+            api::presentation::http::imports::import_document,
             api::presentation::http::documents::get_document_content,
+            api::presentation::http::documents::replace_document_content,
+            api::presentation::http::documents::patch_document_content,
             api::presentation::http::documents::download_document,
             api::presentation::http::documents::list_document_snapshots,
             api::presentation::http::documents::get_document_snapshot_diff,
             api::presentation::http::documents::restore_document_snapshot,
             api::presentation::http::documents::download_document_snapshot,
             api::presentation::http::documents::search_documents,
+            api::presentation::http::documents::quick_search_documents,
             api::presentation::http::documents::get_backlinks,
+            api::presentation::http::documents::preview_rename,
             api::presentation::http::documents::get_outgoing_links,
             api::presentation::http::files::upload_file,
             api::presentation::http::files::get_file,
@@ -59,6 +124,19 @@ use utoipa_swagger_ui::SwaggerUi;
             api::presentation::http::shares::list_active_shares,
             api::presentation::http::shares::list_applicable_shares,
             api::presentation::http::shares::materialize_folder_share,
+            api::presentation::http::grants::list_grants,
+            api::presentation::http::grants::create_grant,
+            api::presentation::http::grants::delete_grant,
+            api::presentation::http::grants::list_shared_with_me,
+            api::presentation::http::comments::list_threads,
+            api::presentation::http::comments::create_thread,
+            api::presentation::http::comments::add_reply,
+            api::presentation::http::comments::resolve_thread,
+            api::presentation::http::comments::reopen_thread,
+            api::presentation::http::comments::delete_thread,
+            api::presentation::http::comments::update_comment,
+            api::presentation::http::comments::delete_comment,
+            api::presentation::http::comments::list_mentions,
             api::presentation::http::public::publish_document,
             api::presentation::http::public::unpublish_document,
             api::presentation::http::public::get_publish_status,
@@ -101,18 +179,76 @@ use utoipa_swagger_ui::SwaggerUi;
             api::presentation::http::auth::LoginRequest,
             api::presentation::http::auth::LoginResponse,
             api::presentation::http::auth::UserResponse,
+            api::presentation::http::auth::RefreshRequest,
+            api::presentation::http::auth::SessionItem,
+            api::presentation::http::auth::AuthProvidersResponse,
+            api::presentation::http::auth::OidcProviderInfo,
+            api::presentation::http::auth::MfaRequiredResponse,
+            api::presentation::http::auth::LoginMfaRequest,
+            api::presentation::http::auth::MfaStatusResponse,
+            api::presentation::http::auth::TotpSetupResponse,
+            api::presentation::http::auth::TotpCodeRequest,
+            api::presentation::http::auth::RecoveryCodesResponse,
+            api::presentation::http::auth::DisableTotpRequest,
+            api::presentation::http::auth::EmailRequest,
+            api::presentation::http::auth::ResetPasswordRequest,
+            api::presentation::http::auth::VerifyEmailRequest,
+            api::presentation::http::auth::UpdateProfileRequest,
+            api::presentation::http::auth::ChangeEmailRequest,
+            api::presentation::http::auth::ChangePasswordRequest,
+            api::presentation::http::admin::AdminUserItem,
+            api::presentation::http::admin::InstanceStatsResponse,
+            api::presentation::http::admin::AdminAuditItem,
+            api::presentation::http::admin::UpdateUserRoleRequest,
+            api::presentation::http::admin::InstallGlobalPluginRequest,
+            api::presentation::http::admin::InstallGlobalPluginResponse,
+            api::presentation::http::api_tokens::ApiTokenItem,
+            api::presentation::http::api_tokens::CreateApiTokenRequest,
+            api::presentation::http::api_tokens::CreateApiTokenResponse,
+            api::presentation::http::takeout::TakeoutJob,
             api::presentation::http::tags::TagItem,
+            api::presentation::http::templates::Template,
+            api::presentation::http::templates::TemplatePromptItem,
+            api::presentation::http::templates::SetTemplateRequest,
+            api::presentation::http::templates::InstantiateTemplateRequest,
+            api::presentation::http::trash::TrashItem,
+            api::presentation::http::trash::EmptyTrashResponse,
+            api::presentation::http::user_documents::RecentDocumentItem,
+            api::presentation::http::user_documents::FavoriteDocumentItem,
+            api::presentation::http::workspaces::WorkspaceResponse,
+            api::presentation::http::workspaces::WorkspaceMemberResponse,
+            api::presentation::http::workspaces::CreateWorkspaceRequest,
+            api::presentation::http::workspaces::UpdateWorkspaceRequest,
+            api::presentation::http::workspaces::AddMemberRequest,
+            api::presentation::http::workspaces::UpdateMemberRequest,
             api::presentation::http::documents::Document,
             api::presentation::http::documents::DocumentListResponse,
             api::presentation::http::documents::CreateDocumentRequest,
             api::presentation::http::documents::UpdateDocumentRequest,
+            api::presentation::http::documents::DuplicateDocumentRequest,
+            api::presentation::http::documents::MoveDocumentRequest,
+            api::presentation::http::documents::ReorderDocumentsRequest,
+            api::presentation::http::documents::ReorderDocumentsResponse,
+            api::presentation::http::documents::DocumentContentResponse,
+            api::presentation::http::documents::ReplaceDocumentContentRequest,
+            api::presentation::http::documents::PatchDocumentContentRequest,
+            api::presentation::http::documents::TextOperationRequest,
             api::presentation::http::documents::BacklinkInfo,
             api::presentation::http::documents::BacklinksResponse,
+            api::presentation::http::documents::LinkRewriteItem,
+            api::presentation::http::documents::RenamePreviewResponse,
             api::presentation::http::documents::OutgoingLink,
             api::presentation::http::documents::OutgoingLinksResponse,
             api::presentation::http::documents::SearchResult,
+            api::presentation::http::documents::QuickSearchResult,
+            api::presentation::http::documents::BreadcrumbItem,
             api::presentation::http::files::UploadFileResponse,
             api::presentation::http::files::UploadFileMultipart,
+            api::presentation::http::imports::VaultImportMultipart,
+            api::presentation::http::imports::DocumentImportMultipart,
+            api::presentation::http::imports::VaultImportResponse,
+            api::presentation::http::imports::ImportCollisionItem,
+            api::presentation::http::imports::UnresolvedLinkItem,
             api::presentation::http::shares::CreateShareRequest,
             api::presentation::http::shares::CreateShareResponse,
             api::presentation::http::shares::ShareItem,
@@ -122,6 +258,14 @@ use utoipa_swagger_ui::SwaggerUi;
             api::presentation::http::shares::ApplicableShareItem,
             api::presentation::http::shares::ActiveShareItem,
             api::presentation::http::shares::MaterializeResponse,
+            api::presentation::http::grants::DocumentGrantItem,
+            api::presentation::http::grants::CreateGrantRequest,
+            api::presentation::http::grants::SharedWithMeItem,
+            api::presentation::http::comments::CommentItem,
+            api::presentation::http::comments::CommentThreadItem,
+            api::presentation::http::comments::CreateCommentThreadRequest,
+            api::presentation::http::comments::CommentBodyRequest,
+            api::presentation::http::comments::MentionItem,
             api::presentation::http::public::PublishResponse,
             api::presentation::http::public::PublicDocumentSummary,
             api::presentation::http::git::GitConfigResponse,
@@ -160,9 +304,14 @@ use utoipa_swagger_ui::SwaggerUi;
         )),
         tags(
             (name = "Auth", description = "Authentication"),
+            (name = "Admin", description = "Instance administration"),
+            (name = "Workspaces", description = "Team workspaces and membership"),
             (name = "Documents", description = "Documents management"),
             (name = "Files", description = "File management"),
             (name = "Sharing", description = "Document sharing"),
+            (name = "Comments", description = "Document comment threads"),
+            (name = "Templates", description = "Document templates"),
+            (name = "Trash", description = "Deleted documents"),
             (name = "Public Documents", description = "Public pages"),
             (name = "Git", description = "Git integration"),
             (name = "Markdown", description = "Markdown rendering"),
@@ -236,6 +385,16 @@ async fn main() -> anyhow::Result<()> {
             pool.clone(),
         ),
     );
+    let document_grants_repo = Arc::new(
+        api::infrastructure::db::repositories::document_grants_repository_sqlx::SqlxDocumentGrantsRepository::new(
+            pool.clone(),
+        ),
+    );
+    let comment_repo = Arc::new(
+        api::infrastructure::db::repositories::comment_repository_sqlx::SqlxCommentRepository::new(
+            pool.clone(),
+        ),
+    );
     let files_repo = Arc::new(
         api::infrastructure::db::repositories::files_repository_sqlx::SqlxFilesRepository::new(
             pool.clone(),
@@ -251,11 +410,94 @@ async fn main() -> anyhow::Result<()> {
             pool.clone(),
         ),
     );
+    let admin_repo = Arc::new(
+        api::infrastructure::db::repositories::admin_repository_sqlx::SqlxAdminRepository::new(
+            pool.clone(),
+        ),
+    );
+    if !cfg.admin_emails.is_empty() {
+        let promoted = admin_repo.promote_admins(&cfg.admin_emails).await?;
+        if promoted > 0 {
+            info!(promoted, "admin_emails_promoted");
+        }
+    }
+    let session_repo = Arc::new(
+        api::infrastructure::db::repositories::session_repository_sqlx::SqlxSessionRepository::new(
+            pool.clone(),
+        ),
+    );
+    let api_token_repo = Arc::new(
+        api::infrastructure::db::repositories::api_token_repository_sqlx::SqlxApiTokenRepository::new(
+            pool.clone(),
+        ),
+    );
+    let mfa_repo = Arc::new(
+        api::infrastructure::db::repositories::mfa_repository_sqlx::SqlxMfaRepository::new(
+            pool.clone(),
+            cfg.encryption_key.clone(),
+        ),
+    );
+    let auth_link_repo = Arc::new(
+        api::infrastructure::db::repositories::auth_link_repository_sqlx::SqlxAuthLinkRepository::new(
+            pool.clone(),
+        ),
+    );
+    let mailer: Arc<dyn api::application::ports::mailer::Mailer> = match &cfg.mail.backend {
+        MailBackend::Smtp {
+            host,
+            port,
+            username,
+            password,
+            tls,
+        } => Arc::new(api::infrastructure::mail::smtp_mailer::SmtpMailer::new(
+            host.clone(),
+            *port,
+            username.clone(),
+            password.clone(),
+            tls.clone(),
+            cfg.mail.from.clone(),
+        )),
+        MailBackend::Outbox { dir } => {
+            Arc::new(api::infrastructure::mail::outbox_mailer::OutboxMailer::new(
+                dir.clone(),
+                cfg.mail.from.clone(),
+            ))
+        }
+    };
+    let oidc_repo = Arc::new(
+        api::infrastructure::db::repositories::oidc_repository_sqlx::SqlxOidcRepository::new(
+            pool.clone(),
+        ),
+    );
+    let oidc_provider = cfg.oidc.clone().map(|oidc| {
+        Arc::new(api::infrastructure::auth::oidc_provider_reqwest::ReqwestOidcProvider::new(oidc))
+            as Arc<dyn api::application::ports::oidc_provider::OidcProvider>
+    });
     let tag_repo = Arc::new(
         api::infrastructure::db::repositories::tag_repository_sqlx::SqlxTagRepository::new(
             pool.clone(),
         ),
     );
+    let takeout_repo = Arc::new(
+        api::infrastructure::db::repositories::takeout_repository_sqlx::SqlxTakeoutRepository::new(
+            pool.clone(),
+        ),
+    );
+    let template_repo = Arc::new(
+        api::infrastructure::db::repositories::template_repository_sqlx::SqlxTemplateRepository::new(
+            pool.clone(),
+        ),
+    );
+    let user_documents_repo = Arc::new(
+        api::infrastructure::db::repositories::user_documents_repository_sqlx::SqlxUserDocumentsRepository::new(
+            pool.clone(),
+        ),
+    );
+    let workspace_repo = Arc::new(
+        api::infrastructure::db::repositories::workspace_repository_sqlx::SqlxWorkspaceRepository::new(
+            pool.clone(),
+        ),
+    );
     let git_repo = Arc::new(
         api::infrastructure::db::repositories::git_repository_sqlx::SqlxGitRepository::new(
             pool.clone(),
@@ -404,10 +646,24 @@ async fn main() -> anyhow::Result<()> {
         shares_repo_impl.clone(),
         shares_repo_impl,
         access_repo,
+        document_grants_repo,
+        comment_repo,
         files_repo,
         public_repo,
         user_repo,
+        admin_repo,
+        session_repo,
+        api_token_repo,
+        mfa_repo,
+        auth_link_repo,
+        mailer,
+        oidc_repo,
+        oidc_provider,
         tag_repo,
+        takeout_repo,
+        template_repo,
+        user_documents_repo,
+        workspace_repo,
         git_repo,
         git_storage,
         gitignore_port,
@@ -516,9 +772,39 @@ async fn main() -> anyhow::Result<()> {
             "/api/auth",
             api::presentation::http::auth::routes(ctx.clone()),
         )
+        .nest(
+            "/api",
+            api::presentation::http::api_tokens::routes(ctx.clone()),
+        )
+        .nest(
+            "/api",
+            api::presentation::http::takeout::routes(ctx.clone()),
+        )
+        .nest(
+            "/api",
+            api::presentation::http::templates::routes(ctx.clone()),
+        )
+        .nest(
+            "/api",
+            api::presentation::http::imports::routes(ctx.clone()),
+        )
+        .nest("/api", api::presentation::http::trash::routes(ctx.clone()))
+        .nest(
+            "/api",
+            api::presentation::http::user_documents::routes(ctx.clone()),
+        )
         .nest("/api", api::presentation::http::shares::routes(ctx.clone()))
+        .nest("/api", api::presentation::http::grants::routes(ctx.clone()))
+        .nest(
+            "/api",
+            api::presentation::http::comments::routes(ctx.clone()),
+        )
         .nest("/api", api::presentation::http::files::routes(ctx.clone()))
         .nest("/api", api::presentation::http::tags::routes(ctx.clone()))
+        .nest(
+            "/api",
+            api::presentation::http::workspaces::routes(ctx.clone()),
+        )
         .nest("/api", api::presentation::http::git::routes(ctx.clone()))
         .nest(
             "/api",
@@ -532,6 +818,10 @@ async fn main() -> anyhow::Result<()> {
             "/api/public",
             api::presentation::http::public::routes(ctx.clone()),
         )
+        .nest(
+            "/api/admin",
+            api::presentation::http::admin::routes(ctx.clone()),
+        )
         .merge(SwaggerUi::new("/api/docs").url("/api/openapi.json", ApiDoc::openapi()))
         .layer(cors)
         // Global body size limit for uploads (configurable)
@@ -609,6 +899,81 @@ async fn main() -> anyhow::Result<()> {
         }))
     };
 
+    // Index documents persisted before full-text search existed
+    const SEARCH_BACKFILL_LOCK_KEY: i64 = i64::from_be_bytes(*b"REFSRCH1");
+    {
+        use api::application::ports::search_index_repository::SearchIndexRepository;
+
+        let pool_for_backfill = pool.clone();
+        let realtime = ctx.realtime_engine();
+        let search_index =
+            api::infrastructure::db::repositories::search_index_repository_sqlx::SqlxSearchIndexRepository::new(
+                pool.clone(),
+            );
+        tokio::spawn(async move {
+            let lock = match AdvisoryLock::try_acquire(&pool_for_backfill, SEARCH_BACKFILL_LOCK_KEY)
+                .await
+            {
+                Ok(Some(lock)) => lock,
+                Ok(None) => return,
+                Err(e) => {
+                    tracing::error!(error = ?e, "search_backfill_lock_error");
+                    return;
+                }
+            };
+            // Keyset by id, so documents that fail to index are skipped rather than refetched
+            let mut after = None;
+            let mut indexed = 0usize;
+            loop {
+                let ids = match search_index.list_unindexed(after, 200).await {
+                    Ok(ids) => ids,
+                    Err(e) => {
+                        tracing::error!(error = ?e, "search_backfill_list_failed");
+                        break;
+                    }
+                };
+                let Some(last) = ids.last().copied() else {
+                    break;
+                };
+                after = Some(last);
+                for id in ids {
+                    match realtime.force_save_to_fs(&id.to_string()).await {
+                        Ok(()) => indexed += 1,
+                        Err(e) => {
+                            tracing::warn!(document_id = %id, error = ?e, "search_backfill_failed")
+                        }
+                    }
+                }
+            }
+            if indexed > 0 {
+                info!(count = indexed, "search_backfill_completed");
+            }
+            if let Err(e) = lock.release().await {
+                tracing::error!(error = ?e, "search_backfill_lock_release_failed");
+            }
+        });
+    }
+
+    // Account exports; jobs are claimed with SKIP LOCKED so every node may run this
+    let takeout_ctx = ctx.clone();
+    tokio::spawn(async move {
+        loop {
+            run_takeout_cycle(&takeout_ctx).await;
+            sleep(TAKEOUT_POLL_INTERVAL).await;
+        }
+    });
+
+    // Trash retention; purging is idempotent so every node may run this
+    if ctx.cfg.trash_retention_secs > 0 {
+        let trash_ctx = ctx.clone();
+        tokio::spawn(async move {
+            loop {
+                run_trash_purge(&trash_ctx).await;
+                sleep(TRASH_PURGE_INTERVAL).await;
+            }
+        });
+    }
+
     match api_handle.await {
         Ok(Ok(())) => {}
         Ok(Err(e)) => error!(?e, "API server task failed"),
@@ -624,3 +989,85 @@ async fn main() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+const TAKEOUT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+// Running jobs older than this are assumed orphaned by a restart
+const TAKEOUT_STALE_AFTER_SECS: i64 = 3600;
+
+const TRASH_PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+async fn run_trash_purge(ctx: &AppContext) {
+    use api::application::use_cases::trash::purge_trash::PurgeTrash;
+
+    let repo = ctx.document_repo();
+    let storage = ctx.storage_port();
+    let uc = PurgeTrash {
+        repo: repo.as_ref(),
+        storage: storage.as_ref(),
+    };
+    let cutoff = chrono::Utc::now() - chrono::Duration::seconds(ctx.cfg.trash_retention_secs);
+    match uc.execute(None, Some(cutoff)).await {
+        Ok(0) => {}
+        Ok(n) => info!(count = n, "trash_purged"),
+        Err(e) => tracing::error!(error = ?e, "trash_purge_failed"),
+    }
+}
+
+async fn run_takeout_cycle(ctx: &AppContext) {
+    use api::application::use_cases::takeout::build_takeout::BuildTakeout;
+    use api::application::use_cases::takeout::purge_takeouts::PurgeExpiredTakeouts;
+
+    let takeouts = ctx.takeout_repo();
+    let storage = ctx.storage_port();
+
+    let cutoff = chrono::Utc::now() - chrono::Duration::seconds(TAKEOUT_STALE_AFTER_SECS);
+    match takeouts.requeue_stale(cutoff).await {
+        Ok(0) => {}
+        Ok(n) => tracing::warn!(count = n, "takeout_jobs_requeued"),
+        Err(e) => tracing::error!(error = ?e, "takeout_requeue_failed"),
+    }
+    let purge = PurgeExpiredTakeouts {
+        repo: takeouts.as_ref(),
+        storage: storage.as_ref(),
+    };
+    if let Err(e) = purge.execute().await {
+        tracing::error!(error = ?e, "takeout_purge_failed");
+    }
+
+    let users = ctx.user_repo();
+    let files = ctx.files_repo();
+    let realtime = ctx.realtime_engine();
+    let snapshot_archives = ctx.snapshot_archives();
+    let snapshots = ctx.snapshot_service();
+    let shares = ctx.shares_repo();
+    let plugins = ctx.plugin_repo();
+    let git = ctx.git_repo();
+    let uc = BuildTakeout {
+        takeouts: takeouts.as_ref(),
+        users: users.as_ref(),
+        files: files.as_ref(),
+        storage: storage.as_ref(),
+        realtime: realtime.as_ref(),
+        snapshot_archives: snapshot_archives.as_ref(),
+        snapshots: snapshots.as_ref(),
+        shares: shares.as_ref(),
+        plugins: plugins.as_ref(),
+        git: git.as_ref(),
+        retention: chrono::Duration::seconds(ctx.cfg.takeout_retention_secs),
+    };
+    loop {
+        let job = match takeouts.claim_next().await {
+            Ok(Some(job)) => job,
+            Ok(None) => break,
+            Err(e) => {
+                tracing::error!(error = ?e, "takeout_claim_failed");
+                break;
+            }
+        };
+        info!(job_id = %job.id, user_id = %job.user_id, "takeout_started");
+        match uc.execute(&job).await {
+            Ok(()) => info!(job_id = %job.id, "takeout_completed"),
+            Err(e) => tracing::error!(job_id = %job.id, error = ?e, "takeout_failed"),
+        }
+    }
+}
@@ -0,0 +1,388 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{delete, get, post, put},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::application::ports::admin_repository::{AdminAuditRow, AdminUserRow, InstanceStats};
+use crate::application::ports::plugin_installer::PluginInstallError;
+use crate::application::use_cases::admin::authorize::AuthorizeAdmin;
+use crate::application::use_cases::admin::delete_document::ForceDeleteDocument;
+use crate::application::use_cases::admin::install_global_plugin::InstallGlobalPlugin;
+use crate::application::use_cases::admin::instance_stats::GetInstanceStats;
+use crate::application::use_cases::admin::list_audit::ListAuditLog;
+use crate::application::use_cases::admin::list_users::{GetUser, ListUsers};
+use crate::application::use_cases::admin::send_password_reset::SendPasswordReset;
+use crate::application::use_cases::admin::set_user_disabled::SetUserDisabled;
+use crate::application::use_cases::admin::set_user_role::SetUserRole;
+use crate::application::use_cases::plugins::install_from_url::InstallPluginError;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminUserItem {
+    pub id: Uuid,
+    pub email: String,
+    pub name: String,
+    /// user | admin
+    pub role: String,
+    pub email_verified: bool,
+    pub disabled_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub document_count: i64,
+    /// Attachment bytes on documents the user owns
+    pub storage_bytes: i64,
+}
+
+impl From<AdminUserRow> for AdminUserItem {
+    fn from(u: AdminUserRow) -> Self {
+        AdminUserItem {
+            id: u.id,
+            email: u.email,
+            name: u.name,
+            role: u.role,
+            email_verified: u.email_verified_at.is_some(),
+            disabled_at: u.disabled_at,
+            created_at: u.created_at,
+            document_count: u.document_count,
+            storage_bytes: u.storage_bytes,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InstanceStatsResponse {
+    pub users: i64,
+    pub admins: i64,
+    pub disabled_users: i64,
+    pub workspaces: i64,
+    pub documents: i64,
+    pub files: i64,
+    pub storage_bytes: i64,
+}
+
+impl From<InstanceStats> for InstanceStatsResponse {
+    fn from(s: InstanceStats) -> Self {
+        InstanceStatsResponse {
+            users: s.users,
+            admins: s.admins,
+            disabled_users: s.disabled_users,
+            workspaces: s.workspaces,
+            documents: s.documents,
+            files: s.files,
+            storage_bytes: s.storage_bytes,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminAuditItem {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub actor_email: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub details: serde_json::Value,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AdminAuditRow> for AdminAuditItem {
+    fn from(a: AdminAuditRow) -> Self {
+        AdminAuditItem {
+            id: a.id,
+            actor_id: a.actor_id,
+            actor_email: a.actor_email,
+            action: a.action,
+            target_type: a.target_type,
+            target_id: a.target_id,
+            details: a.details,
+            created_at: a.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AdminUsersQuery {
+    /// Matches email or name
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AdminPageQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateUserRoleRequest {
+    /// user | admin
+    pub role: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InstallGlobalPluginRequest {
+    pub url: String,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InstallGlobalPluginResponse {
+    pub id: String,
+    pub version: String,
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        "forbidden" => StatusCode::FORBIDDEN,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::error!(error = ?e, "admin_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+/// Admin routes are session-only and require the admin role.
+async fn require_admin(ctx: &AppContext, bearer: Bearer) -> Result<Uuid, StatusCode> {
+    let (user_id, _) = auth::authenticate_token(ctx, &bearer.0).await?;
+    let users = ctx.user_repo();
+    let uc = AuthorizeAdmin {
+        users: users.as_ref(),
+    };
+    uc.execute(user_id).await.map_err(map_error)?;
+    Ok(user_id)
+}
+
+#[utoipa::path(get, path = "/api/admin/users", tag = "Admin", params(AdminUsersQuery),
+    responses((status = 200, body = [AdminUserItem]), (status = 403, description = "Not an admin")))]
+pub async fn list_users(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Query(q): Query<AdminUsersQuery>,
+) -> Result<Json<Vec<AdminUserItem>>, StatusCode> {
+    require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let uc = ListUsers {
+        admin: admin.as_ref(),
+    };
+    let rows = uc
+        .execute(q.q.as_deref(), q.limit, q.offset)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/api/admin/users/{id}", tag = "Admin",
+    params(("id" = Uuid, Path, description = "User ID")),
+    responses((status = 200, body = AdminUserItem)))]
+pub async fn get_user(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AdminUserItem>, StatusCode> {
+    require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let uc = GetUser {
+        admin: admin.as_ref(),
+    };
+    let user = uc.execute(id).await.map_err(map_error)?;
+    Ok(Json(user.into()))
+}
+
+async fn set_disabled(
+    ctx: AppContext,
+    bearer: Bearer,
+    id: Uuid,
+    disabled: bool,
+) -> Result<Json<AdminUserItem>, StatusCode> {
+    let actor_id = require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let sessions = ctx.session_repo();
+    let uc = SetUserDisabled {
+        admin: admin.as_ref(),
+        sessions: sessions.as_ref(),
+    };
+    let user = uc
+        .execute(actor_id, id, disabled)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(user.into()))
+}
+
+#[utoipa::path(post, path = "/api/admin/users/{id}/disable", tag = "Admin",
+    params(("id" = Uuid, Path, description = "User ID")),
+    responses((status = 200, body = AdminUserItem, description = "Disabled; all sessions revoked"),
+        (status = 400, description = "Cannot disable yourself")))]
+pub async fn disable_user(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AdminUserItem>, StatusCode> {
+    set_disabled(ctx, bearer, id, true).await
+}
+
+#[utoipa::path(post, path = "/api/admin/users/{id}/enable", tag = "Admin",
+    params(("id" = Uuid, Path, description = "User ID")),
+    responses((status = 200, body = AdminUserItem)))]
+pub async fn enable_user(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AdminUserItem>, StatusCode> {
+    set_disabled(ctx, bearer, id, false).await
+}
+
+#[utoipa::path(put, path = "/api/admin/users/{id}/role", tag = "Admin", request_body = UpdateUserRoleRequest,
+    params(("id" = Uuid, Path, description = "User ID")),
+    responses((status = 200, body = AdminUserItem), (status = 400, description = "Unknown role or self-demotion")))]
+pub async fn update_user_role(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateUserRoleRequest>,
+) -> Result<Json<AdminUserItem>, StatusCode> {
+    let actor_id = require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let uc = SetUserRole {
+        admin: admin.as_ref(),
+    };
+    let user = uc
+        .execute(actor_id, id, &req.role)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(user.into()))
+}
+
+#[utoipa::path(post, path = "/api/admin/users/{id}/password-reset", tag = "Admin",
+    params(("id" = Uuid, Path, description = "User ID")),
+    responses((status = 204, description = "Reset link mailed to the user")))]
+pub async fn send_password_reset(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let actor_id = require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let users = ctx.user_repo();
+    let links = ctx.auth_link_repo();
+    let mailer = ctx.mailer();
+    let uc = SendPasswordReset {
+        admin: admin.as_ref(),
+        users: users.as_ref(),
+        links: links.as_ref(),
+        mailer: mailer.as_ref(),
+        signing_secret: &ctx.cfg.jwt_secret_pem,
+        base_url: auth::link_base_url(&ctx.cfg),
+    };
+    uc.execute(actor_id, id).await.map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(get, path = "/api/admin/stats", tag = "Admin",
+    responses((status = 200, body = InstanceStatsResponse)))]
+pub async fn instance_stats(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<InstanceStatsResponse>, StatusCode> {
+    require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let uc = GetInstanceStats {
+        admin: admin.as_ref(),
+    };
+    let stats = uc.execute().await.map_err(map_error)?;
+    Ok(Json(stats.into()))
+}
+
+#[utoipa::path(delete, path = "/api/admin/documents/{id}", tag = "Admin",
+    params(("id" = Uuid, Path, description = "Document ID")),
+    responses((status = 204), (status = 404, description = "Document not found")))]
+pub async fn delete_document(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let actor_id = require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let storage = ctx.storage_port();
+    let uc = ForceDeleteDocument {
+        admin: admin.as_ref(),
+        storage: storage.as_ref(),
+    };
+    uc.execute(actor_id, id).await.map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(post, path = "/api/admin/plugins/install-from-url", tag = "Admin", request_body = InstallGlobalPluginRequest,
+    responses((status = 200, body = InstallGlobalPluginResponse, description = "Installed for every user")))]
+pub async fn install_global_plugin(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<InstallGlobalPluginRequest>,
+) -> Result<Json<InstallGlobalPluginResponse>, StatusCode> {
+    let actor_id = require_admin(&ctx, bearer).await?;
+    let fetcher = ctx.plugin_fetcher();
+    let installer = ctx.plugin_installer();
+    let publisher = ctx.plugin_event_publisher();
+    let admin = ctx.admin_repo();
+    let uc = InstallGlobalPlugin {
+        fetcher: fetcher.as_ref(),
+        installer: installer.as_ref(),
+        events: publisher.as_ref(),
+        admin: admin.as_ref(),
+    };
+    match uc.execute(actor_id, &req.url, req.token.as_deref()).await {
+        Ok(installed) => Ok(Json(InstallGlobalPluginResponse {
+            id: installed.id,
+            version: installed.version,
+        })),
+        Err(err) => {
+            tracing::error!(error = ?err, "failed to install global plugin");
+            Err(match err {
+                InstallPluginError::Download(_) => StatusCode::BAD_GATEWAY,
+                InstallPluginError::Install(PluginInstallError::InvalidPackage(_)) => {
+                    StatusCode::BAD_REQUEST
+                }
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            })
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/api/admin/audit", tag = "Admin", params(AdminPageQuery),
+    responses((status = 200, body = [AdminAuditItem])))]
+pub async fn list_audit(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Query(q): Query<AdminPageQuery>,
+) -> Result<Json<Vec<AdminAuditItem>>, StatusCode> {
+    require_admin(&ctx, bearer).await?;
+    let admin = ctx.admin_repo();
+    let uc = ListAuditLog {
+        admin: admin.as_ref(),
+    };
+    let rows = uc.execute(q.limit, q.offset).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/users", get(list_users))
+        .route("/users/:id", get(get_user))
+        .route("/users/:id/disable", post(disable_user))
+        .route("/users/:id/enable", post(enable_user))
+        .route("/users/:id/role", put(update_user_role))
+        .route("/users/:id/password-reset", post(send_password_reset))
+        .route("/stats", get(instance_stats))
+        .route("/documents/:id", delete(delete_document))
+        .route("/plugins/install-from-url", post(install_global_plugin))
+        .route("/audit", get(list_audit))
+        .with_state(ctx)
+}
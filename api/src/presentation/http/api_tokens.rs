@@ -0,0 +1,136 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::ports::api_token_repository::ApiTokenRow;
+use crate::application::use_cases::auth::create_api_token::CreateApiToken;
+use crate::application::use_cases::auth::list_api_tokens::ListApiTokens;
+use crate::application::use_cases::auth::revoke_api_token::RevokeApiToken;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiTokenItem {
+    pub id: Uuid,
+    pub name: String,
+    /// Leading characters of the token, for recognising it
+    pub token_prefix: String,
+    /// documents:read | documents:write | git:sync
+    pub scopes: Vec<String>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ApiTokenRow> for ApiTokenItem {
+    fn from(t: ApiTokenRow) -> Self {
+        ApiTokenItem {
+            id: t.id,
+            name: t.name,
+            token_prefix: t.token_prefix,
+            scopes: t.scopes,
+            expires_at: t.expires_at,
+            last_used_at: t.last_used_at,
+            created_at: t.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateApiTokenRequest {
+    pub name: String,
+    /// documents:read | documents:write | git:sync
+    pub scopes: Vec<String>,
+    /// Never expires when omitted
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateApiTokenResponse {
+    /// Shown only once; send as `Authorization: Bearer <token>`
+    pub token: String,
+    #[serde(flatten)]
+    pub item: ApiTokenItem,
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "bad_request" => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::error!(error = ?e, "api_token_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/api/me/tokens", tag = "Auth", responses((status = 200, body = [ApiTokenItem])))]
+pub async fn list_tokens(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<Vec<ApiTokenItem>>, StatusCode> {
+    let sub = auth::validate_bearer(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let tokens = ctx.api_token_repo();
+    let uc = ListApiTokens {
+        tokens: tokens.as_ref(),
+    };
+    let rows = uc.execute(user_id).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(post, path = "/api/me/tokens", tag = "Auth", request_body = CreateApiTokenRequest,
+    responses((status = 200, body = CreateApiTokenResponse), (status = 400, description = "Invalid name, scopes or expiry")))]
+pub async fn create_token(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<CreateApiTokenRequest>,
+) -> Result<Json<CreateApiTokenResponse>, StatusCode> {
+    let sub = auth::validate_bearer(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let tokens = ctx.api_token_repo();
+    let uc = CreateApiToken {
+        tokens: tokens.as_ref(),
+    };
+    let issued = uc
+        .execute(user_id, &req.name, &req.scopes, req.expires_at)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(CreateApiTokenResponse {
+        token: issued.secret,
+        item: issued.token.into(),
+    }))
+}
+
+#[utoipa::path(delete, path = "/api/me/tokens/{id}", tag = "Auth",
+    params(("id" = Uuid, Path, description = "Token ID")),
+    responses((status = 204), (status = 404, description = "Token not found")))]
+pub async fn delete_token(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let tokens = ctx.api_token_repo();
+    let uc = RevokeApiToken {
+        tokens: tokens.as_ref(),
+    };
+    if uc.execute(user_id, id).await.map_err(map_error)? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(StatusCode::NOT_FOUND)
+    }
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/me/tokens", get(list_tokens).post(create_token))
+        .route("/me/tokens/:id", delete(delete_token))
+        .with_state(ctx)
+}
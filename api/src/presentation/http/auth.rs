@@ -1,21 +1,37 @@
 use crate::application::access;
+use crate::application::ports::user_repository::UserRow;
+use crate::application::services::auth::scopes::ApiTokenScope;
+use crate::application::services::auth::tokens::{hash_token, is_api_token};
+use crate::application::use_cases::auth::create_session::{CreateSession, IssuedSession};
 use crate::application::use_cases::auth::delete_account::DeleteAccount;
+use crate::application::use_cases::auth::email_verification::{SendEmailVerification, VerifyEmail};
+use crate::application::use_cases::auth::list_sessions::ListSessions;
 use crate::application::use_cases::auth::login::{Login as LoginUc, LoginRequest as LoginDto};
 use crate::application::use_cases::auth::me::GetMe;
+use crate::application::use_cases::auth::mfa_challenge::{CompleteMfaChallenge, StartMfaChallenge};
+use crate::application::use_cases::auth::mfa_status::GetMfaStatus;
+use crate::application::use_cases::auth::oidc_login::{BeginOidcLogin, CompleteOidcLogin};
+use crate::application::use_cases::auth::password_reset::{RequestPasswordReset, ResetPassword};
+use crate::application::use_cases::auth::profile::{ChangeEmail, ChangePassword, UpdateName};
+use crate::application::use_cases::auth::refresh_session::RefreshSession;
 use crate::application::use_cases::auth::register::{
     Register as RegisterUc, RegisterRequest as RegisterDto,
 };
+use crate::application::use_cases::auth::revoke_session::{RevokeAllSessions, RevokeSession};
+use crate::application::use_cases::auth::totp_disable::DisableTotp;
+use crate::application::use_cases::auth::totp_setup::{EnableTotp, SetupTotp};
 use crate::bootstrap::app_context::AppContext;
 use crate::bootstrap::config::Config;
 use axum::{
     Json, Router,
-    extract::State,
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
-    routing::{get, post},
+    response::{IntoResponse, Redirect, Response},
+    routing::{delete, get, post, put},
 };
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -30,6 +46,21 @@ pub struct UserResponse {
     pub id: Uuid,
     pub email: String,
     pub name: String,
+    pub email_verified: bool,
+    /// user | admin
+    pub role: String,
+}
+
+impl From<UserRow> for UserResponse {
+    fn from(u: UserRow) -> Self {
+        UserResponse {
+            id: u.id,
+            email: u.email,
+            name: u.name,
+            email_verified: u.email_verified_at.is_some(),
+            role: u.role,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -41,21 +72,176 @@ pub struct LoginRequest {
 #[derive(Debug, Serialize, ToSchema)]
 pub struct LoginResponse {
     pub access_token: String,
+    /// Rotates on every refresh; also set as an HttpOnly cookie scoped to /api/auth
+    pub refresh_token: String,
+    /// Access token lifetime in seconds
+    pub expires_in: i64,
     pub user: UserResponse,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MfaRequiredResponse {
+    pub mfa_required: bool,
+    /// Single-use token for `/api/auth/login/mfa`, valid for 5 minutes
+    pub mfa_token: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LoginMfaRequest {
+    pub mfa_token: String,
+    /// 6-digit TOTP code or a recovery code
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MfaStatusResponse {
+    pub totp_enabled: bool,
+    pub recovery_codes_remaining: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotpSetupResponse {
+    /// Base32 secret for manual entry
+    pub secret: String,
+    /// `otpauth://` provisioning URI; render as a QR code
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TotpCodeRequest {
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecoveryCodesResponse {
+    /// Shown only once; each code works a single time
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DisableTotpRequest {
+    /// Required for accounts with a password
+    pub password: Option<String>,
+    /// Current TOTP code or a recovery code
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EmailRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ResetPasswordRequest {
+    /// Token from the emailed link
+    pub token: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct VerifyEmailRequest {
+    /// Token from the emailed link
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateProfileRequest {
+    /// Also the public page path segment; the old name keeps redirecting
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ChangeEmailRequest {
+    pub email: String,
+    /// Required for accounts with a password
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ChangePasswordRequest {
+    /// Required for accounts with a password
+    pub current_password: Option<String>,
+    pub new_password: String,
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct RefreshRequest {
+    /// Falls back to the `refresh_token` cookie when omitted
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SessionItem {
+    pub id: Uuid,
+    /// User-Agent reported when the session was last refreshed
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub last_seen_at: chrono::DateTime<chrono::Utc>,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    /// Whether this is the session making the request
+    pub current: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthProvidersResponse {
+    /// Whether `/api/auth/register` accepts new accounts
+    pub password_signup_enabled: bool,
+    pub oidc: Option<OidcProviderInfo>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OidcProviderInfo {
+    pub name: String,
+    /// Navigate the browser here to start single sign-on
+    pub login_url: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct OidcLoginQuery {
+    /// Path on the frontend to return to after signing in
+    pub redirect_to: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct OidcCallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub exp: usize,
+    /// Session id; tokens of revoked sessions are rejected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
 }
 
 pub fn routes(ctx: AppContext) -> Router {
     Router::new()
         .route("/register", post(register))
         .route("/login", post(login))
+        .route("/login/mfa", post(login_mfa))
+        .route("/password/forgot", post(forgot_password))
+        .route("/password/reset", post(reset_password))
+        .route("/email/verify", post(verify_email))
+        .route("/email/verification", post(resend_verification))
+        .route("/mfa", get(mfa_status))
+        .route("/mfa/totp/setup", post(setup_totp))
+        .route("/mfa/totp/enable", post(enable_totp))
+        .route("/mfa/totp/disable", post(disable_totp))
+        .route("/refresh", post(refresh))
         .route("/logout", post(logout))
-        .route("/me", get(me).delete(delete_account))
+        .route("/me", get(me).patch(update_profile).delete(delete_account))
+        .route("/me/email", put(change_email))
+        .route("/me/password", put(change_password))
+        .route("/sessions", get(list_sessions).delete(revoke_all_sessions))
+        .route("/sessions/:id", delete(revoke_session))
+        .route("/providers", get(providers))
+        .route("/oidc/login", get(oidc_login))
+        .route("/oidc/callback", get(oidc_callback))
         .with_state(ctx)
 }
 
@@ -66,6 +252,9 @@ pub async fn register(
     State(ctx): State<AppContext>,
     Json(req): Json<RegisterRequest>,
 ) -> Result<Json<UserResponse>, StatusCode> {
+    if !ctx.cfg.password_signup_enabled {
+        return Err(StatusCode::FORBIDDEN);
+    }
     let repo = ctx.user_repo();
     let uc = RegisterUc {
         repo: repo.as_ref(),
@@ -76,20 +265,31 @@ pub async fn register(
         password: req.password.clone(),
     };
     let user = uc.execute(&dto).await.map_err(|_| StatusCode::CONFLICT)?;
-    Ok(Json(UserResponse {
-        id: user.id,
-        email: user.email,
-        name: user.name,
-    }))
+    let links = ctx.auth_link_repo();
+    let mailer = ctx.mailer();
+    let verification = SendEmailVerification {
+        users: repo.as_ref(),
+        links: links.as_ref(),
+        mailer: mailer.as_ref(),
+        signing_secret: &ctx.cfg.jwt_secret_pem,
+        base_url: link_base_url(&ctx.cfg),
+    };
+    if let Err(e) = verification.execute(&user).await {
+        tracing::warn!(error = ?e, user_id = %user.id, "verification_email_failed");
+    }
+    Ok(Json(UserResponse::from(user)))
 }
 
 #[utoipa::path(post, path = "/api/auth/login", tag = "Auth", request_body = LoginRequest, security(()), responses(
-    (status = 200, body = LoginResponse)
+    (status = 200, body = LoginResponse),
+    (status = 202, body = MfaRequiredResponse, description = "Password accepted; complete with /api/auth/login/mfa"),
+    (status = 403, description = "Account disabled or email address not verified yet")
 ))]
 pub async fn login(
     State(ctx): State<AppContext>,
+    req_headers: HeaderMap,
     Json(req): Json<LoginRequest>,
-) -> Result<(HeaderMap, Json<LoginResponse>), StatusCode> {
+) -> Result<Response, StatusCode> {
     let repo = ctx.user_repo();
     let uc = LoginUc {
         repo: repo.as_ref(),
@@ -103,53 +303,209 @@ pub async fn login(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::UNAUTHORIZED)?;
-    let user = UserResponse {
-        id: user.id,
-        email: user.email,
-        name: user.name,
+    if user.disabled_at.is_some()
+        || (ctx.cfg.require_email_verification && user.email_verified_at.is_none())
+    {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    // Accounts with TOTP get no cookies until the second step succeeds
+    let mfa = ctx.mfa_repo();
+    let challenge = StartMfaChallenge { mfa: mfa.as_ref() };
+    if let Some(mfa_token) = challenge
+        .execute(user.id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    {
+        return Ok((
+            StatusCode::ACCEPTED,
+            Json(MfaRequiredResponse {
+                mfa_required: true,
+                mfa_token,
+            }),
+        )
+            .into_response());
+    }
+
+    let user = UserResponse::from(user);
+    Ok(start_session(&ctx, &req_headers, user)
+        .await?
+        .into_response())
+}
+
+#[utoipa::path(post, path = "/api/auth/login/mfa", tag = "Auth", request_body = LoginMfaRequest, security(()), responses(
+    (status = 200, body = LoginResponse),
+    (status = 401, description = "Challenge expired, exhausted or code invalid"),
+    (status = 429, description = "Too many failed codes; try again later")
+))]
+pub async fn login_mfa(
+    State(ctx): State<AppContext>,
+    req_headers: HeaderMap,
+    Json(req): Json<LoginMfaRequest>,
+) -> Result<(HeaderMap, Json<LoginResponse>), StatusCode> {
+    let mfa = ctx.mfa_repo();
+    let uc = CompleteMfaChallenge { mfa: mfa.as_ref() };
+    let user_id = uc
+        .execute(&req.mfa_token, &req.code)
+        .await
+        .map_err(map_mfa_error)?;
+    let user = ctx
+        .user_repo()
+        .find_by_id(user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    if user.disabled_at.is_some() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let user = UserResponse::from(user);
+    start_session(&ctx, &req_headers, user).await
+}
+
+async fn start_session(
+    ctx: &AppContext,
+    req_headers: &HeaderMap,
+    user: UserResponse,
+) -> Result<(HeaderMap, Json<LoginResponse>), StatusCode> {
+    let sessions = ctx.session_repo();
+    let uc = CreateSession {
+        sessions: sessions.as_ref(),
     };
-    let now = chrono::Utc::now().timestamp() as usize;
-    let claims = Claims {
-        sub: user.id.to_string(),
-        exp: now + (ctx.cfg.jwt_expires_secs as usize),
+    let issued = uc
+        .execute(
+            user.id,
+            user_agent(req_headers).as_deref(),
+            client_ip(req_headers).as_deref(),
+            ctx.cfg.refresh_token_ttl_secs,
+        )
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    session_response(ctx, issued, user)
+}
+
+#[utoipa::path(post, path = "/api/auth/refresh", tag = "Auth", request_body = RefreshRequest, security(()), responses(
+    (status = 200, body = LoginResponse),
+    (status = 401, description = "Refresh token invalid, expired or already used")
+))]
+pub async fn refresh(
+    State(ctx): State<AppContext>,
+    req_headers: HeaderMap,
+    body: Option<Json<RefreshRequest>>,
+) -> Result<(HeaderMap, Json<LoginResponse>), StatusCode> {
+    let token = body
+        .and_then(|Json(b)| b.refresh_token)
+        .or_else(|| cookie_value(&req_headers, REFRESH_COOKIE))
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let sessions = ctx.session_repo();
+    let uc = RefreshSession {
+        sessions: sessions.as_ref(),
     };
-    let token = jsonwebtoken::encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(ctx.cfg.jwt_secret_pem.as_bytes()),
-    )
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let issued = uc
+        .execute(
+            &token,
+            user_agent(&req_headers).as_deref(),
+            client_ip(&req_headers).as_deref(),
+            ctx.cfg.refresh_token_ttl_secs,
+        )
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
-    // Set HttpOnly cookie with the access token
+    let repo = ctx.user_repo();
+    let uc = GetMe {
+        repo: repo.as_ref(),
+    };
+    let row = uc
+        .execute(issued.session.user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let user = UserResponse::from(row);
+    session_response(&ctx, issued, user)
+}
+
+fn session_response(
+    ctx: &AppContext,
+    issued: IssuedSession,
+    user: UserResponse,
+) -> Result<(HeaderMap, Json<LoginResponse>), StatusCode> {
+    let token = issue_access_token(&ctx.cfg, user.id, issued.session.id)?;
+
+    // Set HttpOnly cookies with the access and refresh tokens
     let mut headers = HeaderMap::new();
-    let secure = ctx
-        .cfg
-        .frontend_url
-        .as_deref()
-        .map(|u| u.starts_with("https://"))
-        .unwrap_or(false);
+    let secure = cookies_secure(&ctx.cfg);
     let cookie = build_access_cookie(&token, ctx.cfg.jwt_expires_secs, secure);
-    headers.insert(
+    headers.append(
         axum::http::header::SET_COOKIE,
         axum::http::HeaderValue::from_str(&cookie)
             .unwrap_or(axum::http::HeaderValue::from_static("")),
     );
+    let refresh_cookie = build_refresh_cookie(
+        &issued.refresh_token,
+        ctx.cfg.refresh_token_ttl_secs,
+        secure,
+    );
+    headers.append(
+        axum::http::header::SET_COOKIE,
+        axum::http::HeaderValue::from_str(&refresh_cookie)
+            .unwrap_or(axum::http::HeaderValue::from_static("")),
+    );
 
     Ok((
         headers,
         Json(LoginResponse {
             access_token: token,
+            refresh_token: issued.refresh_token,
+            expires_in: ctx.cfg.jwt_expires_secs,
             user,
         }),
     ))
 }
 
+fn issue_access_token(cfg: &Config, user_id: Uuid, session_id: Uuid) -> Result<String, StatusCode> {
+    let now = chrono::Utc::now().timestamp() as usize;
+    let claims = Claims {
+        sub: user_id.to_string(),
+        exp: now + (cfg.jwt_expires_secs as usize),
+        sid: Some(session_id.to_string()),
+    };
+    jsonwebtoken::encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(cfg.jwt_secret_pem.as_bytes()),
+    )
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn user_agent(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(axum::http::header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|ua| ua.chars().take(512).collect())
+}
+
+fn client_ip(headers: &HeaderMap) -> Option<String> {
+    let forwarded = headers
+        .get("x-forwarded-for")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(',').next())
+        .map(|v| v.trim().to_string());
+    forwarded
+        .or_else(|| {
+            headers
+                .get("x-real-ip")
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim().to_string())
+        })
+        .filter(|v| !v.is_empty())
+}
+
 #[utoipa::path(get, path = "/api/auth/me", tag = "Auth", responses((status = 200, body = UserResponse)))]
 pub async fn me(
     State(ctx): State<AppContext>,
     bearer: Result<Bearer, StatusCode>,
 ) -> Result<Json<UserResponse>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer?)?;
+    let sub = validate_bearer(&ctx, bearer?).await?;
     let id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.user_repo();
     let uc = GetMe {
@@ -160,11 +516,92 @@ pub async fn me(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::UNAUTHORIZED)?;
-    Ok(Json(UserResponse {
-        id: row.id,
-        email: row.email,
-        name: row.name,
-    }))
+    Ok(Json(UserResponse::from(row)))
+}
+
+fn map_profile_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "invalid_password" => StatusCode::FORBIDDEN,
+        "not_found" => StatusCode::NOT_FOUND,
+        "conflict" => StatusCode::CONFLICT,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::error!(error = ?e, "profile_update_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(patch, path = "/api/auth/me", tag = "Auth", request_body = UpdateProfileRequest,
+    responses((status = 200, body = UserResponse), (status = 400, description = "Invalid name"), (status = 409, description = "Name already taken")))]
+pub async fn update_profile(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<UpdateProfileRequest>,
+) -> Result<Json<UserResponse>, StatusCode> {
+    let (user_id, _) = authenticate_token(&ctx, &bearer.0).await?;
+    let users = ctx.user_repo();
+    let uc = UpdateName {
+        users: users.as_ref(),
+    };
+    let user = uc
+        .execute(user_id, &req.name)
+        .await
+        .map_err(map_profile_error)?;
+    Ok(Json(UserResponse::from(user)))
+}
+
+#[utoipa::path(put, path = "/api/auth/me/email", tag = "Auth", request_body = ChangeEmailRequest,
+    responses((status = 200, body = UserResponse, description = "Changed; the new address must be verified again"),
+        (status = 403, description = "Password incorrect"), (status = 409, description = "Email already registered")))]
+pub async fn change_email(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<ChangeEmailRequest>,
+) -> Result<Json<UserResponse>, StatusCode> {
+    let (user_id, _) = authenticate_token(&ctx, &bearer.0).await?;
+    let users = ctx.user_repo();
+    let links = ctx.auth_link_repo();
+    let mailer = ctx.mailer();
+    let uc = ChangeEmail {
+        users: users.as_ref(),
+        links: links.as_ref(),
+        mailer: mailer.as_ref(),
+        signing_secret: &ctx.cfg.jwt_secret_pem,
+        base_url: link_base_url(&ctx.cfg),
+    };
+    let user = uc
+        .execute(user_id, &req.email, req.password.as_deref())
+        .await
+        .map_err(map_profile_error)?;
+    Ok(Json(UserResponse::from(user)))
+}
+
+#[utoipa::path(put, path = "/api/auth/me/password", tag = "Auth", request_body = ChangePasswordRequest,
+    responses((status = 204, description = "Changed; other sessions revoked"), (status = 403, description = "Current password incorrect")))]
+pub async fn change_password(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<ChangePasswordRequest>,
+) -> Result<StatusCode, StatusCode> {
+    let (user_id, session_id) = authenticate_token(&ctx, &bearer.0).await?;
+    let users = ctx.user_repo();
+    let links = ctx.auth_link_repo();
+    let sessions = ctx.session_repo();
+    let uc = ChangePassword {
+        users: users.as_ref(),
+        links: links.as_ref(),
+        sessions: sessions.as_ref(),
+    };
+    uc.execute(
+        user_id,
+        session_id,
+        req.current_password.as_deref(),
+        &req.new_password,
+    )
+    .await
+    .map_err(map_profile_error)?;
+    Ok(StatusCode::NO_CONTENT)
 }
 
 #[utoipa::path(delete, path = "/api/auth/me", tag = "Auth", responses((status = 204)))]
@@ -172,7 +609,7 @@ pub async fn delete_account(
     State(ctx): State<AppContext>,
     bearer: Bearer,
 ) -> Result<(HeaderMap, StatusCode), StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
 
     let user_repo = ctx.user_repo();
@@ -183,9 +620,13 @@ pub async fn delete_account(
     let plugin_assets = ctx.plugin_assets();
     let git_repo = ctx.git_repo();
     let git_workspace = ctx.git_workspace();
+    let takeouts = ctx.takeout_repo();
+
+    let workspace_repo = ctx.workspace_repo();
 
     let uc = DeleteAccount {
         user_repo: user_repo.as_ref(),
+        workspace_repo: workspace_repo.as_ref(),
         document_repo: document_repo.as_ref(),
         storage: storage.as_ref(),
         plugin_installations: plugin_installations.as_ref(),
@@ -193,6 +634,7 @@ pub async fn delete_account(
         plugin_assets,
         git_repo: git_repo.as_ref(),
         git_workspace: git_workspace.as_ref(),
+        takeouts: takeouts.as_ref(),
     };
 
     uc.execute(user_id).await.map_err(|err| {
@@ -200,32 +642,18 @@ pub async fn delete_account(
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
-    let mut headers = HeaderMap::new();
-    let secure = ctx
-        .cfg
-        .frontend_url
-        .as_deref()
-        .map(|u| u.starts_with("https://"))
-        .unwrap_or(false);
-    let cookie = if secure {
-        "access_token=; HttpOnly; Secure; Path=/; Max-Age=0; SameSite=Lax"
-    } else {
-        "access_token=; HttpOnly; Path=/; Max-Age=0; SameSite=Lax"
-    };
-    headers.insert(
-        axum::http::header::SET_COOKIE,
-        axum::http::HeaderValue::from_str(cookie)
-            .unwrap_or(axum::http::HeaderValue::from_static("")),
-    );
-
+    let headers = cleared_auth_cookies(&ctx.cfg);
     Ok((headers, StatusCode::NO_CONTENT))
 }
 
 // --- Bearer extractor & JWT utils ---
-use axum::extract::FromRequestParts;
+use axum::extract::{FromRequestParts, OriginalUri};
+use axum::http::Method;
 use axum::http::request::Parts;
 
-pub struct Bearer(pub String);
+/// Bearer token plus the scope a personal access token needs for the current route
+/// (`None` when personal access tokens are not accepted there).
+pub struct Bearer(pub String, Option<ApiTokenScope>);
 
 #[axum::async_trait]
 impl<S> FromRequestParts<S> for Bearer
@@ -235,6 +663,7 @@ where
     type Rejection = StatusCode;
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let scope = required_scope(parts);
         // 1) Prefer Authorization header if present
         if let Some(auth) = parts
             .headers
@@ -242,7 +671,7 @@ where
             .and_then(|v| v.to_str().ok())
         {
             if let Some(t) = auth.strip_prefix("Bearer ") {
-                return Ok(Bearer(t.to_string()));
+                return Ok(Bearer(t.to_string(), scope));
             }
         }
 
@@ -253,7 +682,7 @@ where
             .and_then(|v| v.to_str().ok())
         {
             if let Some(token) = get_cookie(cookie_hdr, "access_token") {
-                return Ok(Bearer(token));
+                return Ok(Bearer(token, scope));
             }
         }
 
@@ -261,52 +690,150 @@ where
     }
 }
 
-pub(crate) fn validate_bearer(cfg: &Config, bearer: Bearer) -> Result<String, StatusCode> {
-    let token = bearer.0;
+/// Scope a personal access token must hold to call this route. Account, token and
+/// workspace management stay session-only.
+fn required_scope(parts: &Parts) -> Option<ApiTokenScope> {
+    let path = parts
+        .extensions
+        .get::<OriginalUri>()
+        .map(|u| u.0.path())
+        .unwrap_or_else(|| parts.uri.path());
+    let path = path.strip_prefix("/api")?;
+    let under = |prefix: &str| {
+        path.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    };
+    if under("/git") {
+        return Some(ApiTokenScope::GitSync);
+    }
+    if under("/markdown") {
+        return Some(ApiTokenScope::DocumentsRead);
+    }
+    let documents = [
+        "/documents",
+        "/files",
+        "/uploads",
+        "/tags",
+        "/templates",
+        "/trash",
+        "/comments",
+        "/shared-with-me",
+        "/me/recent",
+        "/me/favorites",
+    ];
+    if documents.iter().any(|p| under(p)) {
+        let read = matches!(parts.method, Method::GET | Method::HEAD | Method::OPTIONS);
+        return Some(if read {
+            ApiTokenScope::DocumentsRead
+        } else {
+            ApiTokenScope::DocumentsWrite
+        });
+    }
+    None
+}
+
+fn decode_claims(cfg: &Config, token: &str) -> Result<Claims, StatusCode> {
     let data = jsonwebtoken::decode::<Claims>(
-        &token,
+        token,
         &DecodingKey::from_secret(cfg.jwt_secret_pem.as_bytes()),
         &Validation::default(),
     )
     .map_err(|_| StatusCode::UNAUTHORIZED)?;
-    Ok(data.claims.sub)
+    Ok(data.claims)
+}
+
+/// Validates an access token and its session, returning `(user_id, session_id)`.
+pub(crate) async fn authenticate_token(
+    ctx: &AppContext,
+    token: &str,
+) -> Result<(Uuid, Uuid), StatusCode> {
+    let claims = decode_claims(&ctx.cfg, token)?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let session_id = claims
+        .sid
+        .as_deref()
+        .and_then(|sid| Uuid::parse_str(sid).ok())
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let active = ctx
+        .session_repo()
+        .touch(session_id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !active {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok((user_id, session_id))
 }
 
-pub fn validate_bearer_public(cfg: &Config, bearer: Bearer) -> Result<String, StatusCode> {
-    validate_bearer(cfg, bearer)
+/// Validates a personal access token against the scope the route requires.
+async fn authenticate_api_token(
+    ctx: &AppContext,
+    token: &str,
+    scope: Option<ApiTokenScope>,
+) -> Result<Uuid, StatusCode> {
+    let row = ctx
+        .api_token_repo()
+        .authenticate(&hash_token(token))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    if !api_token_allowed(scope, &row.scopes) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(row.user_id)
 }
 
-pub fn validate_bearer_str(cfg: &Config, token: &str) -> Result<String, StatusCode> {
-    let data = jsonwebtoken::decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(cfg.jwt_secret_pem.as_bytes()),
-        &Validation::default(),
-    )
-    .map_err(|_| StatusCode::UNAUTHORIZED)?;
-    Ok(data.claims.sub)
+// Routes without a scope are closed to personal access tokens.
+fn api_token_allowed(scope: Option<ApiTokenScope>, granted: &[String]) -> bool {
+    scope.is_some_and(|scope| scope.granted_by(granted))
+}
+
+pub(crate) async fn validate_bearer(
+    ctx: &AppContext,
+    bearer: Bearer,
+) -> Result<String, StatusCode> {
+    if is_api_token(&bearer.0) {
+        let user_id = authenticate_api_token(ctx, &bearer.0, bearer.1).await?;
+        return Ok(user_id.to_string());
+    }
+    validate_bearer_str(ctx, &bearer.0).await
 }
 
-pub fn resolve_actor_from_parts(
-    cfg: &Config,
+pub async fn validate_bearer_public(
+    ctx: &AppContext,
+    bearer: Bearer,
+) -> Result<String, StatusCode> {
+    validate_bearer(ctx, bearer).await
+}
+
+pub async fn validate_bearer_str(ctx: &AppContext, token: &str) -> Result<String, StatusCode> {
+    let (user_id, _) = authenticate_token(ctx, token).await?;
+    Ok(user_id.to_string())
+}
+
+pub async fn resolve_actor_from_parts(
+    ctx: &AppContext,
     bearer: Option<Bearer>,
     share_token: Option<&str>,
 ) -> Option<access::Actor> {
-    if let Some(b) = bearer {
-        if let Ok(sub) = validate_bearer(cfg, b) {
-            if let Ok(uid) = Uuid::parse_str(&sub) {
-                return Some(access::Actor::User(uid));
-            }
-        }
+    if let Some(b) = bearer
+        && let Ok(sub) = validate_bearer(ctx, b).await
+        && let Ok(uid) = Uuid::parse_str(&sub)
+    {
+        return Some(access::Actor::User(uid));
+    }
+    match share_token {
+        Some(t) => resolve_actor_from_token_str(ctx, t).await,
+        None => None,
     }
-    share_token.and_then(|t| resolve_actor_from_token_str(cfg, t))
 }
 
-pub fn resolve_actor_from_token_str(cfg: &Config, token: &str) -> Option<access::Actor> {
+pub async fn resolve_actor_from_token_str(ctx: &AppContext, token: &str) -> Option<access::Actor> {
     let trimmed = token.trim();
     if trimmed.is_empty() {
         return None;
     }
-    if let Ok(sub) = validate_bearer_str(cfg, trimmed) {
+    if let Ok(sub) = validate_bearer_str(ctx, trimmed).await {
         if let Ok(uid) = Uuid::parse_str(&sub) {
             return Some(access::Actor::User(uid));
         } else {
@@ -321,10 +848,10 @@ pub fn resolve_actor_from_token_str(cfg: &Config, token: &str) -> Option<access:
 fn get_cookie(cookie_header: &str, name: &str) -> Option<String> {
     for part in cookie_header.split(';') {
         let kv = part.trim();
-        if let Some((k, v)) = kv.split_once('=') {
-            if k.trim() == name {
-                return Some(v.trim().to_string());
-            }
+        if let Some((k, v)) = kv.split_once('=')
+            && k.trim() == name
+        {
+            return Some(v.trim().to_string());
         }
     }
     None
@@ -342,25 +869,545 @@ fn build_access_cookie(token: &str, max_age_secs: i64, secure: bool) -> String {
     )
 }
 
-#[utoipa::path(post, path = "/api/auth/logout", tag = "Auth", responses((status = 204)))]
-pub async fn logout(State(ctx): State<AppContext>) -> Result<(HeaderMap, StatusCode), StatusCode> {
-    // Clear cookie by setting it expired
-    let mut headers = HeaderMap::new();
-    let secure = ctx
-        .cfg
-        .frontend_url
+fn cookie_value(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(axum::http::header::COOKIE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|hdr| get_cookie(hdr, name))
+        .filter(|v| !v.is_empty())
+}
+
+const REFRESH_COOKIE: &str = "refresh_token";
+
+fn cookies_secure(cfg: &Config) -> bool {
+    cfg.frontend_url
         .as_deref()
         .map(|u| u.starts_with("https://"))
-        .unwrap_or(false);
-    let cookie = if secure {
-        "access_token=; HttpOnly; Secure; Path=/; Max-Age=0; SameSite=Lax"
+        .unwrap_or(false)
+}
+
+fn build_refresh_cookie(token: &str, max_age_secs: i64, secure: bool) -> String {
+    // Only the auth endpoints ever need the refresh token.
+    let secure_attr = if secure { "; Secure" } else { "" };
+    format!(
+        "{}={}; HttpOnly{}; Path=/api/auth; Max-Age={}; SameSite=Lax",
+        REFRESH_COOKIE,
+        token,
+        secure_attr,
+        max_age_secs.max(0)
+    )
+}
+
+fn cleared_auth_cookies(cfg: &Config) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    let secure = cookies_secure(cfg);
+    for cookie in [
+        build_access_cookie("", 0, secure),
+        build_refresh_cookie("", 0, secure),
+    ] {
+        headers.append(
+            axum::http::header::SET_COOKIE,
+            axum::http::HeaderValue::from_str(&cookie)
+                .unwrap_or(axum::http::HeaderValue::from_static("")),
+        );
+    }
+    headers
+}
+
+#[utoipa::path(post, path = "/api/auth/logout", tag = "Auth", responses((status = 204)))]
+pub async fn logout(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    req_headers: HeaderMap,
+) -> Result<(HeaderMap, StatusCode), StatusCode> {
+    // Revoke the server-side session (from the access token, else the refresh cookie)
+    let sessions = ctx.session_repo();
+    let mut current = match bearer {
+        Some(b) => authenticate_token(&ctx, &b.0).await.ok(),
+        None => None,
+    };
+    if current.is_none()
+        && let Some(token) = cookie_value(&req_headers, REFRESH_COOKIE)
+        && let Ok(Some(session)) = sessions.find_by_refresh_hash(&hash_token(&token)).await
+    {
+        current = Some((session.user_id, session.id));
+    }
+    if let Some((user_id, session_id)) = current {
+        let uc = RevokeSession {
+            sessions: sessions.as_ref(),
+        };
+        uc.execute(user_id, session_id)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+    // Clear cookies by setting them expired
+    Ok((cleared_auth_cookies(&ctx.cfg), StatusCode::NO_CONTENT))
+}
+
+#[utoipa::path(get, path = "/api/auth/sessions", tag = "Auth", responses((status = 200, body = [SessionItem])))]
+pub async fn list_sessions(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<Vec<SessionItem>>, StatusCode> {
+    let (user_id, current_id) = authenticate_token(&ctx, &bearer.0).await?;
+    let sessions = ctx.session_repo();
+    let uc = ListSessions {
+        sessions: sessions.as_ref(),
+    };
+    let rows = uc
+        .execute(user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(
+        rows.into_iter()
+            .map(|s| SessionItem {
+                current: s.id == current_id,
+                id: s.id,
+                user_agent: s.user_agent,
+                ip_address: s.ip_address,
+                created_at: s.created_at,
+                last_seen_at: s.last_seen_at,
+                expires_at: s.expires_at,
+            })
+            .collect(),
+    ))
+}
+
+#[utoipa::path(delete, path = "/api/auth/sessions/{id}", tag = "Auth",
+    params(("id" = Uuid, Path, description = "Session ID")),
+    responses((status = 204), (status = 404, description = "Session not found or already revoked")))]
+pub async fn revoke_session(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<(HeaderMap, StatusCode), StatusCode> {
+    let (user_id, current_id) = authenticate_token(&ctx, &bearer.0).await?;
+    let sessions = ctx.session_repo();
+    let uc = RevokeSession {
+        sessions: sessions.as_ref(),
+    };
+    let revoked = uc
+        .execute(user_id, id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !revoked {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let headers = if id == current_id {
+        cleared_auth_cookies(&ctx.cfg)
     } else {
-        "access_token=; HttpOnly; Path=/; Max-Age=0; SameSite=Lax"
+        HeaderMap::new()
     };
-    headers.insert(
+    Ok((headers, StatusCode::NO_CONTENT))
+}
+
+#[utoipa::path(delete, path = "/api/auth/sessions", tag = "Auth", responses((status = 204)))]
+pub async fn revoke_all_sessions(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<(HeaderMap, StatusCode), StatusCode> {
+    let (user_id, _) = authenticate_token(&ctx, &bearer.0).await?;
+    let sessions = ctx.session_repo();
+    let uc = RevokeAllSessions {
+        sessions: sessions.as_ref(),
+    };
+    uc.execute(user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok((cleared_auth_cookies(&ctx.cfg), StatusCode::NO_CONTENT))
+}
+
+#[utoipa::path(get, path = "/api/auth/providers", tag = "Auth", security(()), responses((status = 200, body = AuthProvidersResponse)))]
+pub async fn providers(State(ctx): State<AppContext>) -> Json<AuthProvidersResponse> {
+    Json(AuthProvidersResponse {
+        password_signup_enabled: ctx.cfg.password_signup_enabled,
+        oidc: ctx.oidc_provider().map(|p| OidcProviderInfo {
+            name: p.name().to_string(),
+            login_url: "/api/auth/oidc/login".into(),
+        }),
+    })
+}
+
+const OIDC_STATE_COOKIE: &str = "oidc_state";
+
+fn build_oidc_state_cookie(state: &str, max_age_secs: i64, secure: bool) -> String {
+    // Binds the login to this browser; Lax still sends it on the IdP's top-level redirect back.
+    let secure_attr = if secure { "; Secure" } else { "" };
+    format!(
+        "{}={}; HttpOnly{}; Path=/api/auth/oidc; Max-Age={}; SameSite=Lax",
+        OIDC_STATE_COOKIE,
+        state,
+        secure_attr,
+        max_age_secs.max(0)
+    )
+}
+
+fn frontend_redirect(cfg: &Config, path: &str) -> Redirect {
+    let base = cfg
+        .frontend_url
+        .as_deref()
+        .unwrap_or("")
+        .trim_end_matches('/');
+    Redirect::to(&format!("{base}{path}"))
+}
+
+#[utoipa::path(get, path = "/api/auth/oidc/login", tag = "Auth", security(()), params(OidcLoginQuery),
+    responses((status = 303, description = "Redirect to the identity provider"), (status = 404, description = "OIDC not configured")))]
+pub async fn oidc_login(
+    State(ctx): State<AppContext>,
+    Query(q): Query<OidcLoginQuery>,
+) -> Result<(HeaderMap, Redirect), StatusCode> {
+    let provider = ctx.oidc_provider().ok_or(StatusCode::NOT_FOUND)?;
+    let repo = ctx.oidc_repo();
+    let uc = BeginOidcLogin {
+        provider: provider.as_ref(),
+        repo: repo.as_ref(),
+    };
+    let redirect = uc.execute(q.redirect_to.as_deref()).await.map_err(|e| {
+        tracing::error!(error = ?e, "oidc_login_failed");
+        StatusCode::BAD_GATEWAY
+    })?;
+    let mut headers = HeaderMap::new();
+    let cookie = build_oidc_state_cookie(&redirect.state, 10 * 60, cookies_secure(&ctx.cfg));
+    headers.append(
         axum::http::header::SET_COOKIE,
-        axum::http::HeaderValue::from_str(cookie)
+        axum::http::HeaderValue::from_str(&cookie)
             .unwrap_or(axum::http::HeaderValue::from_static("")),
     );
-    Ok((headers, StatusCode::NO_CONTENT))
+    Ok((headers, Redirect::to(&redirect.authorization_url)))
+}
+
+#[utoipa::path(get, path = "/api/auth/oidc/callback", tag = "Auth", security(()), params(OidcCallbackQuery),
+    responses((status = 303, description = "Signed in and redirected to the frontend; failures redirect to the sign-in page with an `error` query. Accounts with TOTP are sent to the sign-in page with `#mfa_token=...` to finish through /api/auth/login/mfa")))]
+pub async fn oidc_callback(
+    State(ctx): State<AppContext>,
+    req_headers: HeaderMap,
+    Query(q): Query<OidcCallbackQuery>,
+) -> Result<(HeaderMap, Redirect), StatusCode> {
+    let provider = ctx.oidc_provider().ok_or(StatusCode::NOT_FOUND)?;
+    let secure = cookies_secure(&ctx.cfg);
+    let clear_state = |mut headers: HeaderMap| {
+        headers.append(
+            axum::http::header::SET_COOKIE,
+            axum::http::HeaderValue::from_str(&build_oidc_state_cookie("", 0, secure))
+                .unwrap_or(axum::http::HeaderValue::from_static("")),
+        );
+        headers
+    };
+    let fail = |code: &str| {
+        Ok((
+            clear_state(HeaderMap::new()),
+            frontend_redirect(&ctx.cfg, &format!("/auth/signin?error={code}")),
+        ))
+    };
+
+    let (Some(code), Some(state)) = (q.code.as_deref(), q.state.as_deref()) else {
+        if let Some(err) = q.error.as_deref() {
+            tracing::warn!(error = %err, "oidc_provider_error");
+        }
+        return fail("oidc_failed");
+    };
+    if cookie_value(&req_headers, OIDC_STATE_COOKIE).as_deref() != Some(state) {
+        return fail("oidc_state");
+    }
+
+    let repo = ctx.oidc_repo();
+    let users = ctx.user_repo();
+    let cfg_oidc = ctx.cfg.oidc.as_ref().ok_or(StatusCode::NOT_FOUND)?;
+    let uc = CompleteOidcLogin {
+        provider: provider.as_ref(),
+        repo: repo.as_ref(),
+        users: users.as_ref(),
+        allowed_email_domains: &cfg_oidc.allowed_email_domains,
+    };
+    let result = match uc.execute(state, code).await {
+        Ok(r) => r,
+        Err(e) => {
+            let code = match e.to_string().as_str() {
+                "invalid_state" => "oidc_state",
+                "forbidden" => "oidc_domain",
+                "conflict" => "oidc_account_exists",
+                "bad_request" => "oidc_email_required",
+                _ => {
+                    tracing::error!(error = ?e, "oidc_callback_failed");
+                    "oidc_failed"
+                }
+            };
+            return fail(code);
+        }
+    };
+    if result.user.disabled_at.is_some() {
+        return fail("oidc_disabled");
+    }
+
+    // The identity provider does not stand in for the account's own second factor; the
+    // challenge goes in the fragment so it stays out of server logs and Referer headers.
+    let mfa = ctx.mfa_repo();
+    let challenge = StartMfaChallenge { mfa: mfa.as_ref() };
+    if let Some(mfa_token) = challenge
+        .execute(result.user.id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    {
+        let mut fragment = format!("mfa_token={mfa_token}");
+        if let Some(target) = result.redirect_to.as_deref() {
+            fragment.push_str(&format!("&redirect_to={}", urlencoding::encode(target)));
+        }
+        return Ok((
+            clear_state(HeaderMap::new()),
+            frontend_redirect(&ctx.cfg, &format!("/auth/signin#{fragment}")),
+        ));
+    }
+
+    let user = UserResponse::from(result.user);
+    let (headers, _) = start_session(&ctx, &req_headers, user).await?;
+    let target = result.redirect_to.as_deref().unwrap_or("/dashboard");
+    Ok((clear_state(headers), frontend_redirect(&ctx.cfg, target)))
+}
+
+fn map_mfa_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "unauthorized" | "invalid_code" => StatusCode::UNAUTHORIZED,
+        "locked" => StatusCode::TOO_MANY_REQUESTS,
+        "not_found" => StatusCode::NOT_FOUND,
+        "conflict" => StatusCode::CONFLICT,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::error!(error = ?e, "mfa_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/api/auth/mfa", tag = "Auth", responses((status = 200, body = MfaStatusResponse)))]
+pub async fn mfa_status(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<MfaStatusResponse>, StatusCode> {
+    let (user_id, _) = authenticate_token(&ctx, &bearer.0).await?;
+    let mfa = ctx.mfa_repo();
+    let uc = GetMfaStatus { mfa: mfa.as_ref() };
+    let status = uc.execute(user_id).await.map_err(map_mfa_error)?;
+    Ok(Json(MfaStatusResponse {
+        totp_enabled: status.totp_enabled,
+        recovery_codes_remaining: status.recovery_codes_remaining,
+    }))
+}
+
+#[utoipa::path(post, path = "/api/auth/mfa/totp/setup", tag = "Auth",
+    responses((status = 200, body = TotpSetupResponse), (status = 409, description = "TOTP already enabled")))]
+pub async fn setup_totp(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<TotpSetupResponse>, StatusCode> {
+    let (user_id, _) = authenticate_token(&ctx, &bearer.0).await?;
+    let mfa = ctx.mfa_repo();
+    let users = ctx.user_repo();
+    let uc = SetupTotp {
+        mfa: mfa.as_ref(),
+        users: users.as_ref(),
+    };
+    let enrollment = uc.execute(user_id).await.map_err(map_mfa_error)?;
+    Ok(Json(TotpSetupResponse {
+        secret: enrollment.secret,
+        otpauth_uri: enrollment.otpauth_uri,
+    }))
+}
+
+#[utoipa::path(post, path = "/api/auth/mfa/totp/enable", tag = "Auth", request_body = TotpCodeRequest,
+    responses((status = 200, body = RecoveryCodesResponse), (status = 401, description = "Code invalid")))]
+pub async fn enable_totp(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<TotpCodeRequest>,
+) -> Result<Json<RecoveryCodesResponse>, StatusCode> {
+    let (user_id, _) = authenticate_token(&ctx, &bearer.0).await?;
+    let mfa = ctx.mfa_repo();
+    let uc = EnableTotp { mfa: mfa.as_ref() };
+    let recovery_codes = uc
+        .execute(user_id, &req.code)
+        .await
+        .map_err(map_mfa_error)?;
+    Ok(Json(RecoveryCodesResponse { recovery_codes }))
+}
+
+#[utoipa::path(post, path = "/api/auth/mfa/totp/disable", tag = "Auth", request_body = DisableTotpRequest,
+    responses((status = 204), (status = 401, description = "Password or code invalid"), (status = 404, description = "TOTP not enabled"), (status = 429, description = "Too many failed codes; try again later")))]
+pub async fn disable_totp(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<DisableTotpRequest>,
+) -> Result<StatusCode, StatusCode> {
+    let (user_id, _) = authenticate_token(&ctx, &bearer.0).await?;
+    let mfa = ctx.mfa_repo();
+    let users = ctx.user_repo();
+    let uc = DisableTotp {
+        mfa: mfa.as_ref(),
+        users: users.as_ref(),
+    };
+    uc.execute(user_id, req.password.as_deref(), &req.code)
+        .await
+        .map_err(map_mfa_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn link_base_url(cfg: &Config) -> &str {
+    cfg.frontend_url
+        .as_deref()
+        .or(cfg.public_base_url.as_deref())
+        .unwrap_or("")
+}
+
+fn map_link_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "invalid_token" => StatusCode::BAD_REQUEST,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::error!(error = ?e, "auth_link_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(post, path = "/api/auth/password/forgot", tag = "Auth", request_body = EmailRequest, security(()),
+    responses((status = 204, description = "Reset link sent if the address is registered")))]
+pub async fn forgot_password(
+    State(ctx): State<AppContext>,
+    Json(req): Json<EmailRequest>,
+) -> Result<StatusCode, StatusCode> {
+    let users = ctx.user_repo();
+    let links = ctx.auth_link_repo();
+    let mailer = ctx.mailer();
+    let uc = RequestPasswordReset {
+        users: users.as_ref(),
+        links: links.as_ref(),
+        mailer: mailer.as_ref(),
+        signing_secret: &ctx.cfg.jwt_secret_pem,
+        base_url: link_base_url(&ctx.cfg),
+    };
+    uc.execute(&req.email).await.map_err(map_link_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(post, path = "/api/auth/password/reset", tag = "Auth", request_body = ResetPasswordRequest, security(()),
+    responses((status = 204, description = "Password changed; all sessions revoked"), (status = 400, description = "Link invalid, expired or already used")))]
+pub async fn reset_password(
+    State(ctx): State<AppContext>,
+    Json(req): Json<ResetPasswordRequest>,
+) -> Result<(HeaderMap, StatusCode), StatusCode> {
+    let users = ctx.user_repo();
+    let links = ctx.auth_link_repo();
+    let sessions = ctx.session_repo();
+    let uc = ResetPassword {
+        users: users.as_ref(),
+        links: links.as_ref(),
+        sessions: sessions.as_ref(),
+        signing_secret: &ctx.cfg.jwt_secret_pem,
+    };
+    uc.execute(&req.token, &req.new_password)
+        .await
+        .map_err(map_link_error)?;
+    Ok((cleared_auth_cookies(&ctx.cfg), StatusCode::NO_CONTENT))
+}
+
+#[utoipa::path(post, path = "/api/auth/email/verify", tag = "Auth", request_body = VerifyEmailRequest, security(()),
+    responses((status = 204), (status = 400, description = "Link invalid, expired or already used")))]
+pub async fn verify_email(
+    State(ctx): State<AppContext>,
+    Json(req): Json<VerifyEmailRequest>,
+) -> Result<StatusCode, StatusCode> {
+    let users = ctx.user_repo();
+    let links = ctx.auth_link_repo();
+    let uc = VerifyEmail {
+        users: users.as_ref(),
+        links: links.as_ref(),
+        signing_secret: &ctx.cfg.jwt_secret_pem,
+    };
+    uc.execute(&req.token).await.map_err(map_link_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(post, path = "/api/auth/email/verification", tag = "Auth", request_body = EmailRequest, security(()),
+    responses((status = 204, description = "Verification link re-sent if the address is registered and unverified")))]
+pub async fn resend_verification(
+    State(ctx): State<AppContext>,
+    Json(req): Json<EmailRequest>,
+) -> Result<StatusCode, StatusCode> {
+    let users = ctx.user_repo();
+    let links = ctx.auth_link_repo();
+    let mailer = ctx.mailer();
+    let uc = SendEmailVerification {
+        users: users.as_ref(),
+        links: links.as_ref(),
+        mailer: mailer.as_ref(),
+        signing_secret: &ctx.cfg.jwt_secret_pem,
+        base_url: link_base_url(&ctx.cfg),
+    };
+    uc.execute_for_email(&req.email)
+        .await
+        .map_err(map_link_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(method: Method, path: &str) -> Parts {
+        // Routers are nested under /api, so handlers see the stripped path plus OriginalUri
+        let (mut parts, _) = axum::http::Request::builder()
+            .method(method)
+            .uri(path.strip_prefix("/api").unwrap_or(path))
+            .body(())
+            .unwrap()
+            .into_parts();
+        parts.extensions.insert(OriginalUri(path.parse().unwrap()));
+        parts
+    }
+
+    #[test]
+    fn personal_access_tokens_need_the_route_scope() {
+        use ApiTokenScope::*;
+        for (method, path, expected) in [
+            (Method::GET, "/api/documents", Some(DocumentsRead)),
+            (Method::GET, "/api/documents/1/content", Some(DocumentsRead)),
+            (
+                Method::PATCH,
+                "/api/documents/1/content",
+                Some(DocumentsWrite),
+            ),
+            (Method::DELETE, "/api/trash/1", Some(DocumentsWrite)),
+            (Method::PUT, "/api/me/favorites/1", Some(DocumentsWrite)),
+            (Method::GET, "/api/me/recent", Some(DocumentsRead)),
+            (Method::POST, "/api/markdown/render", Some(DocumentsRead)),
+            (Method::POST, "/api/git/sync", Some(GitSync)),
+            // Session-only: account, tokens, workspaces, admin
+            (Method::GET, "/api/me/tokens", None),
+            (Method::POST, "/api/auth/sessions", None),
+            (Method::GET, "/api/workspaces", None),
+            (Method::GET, "/api/admin/users", None),
+            (Method::GET, "/api/documentsx", None),
+            (Method::GET, "/documents", None),
+        ] {
+            assert_eq!(
+                required_scope(&parts(method.clone(), path)),
+                expected,
+                "{method} {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn session_only_routes_refuse_every_token() {
+        let all: Vec<String> = ApiTokenScope::ALL
+            .iter()
+            .map(|s| s.as_str().to_string())
+            .collect();
+        assert!(!api_token_allowed(None, &all));
+        let read = vec!["documents:read".to_string()];
+        let scope = required_scope(&parts(Method::POST, "/api/documents"));
+        assert!(!api_token_allowed(scope, &read));
+        let scope = required_scope(&parts(Method::GET, "/api/documents"));
+        assert!(api_token_allowed(scope, &read));
+    }
 }
@@ -0,0 +1,502 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{delete, get, patch, post},
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::application::access::Actor;
+use crate::application::ports::comment_repository::{CommentRow, CommentThreadRow, MentionRow};
+use crate::application::use_cases::comments::CommentThreadView;
+use crate::application::use_cases::comments::add_reply::AddReply;
+use crate::application::use_cases::comments::create_thread::{CreateThread, CreateThreadInput};
+use crate::application::use_cases::comments::delete_comment::DeleteComment;
+use crate::application::use_cases::comments::delete_thread::DeleteThread;
+use crate::application::use_cases::comments::list_mentions::ListMentions;
+use crate::application::use_cases::comments::list_threads::ListThreads;
+use crate::application::use_cases::comments::set_thread_status::SetThreadStatus;
+use crate::application::use_cases::comments::update_comment::UpdateComment;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CommentTokenQuery {
+    /// Share token (optional)
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentItem {
+    pub id: Uuid,
+    pub thread_id: Uuid,
+    pub author_id: Option<Uuid>,
+    /// Null for share-link reviewers and deleted accounts
+    pub author_name: Option<String>,
+    pub body: String,
+    pub mentions: Vec<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<CommentRow> for CommentItem {
+    fn from(c: CommentRow) -> Self {
+        CommentItem {
+            id: c.id,
+            thread_id: c.thread_id,
+            author_id: c.author_id,
+            author_name: c.author_name,
+            body: c.body,
+            mentions: c.mentions,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentThreadItem {
+    pub id: Uuid,
+    pub document_id: Uuid,
+    /// Base64-encoded Y.RelativePosition
+    pub anchor_start: Option<String>,
+    pub anchor_end: Option<String>,
+    /// Current UTF-16 offsets of the anchors in the document content
+    pub start_offset: Option<u32>,
+    pub end_offset: Option<u32>,
+    pub quote: Option<String>,
+    /// open | resolved
+    pub status: String,
+    pub created_by: Option<Uuid>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub comments: Vec<CommentItem>,
+}
+
+fn to_thread_item(
+    t: CommentThreadRow,
+    comments: Vec<CommentRow>,
+    start_offset: Option<u32>,
+    end_offset: Option<u32>,
+) -> CommentThreadItem {
+    CommentThreadItem {
+        id: t.id,
+        document_id: t.document_id,
+        anchor_start: t.anchor_start.map(|b| BASE64_STANDARD.encode(b)),
+        anchor_end: t.anchor_end.map(|b| BASE64_STANDARD.encode(b)),
+        start_offset,
+        end_offset,
+        quote: t.quote,
+        status: t.status,
+        created_by: t.created_by,
+        resolved_by: t.resolved_by,
+        resolved_at: t.resolved_at,
+        created_at: t.created_at,
+        updated_at: t.updated_at,
+        comments: comments.into_iter().map(Into::into).collect(),
+    }
+}
+
+impl From<CommentThreadView> for CommentThreadItem {
+    fn from(v: CommentThreadView) -> Self {
+        to_thread_item(v.thread, v.comments, v.start_offset, v.end_offset)
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateCommentThreadRequest {
+    /// Base64-encoded Y.RelativePosition; omit both anchors for a document-level thread
+    pub anchor_start: Option<String>,
+    pub anchor_end: Option<String>,
+    /// Excerpt of the anchored text at creation time
+    pub quote: Option<String>,
+    pub body: String,
+    #[serde(default)]
+    pub mentions: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CommentBodyRequest {
+    pub body: String,
+    #[serde(default)]
+    pub mentions: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MentionItem {
+    pub comment_id: Uuid,
+    pub thread_id: Uuid,
+    pub document_id: Uuid,
+    pub document_title: String,
+    pub author_name: Option<String>,
+    pub body: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<MentionRow> for MentionItem {
+    fn from(m: MentionRow) -> Self {
+        MentionItem {
+            comment_id: m.comment_id,
+            thread_id: m.thread_id,
+            document_id: m.document_id,
+            document_title: m.document_title,
+            author_name: m.author_name,
+            body: m.body,
+            created_at: m.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct MentionsQuery {
+    pub limit: Option<i64>,
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        "forbidden" => StatusCode::FORBIDDEN,
+        "unauthorized" => StatusCode::UNAUTHORIZED,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::error!(error = ?e, "comment_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+async fn resolve_actor(
+    ctx: &AppContext,
+    bearer: Option<Bearer>,
+    q: &CommentTokenQuery,
+) -> Result<Actor, StatusCode> {
+    auth::resolve_actor_from_parts(ctx, bearer, q.token.as_deref())
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)
+}
+
+fn decode_anchor(value: Option<&str>) -> Result<Option<Vec<u8>>, StatusCode> {
+    match value {
+        Some(v) => BASE64_STANDARD
+            .decode(v.trim())
+            .map(Some)
+            .map_err(|_| StatusCode::BAD_REQUEST),
+        None => Ok(None),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/documents/{id}/comments",
+    tag = "Comments",
+    params(("id" = Uuid, Path, description = "Document ID"), CommentTokenQuery),
+    responses((status = 200, body = [CommentThreadItem]))
+)]
+pub async fn list_threads(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    Query(q): Query<CommentTokenQuery>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<CommentThreadItem>>, StatusCode> {
+    let actor = resolve_actor(&ctx, bearer, &q).await?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let shares = ctx.share_access_port();
+    let realtime = ctx.realtime_engine();
+    let uc = ListThreads {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: shares.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let threads = uc
+        .execute(&actor, id)
+        .await
+        .map_err(map_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok(Json(threads.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/documents/{id}/comments",
+    tag = "Comments",
+    request_body = CreateCommentThreadRequest,
+    params(("id" = Uuid, Path, description = "Document ID"), CommentTokenQuery),
+    responses((status = 200, body = CommentThreadItem))
+)]
+pub async fn create_thread(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    Query(q): Query<CommentTokenQuery>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateCommentThreadRequest>,
+) -> Result<Json<CommentThreadItem>, StatusCode> {
+    let actor = resolve_actor(&ctx, bearer, &q).await?;
+    let anchor_start = decode_anchor(req.anchor_start.as_deref())?;
+    let anchor_end = decode_anchor(req.anchor_end.as_deref())?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let shares = ctx.share_access_port();
+    let realtime = ctx.realtime_engine();
+    let uc = CreateThread {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: shares.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let view = uc
+        .execute(
+            &actor,
+            id,
+            CreateThreadInput {
+                anchor_start: anchor_start.as_deref(),
+                anchor_end: anchor_end.as_deref(),
+                quote: req.quote.as_deref(),
+                body: &req.body,
+                mentions: &req.mentions,
+            },
+        )
+        .await
+        .map_err(map_error)?;
+    Ok(Json(view.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/comments/threads/{thread_id}/replies",
+    tag = "Comments",
+    request_body = CommentBodyRequest,
+    params(("thread_id" = Uuid, Path, description = "Thread ID"), CommentTokenQuery),
+    responses((status = 200, body = CommentItem))
+)]
+pub async fn add_reply(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    Query(q): Query<CommentTokenQuery>,
+    Path(thread_id): Path<Uuid>,
+    Json(req): Json<CommentBodyRequest>,
+) -> Result<Json<CommentItem>, StatusCode> {
+    let actor = resolve_actor(&ctx, bearer, &q).await?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let shares = ctx.share_access_port();
+    let realtime = ctx.realtime_engine();
+    let uc = AddReply {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: shares.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let comment = uc
+        .execute(&actor, thread_id, &req.body, &req.mentions)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(comment.into()))
+}
+
+async fn set_status(
+    ctx: AppContext,
+    bearer: Option<Bearer>,
+    q: CommentTokenQuery,
+    thread_id: Uuid,
+    resolved: bool,
+) -> Result<Json<CommentThreadItem>, StatusCode> {
+    let actor = resolve_actor(&ctx, bearer, &q).await?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let shares = ctx.share_access_port();
+    let realtime = ctx.realtime_engine();
+    let uc = SetThreadStatus {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: shares.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let thread = uc
+        .execute(&actor, thread_id, resolved)
+        .await
+        .map_err(map_error)?;
+    let rows = comments
+        .list_comments(thread.document_id)
+        .await
+        .map_err(map_error)?
+        .into_iter()
+        .filter(|c| c.thread_id == thread.id)
+        .collect();
+    Ok(Json(to_thread_item(thread, rows, None, None)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/comments/threads/{thread_id}/resolve",
+    tag = "Comments",
+    params(("thread_id" = Uuid, Path, description = "Thread ID"), CommentTokenQuery),
+    responses((status = 200, body = CommentThreadItem))
+)]
+pub async fn resolve_thread(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    Query(q): Query<CommentTokenQuery>,
+    Path(thread_id): Path<Uuid>,
+) -> Result<Json<CommentThreadItem>, StatusCode> {
+    set_status(ctx, bearer, q, thread_id, true).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/comments/threads/{thread_id}/reopen",
+    tag = "Comments",
+    params(("thread_id" = Uuid, Path, description = "Thread ID"), CommentTokenQuery),
+    responses((status = 200, body = CommentThreadItem))
+)]
+pub async fn reopen_thread(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    Query(q): Query<CommentTokenQuery>,
+    Path(thread_id): Path<Uuid>,
+) -> Result<Json<CommentThreadItem>, StatusCode> {
+    set_status(ctx, bearer, q, thread_id, false).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/comments/threads/{thread_id}",
+    tag = "Comments",
+    params(("thread_id" = Uuid, Path, description = "Thread ID"), CommentTokenQuery),
+    responses((status = 204))
+)]
+pub async fn delete_thread(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    Query(q): Query<CommentTokenQuery>,
+    Path(thread_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let actor = resolve_actor(&ctx, bearer, &q).await?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let shares = ctx.share_access_port();
+    let realtime = ctx.realtime_engine();
+    let uc = DeleteThread {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: shares.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    uc.execute(&actor, thread_id).await.map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/comments/{comment_id}",
+    tag = "Comments",
+    request_body = CommentBodyRequest,
+    params(("comment_id" = Uuid, Path, description = "Comment ID")),
+    responses((status = 200, body = CommentItem))
+)]
+pub async fn update_comment(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(comment_id): Path<Uuid>,
+    Json(req): Json<CommentBodyRequest>,
+) -> Result<Json<CommentItem>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let shares = ctx.share_access_port();
+    let realtime = ctx.realtime_engine();
+    let uc = UpdateComment {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: shares.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let comment = uc
+        .execute(&Actor::User(user_id), comment_id, &req.body, &req.mentions)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(comment.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/comments/{comment_id}",
+    tag = "Comments",
+    params(("comment_id" = Uuid, Path, description = "Comment ID"), CommentTokenQuery),
+    responses((status = 204))
+)]
+pub async fn delete_comment(
+    State(ctx): State<AppContext>,
+    bearer: Option<Bearer>,
+    Query(q): Query<CommentTokenQuery>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let actor = resolve_actor(&ctx, bearer, &q).await?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let shares = ctx.share_access_port();
+    let realtime = ctx.realtime_engine();
+    let uc = DeleteComment {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: shares.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    uc.execute(&actor, comment_id).await.map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/comments/mentions",
+    tag = "Comments",
+    params(MentionsQuery),
+    responses((status = 200, body = [MentionItem]))
+)]
+pub async fn list_mentions(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Query(q): Query<MentionsQuery>,
+) -> Result<Json<Vec<MentionItem>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let comments = ctx.comment_repo();
+    let access_repo = ctx.access_repo();
+    let share_access = ctx.share_access_port();
+    let uc = ListMentions {
+        comments: comments.as_ref(),
+        access: access_repo.as_ref(),
+        shares: share_access.as_ref(),
+    };
+    let rows = uc
+        .execute(user_id, q.limit.unwrap_or(50))
+        .await
+        .map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route(
+            "/documents/:id/comments",
+            get(list_threads).post(create_thread),
+        )
+        .route("/comments/mentions", get(list_mentions))
+        .route("/comments/threads/:thread_id", delete(delete_thread))
+        .route("/comments/threads/:thread_id/replies", post(add_reply))
+        .route("/comments/threads/:thread_id/resolve", post(resolve_thread))
+        .route("/comments/threads/:thread_id/reopen", post(reopen_thread))
+        .route(
+            "/comments/:comment_id",
+            patch(update_comment).delete(delete_comment),
+        )
+        .with_state(ctx)
+}
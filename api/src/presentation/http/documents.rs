@@ -1,9 +1,9 @@
 use axum::{
     Json, Router,
-    extract::{Path, Query, State},
+    extract::{FromRequest, Multipart, Path, Query, State},
     http::{HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::{get, post},
+    routing::{get, post, put},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
@@ -11,19 +11,33 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::application::access;
-use crate::application::ports::document_repository::DocumentListState;
+use crate::application::ports::document_repository::{
+    DocumentListState, DocumentSearch, PropertyFilter,
+};
 use crate::application::ports::document_snapshot_archive_repository::SnapshotArchiveRecord;
+use crate::application::ports::realtime_types::{ContentEdit, ContentState, TextOperation};
 use crate::application::use_cases::documents::archive_document::ArchiveDocument;
-use crate::application::use_cases::documents::create_document::CreateDocument;
+use crate::application::use_cases::documents::create_document::{
+    CreateDocument, InitialAttachment, InitialContent,
+};
 use crate::application::use_cases::documents::delete_document::DeleteDocument;
 use crate::application::use_cases::documents::download_document::{
     DocumentDownloadFormat, DownloadDocument as DownloadDocumentUseCase,
 };
+use crate::application::use_cases::documents::duplicate_document::{
+    DuplicateDocument, DuplicateOptions,
+};
+use crate::application::use_cases::documents::edit_content::{
+    EditDocumentContent, GetDocumentContent,
+};
 use crate::application::use_cases::documents::get_backlinks::GetBacklinks;
 use crate::application::use_cases::documents::get_document::GetDocument;
 use crate::application::use_cases::documents::get_outgoing_links::GetOutgoingLinks;
 use crate::application::use_cases::documents::list_documents::ListDocuments;
 use crate::application::use_cases::documents::list_snapshots::ListSnapshots;
+use crate::application::use_cases::documents::quick_search::QuickSearchDocuments;
+use crate::application::use_cases::documents::rename_links::{LinkRewrite, RenameLinks};
+use crate::application::use_cases::documents::reorder_documents::{Placement, ReorderDocuments};
 use crate::application::use_cases::documents::restore_snapshot::RestoreSnapshot;
 use crate::application::use_cases::documents::search_documents::SearchDocuments;
 use crate::application::use_cases::documents::snapshot_diff::{
@@ -36,6 +50,9 @@ use crate::bootstrap::app_context::AppContext;
 use crate::domain::documents::document as domain;
 use crate::presentation::http::auth::{self, Bearer};
 use crate::presentation::http::git::DocumentDiffResult;
+use crate::presentation::http::imports::resolve_destination;
+use crate::presentation::http::user_documents;
+use crate::presentation::http::workspaces::resolve_workspace;
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct Document {
@@ -49,9 +66,14 @@ pub struct Document {
     pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
     pub archived_by: Option<Uuid>,
     pub archived_parent_id: Option<Uuid>,
+    /// Front matter properties
+    #[schema(value_type = Object)]
+    pub properties: serde_json::Value,
+    /// Position among siblings; unarranged documents (null) follow, folders first then by title
+    pub sort_key: Option<i32>,
 }
 
-fn to_http_document(doc: domain::Document) -> Document {
+pub(crate) fn to_http_document(doc: domain::Document) -> Document {
     Document {
         id: doc.id,
         title: doc.title,
@@ -63,6 +85,8 @@ fn to_http_document(doc: domain::Document) -> Document {
         archived_at: doc.archived_at,
         archived_by: doc.archived_by,
         archived_parent_id: doc.archived_parent_id,
+        properties: doc.properties,
+        sort_key: doc.sort_key,
     }
 }
 
@@ -173,6 +197,10 @@ pub struct CreateDocumentRequest {
     pub title: Option<String>,
     pub parent_id: Option<Uuid>,
     pub r#type: Option<String>,
+    /// Target workspace when no parent is given (defaults to the personal workspace)
+    pub workspace_id: Option<Uuid>,
+    /// Initial Markdown body
+    pub content: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -181,6 +209,8 @@ pub struct UpdateDocumentRequest {
     #[serde(default, deserialize_with = "deserialize_double_option")]
     #[schema(value_type = Option<String>)]
     pub parent_id: DoubleOption<Uuid>,
+    /// On rename, rewrite `[[Old Title]]` links in other documents (default true)
+    pub rewrite_links: Option<bool>,
 }
 
 impl Default for UpdateDocumentRequest {
@@ -188,6 +218,7 @@ impl Default for UpdateDocumentRequest {
         Self {
             title: None,
             parent_id: DoubleOption::NotProvided,
+            rewrite_links: None,
         }
     }
 }
@@ -222,8 +253,11 @@ impl<T> Default for DoubleOption<T> {
 pub struct ListDocumentsQuery {
     pub query: Option<String>,
     pub tag: Option<String>,
+    /// `key` or `key:value`
+    pub property: Option<String>,
     #[serde(default)]
     pub state: Option<DocumentStateFilter>,
+    pub workspace_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
@@ -248,7 +282,9 @@ impl From<DocumentStateFilter> for DocumentListState {
     params(
         ("query" = Option<String>, Query, description = "Search query"),
         ("tag" = Option<String>, Query, description = "Filter by tag"),
-        ("state" = Option<String>, Query, description = "Filter by document state (active|archived|all)")
+        ("property" = Option<String>, Query, description = "Filter by front matter property: `key` or `key:value`"),
+        ("state" = Option<String>, Query, description = "Filter by document state (active|archived|all)"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")
     ),
     responses((status = 200, body = DocumentListResponse)))]
 pub async fn list_documents(
@@ -256,21 +292,32 @@ pub async fn list_documents(
     bearer: Bearer,
     q: Option<Query<ListDocumentsQuery>>,
 ) -> Result<Json<DocumentListResponse>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
-    let (qstr, tag, state_param) = q
-        .map(|Query(v)| (v.query, v.tag, v.state))
-        .unwrap_or((None, None, None));
+    let (qstr, tag, property_param, state_param, workspace_param) = q
+        .map(|Query(v)| (v.query, v.tag, v.property, v.state, v.workspace_id))
+        .unwrap_or((None, None, None, None, None));
+    let property = property_param.map(|p| match p.split_once(':') {
+        Some((key, value)) => PropertyFilter {
+            key: key.trim().to_string(),
+            value: Some(value.trim().to_string()),
+        },
+        None => PropertyFilter {
+            key: p.trim().to_string(),
+            value: None,
+        },
+    });
     let state = state_param
         .map(DocumentStateFilter::into)
         .unwrap_or_default();
+    let ws = resolve_workspace(&ctx, user_id, workspace_param).await?;
 
     let repo = ctx.document_repo();
     let uc = ListDocuments {
         repo: repo.as_ref(),
     };
     let docs: Vec<domain::Document> = uc
-        .execute(user_id, qstr, tag, state)
+        .execute(ws.id, qstr, tag, property, state)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
@@ -278,19 +325,98 @@ pub async fn list_documents(
     Ok(Json(DocumentListResponse { items }))
 }
 
-#[utoipa::path(post, path = "/api/documents", tag = "Documents", request_body = CreateDocumentRequest, responses((status = 200, body = Document)))]
+fn map_create_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "bad_request" => StatusCode::BAD_REQUEST,
+        "forbidden" => StatusCode::FORBIDDEN,
+        _ => {
+            tracing::error!(error = ?e, "create_document_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+async fn read_create_multipart(
+    ctx: &AppContext,
+    mut multipart: Multipart,
+) -> Result<(CreateDocumentRequest, Vec<InitialAttachment>), StatusCode> {
+    let mut req = CreateDocumentRequest {
+        title: None,
+        parent_id: None,
+        r#type: None,
+        workspace_id: None,
+        content: None,
+    };
+    let mut attachments = Vec::new();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+    {
+        let name = field.name().map(|s| s.to_string());
+        if name.as_deref() == Some("file") {
+            let filename = field.file_name().map(|s| s.to_string());
+            let content_type = field.content_type().map(|s| s.to_string());
+            let data = field.bytes().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+            if data.len() > ctx.cfg.upload_max_bytes {
+                return Err(StatusCode::PAYLOAD_TOO_LARGE);
+            }
+            attachments.push(InitialAttachment {
+                filename,
+                content_type,
+                bytes: data.to_vec(),
+            });
+            continue;
+        }
+        let text = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+        let parse_id = |t: &str| Uuid::parse_str(t.trim()).map_err(|_| StatusCode::BAD_REQUEST);
+        match name.as_deref() {
+            Some("title") => req.title = Some(text),
+            Some("parent_id") => req.parent_id = Some(parse_id(&text)?),
+            Some("type") => req.r#type = Some(text),
+            Some("workspace_id") => req.workspace_id = Some(parse_id(&text)?),
+            Some("content") => req.content = Some(text),
+            _ => { /* ignore additional fields */ }
+        }
+    }
+    Ok((req, attachments))
+}
+
+/// Accepts JSON, or multipart/form-data carrying the same fields plus repeated `file` parts
+/// stored as attachments of the new document.
+#[utoipa::path(post, path = "/api/documents", tag = "Documents",
+    request_body(content = CreateDocumentRequest,
+        description = "`./attachments/<name>` links in `content` are rewritten to the stored attachment names"),
+    responses((status = 200, body = Document), (status = 400, description = "Folders cannot have content")))]
 pub async fn create_document(
     State(ctx): State<AppContext>,
     bearer: Bearer,
-    Json(req): Json<CreateDocumentRequest>,
+    request: axum::extract::Request,
 ) -> Result<Json<Document>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let is_multipart = request
+        .headers()
+        .get(axum::http::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("multipart/form-data"));
+    let (req, attachments) = if is_multipart {
+        let multipart = Multipart::from_request(request, &ctx)
+            .await
+            .map_err(|rejection| rejection.status())?;
+        read_create_multipart(&ctx, multipart).await?
+    } else {
+        let Json(req) = Json::<CreateDocumentRequest>::from_request(request, &ctx)
+            .await
+            .map_err(|rejection| rejection.status())?;
+        (req, Vec::new())
+    };
     let title = req.title.unwrap_or_else(|| "Untitled".into());
     let dtype = req.r#type.unwrap_or_else(|| "document".into());
     let repo = ctx.document_repo();
 
-    if let Some(parent_id) = req.parent_id {
+    // Children always live in their parent's workspace.
+    let workspace_id = if let Some(parent_id) = req.parent_id {
         let parent_meta = repo
             .get_meta_for_owner(parent_id, user_id)
             .await
@@ -300,18 +426,43 @@ pub async fn create_document(
                 if meta.archived_at.is_some() {
                     return Err(StatusCode::CONFLICT);
                 }
+                meta.workspace_id
             }
             None => return Err(StatusCode::NOT_FOUND),
         }
-    }
+    } else {
+        let ws = resolve_workspace(&ctx, user_id, req.workspace_id).await?;
+        if !ws.role.can_edit() {
+            return Err(StatusCode::FORBIDDEN);
+        }
+        ws.id
+    };
 
+    let files = ctx.files_repo();
+    let storage = ctx.storage_port();
+    let snapshots = ctx.snapshot_service();
     let uc = CreateDocument {
         repo: repo.as_ref(),
+        files: files.as_ref(),
+        storage: storage.as_ref(),
+        snapshots: snapshots.as_ref(),
+        public_base_url: ctx.cfg.public_base_url.clone(),
+    };
+    let initial = InitialContent {
+        markdown: req.content,
+        attachments,
     };
     let doc = uc
-        .execute(user_id, &title, req.parent_id, &dtype)
+        .execute(
+            user_id,
+            workspace_id,
+            &title,
+            req.parent_id,
+            &dtype,
+            initial,
+        )
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(map_create_error)?;
 
     Ok(Json(to_http_document(doc)))
 }
@@ -326,8 +477,16 @@ pub async fn get_document(
     Path(id): Path<Uuid>,
 ) -> Result<Json<Document>, StatusCode> {
     let token = params.get("token").map(|s| s.as_str());
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let mut user_id = match bearer {
+        Some(b) => auth::validate_bearer(&ctx, b)
+            .await
+            .ok()
+            .and_then(|sub| Uuid::parse_str(&sub).ok()),
+        None => None,
+    };
+    if user_id.is_none() && token.is_none() {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
 
     let repo = ctx.document_repo();
     let share_access = ctx.share_access_port();
@@ -337,28 +496,52 @@ pub async fn get_document(
         shares: share_access.as_ref(),
         access: access_repo.as_ref(),
     };
-    let doc = uc
-        .execute(&actor, id)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .ok_or(StatusCode::NOT_FOUND)?;
+    let mut doc = None;
+    if let Some(uid) = user_id {
+        doc = uc
+            .execute(&access::Actor::User(uid), id)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+    // Signed-in users can still open documents through a share link they hold.
+    let mut via_share = None;
+    if doc.is_none()
+        && let Some(t) = token
+    {
+        let actor = auth::resolve_actor_from_token_str(&ctx, t)
+            .await
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+        doc = uc
+            .execute(&actor, id)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        match actor {
+            access::Actor::ShareToken(_) => via_share = Some(t),
+            access::Actor::User(uid) => user_id = user_id.or(Some(uid)),
+            access::Actor::Public => {}
+        }
+    }
+    let doc = doc.ok_or(StatusCode::NOT_FOUND)?;
+    if let Some(uid) = user_id {
+        user_documents::record_view(&ctx, uid, id, via_share).await;
+    }
 
     Ok(Json(to_http_document(doc)))
 }
 
-#[utoipa::path(delete, path = "/api/documents/{id}", tag = "Documents", params(("id" = Uuid, Path, description = "Document ID"),), responses((status = 204)))]
+#[utoipa::path(delete, path = "/api/documents/{id}", tag = "Documents", params(("id" = Uuid, Path, description = "Document ID"),), responses((status = 204, description = "Moved to the trash")))]
 pub async fn delete_document(
     State(ctx): State<AppContext>,
     bearer: Bearer,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.document_repo();
-    let storage = ctx.storage_port();
+    let realtime = ctx.realtime_engine();
     let uc = DeleteDocument {
         repo: repo.as_ref(),
-        storage: storage.as_ref(),
+        realtime: realtime.as_ref(),
     };
     let ok = uc
         .execute(id, user_id)
@@ -371,32 +554,188 @@ pub async fn delete_document(
     }
 }
 
-#[utoipa::path(get, path = "/api/documents/{id}/content", tag = "Documents", params(("id" = Uuid, Path, description = "Document ID"),), responses((status = 200)))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DocumentContentResponse {
+    pub content: String,
+    /// SHA-256 of the content; also returned in the ETag header
+    pub etag: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReplaceDocumentContentRequest {
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TextOperationRequest {
+    /// Start position in Unicode code points
+    pub offset: usize,
+    /// Number of code points removed at `offset`
+    #[serde(default)]
+    pub delete: usize,
+    #[serde(default)]
+    pub insert: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PatchDocumentContentRequest {
+    /// Applied in order, each against the result of the previous one
+    pub operations: Vec<TextOperationRequest>,
+}
+
+fn map_content_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        "forbidden" => StatusCode::FORBIDDEN,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        "conflict" => StatusCode::CONFLICT,
+        "precondition_failed" => StatusCode::PRECONDITION_FAILED,
+        _ => {
+            tracing::error!(error = ?e, "document_content_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+// `*` and a missing header both skip the check; weak validators compare like strong ones.
+fn parse_if_match(headers: &HeaderMap) -> Result<Option<String>, StatusCode> {
+    let Some(value) = headers.get(axum::http::header::IF_MATCH) else {
+        return Ok(None);
+    };
+    let value = value.to_str().map_err(|_| StatusCode::BAD_REQUEST)?.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    Ok(Some(
+        value.trim_start_matches("W/").trim_matches('"').to_string(),
+    ))
+}
+
+fn content_response(state: ContentState) -> Result<Response, StatusCode> {
+    let etag = HeaderValue::from_str(&format!("\"{}\"", state.hash))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let mut headers = HeaderMap::new();
+    headers.insert(axum::http::header::ETAG, etag);
+    let body = DocumentContentResponse {
+        content: state.content,
+        etag: state.hash,
+    };
+    Ok((headers, Json(body)).into_response())
+}
+
+#[utoipa::path(get, path = "/api/documents/{id}/content", tag = "Documents", params(("id" = Uuid, Path, description = "Document ID"),),
+    responses((status = 200, body = DocumentContentResponse)))]
 pub async fn get_document_content(
     State(ctx): State<AppContext>,
     bearer: Bearer,
     Path(id): Path<Uuid>,
-) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+) -> Result<Response, StatusCode> {
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
-    // authorization via access policy
     let share_access = ctx.share_access_port();
     let access_repo = ctx.access_repo();
-    let actor = access::Actor::User(user_id);
-    access::require_view(access_repo.as_ref(), share_access.as_ref(), &actor, id)
+    let realtime = ctx.realtime_engine();
+    let uc = GetDocumentContent {
+        shares: share_access.as_ref(),
+        access: access_repo.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let state = uc
+        .execute(&access::Actor::User(user_id), id)
         .await
-        .map_err(|_| StatusCode::NOT_FOUND)?;
-    // Load content via realtime engine abstraction
+        .map_err(map_content_error)?;
+    content_response(state)
+}
+
+async fn edit_document_content(
+    ctx: &AppContext,
+    bearer: Bearer,
+    id: Uuid,
+    headers: &HeaderMap,
+    edit: ContentEdit,
+) -> Result<Response, StatusCode> {
+    let sub = crate::presentation::http::auth::validate_bearer_public(ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let if_match = parse_if_match(headers)?;
+    let repo = ctx.document_repo();
+    let share_access = ctx.share_access_port();
+    let access_repo = ctx.access_repo();
     let realtime = ctx.realtime_engine();
-    let content = realtime
-        .get_content(&id.to_string())
+    let uc = EditDocumentContent {
+        repo: repo.as_ref(),
+        shares: share_access.as_ref(),
+        access: access_repo.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let state = uc
+        .execute(
+            &access::Actor::User(user_id),
+            id,
+            &edit,
+            if_match.as_deref(),
+        )
         .await
-        .map_err(|e| {
-            tracing::error!(document_id = %id, error = ?e, "realtime_get_content_failed");
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?
-        .unwrap_or_default();
-    Ok(Json(serde_json::json!({"content": content})))
+        .map_err(map_content_error)?;
+    content_response(state)
+}
+
+#[utoipa::path(put, path = "/api/documents/{id}/content", tag = "Documents", request_body = ReplaceDocumentContentRequest,
+    params(
+        ("id" = Uuid, Path, description = "Document ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag the edit is based on"),
+    ),
+    responses(
+        (status = 200, body = DocumentContentResponse),
+        (status = 404, description = "Document not found"),
+        (status = 409, description = "Document is archived"),
+        (status = 412, description = "Content changed since the given ETag"),
+    ))]
+pub async fn replace_document_content(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(req): Json<ReplaceDocumentContentRequest>,
+) -> Result<Response, StatusCode> {
+    edit_document_content(
+        &ctx,
+        bearer,
+        id,
+        &headers,
+        ContentEdit::Replace(req.content),
+    )
+    .await
+}
+
+#[utoipa::path(patch, path = "/api/documents/{id}/content", tag = "Documents", request_body = PatchDocumentContentRequest,
+    params(
+        ("id" = Uuid, Path, description = "Document ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag the edit is based on"),
+    ),
+    responses(
+        (status = 200, body = DocumentContentResponse),
+        (status = 400, description = "Operation out of range"),
+        (status = 404, description = "Document not found"),
+        (status = 409, description = "Document is archived"),
+        (status = 412, description = "Content changed since the given ETag"),
+    ))]
+pub async fn patch_document_content(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(req): Json<PatchDocumentContentRequest>,
+) -> Result<Response, StatusCode> {
+    let ops = req
+        .operations
+        .into_iter()
+        .map(|op| TextOperation {
+            offset: op.offset,
+            delete: op.delete,
+            insert: op.insert,
+        })
+        .collect();
+    edit_document_content(&ctx, bearer, id, &headers, ContentEdit::Operations(ops)).await
 }
 
 #[allow(dead_code)]
@@ -542,7 +881,7 @@ pub async fn download_document(
         )
     };
 
-    let actor = match auth::resolve_actor_from_parts(&ctx.cfg, bearer, token) {
+    let actor = match auth::resolve_actor_from_parts(&ctx, bearer, token).await {
         Some(actor) => actor,
         None => {
             return Err(error_response(
@@ -634,7 +973,7 @@ pub async fn update_document(
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateDocumentRequest>,
 ) -> Result<Json<Document>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.document_repo();
     let meta = repo
@@ -677,9 +1016,302 @@ pub async fn update_document(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::NOT_FOUND)?;
+    if req.title.is_some() && req.rewrite_links.unwrap_or(true) {
+        let share_access = ctx.share_access_port();
+        let access_repo = ctx.access_repo();
+        let rename = RenameLinks {
+            repo: repo.as_ref(),
+            shares: share_access.as_ref(),
+            access: access_repo.as_ref(),
+            realtime: realtime.as_ref(),
+        };
+        // The rename itself already succeeded; a failed rewrite only leaves links stale.
+        if let Err(e) = rename.apply(user_id, id, &meta.title, &doc.title).await {
+            tracing::warn!(error = ?e, document_id = %id, "rename_links_failed");
+        }
+    }
+    Ok(Json(to_http_document(doc)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RenamePreviewQuery {
+    pub title: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LinkRewriteItem {
+    pub document_id: Uuid,
+    pub title: String,
+    /// Links in this document that would be rewritten
+    pub occurrences: usize,
+}
+
+impl From<LinkRewrite> for LinkRewriteItem {
+    fn from(r: LinkRewrite) -> Self {
+        LinkRewriteItem {
+            document_id: r.document_id,
+            title: r.title,
+            occurrences: r.occurrences,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RenamePreviewResponse {
+    pub documents: Vec<LinkRewriteItem>,
+    pub total_links: usize,
+}
+
+#[utoipa::path(get, path = "/api/documents/{id}/rename-preview", tag = "Documents",
+    params(("id" = Uuid, Path, description = "Document ID"), ("title" = String, Query, description = "Proposed title")),
+    responses((status = 200, body = RenamePreviewResponse)))]
+pub async fn preview_rename(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    Query(q): Query<RenamePreviewQuery>,
+) -> Result<Json<RenamePreviewResponse>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.document_repo();
+    let meta = repo
+        .get_meta_for_owner(id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let share_access = ctx.share_access_port();
+    let access_repo = ctx.access_repo();
+    let realtime = ctx.realtime_engine();
+    let uc = RenameLinks {
+        repo: repo.as_ref(),
+        shares: share_access.as_ref(),
+        access: access_repo.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let rewrites = uc
+        .preview(user_id, id, &meta.title, &q.title)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let total_links = rewrites.iter().map(|r| r.occurrences).sum();
+    Ok(Json(RenamePreviewResponse {
+        documents: rewrites.into_iter().map(Into::into).collect(),
+        total_links,
+    }))
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct DuplicateDocumentRequest {
+    /// Destination folder (defaults to the source's parent)
+    pub parent_id: Option<Uuid>,
+    /// Title of the copied root (defaults to "<title> (copy)")
+    pub title: Option<String>,
+    /// Point wikilinks between copied documents at the copies (default true)
+    pub rewrite_links: Option<bool>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/documents/{id}/duplicate",
+    tag = "Documents",
+    request_body = DuplicateDocumentRequest,
+    params(("id" = Uuid, Path, description = "Document or folder ID")),
+    responses(
+        (status = 200, body = Document),
+        (status = 400, description = "Destination is not a folder or lies inside the copied subtree"),
+        (status = 404, description = "Document not found"),
+        (status = 409, description = "Source or destination is archived")
+    )
+)]
+pub async fn duplicate_document(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    body: Option<Json<DuplicateDocumentRequest>>,
+) -> Result<Json<Document>, StatusCode> {
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let req = body.map(|Json(b)| b).unwrap_or_default();
+    let repo = ctx.document_repo();
+    let meta = repo
+        .get_meta_for_owner(id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    if meta.archived_at.is_some() {
+        return Err(StatusCode::CONFLICT);
+    }
+    let parent_id = match req.parent_id {
+        Some(parent_id) => Some(parent_id),
+        None => {
+            repo.get_by_id(id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::NOT_FOUND)?
+                .parent_id
+        }
+    };
+    let workspace_id = match parent_id {
+        Some(parent_id) => {
+            let parent = repo
+                .get_meta_for_owner(parent_id, user_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::NOT_FOUND)?;
+            if parent.archived_at.is_some() {
+                return Err(StatusCode::CONFLICT);
+            }
+            if parent.doc_type != "folder" {
+                return Err(StatusCode::BAD_REQUEST);
+            }
+            parent.workspace_id
+        }
+        None => meta.workspace_id,
+    };
+
+    let files = ctx.files_repo();
+    let storage = ctx.storage_port();
+    let realtime = ctx.realtime_engine();
+    let snapshots = ctx.snapshot_service();
+    let uc = DuplicateDocument {
+        repo: repo.as_ref(),
+        files: files.as_ref(),
+        storage: storage.as_ref(),
+        realtime: realtime.as_ref(),
+        snapshots: snapshots.as_ref(),
+    };
+    let options = DuplicateOptions {
+        workspace_id,
+        parent_id,
+        title: req.title,
+        rewrite_links: req.rewrite_links.unwrap_or(true),
+        variables: None,
+    };
+    let doc = uc
+        .execute(user_id, id, options)
+        .await
+        .map_err(|e| match e.to_string().as_str() {
+            "not_found" => StatusCode::NOT_FOUND,
+            "bad_request" => StatusCode::BAD_REQUEST,
+            _ => {
+                tracing::error!(document_id = %id, error = ?e, "duplicate_document_failed");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })?;
+    Ok(Json(to_http_document(doc)))
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct MoveDocumentRequest {
+    /// Sibling to place the document before
+    pub before: Option<Uuid>,
+    /// Sibling to place the document after
+    pub after: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReorderDocumentsRequest {
+    /// Folder whose children are arranged; the workspace root when omitted
+    pub parent_id: Option<Uuid>,
+    /// Workspace of a root-level reorder (defaults to the personal workspace)
+    pub workspace_id: Option<Uuid>,
+    /// New order; children not listed follow in their current order
+    pub ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReorderDocumentsResponse {
+    /// Resulting order of all children
+    pub ids: Vec<Uuid>,
+}
+
+fn map_reorder_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        "conflict" => StatusCode::CONFLICT,
+        _ => {
+            tracing::error!(error = ?e, "reorder_documents_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/documents/{id}/move",
+    tag = "Documents",
+    request_body = MoveDocumentRequest,
+    params(("id" = Uuid, Path, description = "Document or folder ID")),
+    responses(
+        (status = 200, body = Document),
+        (status = 400, description = "Exactly one of before/after is required"),
+        (status = 404, description = "Document or sibling not found"),
+        (status = 409, description = "Document or sibling archived")
+    )
+)]
+pub async fn move_document(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    Json(req): Json<MoveDocumentRequest>,
+) -> Result<Json<Document>, StatusCode> {
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let placement = match (req.before, req.after) {
+        (Some(before), None) => Placement::Before(before),
+        (None, Some(after)) => Placement::After(after),
+        _ => return Err(StatusCode::BAD_REQUEST),
+    };
+    let repo = ctx.document_repo();
+    let storage = ctx.storage_port();
+    let realtime = ctx.realtime_engine();
+    let uc = ReorderDocuments {
+        repo: repo.as_ref(),
+        storage: storage.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let doc = uc
+        .place(user_id, id, placement)
+        .await
+        .map_err(map_reorder_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
     Ok(Json(to_http_document(doc)))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/documents/order",
+    tag = "Documents",
+    request_body = ReorderDocumentsRequest,
+    responses(
+        (status = 200, body = ReorderDocumentsResponse),
+        (status = 400, description = "Unknown or repeated id"),
+        (status = 404, description = "Folder not found")
+    )
+)]
+pub async fn reorder_documents(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<ReorderDocumentsRequest>,
+) -> Result<Json<ReorderDocumentsResponse>, StatusCode> {
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id = resolve_destination(&ctx, user_id, req.parent_id, req.workspace_id).await?;
+    let repo = ctx.document_repo();
+    let storage = ctx.storage_port();
+    let realtime = ctx.realtime_engine();
+    let uc = ReorderDocuments {
+        repo: repo.as_ref(),
+        storage: storage.as_ref(),
+        realtime: realtime.as_ref(),
+    };
+    let ids = uc
+        .reorder(workspace_id, req.parent_id, &req.ids)
+        .await
+        .map_err(map_reorder_error)?;
+    Ok(Json(ReorderDocumentsResponse { ids }))
+}
+
 #[utoipa::path(
     post,
     path = "/api/documents/{id}/archive",
@@ -696,7 +1328,7 @@ pub async fn archive_document(
     bearer: Bearer,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Document>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.document_repo();
     let meta = repo
@@ -710,10 +1342,12 @@ pub async fn archive_document(
 
     let realtime = ctx.realtime_engine();
     let storage = ctx.storage_port();
+    let comments = ctx.comment_repo();
     let uc = ArchiveDocument {
         repo: repo.as_ref(),
         realtime: realtime.as_ref(),
         storage: storage.as_ref(),
+        comments: comments.as_ref(),
     };
     let doc = uc
         .execute(user_id, id)
@@ -739,7 +1373,7 @@ pub async fn unarchive_document(
     bearer: Bearer,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Document>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.document_repo();
     let meta = repo
@@ -784,8 +1418,9 @@ pub async fn list_document_snapshots(
 ) -> Result<Json<SnapshotListResponse>, StatusCode> {
     let params = q.map(|Query(v)| v).unwrap_or_default();
     let token = params.token.as_deref();
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
     let access_repo = ctx.access_repo();
     let share_access = ctx.share_access_port();
@@ -830,8 +1465,9 @@ pub async fn get_document_snapshot_diff(
 ) -> Result<Json<SnapshotDiffResponse>, StatusCode> {
     let params = q.map(|Query(v)| v).unwrap_or_default();
     let token = params.token.as_deref();
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
     let access_repo = ctx.access_repo();
     let share_access = ctx.share_access_port();
@@ -882,8 +1518,9 @@ pub async fn restore_document_snapshot(
 ) -> Result<Json<SnapshotRestoreResponse>, StatusCode> {
     let params = q.map(|Query(v)| v).unwrap_or_default();
     let token = params.token.as_deref();
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
     let access_repo = ctx.access_repo();
     let share_access = ctx.share_access_port();
@@ -936,8 +1573,9 @@ pub async fn download_document_snapshot(
 ) -> Result<Response, StatusCode> {
     let params = q.map(|Query(v)| v).unwrap_or_default();
     let token = params.token.as_deref();
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
     let access_repo = ctx.access_repo();
     let share_access = ctx.share_access_port();
@@ -981,7 +1619,15 @@ pub fn routes(ctx: AppContext) -> Router {
                 .delete(delete_document)
                 .patch(update_document),
         )
-        .route("/documents/:id/content", get(get_document_content))
+        .route(
+            "/documents/:id/content",
+            get(get_document_content)
+                .put(replace_document_content)
+                .patch(patch_document_content),
+        )
+        .route("/documents/:id/duplicate", post(duplicate_document))
+        .route("/documents/:id/move", post(move_document))
+        .route("/documents/order", put(reorder_documents))
         .route("/documents/:id/archive", post(archive_document))
         .route("/documents/:id/unarchive", post(unarchive_document))
         .route("/documents/:id/snapshots", get(list_document_snapshots))
@@ -999,8 +1645,10 @@ pub fn routes(ctx: AppContext) -> Router {
         )
         .route("/documents/:id/download", get(download_document))
         .route("/documents/:id/backlinks", get(get_backlinks))
+        .route("/documents/:id/rename-preview", get(preview_rename))
         .route("/documents/:id/links", get(get_outgoing_links))
         .route("/documents/search", get(search_documents))
+        .route("/documents/quick-search", get(quick_search_documents))
         .with_state(ctx)
 }
 
@@ -1011,11 +1659,24 @@ pub struct SearchResult {
     pub document_type: String,
     pub path: Option<String>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Relevance score; absent when no query text was given
+    pub rank: Option<f32>,
+    /// HTML-escaped content excerpt with matches wrapped in `<mark>`
+    pub snippet: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct SearchQuery {
     pub q: Option<String>,
+    pub tag: Option<String>,
+    pub folder_id: Option<Uuid>,
+    #[serde(default)]
+    pub state: Option<DocumentStateFilter>,
+    pub updated_after: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_before: Option<chrono::DateTime<chrono::Utc>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -1039,25 +1700,54 @@ pub struct SnapshotTokenQuery {
 }
 
 #[utoipa::path(get, path = "/api/documents/search", tag = "Documents",
-    params(("q" = Option<String>, Query, description = "Query")),
-    responses((status = 200, body = [SearchResult])))]
+    params(
+        ("q" = Option<String>, Query, description = "Words, \"phrases\", prefix*, -excluded and OR; empty lists recent documents"),
+        ("tag" = Option<String>, Query, description = "Filter by tag"),
+        ("folder_id" = Option<Uuid>, Query, description = "Only documents inside this folder's subtree"),
+        ("state" = Option<String>, Query, description = "Filter by document state (active|archived|all), default active"),
+        ("updated_after" = Option<chrono::DateTime<chrono::Utc>>, Query, description = "Updated at or after"),
+        ("updated_before" = Option<chrono::DateTime<chrono::Utc>>, Query, description = "Updated before"),
+        ("limit" = Option<i64>, Query, description = "Max results (default 20, max 100)"),
+        ("offset" = Option<i64>, Query, description = "Pagination offset"),
+    ),
+    responses((status = 200, body = [SearchResult]), (status = 400, description = "Invalid date range")))]
 pub async fn search_documents(
     State(ctx): State<AppContext>,
     bearer: crate::presentation::http::auth::Bearer,
     q: Option<Query<SearchQuery>>,
 ) -> Result<Json<Vec<SearchResult>>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
-    let query_text = q.and_then(|Query(v)| v.q);
+    let search = match q {
+        Some(Query(v)) => DocumentSearch {
+            text: v.q,
+            tsquery: None,
+            tag: v.tag.filter(|t| !t.trim().is_empty()),
+            folder_id: v.folder_id,
+            state: v.state.map(Into::into).unwrap_or_default(),
+            updated_after: v.updated_after,
+            updated_before: v.updated_before,
+            limit: v.limit.unwrap_or(20),
+            offset: v.offset.unwrap_or(0),
+        },
+        None => DocumentSearch {
+            limit: 20,
+            ..Default::default()
+        },
+    };
 
     let repo = ctx.document_repo();
     let uc = SearchDocuments {
         repo: repo.as_ref(),
     };
-    let hits = uc
-        .execute(user_id, query_text, 20)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let hits = uc.execute(user_id, search).await.map_err(|e| {
+        if e.to_string() == "bad_request" {
+            StatusCode::BAD_REQUEST
+        } else {
+            tracing::error!(error = ?e, "document_search_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    })?;
     let items = hits
         .into_iter()
         .map(|h| SearchResult {
@@ -1066,6 +1756,83 @@ pub async fn search_documents(
             document_type: h.doc_type,
             path: h.path,
             updated_at: h.updated_at,
+            archived_at: h.archived_at,
+            rank: h.rank,
+            snippet: h.snippet,
+        })
+        .collect();
+    Ok(Json(items))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BreadcrumbItem {
+    pub id: Uuid,
+    pub title: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuickSearchResult {
+    pub id: Uuid,
+    pub title: String,
+    pub document_type: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Title similarity with a boost for prefixes and recent edits
+    pub score: f32,
+    /// Ancestor folders, root first
+    pub breadcrumbs: Vec<BreadcrumbItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuickSearchQuery {
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[utoipa::path(get, path = "/api/documents/quick-search", tag = "Documents",
+    params(
+        ("q" = Option<String>, Query, description = "Title text; tolerant of typos"),
+        ("limit" = Option<i64>, Query, description = "Max results (default 10, max 50)"),
+    ),
+    responses((status = 200, body = [QuickSearchResult])))]
+pub async fn quick_search_documents(
+    State(ctx): State<AppContext>,
+    bearer: crate::presentation::http::auth::Bearer,
+    Query(params): Query<QuickSearchQuery>,
+) -> Result<Json<Vec<QuickSearchResult>>, StatusCode> {
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let repo = ctx.document_repo();
+    let uc = QuickSearchDocuments {
+        repo: repo.as_ref(),
+    };
+    let hits = uc
+        .execute(
+            user_id,
+            params.q.as_deref().unwrap_or_default(),
+            params.limit.unwrap_or(10),
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!(error = ?e, "document_quick_search_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    let items = hits
+        .into_iter()
+        .map(|h| QuickSearchResult {
+            id: h.id,
+            title: h.title,
+            document_type: h.doc_type,
+            updated_at: h.updated_at,
+            score: h.score,
+            breadcrumbs: h
+                .breadcrumbs
+                .into_iter()
+                .map(|b| BreadcrumbItem {
+                    id: b.id,
+                    title: b.title,
+                })
+                .collect(),
         })
         .collect();
     Ok(Json(items))
@@ -1114,7 +1881,7 @@ pub async fn get_backlinks(
     bearer: crate::presentation::http::auth::Bearer,
     Path(id): Path<Uuid>,
 ) -> Result<Json<BacklinksResponse>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let share_access = ctx.share_access_port();
     let access_repo = ctx.access_repo();
@@ -1157,7 +1924,7 @@ pub async fn get_outgoing_links(
     bearer: crate::presentation::http::auth::Bearer,
     Path(id): Path<Uuid>,
 ) -> Result<Json<OutgoingLinksResponse>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let share_access = ctx.share_access_port();
     let access_repo = ctx.access_repo();
@@ -1,6 +1,6 @@
 use axum::{
     Json, Router,
-    extract::State,
+    extract::{Query, State},
     http::StatusCode,
     routing::{get, post},
 };
@@ -17,14 +17,30 @@ use crate::application::dto::git::{
     GitChangeItem as GitChangeDto, GitCommitInfo, GitConfigDto, GitStatusDto, GitSyncRequestDto,
     UpsertGitConfigInput,
 };
+use crate::application::ports::workspace_repository::WorkspaceRole;
 use crate::application::use_cases::git::delete_config::DeleteGitConfig;
 use crate::application::use_cases::git::get_config::GetGitConfig;
 use crate::application::use_cases::git::get_status::GetGitStatus;
 use crate::application::use_cases::git::init_repo::{DeinitRepo, InitRepo};
 use crate::application::use_cases::git::upsert_config::UpsertGitConfig;
 use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::workspaces::{WorkspaceQuery, resolve_workspace};
 use uuid::Uuid;
 
+// Git state is kept per workspace; resolves the target and enforces the minimum role.
+async fn scoped_workspace(
+    ctx: &AppContext,
+    user_id: Uuid,
+    requested: Option<Uuid>,
+    min_role: WorkspaceRole,
+) -> Result<Uuid, StatusCode> {
+    let ws = resolve_workspace(ctx, user_id, requested).await?;
+    if ws.role < min_role {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(ws.id)
+}
+
 // Uses AppContext as router state
 
 pub fn routes(ctx: AppContext) -> Router {
@@ -106,33 +122,39 @@ pub struct UpdateGitConfigRequest {
     pub auto_sync: Option<bool>,
 }
 
-#[utoipa::path(get, path = "/api/git/config", tag = "Git", responses((status = 200, body = Option<GitConfigResponse>)))]
+#[utoipa::path(get, path = "/api/git/config", params(WorkspaceQuery), tag = "Git", responses((status = 200, body = Option<GitConfigResponse>)))]
 pub async fn get_config(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<Option<GitConfigResponse>>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let repo = ctx.git_repo();
     let uc = GetGitConfig {
         repo: repo.as_ref(),
     };
     let resp: Option<GitConfigDto> = uc
-        .execute(user_id)
+        .execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let out = resp.map(Into::into);
     Ok(Json(out))
 }
 
-#[utoipa::path(post, path = "/api/git/config", tag = "Git", request_body = CreateGitConfigRequest, responses((status = 200, body = GitConfigResponse)))]
+#[utoipa::path(post, path = "/api/git/config", params(WorkspaceQuery), tag = "Git", request_body = CreateGitConfigRequest, responses((status = 200, body = GitConfigResponse)))]
 pub async fn create_or_update_config(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     Json(req): Json<CreateGitConfigRequest>,
 ) -> Result<Json<GitConfigResponse>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Admin).await?;
     let repo = ctx.git_repo();
     let gitignore = ctx.gitignore_port();
     let storage = ctx.storage_port();
@@ -145,26 +167,29 @@ pub async fn create_or_update_config(
     };
     let input: UpsertGitConfigInput = req.into();
     let resp: GitConfigDto = uc
-        .execute(user_id, &input)
+        .execute(workspace_id, &input)
         .await
         .map_err(|_| StatusCode::BAD_REQUEST)?;
     let out: GitConfigResponse = resp.into();
     Ok(Json(out))
 }
 
-#[utoipa::path(delete, path = "/api/git/config", tag = "Git", responses((status = 204, description = "Deleted")))]
+#[utoipa::path(delete, path = "/api/git/config", params(WorkspaceQuery), tag = "Git", responses((status = 204, description = "Deleted")))]
 pub async fn delete_config(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<StatusCode, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Admin).await?;
     let repo = ctx.git_repo();
     let uc = DeleteGitConfig {
         repo: repo.as_ref(),
     };
     let _ = uc
-        .execute(user_id)
+        .execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     Ok(StatusCode::NO_CONTENT)
@@ -201,20 +226,23 @@ impl From<GitStatusDto> for GitStatus {
 }
 
 // Diff models are provided in application::dto::git
-// strip_user_prefix moved to application/use_cases/git/helpers
+// strip_workspace_prefix lives in application/use_cases/git/helpers
 
 // compute_doc_patterns_with is provided in use-cases layer; no local definition here
 
 // compute_doc_patterns: no longer used (use-case handles patterns via shared helper)
 
-#[utoipa::path(post, path = "/api/git/ignore/doc/{id}", params(("id" = String, Path, description = "Document ID")), tag = "Git", responses((status = 200, description = "OK")))]
+#[utoipa::path(post, path = "/api/git/ignore/doc/{id}", params(("id" = String, Path, description = "Document ID"), WorkspaceQuery), tag = "Git", responses((status = 200, description = "OK")))]
 pub async fn ignore_document(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     axum::extract::Path(id): axum::extract::Path<String>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Member).await?;
     let doc_id = Uuid::parse_str(&id).map_err(|_| StatusCode::BAD_REQUEST)?;
     let gitignore = ctx.gitignore_port();
     let storage = ctx.storage_port();
@@ -229,7 +257,7 @@ pub async fn ignore_document(
         workspace: workspace.as_ref(),
     };
     let res = uc
-        .execute(user_id, doc_id)
+        .execute(workspace_id, user_id, doc_id)
         .await
         .map_err(|_| StatusCode::NOT_FOUND)?;
     Ok(Json(
@@ -237,14 +265,17 @@ pub async fn ignore_document(
     ))
 }
 
-#[utoipa::path(post, path = "/api/git/ignore/folder/{id}", params(("id" = String, Path, description = "Folder ID")), tag = "Git", responses((status = 200, description = "OK")))]
+#[utoipa::path(post, path = "/api/git/ignore/folder/{id}", params(("id" = String, Path, description = "Folder ID"), WorkspaceQuery), tag = "Git", responses((status = 200, description = "OK")))]
 pub async fn ignore_folder(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     axum::extract::Path(id): axum::extract::Path<String>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Member).await?;
     let folder_id = Uuid::parse_str(&id).map_err(|_| StatusCode::BAD_REQUEST)?;
     let gitignore = ctx.gitignore_port();
     let storage = ctx.storage_port();
@@ -259,7 +290,7 @@ pub async fn ignore_folder(
         workspace: workspace.as_ref(),
     };
     let res = uc
-        .execute(user_id, folder_id)
+        .execute(workspace_id, user_id, folder_id)
         .await
         .map_err(|_| StatusCode::NOT_FOUND)?;
     Ok(Json(
@@ -272,14 +303,17 @@ pub struct AddPatternsRequest {
     pub patterns: Vec<String>,
 }
 
-#[utoipa::path(post, path = "/api/git/gitignore/patterns", tag = "Git", request_body = AddPatternsRequest, responses((status = 200, description = "OK")))]
+#[utoipa::path(post, path = "/api/git/gitignore/patterns", params(WorkspaceQuery), tag = "Git", request_body = AddPatternsRequest, responses((status = 200, description = "OK")))]
 pub async fn add_gitignore_patterns(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     Json(req): Json<AddPatternsRequest>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Member).await?;
     let gitignore = ctx.gitignore_port();
     let storage = ctx.storage_port();
     let workspace = ctx.git_workspace();
@@ -289,19 +323,22 @@ pub async fn add_gitignore_patterns(
         workspace: workspace.as_ref(),
     };
     let added = uc
-        .execute(user_id, req.patterns)
+        .execute(workspace_id, req.patterns)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     Ok(Json(serde_json::json!({"added": added})))
 }
 
-#[utoipa::path(get, path = "/api/git/gitignore/patterns", tag = "Git", responses((status = 200, description = "OK")))]
+#[utoipa::path(get, path = "/api/git/gitignore/patterns", params(WorkspaceQuery), tag = "Git", responses((status = 200, description = "OK")))]
 pub async fn get_gitignore_patterns(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let gitignore = ctx.gitignore_port();
     let storage = ctx.storage_port();
     let uc = crate::application::use_cases::git::gitignore_patterns::GetGitignorePatterns {
@@ -309,7 +346,7 @@ pub async fn get_gitignore_patterns(
         gitignore: gitignore.as_ref(),
     };
     let patterns = uc
-        .execute(user_id)
+        .execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     Ok(Json(serde_json::json!({"patterns": patterns})))
@@ -320,14 +357,17 @@ pub struct CheckIgnoredRequest {
     pub path: String,
 }
 
-#[utoipa::path(post, path = "/api/git/gitignore/check", tag = "Git", request_body = CheckIgnoredRequest, responses((status = 200, description = "OK")))]
+#[utoipa::path(post, path = "/api/git/gitignore/check", params(WorkspaceQuery), tag = "Git", request_body = CheckIgnoredRequest, responses((status = 200, description = "OK")))]
 pub async fn check_path_ignored(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     Json(req): Json<CheckIgnoredRequest>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let storage = ctx.storage_port();
     let gitignore = ctx.gitignore_port();
     let uc = crate::application::use_cases::git::gitignore_patterns::CheckPathIgnored {
@@ -335,7 +375,7 @@ pub async fn check_path_ignored(
         storage: storage.as_ref(),
     };
     let is_ignored = uc
-        .execute(user_id, &req.path)
+        .execute(workspace_id, &req.path)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     Ok(Json(
@@ -343,13 +383,16 @@ pub async fn check_path_ignored(
     ))
 }
 
-#[utoipa::path(get, path = "/api/git/status", tag = "Git", responses((status = 200, body = GitStatus)))]
+#[utoipa::path(get, path = "/api/git/status", params(WorkspaceQuery), tag = "Git", responses((status = 200, body = GitStatus)))]
 pub async fn get_status(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<GitStatus>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let repo = ctx.git_repo();
     let workspace = ctx.git_workspace();
     let uc = GetGitStatus {
@@ -357,7 +400,7 @@ pub async fn get_status(
         workspace: workspace.as_ref(),
     };
     let dto: GitStatusDto = uc
-        .execute(user_id)
+        .execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let out: GitStatus = dto.into();
@@ -378,14 +421,17 @@ pub struct GitSyncResponse {
     pub files_changed: u32,
 }
 
-#[utoipa::path(post, path = "/api/git/sync", tag = "Git", request_body = GitSyncRequest, responses((status = 200, body = GitSyncResponse), (status = 409, description = "Conflicts during rebase/pull")))]
+#[utoipa::path(post, path = "/api/git/sync", params(WorkspaceQuery), tag = "Git", request_body = GitSyncRequest, responses((status = 200, body = GitSyncResponse), (status = 409, description = "Conflicts during rebase/pull")))]
 pub async fn sync_now(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     Json(req): Json<GitSyncRequest>,
 ) -> Result<Json<GitSyncResponse>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Member).await?;
     let repo = ctx.git_repo();
     let workspace = ctx.git_workspace();
     let uc = crate::application::use_cases::git::sync_now::SyncNow {
@@ -394,7 +440,7 @@ pub async fn sync_now(
     };
     let out = uc
         .execute(
-            user_id,
+            workspace_id,
             GitSyncRequestDto {
                 message: req.message.clone(),
                 force: req.force,
@@ -424,19 +470,22 @@ pub struct GitChangesResponse {
     pub files: Vec<GitChangeItem>,
 }
 
-#[utoipa::path(get, path = "/api/git/changes", tag = "Git", responses((status = 200, body = GitChangesResponse)))]
+#[utoipa::path(get, path = "/api/git/changes", params(WorkspaceQuery), tag = "Git", responses((status = 200, body = GitChangesResponse)))]
 pub async fn get_changes(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<GitChangesResponse>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let workspace = ctx.git_workspace();
     let uc = crate::application::use_cases::git::get_changes::GetChanges {
         workspace: workspace.as_ref(),
     };
     let files: Vec<GitChangeDto> = uc
-        .execute(user_id)
+        .execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let items = files
@@ -523,19 +572,22 @@ impl From<DiffResultDto> for DocumentDiffResult {
     }
 }
 
-#[utoipa::path(get, path = "/api/git/history", tag = "Git", responses((status = 200, body = GitHistoryResponse)))]
+#[utoipa::path(get, path = "/api/git/history", params(WorkspaceQuery), tag = "Git", responses((status = 200, body = GitHistoryResponse)))]
 pub async fn get_history(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<GitHistoryResponse>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let workspace = ctx.git_workspace();
     let uc = crate::application::use_cases::git::get_history::GetHistory {
         workspace: workspace.as_ref(),
     };
     let commits: Vec<GitCommitInfo> = uc
-        .execute(user_id)
+        .execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let out = commits
@@ -554,21 +606,24 @@ pub async fn get_history(
 #[utoipa::path(
     get,
     path = "/api/git/diff/working",
-    tag = "Git",
+    params(WorkspaceQuery), tag = "Git",
     responses((status = 200, body = [DocumentDiffResult]))
 )]
 pub async fn get_working_diff(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<Vec<DocumentDiffResult>>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let workspace = ctx.git_workspace();
     let uc = crate::application::use_cases::git::get_working_diff::GetWorkingDiff {
         workspace: workspace.as_ref(),
     };
     let diffs = uc
-        .execute(user_id)
+        .execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let body = diffs.into_iter().map(DocumentDiffResult::from).collect();
@@ -578,23 +633,26 @@ pub async fn get_working_diff(
 #[utoipa::path(
     get,
     path = "/api/git/diff/commits/{from}/{to}",
-    params(("from" = String, Path, description = "From"), ("to" = String, Path, description = "To")),
+    params(("from" = String, Path, description = "From"), ("to" = String, Path, description = "To"), WorkspaceQuery),
     tag = "Git",
     responses((status = 200, body = [DocumentDiffResult]))
 )]
 pub async fn get_commit_diff(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     axum::extract::Path((from, to)): axum::extract::Path<(String, String)>,
 ) -> Result<Json<Vec<DocumentDiffResult>>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Guest).await?;
     let workspace = ctx.git_workspace();
     let uc = crate::application::use_cases::git::get_commit_diff::GetCommitDiff {
         workspace: workspace.as_ref(),
     };
     let diffs = uc
-        .execute(user_id, from, to)
+        .execute(workspace_id, from, to)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let body = diffs.into_iter().map(DocumentDiffResult::from).collect();
@@ -603,13 +661,16 @@ pub async fn get_commit_diff(
 
 // pull endpoint intentionally removed in push-only backup mode
 
-#[utoipa::path(post, path = "/api/git/init", tag = "Git", responses((status = 200, description = "OK")))]
+#[utoipa::path(post, path = "/api/git/init", params(WorkspaceQuery), tag = "Git", responses((status = 200, description = "OK")))]
 pub async fn init_repository(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Member).await?;
     let repo = ctx.git_repo();
     let gitignore = ctx.gitignore_port();
     let storage = ctx.storage_port();
@@ -620,24 +681,27 @@ pub async fn init_repository(
         gitignore: gitignore.as_ref(),
         workspace: workspace.as_ref(),
     };
-    uc.execute(user_id)
+    uc.execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     Ok(Json(serde_json::json!({"success":true})))
 }
 
-#[utoipa::path(post, path = "/api/git/deinit", tag = "Git", responses((status = 200, description = "OK")))]
+#[utoipa::path(post, path = "/api/git/deinit", params(WorkspaceQuery), tag = "Git", responses((status = 200, description = "OK")))]
 pub async fn deinit_repository(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
-    let sub = validate_bearer(&ctx.cfg, bearer)?;
+    let sub = validate_bearer(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id =
+        scoped_workspace(&ctx, user_id, wq.workspace_id, WorkspaceRole::Member).await?;
     let workspace = ctx.git_workspace();
     let uc = DeinitRepo {
         workspace: workspace.as_ref(),
     };
-    uc.execute(user_id)
+    uc.execute(workspace_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     Ok(Json(serde_json::json!({"success":true})))
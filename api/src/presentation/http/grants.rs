@@ -0,0 +1,206 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::ports::document_grants_repository::{DocumentGrantRow, SharedWithMeRow};
+use crate::application::use_cases::grants::grant_access::GrantAccess;
+use crate::application::use_cases::grants::list_grants::ListGrants;
+use crate::application::use_cases::grants::list_shared_with_me::ListSharedWithMe;
+use crate::application::use_cases::grants::revoke_access::RevokeAccess;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DocumentGrantItem {
+    pub user_id: Uuid,
+    pub email: String,
+    pub name: String,
+    /// view | comment | edit
+    pub permission: String,
+    pub granted_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<DocumentGrantRow> for DocumentGrantItem {
+    fn from(g: DocumentGrantRow) -> Self {
+        DocumentGrantItem {
+            user_id: g.user_id,
+            email: g.email,
+            name: g.name,
+            permission: g.permission,
+            granted_by: g.granted_by,
+            created_at: g.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateGrantRequest {
+    pub email: String,
+    /// view | comment | edit (defaults to view)
+    pub permission: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SharedWithMeItem {
+    pub document_id: Uuid,
+    pub title: String,
+    /// document | folder
+    pub document_type: String,
+    pub permission: String,
+    pub granted_by_name: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<SharedWithMeRow> for SharedWithMeItem {
+    fn from(r: SharedWithMeRow) -> Self {
+        SharedWithMeItem {
+            document_id: r.document_id,
+            title: r.title,
+            document_type: r.doc_type,
+            permission: r.permission,
+            granted_by_name: r.granted_by_name,
+            created_at: r.created_at,
+        }
+    }
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" | "user_not_found" => StatusCode::NOT_FOUND,
+        "forbidden" => StatusCode::FORBIDDEN,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        _ => {
+            tracing::error!(error = ?e, "document_grant_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/documents/{id}/grants",
+    tag = "Sharing",
+    params(("id" = Uuid, Path, description = "Document ID")),
+    responses((status = 200, body = [DocumentGrantItem]))
+)]
+pub async fn list_grants(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<DocumentGrantItem>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let grants = ctx.document_grants_repo();
+    let documents = ctx.document_repo();
+    let uc = ListGrants {
+        grants: grants.as_ref(),
+        documents: documents.as_ref(),
+    };
+    let rows = uc
+        .execute(user_id, id)
+        .await
+        .map_err(map_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/documents/{id}/grants",
+    tag = "Sharing",
+    request_body = CreateGrantRequest,
+    params(("id" = Uuid, Path, description = "Document ID")),
+    responses((status = 200, body = DocumentGrantItem))
+)]
+pub async fn create_grant(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateGrantRequest>,
+) -> Result<Json<DocumentGrantItem>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let grants = ctx.document_grants_repo();
+    let documents = ctx.document_repo();
+    let users = ctx.user_repo();
+    let uc = GrantAccess {
+        grants: grants.as_ref(),
+        documents: documents.as_ref(),
+        users: users.as_ref(),
+    };
+    let permission = req.permission.as_deref().unwrap_or("view");
+    let grant = uc
+        .execute(user_id, id, &req.email, permission)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(grant.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/documents/{id}/grants/{user_id}",
+    tag = "Sharing",
+    params(
+        ("id" = Uuid, Path, description = "Document ID"),
+        ("user_id" = Uuid, Path, description = "Grantee user ID"),
+    ),
+    responses((status = 204))
+)]
+pub async fn delete_grant(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path((id, grantee_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let grants = ctx.document_grants_repo();
+    let documents = ctx.document_repo();
+    let uc = RevokeAccess {
+        grants: grants.as_ref(),
+        documents: documents.as_ref(),
+    };
+    let removed = uc
+        .execute(user_id, id, grantee_id)
+        .await
+        .map_err(map_error)?;
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(StatusCode::NOT_FOUND)
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shared-with-me",
+    tag = "Sharing",
+    responses((status = 200, body = [SharedWithMeItem]))
+)]
+pub async fn list_shared_with_me(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<Vec<SharedWithMeItem>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let grants = ctx.document_grants_repo();
+    let uc = ListSharedWithMe {
+        grants: grants.as_ref(),
+    };
+    let rows = uc.execute(user_id).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/documents/:id/grants", get(list_grants).post(create_grant))
+        .route("/documents/:id/grants/:user_id", delete(delete_grant))
+        .route("/shared-with-me", get(list_shared_with_me))
+        .with_state(ctx)
+}
@@ -0,0 +1,326 @@
+use axum::{
+    Json, Router,
+    extract::{Multipart, State},
+    http::StatusCode,
+    routing::post,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::use_cases::documents::import_document::{ImportDocument, ImportSource};
+use crate::application::use_cases::documents::import_vault::{
+    ImportCollision, ImportVault, ImportVaultOptions, UnresolvedLink, VaultImportReport,
+};
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+use crate::presentation::http::documents::{Document, to_http_document};
+use crate::presentation::http::workspaces::resolve_workspace;
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct VaultImportMultipart {
+    /// Zip of the vault folder
+    #[schema(value_type = String, format = Binary)]
+    file: String,
+    /// Destination folder; defaults to the workspace root
+    #[schema(value_type = Option<String>, format = Uuid)]
+    parent_id: Option<String>,
+    /// Defaults to the personal workspace
+    #[schema(value_type = Option<String>, format = Uuid)]
+    workspace_id: Option<String>,
+    /// `true` to only report what would be created
+    dry_run: Option<bool>,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct DocumentImportMultipart {
+    /// .docx, .odt, .html, .rst, .org, .tex or .epub
+    #[schema(value_type = String, format = Binary)]
+    file: String,
+    /// Defaults to the file name without extension
+    title: Option<String>,
+    /// Destination folder; defaults to the workspace root
+    #[schema(value_type = Option<String>, format = Uuid)]
+    parent_id: Option<String>,
+    /// Defaults to the personal workspace
+    #[schema(value_type = Option<String>, format = Uuid)]
+    workspace_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportCollisionItem {
+    pub path: String,
+    pub title: String,
+    /// existing | duplicate
+    pub kind: String,
+    pub existing_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnresolvedLinkItem {
+    /// Path of the note inside the archive
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VaultImportResponse {
+    pub dry_run: bool,
+    pub folders: usize,
+    pub documents: usize,
+    pub attachments: usize,
+    /// Top-level folders and documents created
+    pub created: Vec<Uuid>,
+    pub collisions: Vec<ImportCollisionItem>,
+    pub unresolved_links: Vec<UnresolvedLinkItem>,
+    /// Archive entries that were not imported
+    pub skipped: Vec<String>,
+}
+
+impl From<VaultImportReport> for VaultImportResponse {
+    fn from(r: VaultImportReport) -> Self {
+        VaultImportResponse {
+            dry_run: r.dry_run,
+            folders: r.folders,
+            documents: r.documents,
+            attachments: r.attachments,
+            created: r.created,
+            collisions: r
+                .collisions
+                .into_iter()
+                .map(|c: ImportCollision| ImportCollisionItem {
+                    path: c.path,
+                    title: c.title,
+                    kind: c.kind.to_string(),
+                    existing_id: c.existing_id,
+                })
+                .collect(),
+            unresolved_links: r
+                .unresolved_links
+                .into_iter()
+                .map(|u: UnresolvedLink| UnresolvedLinkItem {
+                    source: u.source,
+                    target: u.target,
+                })
+                .collect(),
+            skipped: r.skipped,
+        }
+    }
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "bad_request" => StatusCode::BAD_REQUEST,
+        "payload_too_large" => StatusCode::PAYLOAD_TOO_LARGE,
+        "unsupported_format" => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        "forbidden" => StatusCode::FORBIDDEN,
+        _ => {
+            tracing::error!(error = ?e, "document_import_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+/// Workspace that imported documents go to: the parent folder's, else the requested one.
+pub(crate) async fn resolve_destination(
+    ctx: &AppContext,
+    user_id: Uuid,
+    parent_id: Option<Uuid>,
+    workspace_id: Option<Uuid>,
+) -> Result<Uuid, StatusCode> {
+    match parent_id {
+        Some(parent_id) => {
+            let parent = ctx
+                .document_repo()
+                .get_meta_for_owner(parent_id, user_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::NOT_FOUND)?;
+            if parent.archived_at.is_some() {
+                return Err(StatusCode::CONFLICT);
+            }
+            if parent.doc_type != "folder" {
+                return Err(StatusCode::BAD_REQUEST);
+            }
+            Ok(parent.workspace_id)
+        }
+        None => {
+            let ws = resolve_workspace(ctx, user_id, workspace_id).await?;
+            if !ws.role.can_edit() {
+                return Err(StatusCode::FORBIDDEN);
+            }
+            Ok(ws.id)
+        }
+    }
+}
+
+fn parse_uuid_field(value: &str) -> Result<Option<Uuid>, StatusCode> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    Uuid::parse_str(value)
+        .map(Some)
+        .map_err(|_| StatusCode::BAD_REQUEST)
+}
+
+#[utoipa::path(post, path = "/api/documents/import/vault", tag = "Documents",
+    request_body(content = VaultImportMultipart, content_type = "multipart/form-data",
+        description = "Markdown files become documents, directories folders; referenced assets are stored as attachments"),
+    responses(
+        (status = 200, body = VaultImportResponse),
+        (status = 400, description = "Not a zip archive or no Markdown files"),
+        (status = 404, description = "Destination folder not found"),
+        (status = 413, description = "Archive too large")
+    ))]
+pub async fn import_vault(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    mut multipart: Multipart,
+) -> Result<Json<VaultImportResponse>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let mut archive: Option<Vec<u8>> = None;
+    let mut parent_id = None;
+    let mut workspace_id = None;
+    let mut dry_run = false;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+    {
+        let name = field.name().map(|s| s.to_string());
+        match name.as_deref() {
+            Some("file") => {
+                let data = field.bytes().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                if data.len() > ctx.cfg.upload_max_bytes {
+                    return Err(StatusCode::PAYLOAD_TOO_LARGE);
+                }
+                archive = Some(data.to_vec());
+            }
+            Some("parent_id") => {
+                let t = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                parent_id = parse_uuid_field(&t)?;
+            }
+            Some("workspace_id") => {
+                let t = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                workspace_id = parse_uuid_field(&t)?;
+            }
+            Some("dry_run") => {
+                let t = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                dry_run = matches!(t.trim(), "true" | "1");
+            }
+            _ => {}
+        }
+    }
+    let archive = archive.ok_or(StatusCode::BAD_REQUEST)?;
+    let workspace_id = resolve_destination(&ctx, user_id, parent_id, workspace_id).await?;
+
+    let repo = ctx.document_repo();
+    let files = ctx.files_repo();
+    let storage = ctx.storage_port();
+    let snapshots = ctx.snapshot_service();
+    let uc = ImportVault {
+        repo: repo.as_ref(),
+        files: files.as_ref(),
+        storage: storage.as_ref(),
+        snapshots: snapshots.as_ref(),
+    };
+    let options = ImportVaultOptions {
+        workspace_id,
+        parent_id,
+        dry_run,
+        // Vaults compress well; allow a few times the upload limit once unpacked
+        max_bytes: ctx.cfg.upload_max_bytes.saturating_mul(4),
+    };
+    let report = uc
+        .execute(user_id, archive, options)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(report.into()))
+}
+
+#[utoipa::path(post, path = "/api/documents/import", tag = "Documents",
+    request_body(content = DocumentImportMultipart, content_type = "multipart/form-data",
+        description = "Converted to Markdown with pandoc; embedded media become attachments"),
+    responses(
+        (status = 200, body = Document),
+        (status = 400, description = "File could not be converted"),
+        (status = 404, description = "Destination folder not found"),
+        (status = 413, description = "File too large"),
+        (status = 415, description = "Unsupported file type")
+    ))]
+pub async fn import_document(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    mut multipart: Multipart,
+) -> Result<Json<Document>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let mut source: Option<ImportSource> = None;
+    let mut title = None;
+    let mut parent_id = None;
+    let mut workspace_id = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+    {
+        let name = field.name().map(|s| s.to_string());
+        match name.as_deref() {
+            Some("file") => {
+                let filename = field.file_name().map(|s| s.to_string()).unwrap_or_default();
+                let data = field.bytes().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                if data.len() > ctx.cfg.upload_max_bytes {
+                    return Err(StatusCode::PAYLOAD_TOO_LARGE);
+                }
+                source = Some(ImportSource {
+                    filename,
+                    bytes: data.to_vec(),
+                });
+            }
+            Some("title") => {
+                title = Some(field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?);
+            }
+            Some("parent_id") => {
+                let t = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                parent_id = parse_uuid_field(&t)?;
+            }
+            Some("workspace_id") => {
+                let t = field.text().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                workspace_id = parse_uuid_field(&t)?;
+            }
+            _ => {}
+        }
+    }
+    let source = source.ok_or(StatusCode::BAD_REQUEST)?;
+    let workspace_id = resolve_destination(&ctx, user_id, parent_id, workspace_id).await?;
+
+    let repo = ctx.document_repo();
+    let files = ctx.files_repo();
+    let storage = ctx.storage_port();
+    let snapshots = ctx.snapshot_service();
+    let uc = ImportDocument {
+        repo: repo.as_ref(),
+        files: files.as_ref(),
+        storage: storage.as_ref(),
+        snapshots: snapshots.as_ref(),
+        public_base_url: ctx.cfg.public_base_url.clone(),
+    };
+    let doc = uc
+        .execute(user_id, workspace_id, parent_id, title, source)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(to_http_document(doc)))
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/documents/import", post(import_document))
+        .route("/documents/import/vault", post(import_vault))
+        .with_state(ctx)
+}
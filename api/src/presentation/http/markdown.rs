@@ -138,8 +138,13 @@ pub async fn render_markdown(
     let options: RenderOptions = options.into();
 
     let bearer_token = bearer.as_ref().map(|b| b.0.as_str());
-    let user_scope =
-        resolve_user_scope_from_inputs(&ctx.cfg, bearer_token, options.token.as_deref());
+    let user_scope = resolve_user_scope_from_inputs(
+        &ctx,
+        bearer_token,
+        options.token.as_deref(),
+        options.doc_id,
+    )
+    .await;
 
     let assets = ctx.plugin_assets();
     let installations = ctx.plugin_installations();
@@ -214,10 +219,12 @@ pub async fn render_markdown_many(
         let options: RenderOptions = options.into();
 
         let user_scope = resolve_user_scope_from_inputs(
-            &ctx.cfg,
+            &ctx,
             bearer_token.as_deref(),
             options.token.as_deref(),
-        );
+            options.doc_id,
+        )
+        .await;
 
         let specs_arc = if let Some(existing) = spec_cache.get(&user_scope) {
             existing.clone()
@@ -592,26 +599,34 @@ fn build_hydrated_fragment(
     ))
 }
 
-fn resolve_user_scope_from_inputs(
-    cfg: &crate::bootstrap::config::Config,
+// Renderer plugins are installed per workspace: use the rendered document's workspace
+// when the user can edit it, otherwise the user's personal workspace.
+async fn resolve_user_scope_from_inputs(
+    ctx: &AppContext,
     bearer_token: Option<&str>,
     share_token: Option<&str>,
+    doc_id: Option<Uuid>,
 ) -> Option<Uuid> {
-    if let Some(token) = bearer_token {
-        if let Ok(sub) = auth::validate_bearer_str(cfg, token) {
-            if let Ok(uid) = Uuid::parse_str(&sub) {
-                return Some(uid);
-            }
-        }
+    let mut user_id = None;
+    if let Some(token) = bearer_token
+        && let Ok(sub) = auth::validate_bearer_str(ctx, token).await
+        && let Ok(uid) = Uuid::parse_str(&sub)
+    {
+        user_id = Some(uid);
     }
-    if let Some(token) = share_token {
-        if let Some(actor) = auth::resolve_actor_from_token_str(cfg, token) {
-            if let access::Actor::User(uid) = actor {
-                return Some(uid);
-            }
-        }
+    if user_id.is_none()
+        && let Some(token) = share_token
+        && let Some(access::Actor::User(uid)) = auth::resolve_actor_from_token_str(ctx, token).await
+    {
+        user_id = Some(uid);
+    }
+    let uid = user_id?;
+    if let Some(doc_id) = doc_id
+        && let Ok(Some(meta)) = ctx.document_repo().get_meta_for_owner(doc_id, uid).await
+    {
+        return Some(meta.workspace_id);
     }
-    None
+    Some(uid)
 }
 
 fn parse_hydrate_spec(value: Option<&serde_json::Value>) -> Option<HydrateSpec> {
@@ -1,10 +1,20 @@
+pub mod admin;
+pub mod api_tokens;
 pub mod auth;
+pub mod comments;
 pub mod documents;
 pub mod files;
 pub mod git;
+pub mod grants;
 pub mod health;
+pub mod imports;
 pub mod markdown;
 pub mod plugins;
 pub mod public;
 pub mod shares;
 pub mod tags;
+pub mod takeout;
+pub mod templates;
+pub mod trash;
+pub mod user_documents;
+pub mod workspaces;
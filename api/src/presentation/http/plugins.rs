@@ -29,8 +29,10 @@ use crate::application::use_cases::plugins::kv::{GetPluginKv, PutPluginKv};
 use crate::application::use_cases::plugins::records::{
     CreatePluginRecord, DeletePluginRecord, GetPluginRecord, ListPluginRecords, UpdatePluginRecord,
 };
+use crate::application::use_cases::workspaces::resolve_workspace::ResolveWorkspace;
 use crate::bootstrap::app_context::AppContext;
 use crate::presentation::http::auth::{self, Bearer};
+use crate::presentation::http::workspaces::{WorkspaceQuery, resolve_workspace};
 
 const PERMISSION_DOC_READ: &str = "doc.read";
 const PERMISSION_DOC_WRITE: &str = "doc.write";
@@ -104,7 +106,8 @@ impl From<ExecResult> for ExecResultResponse {
         ("kind" = String, Path, description = "Record kind"),
         ("limit" = Option<i64>, Query, description = "Limit"),
         ("offset" = Option<i64>, Query, description = "Offset"),
-        ("token" = Option<String>, Query, description = "Share token")
+        ("token" = Option<String>, Query, description = "Share token"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")
     ),
     responses((status = 200, body = RecordsResponse)),
     tag = "Plugins"
@@ -117,8 +120,9 @@ pub async fn list_records(
 ) -> Result<Json<RecordsResponse>, StatusCode> {
     ensure_valid_plugin_id(&p.plugin)?;
     let token = params.get("token").map(|s| s.as_str());
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
     // View permission required on doc
     let share_access = ctx.share_access_port();
     let access_repo = ctx.access_repo();
@@ -131,9 +135,10 @@ pub async fn list_records(
     .await
     .map_err(|_| StatusCode::FORBIDDEN)?;
 
-    let owner_user_id = resolve_plugin_owner_id(&ctx, &actor, token)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let plugin_workspace_id =
+        resolve_plugin_workspace_id(&ctx, &actor, token, workspace_hint(&params)?)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let limit = params
         .get("limit")
@@ -147,7 +152,13 @@ pub async fn list_records(
         .max(0);
 
     let runtime = ctx.plugin_runtime();
-    ensure_plugin_permission(&runtime, owner_user_id, &p.plugin, PERMISSION_DOC_READ).await?;
+    ensure_plugin_permission(
+        &runtime,
+        plugin_workspace_id,
+        &p.plugin,
+        PERMISSION_DOC_READ,
+    )
+    .await?;
 
     let repo = ctx.plugin_repo();
     let list_uc = ListPluginRecords {
@@ -185,7 +196,8 @@ pub struct CreateRecordBody {
         ("plugin" = String, Path, description = "Plugin ID"),
         ("doc_id" = Uuid, Path, description = "Document ID"),
         ("kind" = String, Path, description = "Record kind"),
-        ("token" = Option<String>, Query, description = "Share token")
+        ("token" = Option<String>, Query, description = "Share token"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")
     ),
     responses((status = 200, body = serde_json::Value)),
     tag = "Plugins",
@@ -200,8 +212,9 @@ pub async fn create_record(
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     ensure_valid_plugin_id(&p.plugin)?;
     let token = params.get("token").map(|s| s.as_str());
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
     // Edit permission required on doc
     let share_access = ctx.share_access_port();
     let access_repo = ctx.access_repo();
@@ -214,12 +227,19 @@ pub async fn create_record(
     .await
     .map_err(|_| StatusCode::FORBIDDEN)?;
 
-    let owner_user_id = resolve_plugin_owner_id(&ctx, &actor, token)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let plugin_workspace_id =
+        resolve_plugin_workspace_id(&ctx, &actor, token, workspace_hint(&params)?)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let runtime = ctx.plugin_runtime();
-    ensure_plugin_permission(&runtime, owner_user_id, &p.plugin, PERMISSION_DOC_WRITE).await?;
+    ensure_plugin_permission(
+        &runtime,
+        plugin_workspace_id,
+        &p.plugin,
+        PERMISSION_DOC_WRITE,
+    )
+    .await?;
 
     // Attach authorId and timestamps if not provided
     let mut data = body.data;
@@ -270,7 +290,7 @@ pub async fn update_record(
     Json(body): Json<UpdateRecordBody>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     ensure_valid_plugin_id(&p.plugin)?;
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
 
     let repo = ctx.plugin_repo();
@@ -300,8 +320,16 @@ pub async fn update_record(
     .await
     .map_err(|_| StatusCode::FORBIDDEN)?;
 
+    // Records follow the plugin installation of the document's workspace
+    let workspace_id = ctx
+        .document_repo()
+        .get_meta_for_owner(rec.scope_id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(|meta| meta.workspace_id);
+
     let runtime = ctx.plugin_runtime();
-    ensure_plugin_permission(&runtime, Some(user_id), &p.plugin, PERMISSION_DOC_WRITE).await?;
+    ensure_plugin_permission(&runtime, workspace_id, &p.plugin, PERMISSION_DOC_WRITE).await?;
 
     let update_uc = UpdatePluginRecord {
         repo: repo.as_ref(),
@@ -333,7 +361,7 @@ pub async fn delete_record(
     Path(p): Path<UpdateRecordPath>,
 ) -> Result<StatusCode, StatusCode> {
     ensure_valid_plugin_id(&p.plugin)?;
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.plugin_repo();
     // Get record to authorize
@@ -361,8 +389,16 @@ pub async fn delete_record(
     .await
     .map_err(|_| StatusCode::FORBIDDEN)?;
 
+    // Records follow the plugin installation of the document's workspace
+    let workspace_id = ctx
+        .document_repo()
+        .get_meta_for_owner(rec.scope_id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(|meta| meta.workspace_id);
+
     let runtime = ctx.plugin_runtime();
-    ensure_plugin_permission(&runtime, Some(user_id), &p.plugin, PERMISSION_DOC_WRITE).await?;
+    ensure_plugin_permission(&runtime, workspace_id, &p.plugin, PERMISSION_DOC_WRITE).await?;
 
     let delete_uc = DeletePluginRecord {
         repo: repo.as_ref(),
@@ -397,7 +433,8 @@ pub struct KvValueBody {
 #[utoipa::path(
     get,
     path = "/api/plugins/{plugin}/docs/{doc_id}/kv/{key}",
-    params(("plugin" = String, Path, description = "Plugin ID"), ("doc_id" = Uuid, Path, description = "Document ID"), ("key" = String, Path, description = "Key"), ("token" = Option<String>, Query, description = "Share token")),
+    params(("plugin" = String, Path, description = "Plugin ID"), ("doc_id" = Uuid, Path, description = "Document ID"), ("key" = String, Path, description = "Key"), ("token" = Option<String>, Query, description = "Share token"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")),
     responses((status = 200, body = KvValueResponse)),
     tag = "Plugins",
     operation_id = "pluginsGetKv"
@@ -410,8 +447,9 @@ pub async fn get_kv_value(
 ) -> Result<Json<KvValueResponse>, StatusCode> {
     ensure_valid_plugin_id(&p.plugin)?;
     let token = params.get("token").map(|s| s.as_str());
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
     // View permission required on doc
     let share_access = ctx.share_access_port();
     let access_repo = ctx.access_repo();
@@ -424,12 +462,19 @@ pub async fn get_kv_value(
     .await
     .map_err(|_| StatusCode::FORBIDDEN)?;
 
-    let owner_user_id = resolve_plugin_owner_id(&ctx, &actor, token)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let plugin_workspace_id =
+        resolve_plugin_workspace_id(&ctx, &actor, token, workspace_hint(&params)?)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let runtime = ctx.plugin_runtime();
-    ensure_plugin_permission(&runtime, owner_user_id, &p.plugin, PERMISSION_DOC_READ).await?;
+    ensure_plugin_permission(
+        &runtime,
+        plugin_workspace_id,
+        &p.plugin,
+        PERMISSION_DOC_READ,
+    )
+    .await?;
 
     let repo = ctx.plugin_repo();
     let get_uc = GetPluginKv {
@@ -447,7 +492,8 @@ pub async fn get_kv_value(
     put,
     path = "/api/plugins/{plugin}/docs/{doc_id}/kv/{key}",
     request_body = KvValueBody,
-    params(("plugin" = String, Path, description = "Plugin ID"), ("doc_id" = Uuid, Path, description = "Document ID"), ("key" = String, Path, description = "Key"), ("token" = Option<String>, Query, description = "Share token")),
+    params(("plugin" = String, Path, description = "Plugin ID"), ("doc_id" = Uuid, Path, description = "Document ID"), ("key" = String, Path, description = "Key"), ("token" = Option<String>, Query, description = "Share token"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")),
     responses((status = 204)),
     tag = "Plugins",
     operation_id = "pluginsPutKv"
@@ -461,8 +507,9 @@ pub async fn put_kv_value(
 ) -> Result<StatusCode, StatusCode> {
     ensure_valid_plugin_id(&p.plugin)?;
     let token = params.get("token").map(|s| s.as_str());
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
+        .await
+        .ok_or(StatusCode::UNAUTHORIZED)?;
     // Edit permission required on doc
     let share_access = ctx.share_access_port();
     let access_repo = ctx.access_repo();
@@ -475,12 +522,19 @@ pub async fn put_kv_value(
     .await
     .map_err(|_| StatusCode::FORBIDDEN)?;
 
-    let owner_user_id = resolve_plugin_owner_id(&ctx, &actor, token)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let plugin_workspace_id =
+        resolve_plugin_workspace_id(&ctx, &actor, token, workspace_hint(&params)?)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let runtime = ctx.plugin_runtime();
-    ensure_plugin_permission(&runtime, owner_user_id, &p.plugin, PERMISSION_DOC_WRITE).await?;
+    ensure_plugin_permission(
+        &runtime,
+        plugin_workspace_id,
+        &p.plugin,
+        PERMISSION_DOC_WRITE,
+    )
+    .await?;
 
     let repo = ctx.plugin_repo();
     let put_uc = PutPluginKv {
@@ -641,13 +695,22 @@ fn normalize_manifest_path(raw: &str) -> Option<String> {
     Some(trimmed.to_string())
 }
 
-async fn resolve_plugin_owner_id(
+// Plugin installations belong to a workspace. Signed-in users pick it with `workspace_id`
+// (defaulting to their personal one); share tokens use the shared document's workspace.
+async fn resolve_plugin_workspace_id(
     ctx: &AppContext,
     actor: &access::Actor,
     token_hint: Option<&str>,
+    workspace_hint: Option<Uuid>,
 ) -> AnyResult<Option<Uuid>> {
     match actor {
-        access::Actor::User(uid) => Ok(Some(*uid)),
+        access::Actor::User(uid) => {
+            let repo = ctx.workspace_repo();
+            let uc = ResolveWorkspace {
+                repo: repo.as_ref(),
+            };
+            Ok(uc.execute(*uid, workspace_hint).await?.map(|ws| ws.id))
+        }
         access::Actor::ShareToken(token_str) => {
             let lookup_token = token_hint
                 .filter(|s| !s.is_empty())
@@ -656,13 +719,22 @@ async fn resolve_plugin_owner_id(
                 return Ok(None);
             }
             let repo = ctx.shares_repo();
-            let owner = repo.get_document_owner_by_token(lookup_token).await?;
-            Ok(owner)
+            let workspace = repo.get_document_workspace_by_token(lookup_token).await?;
+            Ok(workspace)
         }
         access::Actor::Public => Ok(None),
     }
 }
 
+fn workspace_hint(params: &HashMap<String, String>) -> Result<Option<Uuid>, StatusCode> {
+    match params.get("workspace_id") {
+        Some(v) => Uuid::parse_str(v)
+            .map(Some)
+            .map_err(|_| StatusCode::BAD_REQUEST),
+        None => Ok(None),
+    }
+}
+
 fn extract_doc_id(value: &serde_json::Value) -> Option<Uuid> {
     value
         .get("docId")
@@ -680,7 +752,10 @@ fn extract_doc_id(value: &serde_json::Value) -> Option<Uuid> {
 #[utoipa::path(
     get,
     path = "/api/me/plugins/manifest",
-    params(("token" = Option<String>, Query, description = "Share token (optional)")),
+    params(
+        ("token" = Option<String>, Query, description = "Share token (optional)"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")
+    ),
     responses((status = 200, body = [ManifestItem])),
     tag = "Plugins",
     operation_id = "pluginsGetManifest"
@@ -695,7 +770,8 @@ pub async fn get_manifest(
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty());
     let token_hint = raw_token.as_deref();
-    let actor = auth::resolve_actor_from_parts(&ctx.cfg, bearer, token_hint)
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token_hint)
+        .await
         .ok_or(StatusCode::UNAUTHORIZED)?;
     let asset_token = match actor {
         access::Actor::ShareToken(_) => token_hint,
@@ -707,12 +783,13 @@ pub async fn get_manifest(
     let ttl = ctx.cfg.plugin_asset_url_ttl_secs;
     let mut items: Vec<ManifestItem> = Vec::new();
 
-    let user_scope_owner = resolve_plugin_owner_id(&ctx, &actor, token_hint)
-        .await
-        .map_err(|err| {
-            tracing::warn!(error = ?err, "share_owner_lookup_failed");
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
+    let user_scope_owner =
+        resolve_plugin_workspace_id(&ctx, &actor, token_hint, workspace_hint(&params)?)
+            .await
+            .map_err(|err| {
+                tracing::warn!(error = ?err, "share_owner_lookup_failed");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
 
     let global_plugins = store
         .list_latest_global_manifests()
@@ -796,7 +873,8 @@ pub struct ExecBody {
     params(
         ("plugin" = String, Path, description = "Plugin ID"),
         ("action" = String, Path, description = "Action"),
-        ("token" = Option<String>, Query, description = "Share token")
+        ("token" = Option<String>, Query, description = "Share token"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")
     ),
     responses((status = 200, body = ExecResultResponse)),
     tag = "Plugins",
@@ -811,13 +889,24 @@ pub async fn exec_action(
 ) -> Result<Json<ExecResultResponse>, StatusCode> {
     ensure_valid_plugin_id(&plugin)?;
     let token = params.get("token").map(|s| s.as_str());
-    let actor =
-        auth::resolve_actor_from_parts(&ctx.cfg, bearer, token).ok_or(StatusCode::UNAUTHORIZED)?;
-
-    let owner_user_id = resolve_plugin_owner_id(&ctx, &actor, token)
+    let actor = auth::resolve_actor_from_parts(&ctx, bearer, token)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .ok_or(StatusCode::FORBIDDEN)?;
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let plugin_workspace_id =
+        resolve_plugin_workspace_id(&ctx, &actor, token, workspace_hint(&params)?)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::FORBIDDEN)?;
+    let author_id = match &actor {
+        access::Actor::User(uid) => *uid,
+        _ => ctx
+            .shares_repo()
+            .get_document_owner_by_token(token.unwrap_or_default())
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::FORBIDDEN)?,
+    };
 
     if let access::Actor::ShareToken(_) = actor {
         if let Some(payload) = body.payload.as_ref() {
@@ -838,14 +927,32 @@ pub async fn exec_action(
     let plugin_repo = ctx.plugin_repo();
     let document_repo = ctx.document_repo();
     let runtime_store = ctx.plugin_runtime();
+    let template_repo = ctx.template_repo();
+    let user_repo = ctx.user_repo();
+    let files_repo = ctx.files_repo();
+    let storage = ctx.storage_port();
+    let realtime = ctx.realtime_engine();
+    let snapshots = ctx.snapshot_service();
     let exec_uc = ExecutePluginAction {
         runtime: runtime_store.as_ref(),
         plugin_repo: plugin_repo.as_ref(),
         document_repo: document_repo.as_ref(),
+        template_repo: template_repo.as_ref(),
+        user_repo: user_repo.as_ref(),
+        files_repo: files_repo.as_ref(),
+        storage: storage.as_ref(),
+        realtime: realtime.as_ref(),
+        snapshots: snapshots.as_ref(),
     };
 
     match exec_uc
-        .execute(owner_user_id, &plugin, &action, body.payload.clone())
+        .execute(
+            plugin_workspace_id,
+            author_id,
+            &plugin,
+            &action,
+            body.payload.clone(),
+        )
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     {
@@ -862,31 +969,31 @@ pub async fn exec_action(
 #[utoipa::path(
     get,
     path = "/api/me/plugins/updates",
+    params(WorkspaceQuery),
     tag = "Plugins",
     responses((status = 200, description = "Plugin event stream", content_type = "text/event-stream"))
 )]
 pub async fn sse_updates(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
 ) -> Result<Sse<impl Stream<Item = Result<Event, std::convert::Infallible>>>, StatusCode> {
-    // authenticate user (per-user stream)
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    // authenticate user (per-workspace stream)
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id = resolve_workspace(&ctx, user_id, wq.workspace_id).await?.id;
 
     let initial = stream::iter(vec![Ok(Event::default().event("ready").data("{}\n"))]);
     let event_stream = ctx
         .subscribe_plugin_events()
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    let broadcast = event_stream.filter_map(move |ev| {
-        let user_id = user_id.clone();
-        async move {
-            if ev.user_id.is_some() && ev.user_id != Some(user_id) {
-                return None;
-            }
-            let payload = ev.payload.to_string();
-            Some(Ok(Event::default().event("update").data(payload)))
+    let broadcast = event_stream.filter_map(move |ev| async move {
+        if ev.user_id.is_some() && ev.user_id != Some(workspace_id) {
+            return None;
         }
+        let payload = ev.payload.to_string();
+        Some(Ok(Event::default().event("update").data(payload)))
     });
     let merged = initial.chain(broadcast);
     let keepalive = KeepAlive::new()
@@ -910,6 +1017,7 @@ pub struct InstallResponse {
 #[utoipa::path(
     post,
     path = "/api/me/plugins/install-from-url",
+    params(WorkspaceQuery),
     request_body = InstallFromUrlBody,
     responses((status = 200, body = InstallResponse)),
     tag = "Plugins",
@@ -918,10 +1026,12 @@ pub struct InstallResponse {
 pub async fn install_from_url(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     Json(body): Json<InstallFromUrlBody>,
 ) -> Result<Json<InstallResponse>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id = managed_workspace_id(&ctx, user_id, wq.workspace_id).await?;
 
     let fetcher = ctx.plugin_fetcher();
     let installer = ctx.plugin_installer();
@@ -935,7 +1045,7 @@ pub async fn install_from_url(
     };
 
     match install_uc
-        .execute(user_id, &body.url, body.token.as_deref())
+        .execute(workspace_id, &body.url, body.token.as_deref())
         .await
     {
         Ok(installed) => Ok(Json(InstallResponse {
@@ -969,6 +1079,7 @@ pub struct UninstallBody {
 #[utoipa::path(
     post,
     path = "/api/me/plugins/uninstall",
+    params(WorkspaceQuery),
     request_body = UninstallBody,
     responses((status = 204)),
     tag = "Plugins",
@@ -977,10 +1088,12 @@ pub struct UninstallBody {
 pub async fn uninstall(
     State(ctx): State<AppContext>,
     bearer: Bearer,
+    Query(wq): Query<WorkspaceQuery>,
     Json(body): Json<UninstallBody>,
 ) -> Result<StatusCode, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let workspace_id = managed_workspace_id(&ctx, user_id, wq.workspace_id).await?;
     let UninstallBody { id } = body;
     let trimmed_id = id.trim();
     ensure_valid_plugin_id(trimmed_id)?;
@@ -988,14 +1101,14 @@ pub async fn uninstall(
     // For global plugins, uninstall endpoint no longer updates per-user list.
     // Optionally we could implement deletion from disk by id+version (not done here).
     let installations = ctx.plugin_installations();
-    let _ = installations.remove(user_id, &plugin_id).await;
+    let _ = installations.remove(workspace_id, &plugin_id).await;
 
     let store = ctx.plugin_assets();
     let plugin_id_for_remove = plugin_id.clone();
     let store_for_remove = store.clone();
-    let user_id_for_remove = user_id;
+    let workspace_id_for_remove = workspace_id;
     match tokio::task::spawn_blocking(move || {
-        store_for_remove.remove_user_plugin_dir(&user_id_for_remove, &plugin_id_for_remove)
+        store_for_remove.remove_user_plugin_dir(&workspace_id_for_remove, &plugin_id_for_remove)
     })
     .await
     {
@@ -1006,13 +1119,26 @@ pub async fn uninstall(
 
     let publisher = ctx.plugin_event_publisher();
     let event = crate::application::ports::plugin_event_publisher::PluginScopedEvent {
-        user_id: Some(user_id),
+        user_id: Some(workspace_id),
         payload: json!({ "event": "uninstalled", "id": plugin_id }),
     };
     let _ = publisher.publish(&event).await;
     Ok(StatusCode::NO_CONTENT)
 }
 
+// Installing and removing plugins changes the workspace for everyone, so it needs admin rights.
+async fn managed_workspace_id(
+    ctx: &AppContext,
+    user_id: Uuid,
+    requested: Option<Uuid>,
+) -> Result<Uuid, StatusCode> {
+    let ws = resolve_workspace(ctx, user_id, requested).await?;
+    if !ws.role.can_manage() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(ws.id)
+}
+
 async fn ensure_plugin_permission(
     runtime: &Arc<dyn crate::application::ports::plugin_runtime::PluginRuntime>,
     user_id: Option<Uuid>,
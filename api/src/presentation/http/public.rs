@@ -2,6 +2,7 @@ use axum::{
     Json, Router,
     extract::{Path, State},
     http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
     routing::{get, post},
 };
 use serde::Serialize;
@@ -38,7 +39,7 @@ pub async fn publish_document(
     bearer: Bearer,
     Path(id): Path<Uuid>,
 ) -> Result<Json<PublishResponse>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.public_repo();
     let uc = PublishDocument {
@@ -67,7 +68,7 @@ pub async fn unpublish_document(
     bearer: Bearer,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.public_repo();
     let uc = UnpublishDocument {
@@ -97,7 +98,7 @@ pub async fn get_publish_status(
     Path(id): Path<Uuid>,
 ) -> Result<Json<PublishResponse>, StatusCode> {
     // Validate ownership
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.public_repo();
     let uc = GetPublishStatus {
@@ -124,17 +125,41 @@ pub struct PublicDocumentSummary {
     pub published_at: chrono::DateTime<chrono::Utc>,
 }
 
+// Owners who changed their name keep their old public URLs working via a permanent redirect.
+async fn renamed_owner_redirect(
+    ctx: &AppContext,
+    name: &str,
+    rest: &str,
+) -> Result<Option<Response>, StatusCode> {
+    let repo = ctx.public_repo();
+    let current = repo
+        .resolve_renamed_owner(name)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(current.map(|current| {
+        let target = format!(
+            "/api/public/users/{}{}",
+            urlencoding::encode(&current),
+            rest
+        );
+        Redirect::permanent(&target).into_response()
+    }))
+}
+
 #[utoipa::path(
     get,
     path = "/api/public/users/{name}",
     tag = "Public Documents",
     params(("name" = String, Path, description = "Owner name")),
-    responses((status = 200, description = "Public documents for user", body = [PublicDocumentSummary]))
+    responses(
+        (status = 200, description = "Public documents for user", body = [PublicDocumentSummary]),
+        (status = 308, description = "Owner was renamed; follow Location")
+    )
 )]
 pub async fn list_user_public_documents(
     State(ctx): State<AppContext>,
     Path(name): Path<String>,
-) -> Result<Json<Vec<PublicDocumentSummary>>, StatusCode> {
+) -> Result<Response, StatusCode> {
     let repo = ctx.public_repo();
     let uc = ListUserPublic {
         repo: repo.as_ref(),
@@ -143,6 +168,11 @@ pub async fn list_user_public_documents(
         .execute(&name)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if items.is_empty()
+        && let Some(redirect) = renamed_owner_redirect(&ctx, &name, "").await?
+    {
+        return Ok(redirect);
+    }
     Ok(Json(
         items
             .into_iter()
@@ -152,8 +182,9 @@ pub async fn list_user_public_documents(
                 updated_at: d.updated_at,
                 published_at: d.published_at,
             })
-            .collect(),
-    ))
+            .collect::<Vec<_>>(),
+    )
+    .into_response())
 }
 
 #[utoipa::path(
@@ -161,12 +192,15 @@ pub async fn list_user_public_documents(
     path = "/api/public/users/{name}/{id}",
     tag = "Public Documents",
     params(("name" = String, Path, description = "Owner name"), ("id" = Uuid, Path, description = "Document ID")),
-    responses((status = 200, description = "Document metadata", body = Document))
+    responses(
+        (status = 200, description = "Document metadata", body = Document),
+        (status = 308, description = "Owner was renamed; follow Location")
+    )
 )]
 pub async fn get_public_by_owner_and_id(
     State(ctx): State<AppContext>,
     Path((name, id)): Path<(String, Uuid)>,
-) -> Result<Json<Document>, StatusCode> {
+) -> Result<Response, StatusCode> {
     let repo = ctx.public_repo();
     let uc = GetPublicByOwnerAndId {
         repo: repo.as_ref(),
@@ -175,7 +209,11 @@ pub async fn get_public_by_owner_and_id(
         .execute(&name, id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    let d = res.ok_or(StatusCode::NOT_FOUND)?;
+    let Some(d) = res else {
+        return renamed_owner_redirect(&ctx, &name, &format!("/{id}"))
+            .await?
+            .ok_or(StatusCode::NOT_FOUND);
+    };
     Ok(Json(Document {
         id: d.id,
         title: d.title,
@@ -187,7 +225,10 @@ pub async fn get_public_by_owner_and_id(
         archived_at: d.archived_at,
         archived_by: d.archived_by,
         archived_parent_id: d.archived_parent_id,
-    }))
+        properties: d.properties,
+        sort_key: d.sort_key,
+    })
+    .into_response())
 }
 
 #[utoipa::path(
@@ -195,19 +236,24 @@ pub async fn get_public_by_owner_and_id(
     path = "/api/public/users/{name}/{id}/content",
     tag = "Public Documents",
     params(("name" = String, Path, description = "Owner name"), ("id" = Uuid, Path, description = "Document ID")),
-    responses((status = 200, description = "Document content"))
+    responses(
+        (status = 200, description = "Document content"),
+        (status = 308, description = "Owner was renamed; follow Location")
+    )
 )]
 pub async fn get_public_content_by_owner_and_id(
     State(ctx): State<AppContext>,
     Path((name, id)): Path<(String, Uuid)>,
-) -> Result<Json<serde_json::Value>, StatusCode> {
+) -> Result<Response, StatusCode> {
     let repo = ctx.public_repo();
     let exists = repo
         .public_exists_by_owner_and_id(&name, id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     if !exists {
-        return Err(StatusCode::NOT_FOUND);
+        return renamed_owner_redirect(&ctx, &name, &format!("/{id}/content"))
+            .await?
+            .ok_or(StatusCode::NOT_FOUND);
     }
     let realtime = ctx.realtime_engine();
     let content = realtime
@@ -218,7 +264,7 @@ pub async fn get_public_content_by_owner_and_id(
             StatusCode::INTERNAL_SERVER_ERROR
         })?
         .unwrap_or_default();
-    Ok(Json(serde_json::json!({"content": content, "id": id})))
+    Ok(Json(serde_json::json!({"content": content, "id": id})).into_response())
 }
 pub fn routes(ctx: AppContext) -> Router {
     Router::new()
@@ -50,6 +50,7 @@ fn share_scope(document_type: &str) -> String {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateShareRequest {
     pub document_id: Uuid,
+    /// view | comment | edit (defaults to view)
     pub permission: Option<String>,
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -72,7 +73,7 @@ pub async fn create_share(
     bearer: Bearer,
     Json(req): Json<CreateShareRequest>,
 ) -> Result<Json<CreateShareResponse>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.shares_repo();
     let uc = CreateShare {
@@ -84,7 +85,11 @@ pub async fn create_share(
         .await
         .map_err(|e| {
             tracing::debug!(error=?e, "create_share_failed");
-            StatusCode::FORBIDDEN
+            if e.to_string() == "bad_request" {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::FORBIDDEN
+            }
         })?;
     let base = frontend_base(&ctx.cfg);
     let url = build_share_url(&base, &res.document_type, res.document_id, &res.token);
@@ -119,7 +124,7 @@ pub async fn list_document_shares(
     bearer: Bearer,
     axum::extract::Path(id): axum::extract::Path<Uuid>,
 ) -> Result<Json<Vec<ShareItem>>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     // authorization: require edit on the document
     let share_access = ctx.share_access_port();
@@ -177,7 +182,7 @@ pub async fn delete_share(
     bearer: Bearer,
     axum::extract::Path(token): axum::extract::Path<String>,
 ) -> Result<StatusCode, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.shares_repo();
     let uc = DeleteShare {
@@ -227,7 +232,7 @@ pub async fn list_applicable_shares(
     bearer: Bearer,
     Query(q): Query<ApplicableQuery>,
 ) -> Result<Json<Vec<ApplicableShareItem>>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     // authorize: require view on the document
     let share_access = ctx.share_access_port();
@@ -328,7 +333,7 @@ pub async fn list_active_shares(
     State(ctx): State<AppContext>,
     bearer: Bearer,
 ) -> Result<Json<Vec<ActiveShareItem>>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.shares_repo();
     let uc = crate::application::use_cases::shares::list_active::ListActiveShares {
@@ -449,7 +454,7 @@ pub async fn materialize_folder_share(
     bearer: Bearer,
     axum::extract::Path(token): axum::extract::Path<String>,
 ) -> Result<Json<MaterializeResponse>, StatusCode> {
-    let sub = auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = uuid::Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
     let repo = ctx.shares_repo();
     let created = repo
@@ -11,6 +11,7 @@ use uuid::Uuid;
 use crate::application::dto::tags::TagItemDto;
 use crate::application::use_cases::tags::list_tags::ListTags;
 use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::workspaces::resolve_workspace;
 
 #[derive(Serialize, ToSchema)]
 pub struct TagItem {
@@ -28,22 +29,31 @@ impl From<TagItemDto> for TagItem {
 }
 
 #[utoipa::path(get, path = "/api/tags", tag = "Tags",
-    params(("q" = Option<String>, Query, description = "Filter contains")),
+    params(
+        ("q" = Option<String>, Query, description = "Filter contains"),
+        ("workspace_id" = Option<Uuid>, Query, description = "Workspace ID (defaults to the personal workspace)")
+    ),
     responses((status = 200, body = [TagItem])))]
 pub async fn list_tags(
     State(ctx): State<AppContext>,
     bearer: crate::presentation::http::auth::Bearer,
     q: Option<Query<std::collections::HashMap<String, String>>>,
 ) -> Result<Json<Vec<TagItem>>, StatusCode> {
-    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx.cfg, bearer)?;
+    let sub = crate::presentation::http::auth::validate_bearer_public(&ctx, bearer).await?;
     let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
-    let filter = q.and_then(|Query(m)| m.get("q").cloned());
+    let params = q.map(|Query(m)| m).unwrap_or_default();
+    let filter = params.get("q").cloned();
+    let requested = match params.get("workspace_id") {
+        Some(v) => Some(Uuid::parse_str(v).map_err(|_| StatusCode::BAD_REQUEST)?),
+        None => None,
+    };
+    let ws = resolve_workspace(&ctx, user_id, requested).await?;
     let repo = ctx.tag_repo();
     let uc = ListTags {
         repo: repo.as_ref(),
     };
     let items: Vec<TagItemDto> = uc
-        .execute(user_id, filter)
+        .execute(ws.id, filter)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
     let out: Vec<TagItem> = items.into_iter().map(Into::into).collect();
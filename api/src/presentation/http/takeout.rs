@@ -0,0 +1,151 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::ports::takeout_repository::TakeoutJobRow;
+use crate::application::use_cases::takeout::download_takeout::DownloadTakeout;
+use crate::application::use_cases::takeout::list_takeouts::{GetTakeout, ListTakeouts};
+use crate::application::use_cases::takeout::request_takeout::RequestTakeout;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TakeoutJob {
+    pub id: Uuid,
+    /// pending | running | completed | failed
+    pub status: String,
+    pub error: Option<String>,
+    pub size_bytes: Option<i64>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The archive is deleted after this point
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<TakeoutJobRow> for TakeoutJob {
+    fn from(j: TakeoutJobRow) -> Self {
+        TakeoutJob {
+            id: j.id,
+            status: j.status,
+            error: j.error,
+            size_bytes: j.size_bytes,
+            created_at: j.created_at,
+            started_at: j.started_at,
+            completed_at: j.completed_at,
+            expires_at: j.expires_at,
+        }
+    }
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        "conflict" | "not_ready" => StatusCode::CONFLICT,
+        "gone" => StatusCode::GONE,
+        _ => {
+            tracing::error!(error = ?e, "takeout_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/api/me/takeout", tag = "Auth", responses((status = 200, body = [TakeoutJob])))]
+pub async fn list_takeouts(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<Vec<TakeoutJob>>, StatusCode> {
+    let sub = auth::validate_bearer(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.takeout_repo();
+    let uc = ListTakeouts {
+        repo: repo.as_ref(),
+    };
+    let rows = uc.execute(user_id).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(post, path = "/api/me/takeout", tag = "Auth",
+    responses((status = 202, body = TakeoutJob), (status = 409, description = "An export is already in progress")))]
+pub async fn request_takeout(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<(StatusCode, Json<TakeoutJob>), StatusCode> {
+    let sub = auth::validate_bearer(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.takeout_repo();
+    let uc = RequestTakeout {
+        repo: repo.as_ref(),
+    };
+    let job = uc.execute(user_id).await.map_err(map_error)?;
+    Ok((StatusCode::ACCEPTED, Json(job.into())))
+}
+
+#[utoipa::path(get, path = "/api/me/takeout/{id}", tag = "Auth",
+    params(("id" = Uuid, Path, description = "Takeout job ID")),
+    responses((status = 200, body = TakeoutJob), (status = 404, description = "Job not found")))]
+pub async fn get_takeout(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TakeoutJob>, StatusCode> {
+    let sub = auth::validate_bearer(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.takeout_repo();
+    let uc = GetTakeout {
+        repo: repo.as_ref(),
+    };
+    let job = uc.execute(user_id, id).await.map_err(map_error)?;
+    Ok(Json(job.into()))
+}
+
+#[utoipa::path(get, path = "/api/me/takeout/{id}/download", tag = "Auth",
+    params(("id" = Uuid, Path, description = "Takeout job ID")),
+    responses(
+        (status = 200, description = "Zip archive", body = Vec<u8>, content_type = "application/zip"),
+        (status = 404, description = "Job not found"),
+        (status = 409, description = "Export not finished"),
+        (status = 410, description = "Export expired"),
+    ))]
+pub async fn download_takeout(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Response, StatusCode> {
+    let sub = auth::validate_bearer(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.takeout_repo();
+    let storage = ctx.storage_port();
+    let uc = DownloadTakeout {
+        repo: repo.as_ref(),
+        storage: storage.as_ref(),
+    };
+    let download = uc.execute(user_id, id).await.map_err(map_error)?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        axum::http::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/zip"),
+    );
+    let disposition = format!("attachment; filename=\"{}\"", download.filename);
+    let content_disposition =
+        HeaderValue::from_str(&disposition).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    headers.insert(axum::http::header::CONTENT_DISPOSITION, content_disposition);
+
+    Ok((headers, download.bytes).into_response())
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/me/takeout", get(list_takeouts).post(request_takeout))
+        .route("/me/takeout/:id", get(get_takeout))
+        .route("/me/takeout/:id/download", get(download_takeout))
+        .with_state(ctx)
+}
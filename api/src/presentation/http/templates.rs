@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post, put},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::application::ports::template_repository::{TemplatePrompt, TemplateRow};
+use crate::application::use_cases::templates::instantiate_template::{
+    InstantiateOptions, InstantiateTemplate,
+};
+use crate::application::use_cases::templates::list_templates::ListTemplates;
+use crate::application::use_cases::templates::remove_template::RemoveTemplate;
+use crate::application::use_cases::templates::set_template::SetTemplate;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+use crate::presentation::http::documents::{Document, to_http_document};
+use crate::presentation::http::workspaces::resolve_workspace;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TemplatePromptItem {
+    /// Referenced as `{{name}}`
+    pub name: String,
+    pub label: Option<String>,
+    /// Used when no value is supplied; prompts without a default are required
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Template {
+    pub document_id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub prompts: Vec<TemplatePromptItem>,
+    /// document | folder
+    pub doc_type: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<TemplateRow> for Template {
+    fn from(t: TemplateRow) -> Self {
+        Template {
+            document_id: t.document_id,
+            workspace_id: t.workspace_id,
+            name: t.name,
+            description: t.description,
+            prompts: t
+                .prompts
+                .into_iter()
+                .map(|p| TemplatePromptItem {
+                    name: p.name,
+                    label: p.label,
+                    default: p.default,
+                })
+                .collect(),
+            doc_type: t.doc_type,
+            created_by: t.created_by,
+            created_at: t.created_at,
+            updated_at: t.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListTemplatesQuery {
+    pub workspace_id: Option<Uuid>,
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct SetTemplateRequest {
+    /// Defaults to the document title
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub prompts: Vec<TemplatePromptItem>,
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct InstantiateTemplateRequest {
+    /// Destination folder; defaults to the workspace root
+    pub parent_id: Option<Uuid>,
+    /// Defaults to the template's workspace
+    pub workspace_id: Option<Uuid>,
+    /// Defaults to the template title with variables substituted
+    pub title: Option<String>,
+    /// Values for the template's prompts
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        "forbidden" => StatusCode::FORBIDDEN,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        "conflict" => StatusCode::CONFLICT,
+        _ => {
+            tracing::error!(error = ?e, "template_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/api/templates", tag = "Templates",
+    params(ListTemplatesQuery),
+    responses((status = 200, body = [Template])))]
+pub async fn list_templates(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Query(q): Query<ListTemplatesQuery>,
+) -> Result<Json<Vec<Template>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let ws = resolve_workspace(&ctx, user_id, q.workspace_id).await?;
+    let templates = ctx.template_repo();
+    let uc = ListTemplates {
+        templates: templates.as_ref(),
+    };
+    let rows = uc.execute(ws.id).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(put, path = "/api/documents/{id}/template", tag = "Templates",
+    params(("id" = Uuid, Path, description = "Document or folder ID")),
+    request_body = SetTemplateRequest,
+    responses(
+        (status = 200, body = Template),
+        (status = 400, description = "Invalid prompt names"),
+        (status = 404, description = "Document not found"),
+        (status = 409, description = "Document is archived")
+    ))]
+pub async fn set_template(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    body: Option<Json<SetTemplateRequest>>,
+) -> Result<Json<Template>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let req = body.map(|Json(b)| b).unwrap_or_default();
+    let prompts: Vec<TemplatePrompt> = req
+        .prompts
+        .into_iter()
+        .map(|p| TemplatePrompt {
+            name: p.name,
+            label: p.label,
+            default: p.default,
+        })
+        .collect();
+    let repo = ctx.document_repo();
+    let templates = ctx.template_repo();
+    let uc = SetTemplate {
+        repo: repo.as_ref(),
+        templates: templates.as_ref(),
+    };
+    let row = uc
+        .execute(
+            user_id,
+            id,
+            req.name.as_deref(),
+            req.description.as_deref(),
+            &prompts,
+        )
+        .await
+        .map_err(map_error)?;
+    Ok(Json(row.into()))
+}
+
+#[utoipa::path(delete, path = "/api/documents/{id}/template", tag = "Templates",
+    params(("id" = Uuid, Path, description = "Document or folder ID")),
+    responses((status = 204), (status = 404, description = "Not a template")))]
+pub async fn remove_template(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.document_repo();
+    let templates = ctx.template_repo();
+    let uc = RemoveTemplate {
+        repo: repo.as_ref(),
+        templates: templates.as_ref(),
+    };
+    if uc.execute(user_id, id).await.map_err(map_error)? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(StatusCode::NOT_FOUND)
+    }
+}
+
+#[utoipa::path(post, path = "/api/templates/{id}/instantiate", tag = "Templates",
+    params(("id" = Uuid, Path, description = "Template document ID")),
+    request_body = InstantiateTemplateRequest,
+    responses(
+        (status = 200, body = Document),
+        (status = 400, description = "Missing prompt value or invalid destination"),
+        (status = 404, description = "Template or destination not found"),
+        (status = 409, description = "Template or destination is archived")
+    ))]
+pub async fn instantiate_template(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    body: Option<Json<InstantiateTemplateRequest>>,
+) -> Result<Json<Document>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let req = body.map(|Json(b)| b).unwrap_or_default();
+    let repo = ctx.document_repo();
+    let templates = ctx.template_repo();
+    let workspace_id = match req.parent_id {
+        Some(parent_id) => {
+            repo.get_meta_for_owner(parent_id, user_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::NOT_FOUND)?
+                .workspace_id
+        }
+        None => {
+            let requested = match req.workspace_id {
+                Some(ws) => ws,
+                None => {
+                    templates
+                        .get(id)
+                        .await
+                        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                        .ok_or(StatusCode::NOT_FOUND)?
+                        .workspace_id
+                }
+            };
+            let ws = resolve_workspace(&ctx, user_id, Some(requested)).await?;
+            if !ws.role.can_edit() {
+                return Err(StatusCode::FORBIDDEN);
+            }
+            ws.id
+        }
+    };
+
+    let users = ctx.user_repo();
+    let files = ctx.files_repo();
+    let storage = ctx.storage_port();
+    let realtime = ctx.realtime_engine();
+    let snapshots = ctx.snapshot_service();
+    let uc = InstantiateTemplate {
+        repo: repo.as_ref(),
+        templates: templates.as_ref(),
+        users: users.as_ref(),
+        files: files.as_ref(),
+        storage: storage.as_ref(),
+        realtime: realtime.as_ref(),
+        snapshots: snapshots.as_ref(),
+    };
+    let options = InstantiateOptions {
+        workspace_id,
+        parent_id: req.parent_id,
+        title: req.title,
+        variables: req.variables,
+    };
+    let doc = uc.execute(user_id, id, options).await.map_err(map_error)?;
+    Ok(Json(to_http_document(doc)))
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/templates", get(list_templates))
+        .route("/templates/:id/instantiate", post(instantiate_template))
+        .route(
+            "/documents/:id/template",
+            put(set_template).delete(remove_template),
+        )
+        .with_state(ctx)
+}
@@ -0,0 +1,154 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::application::ports::document_repository::TrashedDocument;
+use crate::application::use_cases::trash::list_trash::ListTrash;
+use crate::application::use_cases::trash::purge_trash::PurgeTrash;
+use crate::application::use_cases::trash::restore_document::RestoreDocument;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+use crate::presentation::http::documents::{Document, to_http_document};
+use crate::presentation::http::workspaces::resolve_workspace;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashItem {
+    pub id: Uuid,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub doc_type: String,
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+    pub deleted_by: Option<Uuid>,
+    /// Folder the item is restored into, while it still exists
+    pub original_parent_id: Option<Uuid>,
+    pub original_parent_title: Option<String>,
+    /// When the retention job deletes the item for good; null when retention is disabled
+    pub purge_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmptyTrashResponse {
+    /// Documents and folders deleted, descendants included
+    pub deleted: u64,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TrashQuery {
+    pub workspace_id: Option<Uuid>,
+}
+
+fn to_trash_item(doc: TrashedDocument, retention_secs: i64) -> TrashItem {
+    TrashItem {
+        id: doc.id,
+        title: doc.title,
+        doc_type: doc.doc_type,
+        deleted_at: doc.deleted_at,
+        deleted_by: doc.deleted_by,
+        original_parent_id: doc.original_parent_id,
+        original_parent_title: doc.original_parent_title,
+        purge_at: (retention_secs > 0)
+            .then(|| doc.deleted_at + chrono::Duration::seconds(retention_secs)),
+    }
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        "forbidden" => StatusCode::FORBIDDEN,
+        _ => {
+            tracing::error!(error = ?e, "trash_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/api/trash", tag = "Trash",
+    params(TrashQuery),
+    responses((status = 200, body = [TrashItem]), (status = 403, description = "Workspace is read-only for the user")))]
+pub async fn list_trash(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Query(q): Query<TrashQuery>,
+) -> Result<Json<Vec<TrashItem>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let ws = resolve_workspace(&ctx, user_id, q.workspace_id).await?;
+    if !ws.role.can_edit() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let repo = ctx.document_repo();
+    let uc = ListTrash {
+        repo: repo.as_ref(),
+    };
+    let items = uc.execute(ws.id).await.map_err(map_error)?;
+    let retention = ctx.cfg.trash_retention_secs;
+    Ok(Json(
+        items
+            .into_iter()
+            .map(|doc| to_trash_item(doc, retention))
+            .collect(),
+    ))
+}
+
+#[utoipa::path(delete, path = "/api/trash", tag = "Trash",
+    params(TrashQuery),
+    responses((status = 200, body = EmptyTrashResponse), (status = 403, description = "Workspace is read-only for the user")))]
+pub async fn empty_trash(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Query(q): Query<TrashQuery>,
+) -> Result<Json<EmptyTrashResponse>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let ws = resolve_workspace(&ctx, user_id, q.workspace_id).await?;
+    if !ws.role.can_edit() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let repo = ctx.document_repo();
+    let storage = ctx.storage_port();
+    let uc = PurgeTrash {
+        repo: repo.as_ref(),
+        storage: storage.as_ref(),
+    };
+    let deleted = uc.execute(Some(ws.id), None).await.map_err(map_error)?;
+    Ok(Json(EmptyTrashResponse { deleted }))
+}
+
+#[utoipa::path(post, path = "/api/trash/{id}/restore", tag = "Trash",
+    params(("id" = Uuid, Path, description = "Trashed document or folder ID")),
+    responses((status = 200, body = Document), (status = 404, description = "Not in the trash")))]
+pub async fn restore_document(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Document>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.document_repo();
+    let realtime = ctx.realtime_engine();
+    let storage = ctx.storage_port();
+    let uc = RestoreDocument {
+        repo: repo.as_ref(),
+        realtime: realtime.as_ref(),
+        storage: storage.as_ref(),
+    };
+    let doc = uc
+        .execute(user_id, id)
+        .await
+        .map_err(map_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok(Json(to_http_document(doc)))
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/trash", get(list_trash).delete(empty_trash))
+        .route("/trash/:id/restore", post(restore_document))
+        .with_state(ctx)
+}
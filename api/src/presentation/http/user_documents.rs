@@ -0,0 +1,216 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, put},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::application::ports::user_documents_repository::{
+    FavoriteDocumentRow, RecentDocumentRow,
+};
+use crate::application::use_cases::user_documents::favorites::{
+    AddFavorite, ListFavorites, RemoveFavorite,
+};
+use crate::application::use_cases::user_documents::list_recent::ListRecentDocuments;
+use crate::application::use_cases::user_documents::record_view::{
+    RECENT_VIEWS_KEPT, RecordDocumentView,
+};
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecentDocumentItem {
+    pub document_id: Uuid,
+    pub workspace_id: Uuid,
+    pub title: String,
+    /// document | folder
+    pub document_type: String,
+    pub view_count: i32,
+    pub last_viewed_at: chrono::DateTime<chrono::Utc>,
+    /// Share token to open the document with when the user has no direct access
+    pub share_token: Option<String>,
+}
+
+impl From<RecentDocumentRow> for RecentDocumentItem {
+    fn from(r: RecentDocumentRow) -> Self {
+        RecentDocumentItem {
+            document_id: r.document_id,
+            workspace_id: r.workspace_id,
+            title: r.title,
+            document_type: r.doc_type,
+            view_count: r.view_count,
+            last_viewed_at: r.last_viewed_at,
+            share_token: r.share_token,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FavoriteDocumentItem {
+    pub document_id: Uuid,
+    pub workspace_id: Uuid,
+    pub title: String,
+    /// document | folder
+    pub document_type: String,
+    pub favorited_at: chrono::DateTime<chrono::Utc>,
+    /// Share token to open the document with when the user has no direct access
+    pub share_token: Option<String>,
+}
+
+impl From<FavoriteDocumentRow> for FavoriteDocumentItem {
+    fn from(f: FavoriteDocumentRow) -> Self {
+        FavoriteDocumentItem {
+            document_id: f.document_id,
+            workspace_id: f.workspace_id,
+            title: f.title,
+            document_type: f.doc_type,
+            favorited_at: f.created_at,
+            share_token: f.share_token,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RecentQuery {
+    /// Defaults to 20
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct FavoriteQuery {
+    /// Share token, for documents the user only reaches through a share link
+    pub token: Option<String>,
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" => StatusCode::NOT_FOUND,
+        _ => {
+            tracing::error!(error = ?e, "user_documents_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+/// Records an open by a signed-in user, with the share token that granted it if any;
+/// failures are logged and never fail the read.
+pub(crate) async fn record_view(
+    ctx: &AppContext,
+    user_id: Uuid,
+    doc_id: Uuid,
+    share_token: Option<&str>,
+) {
+    let repo = ctx.user_documents_repo();
+    let share_access = ctx.share_access_port();
+    let uc = RecordDocumentView {
+        repo: repo.as_ref(),
+        shares: share_access.as_ref(),
+    };
+    if let Err(e) = uc.execute(user_id, doc_id, share_token).await {
+        tracing::warn!(error = ?e, document_id = %doc_id, "record_document_view_failed");
+    }
+}
+
+#[utoipa::path(get, path = "/api/me/recent", tag = "Documents",
+    params(RecentQuery),
+    responses((status = 200, body = [RecentDocumentItem])))]
+pub async fn list_recent(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Query(q): Query<RecentQuery>,
+) -> Result<Json<Vec<RecentDocumentItem>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let limit = q.limit.unwrap_or(20).clamp(1, RECENT_VIEWS_KEPT as usize);
+    let repo = ctx.user_documents_repo();
+    let access_repo = ctx.access_repo();
+    let share_access = ctx.share_access_port();
+    let uc = ListRecentDocuments {
+        repo: repo.as_ref(),
+        access: access_repo.as_ref(),
+        shares: share_access.as_ref(),
+    };
+    let rows = uc.execute(user_id, limit).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/api/me/favorites", tag = "Documents",
+    responses((status = 200, body = [FavoriteDocumentItem])))]
+pub async fn list_favorites(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<Vec<FavoriteDocumentItem>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.user_documents_repo();
+    let access_repo = ctx.access_repo();
+    let share_access = ctx.share_access_port();
+    let uc = ListFavorites {
+        repo: repo.as_ref(),
+        access: access_repo.as_ref(),
+        shares: share_access.as_ref(),
+    };
+    let rows = uc.execute(user_id).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(put, path = "/api/me/favorites/{id}", tag = "Documents",
+    params(("id" = Uuid, Path, description = "Document or folder ID"), FavoriteQuery),
+    responses((status = 204), (status = 404, description = "Document not found or not visible")))]
+pub async fn add_favorite(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    Query(q): Query<FavoriteQuery>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.user_documents_repo();
+    let access_repo = ctx.access_repo();
+    let share_access = ctx.share_access_port();
+    let uc = AddFavorite {
+        repo: repo.as_ref(),
+        access: access_repo.as_ref(),
+        shares: share_access.as_ref(),
+    };
+    uc.execute(user_id, id, q.token.as_deref())
+        .await
+        .map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(delete, path = "/api/me/favorites/{id}", tag = "Documents",
+    params(("id" = Uuid, Path, description = "Document or folder ID")),
+    responses((status = 204), (status = 404, description = "Not a favorite")))]
+pub async fn remove_favorite(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.user_documents_repo();
+    let uc = RemoveFavorite {
+        repo: repo.as_ref(),
+    };
+    let removed = uc.execute(user_id, id).await.map_err(map_error)?;
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(StatusCode::NOT_FOUND)
+    }
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/me/recent", get(list_recent))
+        .route("/me/favorites", get(list_favorites))
+        .route(
+            "/me/favorites/:id",
+            put(add_favorite).delete(remove_favorite),
+        )
+        .with_state(ctx)
+}
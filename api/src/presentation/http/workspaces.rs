@@ -0,0 +1,369 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, patch},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::application::ports::workspace_repository::{
+    WorkspaceMemberRow, WorkspaceRole, WorkspaceRow,
+};
+use crate::application::use_cases::workspaces::add_member::AddMember;
+use crate::application::use_cases::workspaces::create_workspace::CreateWorkspace;
+use crate::application::use_cases::workspaces::delete_workspace::DeleteWorkspace;
+use crate::application::use_cases::workspaces::list_members::ListMembers;
+use crate::application::use_cases::workspaces::list_workspaces::ListWorkspaces;
+use crate::application::use_cases::workspaces::remove_member::RemoveMember;
+use crate::application::use_cases::workspaces::resolve_workspace::ResolveWorkspace;
+use crate::application::use_cases::workspaces::update_member::UpdateMember;
+use crate::application::use_cases::workspaces::update_workspace::UpdateWorkspace;
+use crate::bootstrap::app_context::AppContext;
+use crate::presentation::http::auth::{self, Bearer};
+
+/// Optional workspace selector shared by workspace-scoped endpoints.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct WorkspaceQuery {
+    /// Defaults to the caller's personal workspace
+    pub workspace_id: Option<Uuid>,
+}
+
+/// Resolves the workspace a request targets, hiding workspaces the user is not a member of.
+pub async fn resolve_workspace(
+    ctx: &AppContext,
+    user_id: Uuid,
+    requested: Option<Uuid>,
+) -> Result<WorkspaceRow, StatusCode> {
+    let repo = ctx.workspace_repo();
+    let uc = ResolveWorkspace {
+        repo: repo.as_ref(),
+    };
+    uc.execute(user_id, requested)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+fn map_error(e: anyhow::Error) -> StatusCode {
+    match e.to_string().as_str() {
+        "not_found" | "user_not_found" => StatusCode::NOT_FOUND,
+        "forbidden" => StatusCode::FORBIDDEN,
+        "bad_request" => StatusCode::BAD_REQUEST,
+        "conflict" => StatusCode::CONFLICT,
+        _ => {
+            tracing::error!(error = ?e, "workspace_request_failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+fn parse_role(value: &str) -> Result<WorkspaceRole, StatusCode> {
+    WorkspaceRole::parse(value).ok_or(StatusCode::BAD_REQUEST)
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkspaceResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub is_personal: bool,
+    /// owner | admin | member | guest
+    pub role: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WorkspaceRow> for WorkspaceResponse {
+    fn from(w: WorkspaceRow) -> Self {
+        WorkspaceResponse {
+            id: w.id,
+            name: w.name,
+            is_personal: w.is_personal,
+            role: w.role.as_str().to_string(),
+            created_at: w.created_at,
+            updated_at: w.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkspaceMemberResponse {
+    pub user_id: Uuid,
+    pub email: String,
+    pub name: String,
+    /// owner | admin | member | guest
+    pub role: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WorkspaceMemberRow> for WorkspaceMemberResponse {
+    fn from(m: WorkspaceMemberRow) -> Self {
+        WorkspaceMemberResponse {
+            user_id: m.user_id,
+            email: m.email,
+            name: m.name,
+            role: m.role.as_str().to_string(),
+            created_at: m.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWorkspaceRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWorkspaceRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddMemberRequest {
+    pub email: String,
+    /// owner | admin | member | guest (defaults to member)
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateMemberRequest {
+    /// owner | admin | member | guest
+    pub role: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/workspaces",
+    tag = "Workspaces",
+    responses((status = 200, body = [WorkspaceResponse]))
+)]
+pub async fn list_workspaces(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+) -> Result<Json<Vec<WorkspaceResponse>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.workspace_repo();
+    let uc = ListWorkspaces {
+        repo: repo.as_ref(),
+    };
+    let rows = uc.execute(user_id).await.map_err(map_error)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/workspaces",
+    tag = "Workspaces",
+    request_body = CreateWorkspaceRequest,
+    responses((status = 200, body = WorkspaceResponse))
+)]
+pub async fn create_workspace(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Json(req): Json<CreateWorkspaceRequest>,
+) -> Result<Json<WorkspaceResponse>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.workspace_repo();
+    let uc = CreateWorkspace {
+        repo: repo.as_ref(),
+    };
+    let ws = uc.execute(user_id, &req.name).await.map_err(map_error)?;
+    Ok(Json(ws.into()))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/workspaces/{id}",
+    tag = "Workspaces",
+    request_body = UpdateWorkspaceRequest,
+    params(("id" = Uuid, Path, description = "Workspace ID")),
+    responses((status = 200, body = WorkspaceResponse))
+)]
+pub async fn update_workspace(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateWorkspaceRequest>,
+) -> Result<Json<WorkspaceResponse>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.workspace_repo();
+    let uc = UpdateWorkspace {
+        repo: repo.as_ref(),
+    };
+    let ws = uc
+        .execute(user_id, id, &req.name)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(ws.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/workspaces/{id}",
+    tag = "Workspaces",
+    params(("id" = Uuid, Path, description = "Workspace ID")),
+    responses((status = 204))
+)]
+pub async fn delete_workspace(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.workspace_repo();
+    let documents = ctx.document_repo();
+    let storage = ctx.storage_port();
+    let plugin_installations = ctx.plugin_installations();
+    let git_repo = ctx.git_repo();
+    let git_workspace = ctx.git_workspace();
+    let uc = DeleteWorkspace {
+        repo: repo.as_ref(),
+        documents: documents.as_ref(),
+        storage: storage.as_ref(),
+        plugin_installations: plugin_installations.as_ref(),
+        plugin_assets: ctx.plugin_assets(),
+        git_repo: git_repo.as_ref(),
+        git_workspace: git_workspace.as_ref(),
+    };
+    uc.execute(user_id, id).await.map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/workspaces/{id}/members",
+    tag = "Workspaces",
+    params(("id" = Uuid, Path, description = "Workspace ID")),
+    responses((status = 200, body = [WorkspaceMemberResponse]))
+)]
+pub async fn list_members(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<WorkspaceMemberResponse>>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.workspace_repo();
+    let uc = ListMembers {
+        repo: repo.as_ref(),
+    };
+    let rows = uc
+        .execute(user_id, id)
+        .await
+        .map_err(map_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/workspaces/{id}/members",
+    tag = "Workspaces",
+    request_body = AddMemberRequest,
+    params(("id" = Uuid, Path, description = "Workspace ID")),
+    responses((status = 200, body = WorkspaceMemberResponse))
+)]
+pub async fn add_member(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AddMemberRequest>,
+) -> Result<Json<WorkspaceMemberResponse>, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let role = match req.role.as_deref() {
+        Some(r) => parse_role(r)?,
+        None => WorkspaceRole::Member,
+    };
+    let repo = ctx.workspace_repo();
+    let users = ctx.user_repo();
+    let uc = AddMember {
+        repo: repo.as_ref(),
+        users: users.as_ref(),
+    };
+    let member = uc
+        .execute(user_id, id, &req.email, role)
+        .await
+        .map_err(map_error)?;
+    Ok(Json(member.into()))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/workspaces/{id}/members/{user_id}",
+    tag = "Workspaces",
+    request_body = UpdateMemberRequest,
+    params(
+        ("id" = Uuid, Path, description = "Workspace ID"),
+        ("user_id" = Uuid, Path, description = "Member user ID"),
+    ),
+    responses((status = 204))
+)]
+pub async fn update_member(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path((id, member_id)): Path<(Uuid, Uuid)>,
+    Json(req): Json<UpdateMemberRequest>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let role = parse_role(&req.role)?;
+    let repo = ctx.workspace_repo();
+    let uc = UpdateMember {
+        repo: repo.as_ref(),
+    };
+    uc.execute(user_id, id, member_id, role)
+        .await
+        .map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/workspaces/{id}/members/{user_id}",
+    tag = "Workspaces",
+    params(
+        ("id" = Uuid, Path, description = "Workspace ID"),
+        ("user_id" = Uuid, Path, description = "Member user ID"),
+    ),
+    responses((status = 204))
+)]
+pub async fn remove_member(
+    State(ctx): State<AppContext>,
+    bearer: Bearer,
+    Path((id, member_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, StatusCode> {
+    let sub = auth::validate_bearer_public(&ctx, bearer).await?;
+    let user_id = Uuid::parse_str(&sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let repo = ctx.workspace_repo();
+    let uc = RemoveMember {
+        repo: repo.as_ref(),
+    };
+    uc.execute(user_id, id, member_id)
+        .await
+        .map_err(map_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn routes(ctx: AppContext) -> Router {
+    Router::new()
+        .route("/workspaces", get(list_workspaces).post(create_workspace))
+        .route(
+            "/workspaces/:id",
+            patch(update_workspace).delete(delete_workspace),
+        )
+        .route(
+            "/workspaces/:id/members",
+            get(list_members).post(add_member),
+        )
+        .route(
+            "/workspaces/:id/members/:user_id",
+            patch(update_member).delete(remove_member),
+        )
+        .with_state(ctx)
+}
@@ -3,8 +3,10 @@ use std::sync::Arc;
 
 use crate::application::access::{self, Capability};
 use crate::application::ports::realtime_port::RealtimeError;
-use crate::bootstrap::app_context::{AppContext, DynRealtimeSink, DynRealtimeStream};
-use crate::presentation::http::auth;
+use crate::bootstrap::app_context::{
+    AppContext, DynRealtimeSink, DynRealtimeStream, RealtimeAccess,
+};
+use crate::presentation::http::{auth, user_documents};
 use axum::extract::ws::{Message as AxumMessage, WebSocket, WebSocketUpgrade};
 use axum::extract::{Path, Query, State};
 use axum::http::HeaderMap;
@@ -44,15 +46,10 @@ pub async fn axum_ws_entry(
     headers: HeaderMap,
     State(state): State<AppContext>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    let token = query
-        .token
-        .or(query.access_token)
-        .or_else(|| {
-            headers
-                .get(axum::http::header::AUTHORIZATION)
-                .and_then(|h| h.to_str().ok().map(|s| s.to_owned()))
-                .and_then(|s| s.strip_prefix("Bearer ").map(|s| s.to_string()))
-        })
+    let session_token = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok().map(|s| s.to_owned()))
+        .and_then(|s| s.strip_prefix("Bearer ").map(|s| s.to_string()))
         .or_else(|| {
             // Fallback to cookie `access_token`
             headers
@@ -70,15 +67,20 @@ pub async fn axum_ws_entry(
                     None
                 })
         });
+    let token = query
+        .token
+        .or(query.access_token)
+        .or_else(|| session_token.clone());
 
     // Try to parse document ID
     let doc_uuid = Uuid::parse_str(&doc_id).map_err(|_| StatusCode::UNAUTHORIZED)?;
 
     // Resolve actor capability
-    let actor = token
-        .as_deref()
-        .and_then(|t| auth::resolve_actor_from_token_str(&state.cfg, t))
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let actor = match token.as_deref() {
+        Some(t) => auth::resolve_actor_from_token_str(&state, t).await,
+        None => None,
+    }
+    .ok_or(StatusCode::UNAUTHORIZED)?;
 
     let share_access = state.share_access_port();
     let access_repo = state.access_repo();
@@ -92,10 +94,29 @@ pub async fn axum_ws_entry(
     if cap == Capability::None {
         return Err(StatusCode::UNAUTHORIZED);
     }
-    let can_edit = matches!(cap, Capability::Edit);
+    let access = match cap {
+        Capability::Edit => RealtimeAccess::Edit,
+        Capability::Comment => RealtimeAccess::Comment,
+        _ => RealtimeAccess::ReadOnly,
+    };
+    // Share-link sessions still count as opens for a signed-in user behind them.
+    let viewer = match &actor {
+        access::Actor::User(uid) => Some((*uid, None)),
+        access::Actor::ShareToken(t) => match session_token.as_deref() {
+            Some(st) => match auth::resolve_actor_from_token_str(&state, st).await {
+                Some(access::Actor::User(uid)) => Some((uid, Some(t.as_str()))),
+                _ => None,
+            },
+            None => None,
+        },
+        access::Actor::Public => None,
+    };
+    if let Some((user_id, share_token)) = viewer {
+        user_documents::record_view(&state, user_id, doc_uuid, share_token).await;
+    }
 
     let ctx = state.clone();
-    Ok(ws.on_upgrade(move |socket| peer_axum(doc_id, socket, ctx, can_edit)))
+    Ok(ws.on_upgrade(move |socket| peer_axum(doc_id, socket, ctx, access)))
 }
 
 // WebSocket <-> Vec<u8> sink adapter
@@ -180,7 +201,7 @@ impl Stream for WsBinaryStream {
 }
 
 // WS peer using Axum WebSocket
-async fn peer_axum(doc_id: String, ws: WebSocket, ctx: AppContext, can_edit: bool) {
+async fn peer_axum(doc_id: String, ws: WebSocket, ctx: AppContext, access: RealtimeAccess) {
     tracing::debug!(%doc_id, "WS peer:upgrade");
     let (sink_raw, stream_raw) = ws.split();
     let sink_box: Pin<Box<WsBinarySink>> = Box::pin(WsBinarySink { inner: sink_raw });
@@ -193,7 +214,7 @@ async fn peer_axum(doc_id: String, ws: WebSocket, ctx: AppContext, can_edit: boo
 
     tracing::debug!(%doc_id, "WS peer:subscribing");
     if let Err(e) = ctx
-        .subscribe_realtime(&doc_id, sink_dyn, stream_dyn, can_edit)
+        .subscribe_realtime(&doc_id, sink_dyn, stream_dyn, access)
         .await
     {
         tracing::warn!(%doc_id, error = %e, "WS subscription ended unexpectedly");